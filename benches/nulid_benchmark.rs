@@ -4,7 +4,9 @@
 
 use core::hint::black_box;
 use criterion::{Criterion, Throughput, criterion_group, criterion_main};
-use nulid::{Generator, Nulid};
+use nulid::collections::NulidSet;
+use nulid::{Generator, LocalGenerator, Nulid};
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::SystemTime;
 
@@ -42,6 +44,14 @@ fn bench_monotonic_generation(c: &mut Criterion) {
         });
     });
 
+    group.bench_function("local_generate", |b| {
+        let generator = LocalGenerator::new();
+        b.iter(|| {
+            let nulid = generator.generate().unwrap();
+            black_box(nulid);
+        });
+    });
+
     group.bench_function("generate_sequential_100", |b| {
         let generator = Generator::new();
         b.iter(|| {
@@ -232,6 +242,37 @@ fn bench_batch(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark deduplicating mostly-ordered IDs with a redelivered tail
+fn bench_dedup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dedup");
+    let generator = Generator::new();
+    let mut ids: Vec<Nulid> = (0..10_000).map(|_| generator.generate().unwrap()).collect();
+    ids.extend_from_within(9_000..);
+
+    group.throughput(Throughput::Elements(ids.len() as u64));
+    group.bench_function("nulid_set", |b| {
+        b.iter(|| {
+            let mut set = NulidSet::new();
+            for &id in &ids {
+                set.insert(black_box(id));
+            }
+            black_box(set);
+        });
+    });
+
+    group.bench_function("hash_set", |b| {
+        b.iter(|| {
+            let mut set = HashSet::new();
+            for &id in &ids {
+                set.insert(black_box(id));
+            }
+            black_box(set);
+        });
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_generation,
@@ -242,6 +283,7 @@ criterion_group!(
     bench_sorting,
     bench_concurrent,
     bench_batch,
+    bench_dedup,
 );
 
 criterion_main!(benches);
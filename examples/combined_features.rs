@@ -24,9 +24,9 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
 
     // Generate typed IDs using the nulid!() macro
     println!("--- Creating Typed IDs with nulid!() ---");
-    let user_id = UserId::from(nulid!());
-    let order_id = OrderId::from(nulid!());
-    let product_id = ProductId::from(nulid!());
+    let user_id = nulid!(UserId);
+    let order_id = nulid!(OrderId);
+    let product_id = nulid!(ProductId);
 
     println!("User ID:    {user_id}");
     println!("Order ID:   {order_id}");
@@ -34,7 +34,7 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
 
     // Using fallible variant with typed IDs
     println!("\n--- Fallible Generation ---");
-    let user_id2 = UserId::from(nulid!(?)?);
+    let user_id2 = nulid!(UserId?)?;
     println!("User ID 2:  {user_id2}");
 
     // Parse from strings (provided by Id derive)
@@ -60,7 +60,7 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
     let mut users = std::collections::HashMap::new();
 
     for i in 1..=3 {
-        let id = UserId::from(nulid!());
+        let id = nulid!(UserId);
         users.insert(id, format!("User {i}"));
         println!("Created user: {} -> {}", id, users[&id]);
     }
@@ -84,11 +84,7 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
 
     // Ordering and sorting
     println!("\n--- Ordering ---");
-    let ids = [
-        UserId::from(nulid!()),
-        UserId::from(nulid!()),
-        UserId::from(nulid!()),
-    ];
+    let ids = [nulid!(UserId), nulid!(UserId), nulid!(UserId)];
 
     println!("Generated IDs:");
     for (i, id) in ids.iter().enumerate() {
@@ -127,8 +123,8 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
     println!("\n=== Example Complete ===");
     println!("\nBenefits of combining these features:");
     println!("  • Type safety: Different ID types can't be mixed");
-    println!("  • Convenience: nulid!() for quick generation");
-    println!("  • Error handling: nulid!(?) for Result-based flow");
+    println!("  • Convenience: nulid!(UserId) for quick typed generation");
+    println!("  • Error handling: nulid!(UserId?) for Result-based flow");
     println!("  • Automatic traits: TryFrom, Display, FromStr, etc.");
     println!("  • Zero runtime overhead: Everything is compile-time");
 
@@ -137,7 +133,7 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
 
 fn create_user() -> Result<UserId, Box<dyn core::error::Error>> {
     // Using fallible macro in a Result-returning function
-    Ok(UserId::from(nulid!(?)?))
+    Ok(nulid!(UserId?)?)
 }
 
 fn try_create_user() -> Result<UserId, Box<dyn core::error::Error>> {
@@ -7,7 +7,13 @@
 #![allow(clippy::expect_used)]
 #![allow(clippy::similar_names)]
 
-use nulid::{Nulid, nulid};
+use nulid::{Nulid, nulid, nulid_str};
+
+/// Anchored at a fixed instant at compile time.
+const LAUNCH: Nulid = nulid!(at: "2024-01-01T00:00:00Z");
+
+/// Validated and canonicalized at compile time.
+const SYSTEM_USER: &str = nulid_str!("01jcqz8abcdefghjkmnpqrstvw");
 
 fn main() -> Result<(), Box<dyn core::error::Error>> {
     println!("=== nulid!() Macro Example ===\n");
@@ -63,6 +69,20 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
     println!("Second: {second}");
     println!("✓ IDs are lexicographically sorted by timestamp");
 
+    println!("\n--- Time-Anchored IDs ---");
+    let fixture = nulid!(at: "2024-01-01T09:30:00.250+01:00", random);
+    println!("Launch:  {LAUNCH} ({} ns)", LAUNCH.nanos());
+    println!("Fixture: {fixture} ({} ns)", fixture.nanos());
+    assert_eq!(LAUNCH.random(), 0);
+    assert_eq!(fixture.nanos() - LAUNCH.nanos(), 30_600_250_000_000);
+    println!("✓ Timestamps resolved at compile time");
+
+    println!("\n--- Validated String Constants ---");
+    println!("System user: {SYSTEM_USER}");
+    assert_eq!(SYSTEM_USER, "01JCQZ8ABCDEFGHJKMNPQRSTVW");
+    assert_eq!(SYSTEM_USER.parse::<Nulid>()?.to_string(), SYSTEM_USER);
+    println!("✓ Literal validated and canonicalized at compile time");
+
     println!("\n--- Comparison with Nulid::new() ---");
     println!("nulid!()           = {}", nulid!());
     println!("Nulid::new()?      = {}", Nulid::new()?);
@@ -8,7 +8,8 @@
 #![allow(clippy::doc_markdown)]
 
 use nulid::Nulid;
-use rkyv::{access_unchecked, to_bytes};
+use nulid::features::rkyv::ArchivedNulid;
+use rkyv::{access, to_bytes};
 
 fn main() -> Result<(), Box<dyn core::error::Error>> {
     println!("=== NULID rkyv Serialization Example ===\n");
@@ -27,9 +28,10 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
     println!();
 
     // Zero-copy access to archived data
-    println!("Accessing archived data (zero-copy)...");
-    let _archived = unsafe { access_unchecked::<rkyv::Archived<Nulid>>(&bytes) };
-    println!("  Archived data accessed without deserialization");
+    println!("Accessing archived data (zero-copy, validated)...");
+    let archived = access::<ArchivedNulid, rkyv::rancor::Error>(&bytes)?;
+    println!("  Archived NULID: {archived}");
+    assert_eq!(*archived, nulid);
     println!();
 
     // Deserialize back to NULID
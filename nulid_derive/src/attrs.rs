@@ -0,0 +1,251 @@
+//! Parsing of `#[id(...)]` container attributes.
+
+use syn::meta::ParseNestedMeta;
+use syn::{Attribute, Expr, ExprLit, Ident, Lit, LitStr, Meta, MetaNameValue, Type};
+
+/// Names of the core impls that `skip(...)` and `only(...)` accept.
+pub const CORE_IMPLS: &[&str] = &[
+    "TryFrom",
+    "From",
+    "AsRef",
+    "Deref",
+    "DerefMut",
+    "Display",
+    "FromStr",
+    "Debug",
+    "Clone",
+    "Copy",
+    "PartialEq",
+    "Eq",
+    "PartialOrd",
+    "Ord",
+    "Hash",
+    "PartialEq_nulid",
+    "PartialOrd_nulid",
+    "Default",
+];
+
+/// Wire representation pinned through `#[id(serde = "...")]`.
+#[derive(Clone, Copy, Default)]
+pub enum SerdeRepr {
+    /// Follow `Nulid`: string when human-readable, bytes otherwise.
+    #[default]
+    Auto,
+    /// Base32 string, prefixed if the type has a prefix.
+    String,
+    /// 16 big-endian bytes.
+    Bytes,
+    /// Hyphenated UUID string.
+    Uuid,
+    /// 128-bit integer.
+    U128,
+}
+
+/// Which core impls to generate.
+#[derive(Default)]
+enum Selection {
+    /// Every core impl.
+    #[default]
+    All,
+    /// Every core impl except the listed ones.
+    Skip(Vec<String>),
+    /// Only the listed core impls.
+    Only(Vec<String>),
+}
+
+/// Options set through `#[id(...)]` on an Id-derived type.
+#[derive(Default)]
+pub struct IdAttrs {
+    /// Prefix written before the Base32 string, e.g. `user_`.
+    pub prefix: Option<LitStr>,
+    /// Whether parsing rejects strings without the prefix.
+    pub strict: bool,
+    /// Error type of `TryFrom` and `FromStr`, converted from `nulid::Error`.
+    pub error: Option<Type>,
+    /// Serde representation used in every format.
+    pub serde: SerdeRepr,
+    /// Whether to emit a typed `<Name>Generator`.
+    pub generator: bool,
+    /// Whether to emit a `NonNil<Name>` companion type.
+    pub non_nil: bool,
+    /// Whether `Debug` masks the ID, and `display_redacted()` keeps the prefix.
+    pub redact: bool,
+    /// Whether the type scrubs its ID on drop (needs `no_copy`).
+    pub zeroize: bool,
+    /// Whether to leave out `Copy`, giving the type move semantics.
+    no_copy: bool,
+    selection: Selection,
+}
+
+impl IdAttrs {
+    /// Parses every `#[id(...)]` attribute on the type.
+    ///
+    /// Supported options:
+    /// - `prefix = "..."`: non-empty prefix for `Display` and `FromStr`
+    /// - `strict`: require the prefix when parsing (needs `prefix`)
+    /// - `error = "..."`: error type for `TryFrom` and `FromStr`
+    /// - `skip(...)`: core impls not to generate, named as in [`CORE_IMPLS`]
+    /// - `only(...)`: the core impls to generate (conflicts with `skip`)
+    /// - `serde = "..."`: one of `string`, `bytes`, `uuid` or `u128`
+    /// - `generator`: emit a typed `<Name>Generator`
+    /// - `non_nil`: emit a `NonNil<Name>` that rejects nil IDs
+    /// - `redact`: mask the ID in `Debug` and emit `display_redacted()`
+    /// - `zeroize`: scrub the ID on drop (needs `Copy` left out)
+    /// - `no_copy`: leave out `Copy`, same as `skip(Copy)`
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+        let mut strict_span = None;
+        let mut zeroize_span = None;
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("id")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("prefix") {
+                    let prefix: LitStr = meta.value()?.parse()?;
+                    if prefix.value().is_empty() {
+                        return Err(syn::Error::new_spanned(prefix, "prefix must not be empty"));
+                    }
+                    parsed.prefix = Some(prefix);
+                    Ok(())
+                } else if meta.path.is_ident("strict") {
+                    parsed.strict = true;
+                    strict_span = Some(meta.path.clone());
+                    Ok(())
+                } else if meta.path.is_ident("error") {
+                    let error: LitStr = meta.value()?.parse()?;
+                    parsed.error = Some(error.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("no_copy") {
+                    parsed.no_copy = true;
+                    Ok(())
+                } else if meta.path.is_ident("generator") {
+                    parsed.generator = true;
+                    Ok(())
+                } else if meta.path.is_ident("non_nil") {
+                    parsed.non_nil = true;
+                    Ok(())
+                } else if meta.path.is_ident("redact") {
+                    parsed.redact = true;
+                    Ok(())
+                } else if meta.path.is_ident("zeroize") {
+                    parsed.zeroize = true;
+                    zeroize_span = Some(meta.path.clone());
+                    Ok(())
+                } else if meta.path.is_ident("serde") {
+                    let repr: LitStr = meta.value()?.parse()?;
+                    parsed.serde = match repr.value().as_str() {
+                        "string" => SerdeRepr::String,
+                        "bytes" => SerdeRepr::Bytes,
+                        "uuid" => SerdeRepr::Uuid,
+                        "u128" => SerdeRepr::U128,
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                repr,
+                                "unknown serde representation, expected one of: string, bytes, uuid, u128",
+                            ));
+                        }
+                    };
+                    Ok(())
+                } else if meta.path.is_ident("skip") || meta.path.is_ident("only") {
+                    let names = parse_impl_names(&meta)?;
+                    parsed.selection = match (&parsed.selection, meta.path.is_ident("skip")) {
+                        (Selection::All, true) => Selection::Skip(names),
+                        (Selection::All, false) => Selection::Only(names),
+                        _ => return Err(meta.error("use at most one of `skip` or `only`")),
+                    };
+                    Ok(())
+                } else {
+                    Err(meta.error(
+                        "unsupported id attribute, expected `prefix`, `strict`, `error`, `serde`, `generator`, `non_nil`, `redact`, `zeroize`, `no_copy`, `skip` or `only`",
+                    ))
+                }
+            })?;
+        }
+
+        if let Some(path) = strict_span
+            && parsed.prefix.is_none()
+        {
+            return Err(syn::Error::new_spanned(
+                path,
+                "`strict` requires a `prefix`",
+            ));
+        }
+
+        // `Copy` types cannot implement `Drop`
+        if let Some(path) = zeroize_span
+            && parsed.generates("Copy")
+        {
+            return Err(syn::Error::new_spanned(
+                path,
+                "`zeroize` requires `no_copy`, since `Copy` types cannot be scrubbed on drop",
+            ));
+        }
+
+        Ok(parsed)
+    }
+
+    /// Returns whether the core impl named `name` should be generated.
+    pub fn generates(&self, name: &str) -> bool {
+        if self.no_copy && name == "Copy" {
+            return false;
+        }
+        match &self.selection {
+            Selection::All => true,
+            Selection::Skip(names) => !names.iter().any(|skipped| skipped == name),
+            Selection::Only(names) => names.iter().any(|kept| kept == name),
+        }
+    }
+}
+
+/// Returns the type's doc comment, one line per `///` line.
+///
+/// Returns `None` for undocumented types.
+pub fn doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(MetaNameValue {
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(line),
+                        ..
+                    }),
+                ..
+            }) => Some(line.value()),
+            _ => None,
+        })
+        .map(|line| {
+            line.strip_prefix(' ')
+                .unwrap_or(&line)
+                .trim_end()
+                .to_owned()
+        })
+        .collect();
+
+    let doc = lines.join("\n").trim().to_owned();
+    (!doc.is_empty()).then_some(doc)
+}
+
+/// Parses the impl names inside `skip(...)` or `only(...)`.
+fn parse_impl_names(meta: &ParseNestedMeta<'_>) -> syn::Result<Vec<String>> {
+    let mut names = Vec::new();
+    meta.parse_nested_meta(|inner| {
+        let ident: &Ident = inner
+            .path
+            .get_ident()
+            .ok_or_else(|| inner.error("expected an impl name"))?;
+        let name = ident.to_string();
+        if !CORE_IMPLS.contains(&name.as_str()) {
+            return Err(syn::Error::new_spanned(
+                ident,
+                format!(
+                    "unknown impl `{name}`, expected one of: {}",
+                    CORE_IMPLS.join(", ")
+                ),
+            ));
+        }
+        names.push(name);
+        Ok(())
+    })?;
+    Ok(names)
+}
@@ -0,0 +1,90 @@
+//! Construction of Id wrapper values in generated code.
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{Ident, Index, Type};
+
+/// Builds wrapper values from a `Nulid`.
+///
+/// Plain wrappers are built as `Name(nulid)`. Fields after the `Nulid` are
+/// filled with `PhantomData` for markers and `Default::default()` otherwise.
+/// Generated code writes `#name(nulid #rest)` or `.map(#ctor)`, which
+/// interpolates a callable.
+pub struct Constructor {
+    name: Ident,
+    rest: TokenStream,
+    clones: TokenStream,
+    constness: TokenStream,
+    extra_fields: usize,
+}
+
+impl Constructor {
+    /// Creates a constructor for `name` whose fields after the `Nulid` have
+    /// the given types.
+    pub fn new<'a>(name: &Ident, extra_fields: impl ExactSizeIterator<Item = &'a Type>) -> Self {
+        let mut rest = TokenStream::new();
+        let mut clones = TokenStream::new();
+        let mut all_markers = true;
+        let count = extra_fields.len();
+
+        for (offset, ty) in extra_fields.enumerate() {
+            let index = Index::from(offset + 1);
+            clones.extend(quote! { , ::core::clone::Clone::clone(&self.#index) });
+            if is_phantom_data(ty) {
+                rest.extend(quote! { , ::core::marker::PhantomData });
+            } else {
+                all_markers = false;
+                rest.extend(quote! { , ::core::default::Default::default() });
+            }
+        }
+
+        Self {
+            name: name.clone(),
+            rest,
+            clones,
+            // `Default::default()` is not callable in `const fn`
+            constness: if all_markers {
+                quote! { const }
+            } else {
+                TokenStream::new()
+            },
+            extra_fields: count,
+        }
+    }
+
+    /// Returns the values to append after the `Nulid` argument.
+    pub const fn rest(&self) -> &TokenStream {
+        &self.rest
+    }
+
+    /// Returns clones of the fields after the `Nulid`, for `#name(self.0 #clones)`.
+    pub const fn clones(&self) -> &TokenStream {
+        &self.clones
+    }
+
+    /// Returns `const` if constructors can be `const fn`, and nothing otherwise.
+    pub const fn constness(&self) -> &TokenStream {
+        &self.constness
+    }
+}
+
+impl ToTokens for Constructor {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.name;
+        if self.extra_fields == 0 {
+            name.to_tokens(tokens);
+        } else {
+            let rest = &self.rest;
+            tokens.extend(quote! { (|nulid: ::nulid::Nulid| #name(nulid #rest)) });
+        }
+    }
+}
+
+/// Returns whether `ty` names `PhantomData`, with or without a path.
+fn is_phantom_data(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path
+        .path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "PhantomData"))
+}
@@ -0,0 +1,60 @@
+//! actix-web support for Id-derived types.
+//!
+//! This module provides code generation for `FromRequest` and `Responder`
+//! implementations for types that derive `Id`. Both go through the wrapper's
+//! `FromStr` and `Display`, so prefixed IDs round-trip.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+/// Generates actix-web trait implementations for the Id wrapper type.
+///
+/// Path extraction reuses `Nulid`'s rejection, so invalid IDs get the same
+/// `400 Bad Request` response. `parser` is a callable turning `&str` into the
+/// wrapper or a `nulid::Error`, which the rejection carries.
+pub fn generate_actix_web_impls(
+    name: &Ident,
+    impl_generics: &syn::ImplGenerics,
+    ty_generics: &syn::TypeGenerics,
+    where_clause: &Option<&syn::WhereClause>,
+    parser: &TokenStream,
+) -> TokenStream {
+    quote! {
+        #[cfg(feature = "actix-web")]
+        impl #impl_generics ::actix_web::FromRequest for #name #ty_generics #where_clause {
+            type Error = ::nulid::features::actix_web::NulidRejection;
+            type Future = ::core::future::Ready<::core::result::Result<Self, Self::Error>>;
+
+            fn from_request(
+                req: &::actix_web::HttpRequest,
+                _payload: &mut ::actix_web::dev::Payload,
+            ) -> Self::Future {
+                let info = req.match_info();
+                let mut segments = info.iter();
+                let result = match (segments.next(), segments.next()) {
+                    (::core::option::Option::Some((_, value)), ::core::option::Option::None) => #parser(value)
+                        .map_err(|error| ::nulid::features::actix_web::NulidRejection::Invalid {
+                            value: ::std::borrow::ToOwned::to_owned(value),
+                            error,
+                        }),
+                    _ => ::core::result::Result::Err(
+                        ::nulid::features::actix_web::NulidRejection::PathSegments(info.segment_count()),
+                    ),
+                };
+                ::core::future::ready(result)
+            }
+        }
+
+        #[cfg(feature = "actix-web")]
+        impl #impl_generics ::actix_web::Responder for #name #ty_generics #where_clause {
+            type Body = ::actix_web::body::BoxBody;
+
+            fn respond_to(self, _req: &::actix_web::HttpRequest) -> ::actix_web::HttpResponse<Self::Body> {
+                ::actix_web::HttpResponse::Ok()
+                    .content_type(::actix_web::http::header::ContentType::plaintext())
+                    .body(::std::string::ToString::to_string(&self))
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! GraphQL scalar support for Id-derived types via `async-graphql`.
+//!
+//! This module provides code generation for an async-graphql `ScalarType`
+//! for types that derive `Id`, transferred through the wrapper's `Display`
+//! and `FromStr` so prefixed IDs round-trip.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+/// Generates an async-graphql scalar for the Id wrapper type.
+///
+/// The scalar is named after the wrapper and described by its doc comment.
+/// Scalars must be `Send`, which marker-tagged generic wrappers only are for
+/// some markers, so generic wrappers get no async-graphql support.
+pub fn generate_async_graphql_impls(
+    name: &Ident,
+    generics: &syn::Generics,
+    doc: Option<&str>,
+) -> TokenStream {
+    if !generics.params.is_empty() {
+        return TokenStream::new();
+    }
+
+    let scalar_name = name.to_string();
+    let description = doc.map(|doc| quote! { #[doc = #doc] });
+
+    quote! {
+        #[cfg(feature = "async-graphql")]
+        #description
+        #[::async_graphql::Scalar(name = #scalar_name)]
+        impl ::async_graphql::ScalarType for #name {
+            fn parse(value: ::async_graphql::Value) -> ::async_graphql::InputValueResult<Self> {
+                match &value {
+                    ::async_graphql::Value::String(s) => {
+                        s.parse().map_err(::async_graphql::InputValueError::custom)
+                    }
+                    _ => ::core::result::Result::Err(::async_graphql::InputValueError::expected_type(value)),
+                }
+            }
+
+            fn is_valid(value: &::async_graphql::Value) -> bool {
+                ::core::matches!(value, ::async_graphql::Value::String(s) if s.parse::<Self>().is_ok())
+            }
+
+            fn to_value(&self) -> ::async_graphql::Value {
+                ::async_graphql::Value::String(::std::string::ToString::to_string(self))
+            }
+        }
+    }
+}
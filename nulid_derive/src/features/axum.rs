@@ -0,0 +1,54 @@
+//! axum support for Id-derived types.
+//!
+//! This module provides code generation for `FromRequestParts` and
+//! `IntoResponse` implementations for types that derive `Id`. Both go through
+//! the wrapper's `FromStr` and `Display`, so prefixed IDs round-trip.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+/// Generates axum trait implementations for the Id wrapper type.
+///
+/// Path extraction reuses `Nulid`'s rejection, so invalid IDs get the same
+/// `400 Bad Request` response. `parser` is a callable turning `&str` into the
+/// wrapper or a `nulid::Error`, which the rejection carries.
+pub fn generate_axum_impls(
+    name: &Ident,
+    generics: &syn::Generics,
+    parser: &TokenStream,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut extract_generics = generics.clone();
+    extract_generics
+        .params
+        .push(syn::parse_quote!(__S: ::core::marker::Send + ::core::marker::Sync));
+    let (extract_impl_generics, _, _) = extract_generics.split_for_impl();
+
+    quote! {
+        #[cfg(feature = "axum")]
+        impl #extract_impl_generics ::axum::extract::FromRequestParts<__S> for #name #ty_generics #where_clause {
+            type Rejection = ::nulid::features::axum::NulidRejection;
+
+            async fn from_request_parts(
+                parts: &mut ::axum::http::request::Parts,
+                state: &__S,
+            ) -> ::core::result::Result<Self, Self::Rejection> {
+                let ::axum::extract::Path(value) =
+                    <::axum::extract::Path<::std::string::String> as ::axum::extract::FromRequestParts<__S>>::from_request_parts(parts, state)
+                        .await
+                        .map_err(::nulid::features::axum::NulidRejection::Path)?;
+                #parser(&value)
+                    .map_err(|error| ::nulid::features::axum::NulidRejection::Invalid { value, error })
+            }
+        }
+
+        #[cfg(feature = "axum")]
+        impl #impl_generics ::axum::response::IntoResponse for #name #ty_generics #where_clause {
+            fn into_response(self) -> ::axum::response::Response {
+                ::axum::response::IntoResponse::into_response(::std::string::ToString::to_string(&self))
+            }
+        }
+    }
+}
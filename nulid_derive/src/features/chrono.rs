@@ -3,6 +3,7 @@
 //! This module provides code generation for chrono DateTime conversion implementations
 //! for types that derive `Id`, delegating to the inner `Nulid`'s chrono support.
 
+use crate::constructor::Constructor;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Ident;
@@ -13,6 +14,7 @@ use syn::Ident;
 /// that delegate to the inner `Nulid` type's implementations.
 pub fn generate_chrono_impls(
     name: &Ident,
+    ctor: &Constructor,
     impl_generics: &syn::ImplGenerics,
     ty_generics: &syn::TypeGenerics,
     where_clause: &Option<&syn::WhereClause>,
@@ -23,7 +25,7 @@ pub fn generate_chrono_impls(
             type Error = ::nulid::Error;
 
             fn try_from(dt: ::chrono::DateTime<::chrono::Utc>) -> ::core::result::Result<Self, Self::Error> {
-                ::nulid::Nulid::from_chrono_datetime(dt).map(#name)
+                ::nulid::Nulid::from_chrono_datetime(dt).map(#ctor)
             }
         }
 
@@ -70,7 +72,7 @@ pub fn generate_chrono_impls(
             ///
             /// Returns an error if random number generation fails.
             pub fn from_chrono_datetime(dt: ::chrono::DateTime<::chrono::Utc>) -> ::core::result::Result<Self, ::nulid::Error> {
-                ::nulid::Nulid::from_chrono_datetime(dt).map(#name)
+                ::nulid::Nulid::from_chrono_datetime(dt).map(#ctor)
             }
         }
     }
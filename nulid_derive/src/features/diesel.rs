@@ -0,0 +1,162 @@
+//! Diesel support for Id-derived types.
+//!
+//! This module provides code generation for Diesel trait implementations
+//! for types that derive `Id`, delegating to the inner `Nulid`'s Diesel support.
+
+use crate::constructor::Constructor;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Ident;
+
+/// SQL type and backend pairs that `Nulid` implements `ToSql`/`FromSql` for,
+/// with the feature that enables each backend.
+const MAPPINGS: &[(&str, &str, &str, &str)] = &[
+    ("Uuid", "pg", "Pg", "diesel-postgres"),
+    ("Text", "pg", "Pg", "diesel-postgres"),
+    ("Binary", "sqlite", "Sqlite", "diesel-sqlite"),
+    ("Text", "sqlite", "Sqlite", "diesel-sqlite"),
+];
+
+/// SQL types that `Nulid` implements `AsExpression` for, with the feature
+/// that makes each type available.
+const SQL_TYPES: &[(&str, &str)] = &[
+    ("Uuid", "diesel-postgres"),
+    ("Binary", "diesel"),
+    ("Text", "diesel"),
+];
+
+/// Generates Diesel trait implementations for the Id wrapper type.
+///
+/// This generates what `#[derive(AsExpression, FromSqlRow)]` generates for
+/// `Nulid` itself: `ToSql`/`FromSql` for PostgreSQL `Uuid`/`Text` and `SQLite`
+/// `Binary`/`Text`, `AsExpression` for those SQL types and their `Nullable`
+/// forms, and `Queryable`. Query bindings go through the inner `Nulid`.
+/// Backend impls are gated on `diesel-postgres` and `diesel-sqlite`.
+pub fn generate_diesel_impls(
+    name: &Ident,
+    ctor: &Constructor,
+    generics: &syn::Generics,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut ref_generics = generics.clone();
+    ref_generics.params.insert(0, syn::parse_quote!('expr));
+    let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+
+    let mut backend_generics = generics.clone();
+    backend_generics.params.push(syn::parse_quote!(__DB));
+    let (backend_impl_generics, _, _) = backend_generics.split_for_impl();
+
+    let mut queryable_generics = backend_generics.clone();
+    queryable_generics.params.push(syn::parse_quote!(__ST));
+    let (queryable_impl_generics, _, _) = queryable_generics.split_for_impl();
+    let predicates = where_clause.map(|clause| &clause.predicates);
+
+    let sql_impls = MAPPINGS.iter().map(|(sql_type, module, backend, feature)| {
+        let sql_type = format_ident!("{}", sql_type);
+        let sql_type = quote! { ::diesel::sql_types::#sql_type };
+        let (module, backend) = (format_ident!("{}", module), format_ident!("{}", backend));
+        let backend = quote! { ::diesel::#module::#backend };
+
+        quote! {
+            #[cfg(feature = #feature)]
+            impl #impl_generics ::diesel::serialize::ToSql<#sql_type, #backend> for #name #ty_generics #where_clause {
+                fn to_sql<'b>(
+                    &'b self,
+                    out: &mut ::diesel::serialize::Output<'b, '_, #backend>,
+                ) -> ::diesel::serialize::Result {
+                    <::nulid::Nulid as ::diesel::serialize::ToSql<#sql_type, #backend>>::to_sql(&self.0, out)
+                }
+            }
+
+            #[cfg(feature = #feature)]
+            impl #impl_generics ::diesel::deserialize::FromSql<#sql_type, #backend> for #name #ty_generics #where_clause {
+                fn from_sql(
+                    value: <#backend as ::diesel::backend::Backend>::RawValue<'_>,
+                ) -> ::diesel::deserialize::Result<Self> {
+                    <::nulid::Nulid as ::diesel::deserialize::FromSql<#sql_type, #backend>>::from_sql(value)
+                        .map(#ctor)
+                }
+            }
+        }
+    });
+
+    let expression_impls = SQL_TYPES.iter().map(|(sql_type, feature)| {
+        let sql_type = format_ident!("{}", sql_type);
+        let sql_type = quote! { ::diesel::sql_types::#sql_type };
+        let nullable = quote! { ::diesel::sql_types::Nullable<#sql_type> };
+
+        quote! {
+            #[cfg(feature = #feature)]
+            impl #impl_generics ::diesel::expression::AsExpression<#sql_type> for #name #ty_generics #where_clause {
+                type Expression = <::nulid::Nulid as ::diesel::expression::AsExpression<#sql_type>>::Expression;
+
+                fn as_expression(self) -> Self::Expression {
+                    ::diesel::expression::AsExpression::<#sql_type>::as_expression(self.0)
+                }
+            }
+
+            #[cfg(feature = #feature)]
+            impl #impl_generics ::diesel::expression::AsExpression<#nullable> for #name #ty_generics #where_clause {
+                type Expression = <::nulid::Nulid as ::diesel::expression::AsExpression<#nullable>>::Expression;
+
+                fn as_expression(self) -> Self::Expression {
+                    ::diesel::expression::AsExpression::<#nullable>::as_expression(self.0)
+                }
+            }
+
+            #[cfg(feature = #feature)]
+            impl #ref_impl_generics ::diesel::expression::AsExpression<#sql_type> for &'expr #name #ty_generics #where_clause {
+                type Expression = <&'expr ::nulid::Nulid as ::diesel::expression::AsExpression<#sql_type>>::Expression;
+
+                fn as_expression(self) -> Self::Expression {
+                    ::diesel::expression::AsExpression::<#sql_type>::as_expression(&self.0)
+                }
+            }
+
+            #[cfg(feature = #feature)]
+            impl #ref_impl_generics ::diesel::expression::AsExpression<#nullable> for &'expr #name #ty_generics #where_clause {
+                type Expression = <&'expr ::nulid::Nulid as ::diesel::expression::AsExpression<#nullable>>::Expression;
+
+                fn as_expression(self) -> Self::Expression {
+                    ::diesel::expression::AsExpression::<#nullable>::as_expression(&self.0)
+                }
+            }
+
+            #[cfg(feature = #feature)]
+            impl #backend_impl_generics ::diesel::serialize::ToSql<#nullable, __DB> for #name #ty_generics
+            where
+                __DB: ::diesel::backend::Backend,
+                Self: ::diesel::serialize::ToSql<#sql_type, __DB>,
+                #predicates
+            {
+                fn to_sql<'b>(
+                    &'b self,
+                    out: &mut ::diesel::serialize::Output<'b, '_, __DB>,
+                ) -> ::diesel::serialize::Result {
+                    ::diesel::serialize::ToSql::<#sql_type, __DB>::to_sql(self, out)
+                }
+            }
+        }
+    });
+
+    quote! {
+        #(#sql_impls)*
+        #(#expression_impls)*
+
+        #[cfg(feature = "diesel")]
+        impl #queryable_impl_generics ::diesel::deserialize::Queryable<__ST, __DB> for #name #ty_generics
+        where
+            __DB: ::diesel::backend::Backend,
+            __ST: ::diesel::sql_types::SingleValue,
+            Self: ::diesel::deserialize::FromSql<__ST, __DB>,
+            #predicates
+        {
+            type Row = Self;
+
+            fn build(row: Self) -> ::diesel::deserialize::Result<Self> {
+                ::core::result::Result::Ok(row)
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! `fake` support for Id-derived types.
+//!
+//! This module provides code generation for `Dummy` implementations for
+//! types that derive `Id`, delegating to the inner `Nulid`'s fake support.
+
+use crate::constructor::Constructor;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+/// Generates `fake` trait implementations for the Id wrapper type.
+///
+/// The wrapper implements `Dummy<T>` for every config `T` that `Nulid`
+/// supports, including `Faker` and `nulid::features::fake::Between`.
+pub fn generate_fake_impls(
+    name: &Ident,
+    ctor: &Constructor,
+    generics: &syn::Generics,
+) -> TokenStream {
+    let rest = ctor.rest();
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    let mut dummy_generics = generics.clone();
+    dummy_generics.params.push(syn::parse_quote!(__T));
+    dummy_generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(::nulid::Nulid: ::fake::Dummy<__T>));
+    let (dummy_impl_generics, _, dummy_where_clause) = dummy_generics.split_for_impl();
+
+    quote! {
+        #[cfg(feature = "fake")]
+        impl #dummy_impl_generics ::fake::Dummy<__T> for #name #ty_generics #dummy_where_clause {
+            fn dummy_with_rng<__R: ::fake::Rng + ?::core::marker::Sized>(config: &__T, rng: &mut __R) -> Self {
+                #name(<::nulid::Nulid as ::fake::Dummy<__T>>::dummy_with_rng(config, rng) #rest)
+            }
+        }
+    }
+}
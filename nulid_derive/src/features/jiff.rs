@@ -3,6 +3,7 @@
 //! This module provides code generation for jiff Timestamp conversion implementations
 //! for types that derive `Id`, delegating to the inner `Nulid`'s jiff support.
 
+use crate::constructor::Constructor;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Ident;
@@ -13,6 +14,7 @@ use syn::Ident;
 /// that delegate to the inner `Nulid` type's implementations.
 pub fn generate_jiff_impls(
     name: &Ident,
+    ctor: &Constructor,
     impl_generics: &syn::ImplGenerics,
     ty_generics: &syn::TypeGenerics,
     where_clause: &Option<&syn::WhereClause>,
@@ -23,7 +25,7 @@ pub fn generate_jiff_impls(
             type Error = ::nulid::Error;
 
             fn try_from(ts: ::jiff::Timestamp) -> ::core::result::Result<Self, Self::Error> {
-                ::nulid::Nulid::from_jiff_timestamp(ts).map(#name)
+                ::nulid::Nulid::from_jiff_timestamp(ts).map(#ctor)
             }
         }
 
@@ -70,7 +72,7 @@ pub fn generate_jiff_impls(
             ///
             /// Returns an error if random number generation fails.
             pub fn from_jiff_timestamp(ts: ::jiff::Timestamp) -> ::core::result::Result<Self, ::nulid::Error> {
-                ::nulid::Nulid::from_jiff_timestamp(ts).map(#name)
+                ::nulid::Nulid::from_jiff_timestamp(ts).map(#ctor)
             }
         }
     }
@@ -0,0 +1,57 @@
+//! GraphQL scalar support for Id-derived types via `juniper`.
+//!
+//! This module provides code generation for a `#[graphql_scalar]` definition
+//! for types that derive `Id`, transferred through the wrapper's `Display` and
+//! `FromStr` so prefixed IDs round-trip.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+/// Generates a juniper scalar for the Id wrapper type.
+///
+/// The scalar is named after the wrapper, described by its doc comment, and
+/// transferred as the Base32 string.
+/// The scalar is declared through a type alias, which cannot carry the
+/// wrapper's generic parameters, so generic wrappers get no juniper support.
+pub fn generate_juniper_impls(
+    name: &Ident,
+    generics: &syn::Generics,
+    doc: Option<&str>,
+) -> TokenStream {
+    if !generics.params.is_empty() {
+        return TokenStream::new();
+    }
+
+    let scalar_name = name.to_string();
+    let description = doc.map(|doc| quote! { description = #doc, });
+
+    quote! {
+        #[cfg(feature = "juniper")]
+        const _: () = {
+            #[::juniper::graphql_scalar(
+                name = #scalar_name,
+                #description
+                to_output_with = __nulid_to_output,
+                from_input_with = __nulid_from_input,
+                parse_token(::std::string::String),
+            )]
+            type __NulidScalar = #name;
+
+            fn __nulid_to_output<S: ::juniper::ScalarValue>(v: &#name) -> ::juniper::Value<S> {
+                ::juniper::Value::scalar(::std::string::ToString::to_string(v))
+            }
+
+            fn __nulid_from_input<S: ::juniper::ScalarValue>(
+                v: &::juniper::InputValue<S>,
+            ) -> ::core::result::Result<#name, ::std::string::String> {
+                v.as_string_value()
+                    .ok_or_else(|| ::std::format!("Expected `String`, found: {v}"))
+                    .and_then(|s| {
+                        s.parse()
+                            .map_err(|e| ::std::format!("Failed to parse `{}`: {e}", #scalar_name))
+                    })
+            }
+        };
+    }
+}
@@ -6,9 +6,59 @@
 //! Each module generates code with `#[cfg(feature = "...")]` attributes
 //! so features are evaluated in the consuming crate, not in the proc macro crate.
 
+pub mod actix_web;
+pub mod async_graphql;
+pub mod axum;
 pub mod chrono;
+pub mod diesel;
+pub mod fake;
 pub mod jiff;
+pub mod juniper;
 pub mod postgres_types;
+pub mod rkyv;
+pub mod schemars;
+pub mod sea_orm;
 pub mod serde;
 pub mod sqlx;
+pub mod utoipa;
 pub mod uuid;
+pub mod zeroize;
+
+/// Canonical example NULID, as used by `nulid`'s own schemas.
+const EXAMPLE: &str = "01GZTV7EQ056J0E6N276XD6F3D";
+
+/// Regex body matching a canonical or lowercase NULID string.
+const PATTERN_BODY: &str = "[0-7][0-9A-HJKMNP-TV-Za-hjkmnp-tv-z]{25}";
+
+/// String-form schema constraints of a prefixed Id type.
+///
+/// `Nulid`'s own schema describes the bare 26-character string, so schema
+/// generators patch these in for types with `#[id(prefix = "...")]`.
+pub struct PrefixedSchema {
+    pub pattern: String,
+    pub min_length: usize,
+    pub max_length: usize,
+    pub example: String,
+}
+
+impl PrefixedSchema {
+    /// Builds the constraints for `prefix`, optional unless `strict`.
+    pub fn new(prefix: &str, strict: bool) -> Self {
+        let escaped: String = prefix
+            .chars()
+            .flat_map(|c| {
+                let escape = "\\.+*?()|[]{}^$-".contains(c).then_some('\\');
+                escape.into_iter().chain([c])
+            })
+            .collect();
+        let optional = if strict { "" } else { "?" };
+        let prefix_len = prefix.chars().count();
+
+        Self {
+            pattern: format!("^(?:{escaped}){optional}{PATTERN_BODY}$"),
+            min_length: if strict { 26 + prefix_len } else { 26 },
+            max_length: 26 + prefix_len,
+            example: format!("{prefix}{EXAMPLE}"),
+        }
+    }
+}
@@ -3,6 +3,7 @@
 //! This module provides code generation for `postgres-types` trait implementations
 //! for types that derive `Id`, delegating to the inner `Nulid`'s postgres-types support.
 
+use crate::constructor::Constructor;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Ident;
@@ -13,18 +14,23 @@ use syn::Ident;
 /// to the inner `Nulid` type's implementations.
 pub fn generate_postgres_types_impls(
     name: &Ident,
-    impl_generics: &syn::ImplGenerics,
-    ty_generics: &syn::TypeGenerics,
-    where_clause: &Option<&syn::WhereClause>,
+    ctor: &Constructor,
+    generics: &syn::Generics,
 ) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut decode_generics = generics.clone();
+    decode_generics.params.insert(0, syn::parse_quote!('a));
+    let (decode_impl_generics, _, _) = decode_generics.split_for_impl();
+
     quote! {
         #[cfg(feature = "postgres-types")]
-        impl<'a> ::postgres_types::FromSql<'a> for #name #where_clause {
+        impl #decode_impl_generics ::postgres_types::FromSql<'a> for #name #ty_generics #where_clause {
             fn from_sql(
                 ty: &::postgres_types::Type,
                 raw: &'a [u8],
             ) -> ::core::result::Result<Self, ::std::boxed::Box<dyn ::core::error::Error + Sync + Send>> {
-                <::nulid::Nulid as ::postgres_types::FromSql>::from_sql(ty, raw).map(#name)
+                <::nulid::Nulid as ::postgres_types::FromSql>::from_sql(ty, raw).map(#ctor)
             }
 
             fn accepts(ty: &::postgres_types::Type) -> bool {
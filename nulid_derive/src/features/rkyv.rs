@@ -0,0 +1,70 @@
+//! rkyv support for Id-derived types.
+//!
+//! This module provides code generation for rkyv `Archive`, `Serialize`, and
+//! `Deserialize` implementations for types that derive `Id`, delegating to
+//! the inner `Nulid`'s rkyv support.
+
+use crate::constructor::Constructor;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+/// Generates rkyv trait implementations for the Id wrapper type.
+///
+/// The wrapper archives to `nulid::features::rkyv::ArchivedNulid`, so it
+/// shares `Nulid`'s `CheckBytes` validation and checked `access` works.
+/// A derive attribute on the wrapper cannot be used instead, because derive
+/// macros cannot add attributes to the item they are applied to.
+pub fn generate_rkyv_impls(
+    name: &Ident,
+    ctor: &Constructor,
+    generics: &syn::Generics,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut serialize_generics = generics.clone();
+    serialize_generics
+        .params
+        .push(syn::parse_quote!(__S: ::rkyv::rancor::Fallible + ?::core::marker::Sized));
+    let (serialize_impl_generics, _, _) = serialize_generics.split_for_impl();
+
+    let mut deserialize_generics = generics.clone();
+    deserialize_generics
+        .params
+        .push(syn::parse_quote!(__D: ::rkyv::rancor::Fallible + ?::core::marker::Sized));
+    let (deserialize_impl_generics, _, _) = deserialize_generics.split_for_impl();
+
+    quote! {
+        #[cfg(feature = "rkyv")]
+        impl #impl_generics ::rkyv::Archive for #name #ty_generics #where_clause {
+            type Archived = ::nulid::features::rkyv::ArchivedNulid;
+            type Resolver = ::nulid::features::rkyv::NulidResolver;
+
+            fn resolve(&self, resolver: Self::Resolver, out: ::rkyv::Place<Self::Archived>) {
+                ::rkyv::Archive::resolve(&self.0, resolver, out);
+            }
+        }
+
+        #[cfg(feature = "rkyv")]
+        impl #serialize_impl_generics ::rkyv::Serialize<__S> for #name #ty_generics #where_clause {
+            fn serialize(
+                &self,
+                serializer: &mut __S,
+            ) -> ::core::result::Result<Self::Resolver, <__S as ::rkyv::rancor::Fallible>::Error> {
+                ::rkyv::Serialize::<__S>::serialize(&self.0, serializer)
+            }
+        }
+
+        #[cfg(feature = "rkyv")]
+        impl #deserialize_impl_generics ::rkyv::Deserialize<#name #ty_generics, __D>
+            for ::nulid::features::rkyv::ArchivedNulid #where_clause
+        {
+            fn deserialize(
+                &self,
+                deserializer: &mut __D,
+            ) -> ::core::result::Result<#name #ty_generics, <__D as ::rkyv::rancor::Fallible>::Error> {
+                ::rkyv::Deserialize::<::nulid::Nulid, __D>::deserialize(self, deserializer).map(#ctor)
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! JSON Schema support for Id-derived types via `schemars`.
+//!
+//! This module provides code generation for `JsonSchema` implementations for
+//! types that derive `Id`, reusing the inner `Nulid`'s schema.
+
+use super::PrefixedSchema;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+/// Generates schemars trait implementations for the Id wrapper type.
+///
+/// The schema is the inner `Nulid`'s schema registered under the wrapper's
+/// own name, with the wrapper's doc comment as description and the prefix
+/// folded into the string constraints.
+pub fn generate_schemars_impls(
+    name: &Ident,
+    impl_generics: &syn::ImplGenerics,
+    ty_generics: &syn::TypeGenerics,
+    where_clause: &Option<&syn::WhereClause>,
+    doc: Option<&str>,
+    prefix: Option<&PrefixedSchema>,
+) -> TokenStream {
+    let description = doc.map(|doc| {
+        quote! { schema.insert("description".into(), #doc.into()); }
+    });
+    let constraints = prefix.map(|prefix| {
+        let PrefixedSchema {
+            pattern,
+            min_length,
+            max_length,
+            example,
+        } = prefix;
+        quote! {
+            schema.insert("pattern".into(), #pattern.into());
+            schema.insert("minLength".into(), #min_length.into());
+            schema.insert("maxLength".into(), #max_length.into());
+            schema.insert("examples".into(), [#example].as_slice().into());
+        }
+    });
+
+    quote! {
+        #[cfg(feature = "schemars")]
+        impl #impl_generics ::schemars::JsonSchema for #name #ty_generics #where_clause {
+            fn schema_name() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(::core::stringify!(#name))
+            }
+
+            fn schema_id() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(::core::concat!(
+                    ::core::module_path!(),
+                    "::",
+                    ::core::stringify!(#name)
+                ))
+            }
+
+            fn json_schema(generator: &mut ::schemars::SchemaGenerator) -> ::schemars::Schema {
+                #[allow(unused_mut)]
+                let mut schema = <::nulid::Nulid as ::schemars::JsonSchema>::json_schema(generator);
+                #description
+                #constraints
+                schema
+            }
+        }
+    }
+}
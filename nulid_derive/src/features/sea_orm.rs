@@ -0,0 +1,77 @@
+//! `SeaORM` support for Id-derived types.
+//!
+//! This module provides code generation for `SeaORM` trait implementations
+//! for types that derive `Id`, delegating to the inner `Nulid`'s `SeaORM` support.
+
+use crate::constructor::Constructor;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+/// Generates `SeaORM` trait implementations for the Id wrapper type.
+///
+/// This generates `From<Name> for Value`, `Nullable`, `ValueType`,
+/// `TryGetable`, and `TryFromU64`, so the wrapper can be used directly as an
+/// entity field or primary key. Values are stored as `ColumnType::Uuid`, and
+/// `ValueType::type_name()` returns the wrapper's name.
+pub fn generate_sea_orm_impls(
+    name: &Ident,
+    ctor: &Constructor,
+    generics: &syn::Generics,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        #[cfg(feature = "sea-orm")]
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for ::sea_orm::sea_query::Value #where_clause {
+            fn from(id: #name #ty_generics) -> Self {
+                ::core::convert::From::from(id.0)
+            }
+        }
+
+        #[cfg(feature = "sea-orm")]
+        impl #impl_generics ::sea_orm::sea_query::Nullable for #name #ty_generics #where_clause {
+            fn null() -> ::sea_orm::sea_query::Value {
+                <::nulid::Nulid as ::sea_orm::sea_query::Nullable>::null()
+            }
+        }
+
+        #[cfg(feature = "sea-orm")]
+        impl #impl_generics ::sea_orm::sea_query::ValueType for #name #ty_generics #where_clause {
+            fn try_from(
+                v: ::sea_orm::sea_query::Value,
+            ) -> ::core::result::Result<Self, ::sea_orm::sea_query::ValueTypeErr> {
+                <::nulid::Nulid as ::sea_orm::sea_query::ValueType>::try_from(v).map(#ctor)
+            }
+
+            fn type_name() -> ::std::string::String {
+                ::std::string::String::from(stringify!(#name))
+            }
+
+            fn array_type() -> ::sea_orm::sea_query::ArrayType {
+                <::nulid::Nulid as ::sea_orm::sea_query::ValueType>::array_type()
+            }
+
+            fn column_type() -> ::sea_orm::sea_query::ColumnType {
+                <::nulid::Nulid as ::sea_orm::sea_query::ValueType>::column_type()
+            }
+        }
+
+        #[cfg(feature = "sea-orm")]
+        impl #impl_generics ::sea_orm::TryGetable for #name #ty_generics #where_clause {
+            fn try_get_by<I: ::sea_orm::ColIdx>(
+                res: &::sea_orm::QueryResult,
+                index: I,
+            ) -> ::core::result::Result<Self, ::sea_orm::TryGetError> {
+                <::nulid::Nulid as ::sea_orm::TryGetable>::try_get_by(res, index).map(#ctor)
+            }
+        }
+
+        #[cfg(feature = "sea-orm")]
+        impl #impl_generics ::sea_orm::TryFromU64 for #name #ty_generics #where_clause {
+            fn try_from_u64(_: u64) -> ::core::result::Result<Self, ::sea_orm::DbErr> {
+                ::core::result::Result::Err(::sea_orm::DbErr::ConvertFromU64(stringify!(#name)))
+            }
+        }
+    }
+}
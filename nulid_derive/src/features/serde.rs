@@ -2,7 +2,11 @@
 //!
 //! This module provides code generation for `Serialize` and `Deserialize` implementations
 //! for types that derive `Id`, delegating to the inner `Nulid`'s serde implementation.
+//! Prefixed types go through their own `Display`/`FromStr` in human-readable formats,
+//! and `#[id(serde = "...")]` pins one representation for every format.
 
+use crate::attrs::SerdeRepr;
+use crate::constructor::Constructor;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Ident;
@@ -10,13 +14,56 @@ use syn::Ident;
 /// Generates serde trait implementations for the Id wrapper type.
 ///
 /// This generates `Serialize` and `Deserialize` implementations that delegate
-/// to the inner `Nulid` type's implementations.
+/// to the inner `Nulid` type's implementations. When `prefixed` is set,
+/// human-readable formats carry the prefixed string instead. A pinned `repr`
+/// goes through the matching `nulid::serde` adapter.
 pub fn generate_serde_impls(
     name: &Ident,
-    impl_generics: &syn::ImplGenerics,
-    ty_generics: &syn::TypeGenerics,
-    where_clause: &Option<&syn::WhereClause>,
+    ctor: &Constructor,
+    generics: &syn::Generics,
+    repr: SerdeRepr,
+    prefixed: bool,
 ) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut de_generics = generics.clone();
+    de_generics.params.insert(0, syn::parse_quote!('de));
+    let (de_impl_generics, _, _) = de_generics.split_for_impl();
+
+    let prefixed_serialize = quote! { serializer.collect_str(self) };
+    let prefixed_deserialize = quote! {
+        let s = <::std::string::String as ::serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(::serde::de::Error::custom)
+    };
+
+    let (serialize_body, deserialize_body) = match repr {
+        SerdeRepr::Auto if prefixed => (
+            quote! {
+                if serializer.is_human_readable() {
+                    #prefixed_serialize
+                } else {
+                    self.0.serialize(serializer)
+                }
+            },
+            quote! {
+                if deserializer.is_human_readable() {
+                    #prefixed_deserialize
+                } else {
+                    ::nulid::Nulid::deserialize(deserializer).map(#ctor)
+                }
+            },
+        ),
+        SerdeRepr::Auto => (
+            quote! { self.0.serialize(serializer) },
+            quote! { ::nulid::Nulid::deserialize(deserializer).map(#ctor) },
+        ),
+        SerdeRepr::String if prefixed => (prefixed_serialize, prefixed_deserialize),
+        SerdeRepr::String => adapter(ctor, &quote! { as_string }),
+        SerdeRepr::Bytes => adapter(ctor, &quote! { as_bytes }),
+        SerdeRepr::Uuid => adapter(ctor, &quote! { as_uuid_string }),
+        SerdeRepr::U128 => adapter(ctor, &quote! { as_u128 }),
+    };
+
     quote! {
         #[cfg(feature = "serde")]
         impl #impl_generics ::serde::Serialize for #name #ty_generics #where_clause {
@@ -24,18 +71,26 @@ pub fn generate_serde_impls(
             where
                 S: ::serde::Serializer,
             {
-                self.0.serialize(serializer)
+                #serialize_body
             }
         }
 
         #[cfg(feature = "serde")]
-        impl<'de> ::serde::Deserialize<'de> for #name #where_clause {
+        impl #de_impl_generics ::serde::Deserialize<'de> for #name #ty_generics #where_clause {
             fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
             where
                 D: ::serde::Deserializer<'de>,
             {
-                ::nulid::Nulid::deserialize(deserializer).map(#name)
+                #deserialize_body
             }
         }
     }
 }
+
+/// Returns serialize and deserialize bodies that use a `nulid::serde` adapter.
+fn adapter(ctor: &Constructor, module: &TokenStream) -> (TokenStream, TokenStream) {
+    (
+        quote! { ::nulid::serde::#module::serialize(&self.0, serializer) },
+        quote! { ::nulid::serde::#module::deserialize(deserializer).map(#ctor) },
+    )
+}
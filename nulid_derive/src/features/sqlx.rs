@@ -3,6 +3,7 @@
 //! This module provides code generation for SQLx trait implementations
 //! for types that derive `Id`, delegating to the inner `Nulid`'s SQLx support.
 
+use crate::constructor::Constructor;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Ident;
@@ -13,10 +14,15 @@ use syn::Ident;
 /// implementations that delegate to the inner `Nulid` type's implementations.
 pub fn generate_sqlx_impls(
     name: &Ident,
-    impl_generics: &syn::ImplGenerics,
-    ty_generics: &syn::TypeGenerics,
-    where_clause: &Option<&syn::WhereClause>,
+    ctor: &Constructor,
+    generics: &syn::Generics,
 ) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut decode_generics = generics.clone();
+    decode_generics.params.insert(0, syn::parse_quote!('r));
+    let (decode_impl_generics, _, _) = decode_generics.split_for_impl();
+
     quote! {
         #[cfg(feature = "sqlx")]
         impl #impl_generics ::sqlx::Type<::sqlx::Postgres> for #name #ty_generics #where_clause {
@@ -51,11 +57,11 @@ pub fn generate_sqlx_impls(
         }
 
         #[cfg(feature = "sqlx")]
-        impl<'r> ::sqlx::Decode<'r, ::sqlx::Postgres> for #name #where_clause {
+        impl #decode_impl_generics ::sqlx::Decode<'r, ::sqlx::Postgres> for #name #ty_generics #where_clause {
             fn decode(
                 value: ::sqlx::postgres::PgValueRef<'r>,
             ) -> ::core::result::Result<Self, ::sqlx::error::BoxDynError> {
-                <::nulid::Nulid as ::sqlx::Decode<::sqlx::Postgres>>::decode(value).map(#name)
+                <::nulid::Nulid as ::sqlx::Decode<::sqlx::Postgres>>::decode(value).map(#ctor)
             }
         }
     }
@@ -0,0 +1,71 @@
+//! `OpenAPI` schema support for Id-derived types via `utoipa`.
+//!
+//! This module provides code generation for `PartialSchema` and `ToSchema`
+//! implementations for types that derive `Id`, reusing the inner `Nulid`'s schema.
+
+use super::PrefixedSchema;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+/// Generates utoipa trait implementations for the Id wrapper type.
+///
+/// The schema is the inner `Nulid`'s schema (`type: string, format: nulid`),
+/// registered under the wrapper's own name, with the wrapper's doc comment
+/// as description and the prefix folded into the string constraints.
+pub fn generate_utoipa_impls(
+    name: &Ident,
+    impl_generics: &syn::ImplGenerics,
+    ty_generics: &syn::TypeGenerics,
+    where_clause: &Option<&syn::WhereClause>,
+    doc: Option<&str>,
+    prefix: Option<&PrefixedSchema>,
+) -> TokenStream {
+    let description = doc.map(|doc| {
+        quote! { object.description = ::core::option::Option::Some(#doc.into()); }
+    });
+    let constraints = prefix.map(|prefix| {
+        let PrefixedSchema {
+            pattern,
+            min_length,
+            max_length,
+            example,
+        } = prefix;
+        quote! {
+            object.pattern = ::core::option::Option::Some(#pattern.into());
+            object.min_length = ::core::option::Option::Some(#min_length);
+            object.max_length = ::core::option::Option::Some(#max_length);
+            object.examples = ::std::vec![#example.into()];
+        }
+    });
+    let schema_body = if description.is_none() && constraints.is_none() {
+        quote! { <::nulid::Nulid as ::utoipa::PartialSchema>::schema() }
+    } else {
+        quote! {
+            let mut schema = <::nulid::Nulid as ::utoipa::PartialSchema>::schema();
+            if let ::utoipa::openapi::RefOr::T(::utoipa::openapi::schema::Schema::Object(object)) =
+                &mut schema
+            {
+                #description
+                #constraints
+            }
+            schema
+        }
+    };
+
+    quote! {
+        #[cfg(feature = "utoipa")]
+        impl #impl_generics ::utoipa::PartialSchema for #name #ty_generics #where_clause {
+            fn schema() -> ::utoipa::openapi::RefOr<::utoipa::openapi::schema::Schema> {
+                #schema_body
+            }
+        }
+
+        #[cfg(feature = "utoipa")]
+        impl #impl_generics ::utoipa::ToSchema for #name #ty_generics #where_clause {
+            fn name() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(::core::stringify!(#name))
+            }
+        }
+    }
+}
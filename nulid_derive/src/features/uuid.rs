@@ -3,6 +3,7 @@
 //! This module provides code generation for UUID conversion implementations
 //! for types that derive `Id`, delegating to the inner `Nulid`'s UUID support.
 
+use crate::constructor::Constructor;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Ident;
@@ -13,15 +14,19 @@ use syn::Ident;
 /// that delegate to the inner `Nulid` type's implementations.
 pub fn generate_uuid_impls(
     name: &Ident,
+    ctor: &Constructor,
     impl_generics: &syn::ImplGenerics,
     ty_generics: &syn::TypeGenerics,
     where_clause: &Option<&syn::WhereClause>,
 ) -> TokenStream {
+    let rest = ctor.rest();
+    let constness = ctor.constness();
+
     quote! {
         #[cfg(feature = "uuid")]
         impl #impl_generics ::core::convert::From<::uuid::Uuid> for #name #ty_generics #where_clause {
             fn from(uuid: ::uuid::Uuid) -> Self {
-                #name(::nulid::Nulid::from_uuid(uuid))
+                #name(::nulid::Nulid::from_uuid(uuid) #rest)
             }
         }
 
@@ -39,7 +44,7 @@ pub fn generate_uuid_impls(
             /// The 128-bit value is preserved exactly, maintaining full compatibility
             /// with UUID-based systems.
             #[must_use]
-            pub const fn to_uuid(self) -> ::uuid::Uuid {
+            pub #constness fn to_uuid(self) -> ::uuid::Uuid {
                 self.0.to_uuid()
             }
 
@@ -47,8 +52,8 @@ pub fn generate_uuid_impls(
             ///
             /// The 128-bit value is preserved exactly.
             #[must_use]
-            pub const fn from_uuid(uuid: ::uuid::Uuid) -> Self {
-                #name(::nulid::Nulid::from_uuid(uuid))
+            pub #constness fn from_uuid(uuid: ::uuid::Uuid) -> Self {
+                #name(::nulid::Nulid::from_uuid(uuid) #rest)
             }
         }
     }
@@ -0,0 +1,47 @@
+//! `zeroize` support for Id-derived types.
+//!
+//! This module provides code generation for `Zeroize` implementations for
+//! types that derive `Id`, delegating to the inner `Nulid`'s zeroize support,
+//! and for the scrub-on-drop impls requested with `#[id(zeroize)]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+/// Generates `zeroize` trait implementations for the Id wrapper type.
+///
+/// `Zeroize` resets the inner `Nulid` to nil; metadata fields are left
+/// alone. With `on_drop`, the type also scrubs itself in `Drop` and is
+/// marked `ZeroizeOnDrop`. That part is not feature-gated: a type that asks
+/// for it must never silently lose it, so the consuming crate needs
+/// `zeroize` as a dependency.
+pub fn generate_zeroize_impls(
+    name: &Ident,
+    impl_generics: &syn::ImplGenerics,
+    ty_generics: &syn::TypeGenerics,
+    where_clause: &Option<&syn::WhereClause>,
+    on_drop: bool,
+) -> TokenStream {
+    let drop_impls = on_drop.then(|| {
+        quote! {
+            impl #impl_generics ::core::ops::Drop for #name #ty_generics #where_clause {
+                fn drop(&mut self) {
+                    ::zeroize::Zeroize::zeroize(&mut self.0);
+                }
+            }
+
+            impl #impl_generics ::zeroize::ZeroizeOnDrop for #name #ty_generics #where_clause {}
+        }
+    });
+
+    quote! {
+        #[cfg(feature = "zeroize")]
+        impl #impl_generics ::zeroize::Zeroize for #name #ty_generics #where_clause {
+            fn zeroize(&mut self) {
+                ::zeroize::Zeroize::zeroize(&mut self.0);
+            }
+        }
+
+        #drop_impls
+    }
+}
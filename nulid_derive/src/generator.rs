@@ -0,0 +1,113 @@
+//! Typed generator support for Id-derived types.
+//!
+//! This module provides code generation for `#[id(generator)]`, which emits a
+//! `<Name>Generator` that wraps `nulid::Generator` and returns the wrapper type.
+
+use crate::constructor::Constructor;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Visibility};
+
+/// Generates a `<Name>Generator` type for the Id wrapper type.
+///
+/// The generator has the same visibility as the wrapper and the same clock,
+/// RNG, and node ID type parameters as `nulid::Generator`.
+pub fn generate_typed_generator(name: &Ident, ctor: &Constructor, vis: &Visibility) -> TokenStream {
+    let generator = format_ident!("{}Generator", name);
+    let type_doc = format!(
+        "Monotonic generator of [`{name}`] values.\n\n\
+         Wraps [`nulid::Generator`] so every `{name}` it returns is strictly\n\
+         greater than the previous one."
+    );
+
+    quote! {
+        #[doc = #type_doc]
+        #vis struct #generator<
+            C: ::nulid::generator::Clock = ::nulid::generator::SystemClock,
+            R: ::nulid::generator::Rng = ::nulid::generator::CryptoRng,
+            N: ::nulid::generator::NodeId = ::nulid::generator::NoNodeId,
+        >(::nulid::Generator<C, R, N>);
+
+        impl #generator {
+            /// Creates a new generator using system clock and cryptographic RNG.
+            #[must_use]
+            pub const fn new() -> Self {
+                Self(::nulid::Generator::new())
+            }
+        }
+
+        impl ::core::default::Default for #generator {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl #generator<
+            ::nulid::generator::SystemClock,
+            ::nulid::generator::CryptoRng,
+            ::nulid::generator::WithNodeId,
+        > {
+            /// Creates a new generator with node ID for distributed deployments.
+            #[must_use]
+            pub const fn with_node_id(node_id: u16) -> Self {
+                Self(::nulid::Generator::with_node_id(node_id))
+            }
+        }
+
+        impl<C, R, N> #generator<C, R, N>
+        where
+            C: ::nulid::generator::Clock,
+            R: ::nulid::generator::Rng,
+            N: ::nulid::generator::NodeId,
+        {
+            /// Wraps an existing generator, e.g. one with custom dependencies.
+            #[must_use]
+            pub const fn from_generator(generator: ::nulid::Generator<C, R, N>) -> Self {
+                Self(generator)
+            }
+
+            /// Generates a new ID, strictly greater than the previous one.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the underlying `Generator` fails.
+            pub fn generate(&self) -> ::core::result::Result<#name, ::nulid::Error> {
+                self.0.generate().map(#ctor)
+            }
+
+            /// Returns the last generated ID, if any.
+            #[must_use]
+            pub fn last(&self) -> ::core::option::Option<#name> {
+                self.0.last().map(#ctor)
+            }
+
+            /// Resets the generator state.
+            pub fn reset(&self) {
+                self.0.reset();
+            }
+
+            /// Returns the node ID if configured.
+            #[must_use]
+            pub fn node_id(&self) -> ::core::option::Option<u16> {
+                self.0.node_id()
+            }
+
+            /// Returns the underlying `Generator`.
+            #[must_use]
+            pub const fn inner(&self) -> &::nulid::Generator<C, R, N> {
+                &self.0
+            }
+        }
+
+        impl<C, R, N> ::core::convert::From<::nulid::Generator<C, R, N>> for #generator<C, R, N>
+        where
+            C: ::nulid::generator::Clock,
+            R: ::nulid::generator::Rng,
+            N: ::nulid::generator::NodeId,
+        {
+            fn from(generator: ::nulid::Generator<C, R, N>) -> Self {
+                Self(generator)
+            }
+        }
+    }
+}
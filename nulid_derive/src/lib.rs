@@ -21,7 +21,11 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{Data, DeriveInput, Fields, parse_macro_input};
 
+mod attrs;
+mod constructor;
 mod features;
+mod generator;
+mod non_nil;
 
 /// Derives common traits for types that wrap `Nulid`.
 ///
@@ -36,7 +40,7 @@ mod features;
 /// - `core::fmt::Display`
 /// - `core::fmt::Debug`
 /// - `core::str::FromStr`
-/// - `Copy` (which automatically provides `Clone`)
+/// - `Copy` and `Clone` (leave out `Copy` with `#[id(no_copy)]`)
 /// - `PartialEq` and `PartialEq<Nulid>` - Equality comparison with wrapper and inner type
 /// - `Eq`
 /// - `PartialOrd` and `PartialOrd<Nulid>` - Ordering comparison with wrapper and inner type
@@ -52,6 +56,11 @@ mod features;
 /// - `Serialize` - Serialization support
 /// - `Deserialize` - Deserialization support
 ///
+/// `#[id(serde = "string" | "bytes" | "uuid" | "u128")]` pins the wire
+/// representation for every format instead of switching on
+/// `is_human_readable()`. `string` carries the prefix if one is set; the
+/// other representations never do.
+///
 /// ## `uuid` feature
 /// - `From<uuid::Uuid>` - Convert from UUID
 /// - `Into<uuid::Uuid>` - Convert to UUID
@@ -68,6 +77,51 @@ mod features;
 /// - `FromSql` - Deserialize from PostgreSQL
 /// - `ToSql` - Serialize to PostgreSQL
 ///
+/// ## `diesel-postgres` and `diesel-sqlite` features
+/// - `ToSql` / `FromSql` - PostgreSQL `Uuid` and `Text`, `SQLite` `Binary` and `Text`
+/// - `AsExpression` - Binds the wrapper (or a reference to it) in queries
+/// - `Queryable` - Loads the wrapper from a single column
+///
+/// ## `sea-orm` feature
+/// - `From<T> for Value`, `Nullable`, `ValueType` - Entity field support (`ColumnType::Uuid`)
+/// - `TryGetable` - Reads the wrapper from query results
+/// - `TryFromU64` - Allows the wrapper as a primary key
+///
+/// ## `rkyv` feature
+/// - `Archive` - Archives to `ArchivedNulid`, with `CheckBytes` validation
+/// - `Serialize` - Serialization support
+/// - `Deserialize` - Deserialization from `ArchivedNulid`
+///
+/// ## `juniper` feature
+/// - GraphQL scalar named after the wrapper (non-generic wrappers only)
+///
+/// ## `async-graphql` feature
+/// - `ScalarType` - GraphQL scalar named after the wrapper (non-generic wrappers only)
+///
+/// ## `utoipa` feature
+/// - `PartialSchema` - `OpenAPI` schema (`type: string, format: nulid`)
+/// - `ToSchema` - Registers the schema under the wrapper's name
+///
+/// ## `schemars` feature
+/// - `JsonSchema` - JSON Schema (`type: string, format: nulid`) under the wrapper's name
+///
+/// Schemas and scalars use the wrapper's doc comment as their description,
+/// and schemas of prefixed types describe the prefixed string.
+///
+/// ## `axum` feature
+/// - `FromRequestParts` - Extracts the wrapper from a single path parameter
+/// - `IntoResponse` - Returns the ID as a `text/plain` body
+///
+/// ## `actix-web` feature
+/// - `FromRequest` - Extracts the wrapper from a single path segment
+/// - `Responder` - Returns the ID as a `text/plain` body
+///
+/// ## `fake` feature
+/// - `Dummy<T>` - Fake data for every config `T` that `Nulid` supports
+///
+/// ## `zeroize` feature
+/// - `Zeroize` - Resets the inner `Nulid` to nil
+///
 /// # Constructor Methods
 ///
 /// It also provides constructor methods that mirror Nulid's API:
@@ -80,6 +134,10 @@ mod features;
 /// - `from_nanos(u128, u64)` - Creates from timestamp and random
 /// - `from_u128(u128)` - Creates from raw u128
 /// - `from_bytes([u8; 16])` - Creates from byte array
+/// - `is_nil()` - Checks for the nil ID, without going through `Deref`
+///
+/// The `NIL`, `MIN` and `MAX` associated constants mirror `nil()`, `min()`
+/// and `max()`. They are not generated for types with metadata fields.
 ///
 /// With `Deref`, you can call any `Nulid` method directly on the wrapper type:
 /// ```ignore
@@ -88,9 +146,169 @@ mod features;
 /// let random = user_id.random(); // Direct access to Nulid::random()
 /// ```
 ///
+/// # Prefixed IDs
+///
+/// `#[id(prefix = "user_")]` makes the type display as `user_01HZ...`, in the
+/// style of Stripe IDs. Parsing accepts the string with or without the
+/// prefix; add `strict` to reject strings that lack it with
+/// `Error::InvalidPrefix`. The prefix is also exposed as `UserId::PREFIX`.
+///
+/// Everything that goes through `Display` and `FromStr` honors the prefix:
+/// `TryFrom<&str>`, human-readable serde formats, and the axum, actix-web and
+/// juniper integrations. Binary formats and database encodings keep storing
+/// the bare 128-bit value.
+///
+/// ```ignore
+/// #[derive(Id)]
+/// #[id(prefix = "user_", strict)]
+/// pub struct UserId(Nulid);
+///
+/// let id: UserId = "user_01HZQWER4TYUIOP9876QWERTY5".parse()?;
+/// assert!(id.to_string().starts_with("user_"));
+/// assert!("01HZQWER4TYUIOP9876QWERTY5".parse::<UserId>().is_err());
+/// ```
+///
+/// # Custom Error Types
+///
+/// `#[id(error = "AppError")]` makes `TryFrom` and `FromStr` fail with
+/// `AppError` instead of `nulid::Error`, converting through
+/// `From<nulid::Error>`. Constructors keep returning `nulid::Error`, and the
+/// axum and actix-web extractors keep rejecting with `Nulid`'s rejection.
+/// Human-readable serde formats and async-graphql report parse errors
+/// through `Display`, so prefixed types need `AppError: Display`.
+///
+/// ```ignore
+/// #[derive(Id)]
+/// #[id(error = "AppError")]
+/// pub struct UserId(Nulid);
+///
+/// let result: Result<UserId, AppError> = "invalid".parse();
+/// ```
+///
+/// # Selecting Generated Impls
+///
+/// `#[id(skip(...))]` suppresses core impls that would conflict with your own,
+/// such as a redacting `Debug`; `#[id(only(...))]` generates just the listed
+/// ones. Feature-gated impls and constructor methods are always generated.
+/// The accepted names are `TryFrom`, `From`, `AsRef`, `Deref`, `DerefMut`,
+/// `Display`, `FromStr`, `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`,
+/// `PartialOrd`, `Ord`, `Hash`, `PartialEq_nulid`, `PartialOrd_nulid` and
+/// `Default`. Each name covers every impl of that trait, e.g. `From` covers
+/// the conversions to and from `Nulid`, `u128` and `[u8; 16]`.
+///
+/// Impls that build on a skipped one, like `PartialOrd` on `Ord` or the
+/// serde and web integrations on `Display` and `FromStr`, then use yours.
+/// Some feature-gated impls need core traits to exist at all, e.g.
+/// `postgres-types` and `diesel` require `Debug`.
+///
+/// ```ignore
+/// #[derive(Id)]
+/// #[id(skip(Debug, PartialEq_nulid, PartialOrd_nulid))]
+/// pub struct SessionId(Nulid);
+///
+/// impl core::fmt::Debug for SessionId {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         f.write_str("SessionId(<redacted>)")
+///     }
+/// }
+/// ```
+///
+/// # Redacted IDs
+///
+/// `#[id(redact)]` is for IDs that act as capability tokens, such as session
+/// or invite IDs. The generated `Debug` shows only the start and end of the
+/// ID, e.g. `SessionId(sess_01HZQW…RTY5)`, so `{:?}` in logs still
+/// correlates without leaking a usable ID. It also emits
+/// `display_redacted()`, which includes the prefix, for `{}`. `Display`,
+/// `FromStr` and serde are unchanged.
+///
+/// ```ignore
+/// #[derive(Id)]
+/// #[id(prefix = "sess_", redact)]
+/// pub struct SessionId(Nulid);
+///
+/// let id: SessionId = "sess_01HZQWER4TYUIOP9876QWERTY5".parse()?;
+/// assert_eq!(format!("{id:?}"), "SessionId(sess_01HZQW…RTY5)");
+/// assert_eq!(id.display_redacted().to_string(), "sess_01HZQW…RTY5");
+/// ```
+///
+/// # Zeroizing IDs
+///
+/// `#[id(zeroize)]` makes the type overwrite its ID when dropped and marks
+/// it `ZeroizeOnDrop`, for IDs that are secrets. Since `Copy` types cannot
+/// be scrubbed on drop, it requires `no_copy`. The generated impls are not
+/// feature-gated, so the crate needs `zeroize` as a dependency.
+///
+/// ```ignore
+/// #[derive(Id)]
+/// #[id(no_copy, zeroize, redact)]
+/// pub struct InviteToken(Nulid);
+/// ```
+///
+/// # Typed Generators
+///
+/// `#[id(generator)]` also emits a `<Name>Generator` with the type's
+/// visibility. It wraps `nulid::Generator` and returns the wrapper type, so
+/// each entity type keeps its own monotonic sequence. It offers `new()`,
+/// `with_node_id()`, `from_generator()`, `generate()`, `last()`, `reset()`,
+/// `node_id()` and `inner()`. Generic wrappers are not supported.
+///
+/// ```ignore
+/// #[derive(Id)]
+/// #[id(generator)]
+/// pub struct UserId(Nulid);
+///
+/// let generator = UserIdGenerator::new();
+/// let first: UserId = generator.generate()?;
+/// assert!(generator.generate()? > first);
+/// ```
+///
+/// # Non-Nil IDs
+///
+/// `#[id(non_nil)]` also emits a `NonNil<Name>` with the type's visibility,
+/// for domains where nil IDs are invalid by contract. It offers `new()`
+/// (returning `None` for nil), `generate()`, `get()` and `as_id()`, and
+/// dereferences to the wrapper. `TryFrom<Name>`, `TryFrom<Nulid>`, `FromStr`
+/// and serde `Deserialize` reject nil with `Error::NilId`. Generic wrappers
+/// are not supported.
+///
+/// ```ignore
+/// #[derive(Id)]
+/// #[id(non_nil)]
+/// pub struct UserId(Nulid);
+///
+/// let user_id = NonNilUserId::generate()?;
+/// assert!(NonNilUserId::new(UserId::NIL).is_none());
+/// ```
+///
 /// # Requirements
 ///
-/// The type must be a tuple struct with exactly one field of type `Nulid`.
+/// The type must be a tuple struct whose first field is of type `Nulid`.
+/// Further fields are allowed if they implement `Default` and `Clone`; the
+/// generated constructors fill them with `Default::default()`. They take no
+/// part in comparisons, hashing or formatting, and with such fields the
+/// constructor methods are not `const`. `PhantomData` markers need neither,
+/// so one generic definition can serve several entity types:
+///
+/// ```ignore
+/// #[derive(Id)]
+/// pub struct Id<T>(Nulid, PhantomData<T>);
+///
+/// let user_id: Id<User> = Id::new()?;
+/// let order_id: Id<Order> = Id::new()?;
+/// ```
+///
+/// Juniper and async-graphql scalars, `#[id(generator)]` and `#[id(non_nil)]`
+/// are not available for generic types.
+///
+/// Metadata fields that are not `Copy` need `#[id(no_copy)]`, which leaves
+/// out `Copy` so the type has move semantics and `Clone` clones every field:
+///
+/// ```ignore
+/// #[derive(Id)]
+/// #[id(no_copy)]
+/// pub struct LabeledId(Nulid, String);
+/// ```
 ///
 /// # Examples
 ///
@@ -134,7 +352,7 @@ mod features;
 /// let (timestamp, rand) = user_id.parts();
 /// ```
 #[allow(clippy::too_many_lines)]
-#[proc_macro_derive(Id)]
+#[proc_macro_derive(Id, attributes(id))]
 pub fn derive_id(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -157,177 +375,394 @@ pub fn derive_id(input: TokenStream) -> TokenStream {
         .into();
     };
 
-    if fields.unnamed.len() != 1 {
-        return syn::Error::new_spanned(&fields.unnamed, "Id requires exactly one field")
+    // The `Nulid` comes first; further fields are markers or metadata
+    if fields.unnamed.is_empty() {
+        return syn::Error::new_spanned(&fields.unnamed, "Id requires a Nulid field")
             .to_compile_error()
             .into();
     }
+    let ctor =
+        constructor::Constructor::new(name, fields.unnamed.iter().skip(1).map(|field| &field.ty));
+    let rest = ctor.rest();
+    let clones = ctor.clones();
+    let constness = ctor.constness();
+
+    let attrs = match attrs::IdAttrs::parse(&input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
-    // Generate core trait implementations
-    let core_impls = quote! {
-        impl #impl_generics ::core::convert::TryFrom<::std::string::String> for #name #ty_generics #where_clause {
-            type Error = ::nulid::Error;
-
-            fn try_from(s: ::std::string::String) -> ::core::result::Result<Self, Self::Error> {
-                use ::core::str::FromStr;
-                ::nulid::Nulid::from_str(&s).map(#name)
-            }
-        }
-
-        impl #impl_generics ::core::convert::TryFrom<&str> for #name #ty_generics #where_clause {
-            type Error = ::nulid::Error;
-
-            fn try_from(s: &str) -> ::core::result::Result<Self, Self::Error> {
-                use ::core::str::FromStr;
-                ::nulid::Nulid::from_str(s).map(#name)
-            }
-        }
-
-        impl #impl_generics ::core::convert::From<::nulid::Nulid> for #name #ty_generics #where_clause {
-            fn from(nulid: ::nulid::Nulid) -> Self {
-                #name(nulid)
-            }
-        }
-
-        impl #impl_generics ::core::convert::From<#name #ty_generics> for ::nulid::Nulid #where_clause {
-            fn from(wrapper: #name #ty_generics) -> Self {
-                wrapper.0
-            }
-        }
-
-        impl #impl_generics ::core::convert::AsRef<::nulid::Nulid> for #name #ty_generics #where_clause {
-            fn as_ref(&self) -> &::nulid::Nulid {
-                &self.0
-            }
-        }
-
-        impl #impl_generics ::core::convert::From<u128> for #name #ty_generics #where_clause {
-            fn from(value: u128) -> Self {
-                #name(::nulid::Nulid::from_u128(value))
-            }
-        }
-
-        impl #impl_generics ::core::convert::From<#name #ty_generics> for u128 #where_clause {
-            fn from(wrapper: #name #ty_generics) -> Self {
-                wrapper.0.as_u128()
-            }
-        }
-
-        impl #impl_generics ::core::convert::From<[u8; 16]> for #name #ty_generics #where_clause {
-            fn from(bytes: [u8; 16]) -> Self {
-                #name(::nulid::Nulid::from_bytes(bytes))
-            }
-        }
-
-        impl #impl_generics ::core::convert::From<#name #ty_generics> for [u8; 16] #where_clause {
-            fn from(wrapper: #name #ty_generics) -> Self {
-                wrapper.0.to_bytes()
-            }
-        }
-
-        impl #impl_generics ::core::convert::AsRef<u128> for #name #ty_generics #where_clause {
-            fn as_ref(&self) -> &u128 {
-                self.0.as_ref()
-            }
-        }
-
-        impl #impl_generics ::core::convert::TryFrom<&[u8]> for #name #ty_generics #where_clause {
-            type Error = ::nulid::Error;
-
-            fn try_from(bytes: &[u8]) -> ::core::result::Result<Self, Self::Error> {
-                ::nulid::Nulid::try_from(bytes).map(#name)
-            }
-        }
-
-        impl #impl_generics ::core::ops::Deref for #name #ty_generics #where_clause {
-            type Target = ::nulid::Nulid;
-
-            fn deref(&self) -> &Self::Target {
-                &self.0
-            }
-        }
+    if attrs.generator && !input.generics.params.is_empty() {
+        return syn::Error::new_spanned(
+            &input.generics,
+            "#[id(generator)] is not supported for generic types",
+        )
+        .to_compile_error()
+        .into();
+    }
 
-        impl #impl_generics ::core::ops::DerefMut for #name #ty_generics #where_clause {
-            fn deref_mut(&mut self) -> &mut Self::Target {
-                &mut self.0
-            }
-        }
+    if attrs.non_nil && !input.generics.params.is_empty() {
+        return syn::Error::new_spanned(
+            &input.generics,
+            "#[id(non_nil)] is not supported for generic types",
+        )
+        .to_compile_error()
+        .into();
+    }
 
-        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    // Prefixed types write the prefix before the Base32 string and strip it
+    // again when parsing; unprefixed types delegate straight to `Nulid`.
+    let display_body = attrs.prefix.as_ref().map_or_else(
+        || quote! { ::core::fmt::Display::fmt(&self.0, f) },
+        |prefix| {
+            quote! {
+                f.write_str(#prefix)?;
                 ::core::fmt::Display::fmt(&self.0, f)
             }
-        }
-
-        impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
-            type Err = ::nulid::Error;
-
-            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
-                ::nulid::Nulid::from_str(s).map(#name)
-            }
-        }
-
-        impl #impl_generics ::core::fmt::Debug for #name #ty_generics #where_clause {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                f.debug_tuple(::core::stringify!(#name))
-                    .field(&self.0)
-                    .finish()
-            }
-        }
-
-        #[allow(clippy::expl_impl_clone_on_copy)]
-        impl #impl_generics ::core::clone::Clone for #name #ty_generics #where_clause {
-            fn clone(&self) -> Self {
-                *self
-            }
-        }
-
-        impl #impl_generics ::core::marker::Copy for #name #ty_generics #where_clause {}
+        },
+    );
+    let parse_body = match &attrs.prefix {
+        None => quote! { ::nulid::Nulid::from_str(s).map(#ctor) },
+        Some(prefix) if attrs.strict => quote! {
+            let s = s
+                .strip_prefix(#prefix)
+                .ok_or(::nulid::Error::InvalidPrefix { expected: #prefix })?;
+            ::nulid::Nulid::from_str(s).map(#ctor)
+        },
+        Some(prefix) => quote! {
+            let s = s.strip_prefix(#prefix).unwrap_or(s);
+            ::nulid::Nulid::from_str(s).map(#ctor)
+        },
+    };
 
-        impl #impl_generics ::core::cmp::PartialEq for #name #ty_generics #where_clause {
-            fn eq(&self, other: &Self) -> bool {
-                self.0 == other.0
-            }
+    // With a custom error type, parsing still yields `nulid::Error` and is
+    // converted once at the `TryFrom`/`FromStr` boundary. `parser` is what
+    // integrations call when they need the `nulid::Error` itself.
+    let (error_ty, parse_str, parse_bytes, parser) = match &attrs.error {
+        None => (
+            quote! { ::nulid::Error },
+            quote! {
+                use ::core::str::FromStr;
+                #parse_body
+            },
+            quote! { ::nulid::Nulid::try_from(bytes).map(#ctor) },
+            quote! { <#name #ty_generics as ::core::str::FromStr>::from_str },
+        ),
+        Some(error) => {
+            let parser = quote! {
+                (|s: &str| -> ::core::result::Result<#name #ty_generics, ::nulid::Error> {
+                    use ::core::str::FromStr;
+                    #parse_body
+                })
+            };
+            (
+                quote! { #error },
+                quote! { #parser(s).map_err(<#error as ::core::convert::From<::nulid::Error>>::from) },
+                quote! {
+                    ::nulid::Nulid::try_from(bytes)
+                        .map(#ctor)
+                        .map_err(<#error as ::core::convert::From<::nulid::Error>>::from)
+                },
+                parser,
+            )
         }
+    };
 
-        impl #impl_generics ::core::cmp::Eq for #name #ty_generics #where_clause {}
-
-        impl #impl_generics ::core::cmp::PartialOrd for #name #ty_generics #where_clause {
-            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
-                ::core::option::Option::Some(self.cmp(other))
-            }
-        }
+    // Metadata fields are filled with `Default::default()`, which is not const
+    let bound_consts = (!constness.is_empty()).then(|| {
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// The nil (all zeros) instance.
+                pub const NIL: Self = #name(::nulid::Nulid::MIN #rest);
 
-        impl #impl_generics ::core::cmp::Ord for #name #ty_generics #where_clause {
-            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
-                self.0.cmp(&other.0)
-            }
-        }
+                /// The minimum possible instance (all zeros).
+                pub const MIN: Self = #name(::nulid::Nulid::MIN #rest);
 
-        impl #impl_generics ::core::hash::Hash for #name #ty_generics #where_clause {
-            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
-                self.0.hash(state);
+                /// The maximum possible instance (all ones).
+                pub const MAX: Self = #name(::nulid::Nulid::MAX #rest);
             }
         }
-
-        impl #impl_generics ::core::cmp::PartialEq<::nulid::Nulid> for #name #ty_generics #where_clause {
-            fn eq(&self, other: &::nulid::Nulid) -> bool {
-                self.0 == *other
+    });
+    let prefix_const = attrs.prefix.as_ref().map(|prefix| {
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Prefix written before the Base32 string.
+                pub const PREFIX: &'static str = #prefix;
             }
         }
+    });
 
-        impl #impl_generics ::core::cmp::PartialOrd<::nulid::Nulid> for #name #ty_generics #where_clause {
-            fn partial_cmp(&self, other: &::nulid::Nulid) -> ::core::option::Option<::core::cmp::Ordering> {
-                self.0.partial_cmp(other)
+    // Redacted types mask the ID in `Debug`, keeping the prefix for context
+    let redacted_display = attrs.prefix.as_ref().map_or_else(
+        || quote! { self.0.display_redacted() },
+        |prefix| quote! { self.0.display_redacted().with_prefix(#prefix) },
+    );
+    let redact_method = attrs.redact.then(|| {
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Returns a `Display` adapter showing only the start and end of the ID.
+                #[must_use]
+                pub const fn display_redacted(&self) -> ::nulid::RedactedDisplay {
+                    #redacted_display
+                }
             }
         }
+    });
+    let debug_field = if attrs.redact {
+        quote! { &self.display_redacted() }
+    } else {
+        quote! { &self.0 }
+    };
 
-        impl #impl_generics ::core::default::Default for #name #ty_generics #where_clause {
-            fn default() -> Self {
-                #name(::nulid::Nulid::default())
-            }
-        }
+    // Without the generated `Copy`, the type may not be `Copy` at all
+    let clone_body = if attrs.generates("Copy") {
+        quote! { *self }
+    } else {
+        quote! { #name(self.0 #clones) }
+    };
 
+    // Generate core trait implementations, keyed by the name `skip`/`only` use
+    let core_impls = [
+        (
+            "TryFrom",
+            quote! {
+                impl #impl_generics ::core::convert::TryFrom<::std::string::String> for #name #ty_generics #where_clause {
+                    type Error = #error_ty;
+
+                    fn try_from(s: ::std::string::String) -> ::core::result::Result<Self, Self::Error> {
+                        let s = s.as_str();
+                        #parse_str
+                    }
+                }
+
+                impl #impl_generics ::core::convert::TryFrom<&str> for #name #ty_generics #where_clause {
+                    type Error = #error_ty;
+
+                    fn try_from(s: &str) -> ::core::result::Result<Self, Self::Error> {
+                        #parse_str
+                    }
+                }
+
+                impl #impl_generics ::core::convert::TryFrom<&[u8]> for #name #ty_generics #where_clause {
+                    type Error = #error_ty;
+
+                    fn try_from(bytes: &[u8]) -> ::core::result::Result<Self, Self::Error> {
+                        #parse_bytes
+                    }
+                }
+            },
+        ),
+        (
+            "From",
+            quote! {
+                impl #impl_generics ::core::convert::From<::nulid::Nulid> for #name #ty_generics #where_clause {
+                    fn from(nulid: ::nulid::Nulid) -> Self {
+                        #name(nulid #rest)
+                    }
+                }
+
+                impl #impl_generics ::core::convert::From<#name #ty_generics> for ::nulid::Nulid #where_clause {
+                    fn from(wrapper: #name #ty_generics) -> Self {
+                        wrapper.0
+                    }
+                }
+
+                impl #impl_generics ::core::convert::From<u128> for #name #ty_generics #where_clause {
+                    fn from(value: u128) -> Self {
+                        #name(::nulid::Nulid::from_u128(value) #rest)
+                    }
+                }
+
+                impl #impl_generics ::core::convert::From<#name #ty_generics> for u128 #where_clause {
+                    fn from(wrapper: #name #ty_generics) -> Self {
+                        wrapper.0.as_u128()
+                    }
+                }
+
+                impl #impl_generics ::core::convert::From<[u8; 16]> for #name #ty_generics #where_clause {
+                    fn from(bytes: [u8; 16]) -> Self {
+                        #name(::nulid::Nulid::from_bytes(bytes) #rest)
+                    }
+                }
+
+                impl #impl_generics ::core::convert::From<#name #ty_generics> for [u8; 16] #where_clause {
+                    fn from(wrapper: #name #ty_generics) -> Self {
+                        wrapper.0.to_bytes()
+                    }
+                }
+            },
+        ),
+        (
+            "AsRef",
+            quote! {
+                impl #impl_generics ::core::convert::AsRef<::nulid::Nulid> for #name #ty_generics #where_clause {
+                    fn as_ref(&self) -> &::nulid::Nulid {
+                        &self.0
+                    }
+                }
+
+                impl #impl_generics ::core::convert::AsRef<u128> for #name #ty_generics #where_clause {
+                    fn as_ref(&self) -> &u128 {
+                        self.0.as_ref()
+                    }
+                }
+            },
+        ),
+        (
+            "Deref",
+            quote! {
+                impl #impl_generics ::core::ops::Deref for #name #ty_generics #where_clause {
+                    type Target = ::nulid::Nulid;
+
+                    fn deref(&self) -> &Self::Target {
+                        &self.0
+                    }
+                }
+            },
+        ),
+        (
+            "DerefMut",
+            quote! {
+                impl #impl_generics ::core::ops::DerefMut for #name #ty_generics #where_clause {
+                    fn deref_mut(&mut self) -> &mut Self::Target {
+                        &mut self.0
+                    }
+                }
+            },
+        ),
+        (
+            "Display",
+            quote! {
+                impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        #display_body
+                    }
+                }
+            },
+        ),
+        (
+            "FromStr",
+            quote! {
+                impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
+                    type Err = #error_ty;
+
+                    fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                        #parse_str
+                    }
+                }
+            },
+        ),
+        (
+            "Debug",
+            quote! {
+                impl #impl_generics ::core::fmt::Debug for #name #ty_generics #where_clause {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_tuple(::core::stringify!(#name))
+                            .field(#debug_field)
+                            .finish()
+                    }
+                }
+            },
+        ),
+        (
+            "Clone",
+            quote! {
+                #[allow(clippy::expl_impl_clone_on_copy)]
+                impl #impl_generics ::core::clone::Clone for #name #ty_generics #where_clause {
+                    fn clone(&self) -> Self {
+                        #clone_body
+                    }
+                }
+            },
+        ),
+        (
+            "Copy",
+            quote! {
+                impl #impl_generics ::core::marker::Copy for #name #ty_generics #where_clause {}
+            },
+        ),
+        (
+            "PartialEq",
+            quote! {
+                impl #impl_generics ::core::cmp::PartialEq for #name #ty_generics #where_clause {
+                    fn eq(&self, other: &Self) -> bool {
+                        self.0 == other.0
+                    }
+                }
+            },
+        ),
+        (
+            "Eq",
+            quote! {
+                impl #impl_generics ::core::cmp::Eq for #name #ty_generics #where_clause {}
+            },
+        ),
+        (
+            "PartialOrd",
+            quote! {
+                impl #impl_generics ::core::cmp::PartialOrd for #name #ty_generics #where_clause {
+                    fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                        ::core::option::Option::Some(self.cmp(other))
+                    }
+                }
+            },
+        ),
+        (
+            "Ord",
+            quote! {
+                impl #impl_generics ::core::cmp::Ord for #name #ty_generics #where_clause {
+                    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                        self.0.cmp(&other.0)
+                    }
+                }
+            },
+        ),
+        (
+            "Hash",
+            quote! {
+                impl #impl_generics ::core::hash::Hash for #name #ty_generics #where_clause {
+                    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                        self.0.hash(state);
+                    }
+                }
+            },
+        ),
+        (
+            "PartialEq_nulid",
+            quote! {
+                impl #impl_generics ::core::cmp::PartialEq<::nulid::Nulid> for #name #ty_generics #where_clause {
+                    fn eq(&self, other: &::nulid::Nulid) -> bool {
+                        self.0 == *other
+                    }
+                }
+            },
+        ),
+        (
+            "PartialOrd_nulid",
+            quote! {
+                impl #impl_generics ::core::cmp::PartialOrd<::nulid::Nulid> for #name #ty_generics #where_clause {
+                    fn partial_cmp(&self, other: &::nulid::Nulid) -> ::core::option::Option<::core::cmp::Ordering> {
+                        self.0.partial_cmp(other)
+                    }
+                }
+            },
+        ),
+        (
+            "Default",
+            quote! {
+                impl #impl_generics ::core::default::Default for #name #ty_generics #where_clause {
+                    fn default() -> Self {
+                        #name(::nulid::Nulid::default() #rest)
+                    }
+                }
+            },
+        ),
+    ]
+    .into_iter()
+    .filter(|(key, _)| attrs.generates(key))
+    .map(|(_, impls)| impls);
+
+    let constructors = quote! {
         impl #impl_generics #name #ty_generics #where_clause {
             /// Creates a new instance with a freshly generated Nulid.
             ///
@@ -335,7 +770,7 @@ pub fn derive_id(input: TokenStream) -> TokenStream {
             ///
             /// Returns an error if the Nulid generation fails.
             pub fn new() -> ::core::result::Result<Self, ::nulid::Error> {
-                ::nulid::Nulid::new().map(#name)
+                ::nulid::Nulid::new().map(#ctor)
             }
 
             /// Generates a new instance with the current timestamp and random bits.
@@ -348,7 +783,7 @@ pub fn derive_id(input: TokenStream) -> TokenStream {
             /// - The system time is before Unix epoch
             /// - Random number generation fails
             pub fn now() -> ::core::result::Result<Self, ::nulid::Error> {
-                ::nulid::Nulid::now().map(#name)
+                ::nulid::Nulid::now().map(#ctor)
             }
 
             /// Creates an instance from a `SystemTime` with random bits.
@@ -367,7 +802,7 @@ pub fn derive_id(input: TokenStream) -> TokenStream {
             /// - The time is before Unix epoch
             /// - Random number generation fails
             pub fn from_datetime(time: ::std::time::SystemTime) -> ::core::result::Result<Self, ::nulid::Error> {
-                ::nulid::Nulid::from_datetime(time).map(#name)
+                ::nulid::Nulid::from_datetime(time).map(#ctor)
             }
 
             /// Creates a nil (zero) instance.
@@ -379,8 +814,21 @@ pub fn derive_id(input: TokenStream) -> TokenStream {
             /// assert!(nil_id.is_nil());
             /// ```
             #[must_use]
-            pub const fn nil() -> Self {
-                #name(::nulid::Nulid::nil())
+            pub #constness fn nil() -> Self {
+                #name(::nulid::Nulid::nil() #rest)
+            }
+
+            /// Returns `true` if this is the nil (zero) instance.
+            ///
+            /// # Examples
+            ///
+            /// ```ignore
+            /// assert!(UserId::nil().is_nil());
+            /// assert!(!UserId::new()?.is_nil());
+            /// ```
+            #[must_use]
+            pub const fn is_nil(&self) -> bool {
+                self.0.is_nil()
             }
 
             /// Returns the minimum possible instance (all zeros).
@@ -392,8 +840,8 @@ pub fn derive_id(input: TokenStream) -> TokenStream {
             /// assert!(min_id.is_nil());
             /// ```
             #[must_use]
-            pub const fn min() -> Self {
-                #name(::nulid::Nulid::min())
+            pub #constness fn min() -> Self {
+                #name(::nulid::Nulid::min() #rest)
             }
 
             /// Returns the maximum possible instance (all ones).
@@ -405,8 +853,8 @@ pub fn derive_id(input: TokenStream) -> TokenStream {
             /// assert_eq!(max_id.as_u128(), u128::MAX);
             /// ```
             #[must_use]
-            pub const fn max() -> Self {
-                #name(::nulid::Nulid::max())
+            pub #constness fn max() -> Self {
+                #name(::nulid::Nulid::max() #rest)
             }
 
             /// Creates an instance from a 16-byte array (big-endian).
@@ -418,8 +866,8 @@ pub fn derive_id(input: TokenStream) -> TokenStream {
             /// let id = UserId::from_bytes(bytes);
             /// ```
             #[must_use]
-            pub const fn from_bytes(bytes: [u8; 16]) -> Self {
-                #name(::nulid::Nulid::from_bytes(bytes))
+            pub #constness fn from_bytes(bytes: [u8; 16]) -> Self {
+                #name(::nulid::Nulid::from_bytes(bytes) #rest)
             }
 
             /// Creates an instance from a raw `u128` value.
@@ -430,8 +878,8 @@ pub fn derive_id(input: TokenStream) -> TokenStream {
             /// let id = UserId::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
             /// ```
             #[must_use]
-            pub const fn from_u128(value: u128) -> Self {
-                #name(::nulid::Nulid::from_u128(value))
+            pub #constness fn from_u128(value: u128) -> Self {
+                #name(::nulid::Nulid::from_u128(value) #rest)
             }
 
             /// Creates an instance from a timestamp (nanoseconds) and random value.
@@ -444,40 +892,126 @@ pub fn derive_id(input: TokenStream) -> TokenStream {
             /// let id = UserId::from_nanos(1_000_000_000_000, 12345);
             /// ```
             #[must_use]
-            pub const fn from_nanos(timestamp_nanos: u128, random: u64) -> Self {
-                #name(::nulid::Nulid::from_nanos(timestamp_nanos, random))
+            pub #constness fn from_nanos(timestamp_nanos: u128, random: u64) -> Self {
+                #name(::nulid::Nulid::from_nanos(timestamp_nanos, random) #rest)
             }
         }
+
+        #bound_consts
+        #prefix_const
+        #redact_method
     };
 
     // Generate feature-gated implementations
     // Always generate the code with #[cfg] attributes so they're evaluated in the consuming crate
-    let serde_impls =
-        features::serde::generate_serde_impls(name, &impl_generics, &ty_generics, &where_clause);
-    let uuid_impls =
-        features::uuid::generate_uuid_impls(name, &impl_generics, &ty_generics, &where_clause);
-    let sqlx_impls =
-        features::sqlx::generate_sqlx_impls(name, &impl_generics, &ty_generics, &where_clause);
-    let postgres_impls = features::postgres_types::generate_postgres_types_impls(
+    let serde_impls = features::serde::generate_serde_impls(
+        name,
+        &ctor,
+        &input.generics,
+        attrs.serde,
+        attrs.prefix.is_some(),
+    );
+    let uuid_impls = features::uuid::generate_uuid_impls(
+        name,
+        &ctor,
+        &impl_generics,
+        &ty_generics,
+        &where_clause,
+    );
+    let sqlx_impls = features::sqlx::generate_sqlx_impls(name, &ctor, &input.generics);
+    let postgres_impls =
+        features::postgres_types::generate_postgres_types_impls(name, &ctor, &input.generics);
+    let diesel_impls = features::diesel::generate_diesel_impls(name, &ctor, &input.generics);
+    let sea_orm_impls = features::sea_orm::generate_sea_orm_impls(name, &ctor, &input.generics);
+    let chrono_impls = features::chrono::generate_chrono_impls(
+        name,
+        &ctor,
+        &impl_generics,
+        &ty_generics,
+        &where_clause,
+    );
+    let jiff_impls = features::jiff::generate_jiff_impls(
+        name,
+        &ctor,
+        &impl_generics,
+        &ty_generics,
+        &where_clause,
+    );
+    let doc = attrs::doc_comment(&input.attrs);
+    let prefixed_schema = attrs
+        .prefix
+        .as_ref()
+        .map(|prefix| features::PrefixedSchema::new(&prefix.value(), attrs.strict));
+    let juniper_impls =
+        features::juniper::generate_juniper_impls(name, &input.generics, doc.as_deref());
+    let utoipa_impls = features::utoipa::generate_utoipa_impls(
+        name,
+        &impl_generics,
+        &ty_generics,
+        &where_clause,
+        doc.as_deref(),
+        prefixed_schema.as_ref(),
+    );
+    let schemars_impls = features::schemars::generate_schemars_impls(
+        name,
+        &impl_generics,
+        &ty_generics,
+        &where_clause,
+        doc.as_deref(),
+        prefixed_schema.as_ref(),
+    );
+    let async_graphql_impls = features::async_graphql::generate_async_graphql_impls(
+        name,
+        &input.generics,
+        doc.as_deref(),
+    );
+    let axum_impls = features::axum::generate_axum_impls(name, &input.generics, &parser);
+    let actix_web_impls = features::actix_web::generate_actix_web_impls(
+        name,
+        &impl_generics,
+        &ty_generics,
+        &where_clause,
+        &parser,
+    );
+    let fake_impls = features::fake::generate_fake_impls(name, &ctor, &input.generics);
+    let zeroize_impls = features::zeroize::generate_zeroize_impls(
         name,
         &impl_generics,
         &ty_generics,
         &where_clause,
+        attrs.zeroize,
     );
-    let chrono_impls =
-        features::chrono::generate_chrono_impls(name, &impl_generics, &ty_generics, &where_clause);
-    let jiff_impls =
-        features::jiff::generate_jiff_impls(name, &impl_generics, &ty_generics, &where_clause);
+    let rkyv_impls = features::rkyv::generate_rkyv_impls(name, &ctor, &input.generics);
+    let typed_generator = attrs
+        .generator
+        .then(|| generator::generate_typed_generator(name, &ctor, &input.vis));
+    let non_nil = attrs
+        .non_nil
+        .then(|| non_nil::generate_non_nil(name, &ctor, &input.vis, &attrs, &error_ty));
 
     // Combine all implementations
     let expanded = quote! {
-        #core_impls
+        #(#core_impls)*
+        #constructors
         #serde_impls
         #uuid_impls
         #sqlx_impls
         #postgres_impls
+        #diesel_impls
+        #sea_orm_impls
         #chrono_impls
         #jiff_impls
+        #juniper_impls
+        #utoipa_impls
+        #schemars_impls
+        #async_graphql_impls
+        #axum_impls
+        #actix_web_impls
+        #fake_impls
+        #zeroize_impls
+        #rkyv_impls
+        #typed_generator
+        #non_nil
     };
 
     TokenStream::from(expanded)
@@ -0,0 +1,183 @@
+//! Non-nil companion type support for Id-derived types.
+//!
+//! This module provides code generation for `#[id(non_nil)]`, which emits a
+//! `NonNil<Name>` that wraps the Id type and can never hold the nil ID.
+
+use crate::attrs::IdAttrs;
+use crate::constructor::Constructor;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Visibility};
+
+/// Generates a `NonNil<Name>` type for the Id wrapper type.
+///
+/// Like `NonZeroU64`, the companion type checks for nil once on construction
+/// and then dereferences to the wrapper. Its comparison, hashing and
+/// formatting impls follow the core impls generated for the wrapper, and its
+/// conversions fail with `error_ty`, the wrapper's own `TryFrom` error.
+pub fn generate_non_nil(
+    name: &Ident,
+    ctor: &Constructor,
+    vis: &Visibility,
+    attrs: &IdAttrs,
+    error_ty: &TokenStream,
+) -> TokenStream {
+    let non_nil = format_ident!("NonNil{}", name);
+    let rest = ctor.rest();
+    let constness = ctor.constness();
+    let reject_nil = if attrs.error.is_some() {
+        quote! {
+            ok_or_else(|| <#error_ty as ::core::convert::From<::nulid::Error>>::from(::nulid::Error::NilId))
+        }
+    } else {
+        quote! { ok_or(::nulid::Error::NilId) }
+    };
+    let type_doc = format!(
+        "A [`{name}`] that is known not to be nil.\n\n\
+         Every constructor rejects the nil ID, so code receiving a `{non_nil}`\n\
+         does not have to check for it again."
+    );
+
+    let derives = [
+        "Debug",
+        "Clone",
+        "Copy",
+        "PartialEq",
+        "Eq",
+        "PartialOrd",
+        "Ord",
+        "Hash",
+    ]
+    .into_iter()
+    .filter(|key| attrs.generates(key))
+    .map(|key| format_ident!("{}", key));
+
+    let display_impl = attrs.generates("Display").then(|| {
+        quote! {
+            impl ::core::fmt::Display for #non_nil {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::fmt::Display::fmt(&self.0, f)
+                }
+            }
+        }
+    });
+    let from_str_impl = attrs.generates("FromStr").then(|| {
+        quote! {
+            impl ::core::str::FromStr for #non_nil {
+                type Err = #error_ty;
+
+                fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                    <#name as ::core::str::FromStr>::from_str(s).and_then(Self::try_from)
+                }
+            }
+        }
+    });
+
+    quote! {
+        #[doc = #type_doc]
+        #[derive(#(#derives),*)]
+        #[repr(transparent)]
+        #vis struct #non_nil(#name);
+
+        impl #non_nil {
+            /// Wraps `id`, or returns `None` if it is nil.
+            #[must_use]
+            pub #constness fn new(id: #name) -> ::core::option::Option<Self> {
+                if id.0.is_nil() {
+                    ::core::option::Option::None
+                } else {
+                    ::core::option::Option::Some(Self(id))
+                }
+            }
+
+            /// Generates a new instance with a freshly generated Nulid.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the Nulid generation fails.
+            pub fn generate() -> ::core::result::Result<Self, ::nulid::Error> {
+                Self::new(#name::new()?).ok_or(::nulid::Error::NilId)
+            }
+
+            /// Returns the wrapped ID.
+            #[must_use]
+            pub #constness fn get(self) -> #name {
+                self.0
+            }
+
+            /// Returns a reference to the wrapped ID.
+            #[must_use]
+            pub const fn as_id(&self) -> &#name {
+                &self.0
+            }
+        }
+
+        impl ::core::convert::TryFrom<#name> for #non_nil {
+            type Error = #error_ty;
+
+            fn try_from(id: #name) -> ::core::result::Result<Self, Self::Error> {
+                Self::new(id).#reject_nil
+            }
+        }
+
+        impl ::core::convert::TryFrom<::nulid::Nulid> for #non_nil {
+            type Error = #error_ty;
+
+            fn try_from(nulid: ::nulid::Nulid) -> ::core::result::Result<Self, Self::Error> {
+                Self::try_from(#name(nulid #rest))
+            }
+        }
+
+        impl ::core::convert::From<#non_nil> for #name {
+            fn from(id: #non_nil) -> Self {
+                id.0
+            }
+        }
+
+        impl ::core::convert::From<#non_nil> for ::nulid::Nulid {
+            fn from(id: #non_nil) -> Self {
+                id.0.0
+            }
+        }
+
+        impl ::core::convert::AsRef<#name> for #non_nil {
+            fn as_ref(&self) -> &#name {
+                &self.0
+            }
+        }
+
+        impl ::core::ops::Deref for #non_nil {
+            type Target = #name;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        #display_impl
+        #from_str_impl
+
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for #non_nil {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                ::serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for #non_nil {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let id = <#name as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                Self::new(id).ok_or_else(|| {
+                    <D::Error as ::serde::de::Error>::custom(::nulid::Error::NilId)
+                })
+            }
+        }
+    }
+}
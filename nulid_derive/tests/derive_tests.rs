@@ -3,6 +3,7 @@
 use core::str::FromStr;
 use nulid::{Id, Nulid};
 use std::collections::HashSet;
+use std::marker::PhantomData;
 use std::time::SystemTime;
 
 #[derive(Id)]
@@ -14,6 +15,110 @@ struct OrderId(pub Nulid);
 #[derive(Id)]
 struct ProductId(Nulid);
 
+#[derive(Id)]
+#[id(prefix = "cus_")]
+struct CustomerId(Nulid);
+
+#[derive(Id)]
+#[id(prefix = "inv_", strict)]
+struct InvoiceId(Nulid);
+
+/// Identifies a warehouse.
+#[derive(Id)]
+struct WarehouseId(Nulid);
+
+#[derive(Id)]
+#[id(generator)]
+struct ShipmentId(Nulid);
+
+#[derive(Id)]
+#[id(non_nil, prefix = "pay_")]
+struct PaymentId(Nulid);
+
+#[derive(Debug, PartialEq, Eq)]
+enum AppError {
+    InvalidId(nulid::Error),
+}
+
+impl From<nulid::Error> for AppError {
+    fn from(err: nulid::Error) -> Self {
+        Self::InvalidId(err)
+    }
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidId(err) => write!(f, "invalid id: {err}"),
+        }
+    }
+}
+
+#[derive(Id)]
+#[id(error = "AppError", prefix = "ref_", strict, non_nil)]
+struct RefundId(Nulid);
+
+struct User;
+struct Order;
+
+#[derive(Id)]
+struct TypedId<T>(Nulid, PhantomData<T>);
+
+#[derive(Id)]
+#[id(no_copy)]
+struct LabeledId(Nulid, String);
+
+#[derive(Id)]
+#[id(no_copy, zeroize)]
+struct SecretId(Nulid, String);
+
+#[derive(Id)]
+#[id(prefix = "acct_", serde = "string")]
+struct AccountId(Nulid);
+
+#[derive(Id)]
+#[id(serde = "bytes")]
+struct BlobId(Nulid);
+
+#[derive(Id)]
+#[id(serde = "uuid")]
+struct LegacyId(Nulid);
+
+#[derive(Id)]
+#[id(serde = "u128")]
+struct CounterId(Nulid);
+
+#[derive(Id)]
+#[id(skip(Debug, Copy, PartialEq_nulid, PartialOrd_nulid))]
+struct SessionId(Nulid);
+
+impl core::fmt::Debug for SessionId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("SessionId(<redacted>)")
+    }
+}
+
+#[derive(Id)]
+#[id(only(From, Display, FromStr, Debug, Clone, Copy, PartialEq, Eq))]
+struct TraceId(Nulid);
+
+#[derive(Id)]
+#[id(prefix = "inv_", redact)]
+struct InviteId(Nulid);
+
+#[derive(Id)]
+#[id(redact)]
+struct TokenId(Nulid);
+
+// Coexists with the generated `TryFrom<&str>` because `TryFrom` is not in `only`.
+impl TryFrom<&str> for TraceId {
+    type Error = &'static str;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse().map_err(|_| "invalid trace id")
+    }
+}
+
 #[test]
 fn test_try_from_str() {
     let nulid = Nulid::new().unwrap();
@@ -556,6 +661,18 @@ fn test_nil() {
     assert_eq!(user_id.as_u128(), 0);
 }
 
+#[test]
+fn test_nil_min_max_consts() {
+    const NIL: UserId = UserId::NIL;
+
+    assert_eq!(NIL, UserId::nil());
+    assert!(NIL.is_nil());
+    assert_eq!(UserId::MIN, UserId::min());
+    assert_eq!(UserId::MAX, UserId::max());
+    assert_eq!(TypedId::<User>::MAX.as_u128(), u128::MAX);
+    assert!(TypedId::<Order>::NIL.is_nil());
+}
+
 #[test]
 fn test_from_datetime() {
     let time = SystemTime::now();
@@ -690,6 +807,235 @@ fn test_all_trait_conversions() {
     assert_eq!(original, from_slice);
 }
 
+#[test]
+fn test_prefix_display_and_parse() {
+    let nulid = Nulid::new().unwrap();
+    let customer_id = CustomerId::from(nulid);
+
+    let s = customer_id.to_string();
+    assert_eq!(s, format!("cus_{nulid}"));
+    assert_eq!(CustomerId::PREFIX, "cus_");
+    assert_eq!(s.parse::<CustomerId>().unwrap(), customer_id);
+    assert_eq!(CustomerId::try_from(s).unwrap(), customer_id);
+}
+
+#[test]
+fn test_prefix_lenient_accepts_bare() {
+    let nulid = Nulid::new().unwrap();
+    let customer_id = CustomerId::try_from(nulid.to_string().as_str()).unwrap();
+    assert_eq!(customer_id, nulid);
+    assert!(CustomerId::from_str(&format!("inv_{nulid}")).is_err());
+}
+
+#[test]
+fn test_prefix_strict_rejects_bare() {
+    let nulid = Nulid::new().unwrap();
+    assert_eq!(
+        InvoiceId::from_str(&nulid.to_string()),
+        Err(nulid::Error::InvalidPrefix { expected: "inv_" })
+    );
+
+    let invoice_id: InvoiceId = format!("inv_{nulid}").parse().unwrap();
+    assert_eq!(invoice_id, nulid);
+}
+
+#[test]
+fn test_skip_uses_custom_impls() {
+    let nulid = Nulid::new().unwrap();
+    let session_id = SessionId::from(nulid);
+
+    assert_eq!(format!("{session_id:?}"), "SessionId(<redacted>)");
+    assert_eq!(session_id.clone(), session_id);
+    assert_eq!(session_id.to_string(), nulid.to_string());
+}
+
+#[test]
+fn test_zeroize() {
+    use zeroize::{Zeroize, ZeroizeOnDrop};
+
+    fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+    assert_zeroize_on_drop::<SecretId>();
+
+    let nulid = Nulid::new().unwrap();
+    let mut secret = SecretId::from(nulid);
+    secret.1.push_str("label");
+    let moved: Nulid = secret.clone().into();
+    assert_eq!(moved, nulid);
+
+    secret.zeroize();
+    assert!(secret.is_nil());
+    assert_eq!(secret.1, "label");
+
+    let mut account_id = AccountId::from(nulid);
+    account_id.zeroize();
+    assert!(account_id.is_nil());
+}
+
+#[test]
+fn test_redact() {
+    let nulid: Nulid = "01GZTV7EQ056J0E6N276XD6F3D".parse().unwrap();
+
+    let invite_id = InviteId::from(nulid);
+    assert_eq!(format!("{invite_id:?}"), "InviteId(inv_01GZTV…6F3D)");
+    assert_eq!(invite_id.display_redacted().to_string(), "inv_01GZTV…6F3D");
+    assert_eq!(invite_id.to_string(), format!("inv_{nulid}"));
+
+    let token_id = TokenId::from(nulid);
+    assert_eq!(format!("{token_id:?}"), "TokenId(01GZTV…6F3D)");
+    assert_eq!(token_id.to_string().parse::<TokenId>().unwrap(), token_id);
+}
+
+#[test]
+fn test_only_generates_listed_impls() {
+    let nulid = Nulid::new().unwrap();
+    let trace_id = TraceId::try_from(nulid.to_string().as_str()).unwrap();
+
+    assert!(trace_id == TraceId::from(nulid));
+    assert_eq!(Nulid::from(trace_id).to_string(), trace_id.to_string());
+    assert_eq!(TraceId::try_from("invalid").err(), Some("invalid trace id"));
+}
+
+#[test]
+fn test_typed_generator_monotonic() {
+    let generator = ShipmentIdGenerator::new();
+    assert!(generator.last().is_none());
+
+    let ids: Vec<ShipmentId> = (0..100).map(|_| generator.generate().unwrap()).collect();
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(generator.last(), ids.last().copied());
+
+    generator.reset();
+    assert!(generator.last().is_none());
+}
+
+#[test]
+fn test_typed_generator_with_deps() {
+    use nulid::generator::{MockClock, SequentialRng, WithNodeId};
+
+    let generator = ShipmentIdGenerator::with_node_id(7);
+    assert_eq!(generator.node_id(), Some(7));
+
+    let clock = MockClock::new(1_000);
+    let inner =
+        nulid::Generator::with_deps_and_node_id(&clock, SequentialRng::new(), WithNodeId::new(3));
+    let generator = ShipmentIdGenerator::from_generator(inner);
+    let id = generator.generate().unwrap();
+    assert_eq!(id.nanos(), 1_000);
+    assert_eq!(generator.inner().node_id(), Some(3));
+}
+
+#[test]
+fn test_phantom_tagged_wrapper() {
+    let nulid = Nulid::new().unwrap();
+    let user_id = TypedId::<User>::from(nulid);
+    let order_id: TypedId<Order> = nulid.to_string().parse().unwrap();
+
+    assert_eq!(user_id, nulid);
+    assert_eq!(order_id, nulid);
+    assert_eq!(user_id.to_string(), order_id.to_string());
+    assert_eq!(Nulid::from(order_id), nulid);
+
+    let copied = user_id;
+    assert_eq!(copied, user_id);
+    assert!(TypedId::<User>::default().is_nil());
+    assert_eq!(TypedId::<Order>::from_u128(7).as_u128(), 7);
+    assert_eq!(
+        format!("{:?}", TypedId::<User>::nil()),
+        format!("TypedId({:?})", Nulid::nil())
+    );
+}
+
+#[test]
+fn test_no_copy_with_metadata() {
+    let nulid = Nulid::new().unwrap();
+    let mut labeled = LabeledId::from(nulid);
+    assert!(labeled.1.is_empty());
+
+    labeled.1.push_str("primary");
+    let cloned = labeled.clone();
+    assert_eq!(cloned.1, "primary");
+    assert_eq!(cloned, labeled);
+
+    // Metadata takes no part in equality or formatting
+    assert_eq!(LabeledId::from(nulid), labeled);
+    assert_eq!(labeled.to_string(), nulid.to_string());
+
+    let moved: Nulid = labeled.into();
+    assert_eq!(moved, nulid);
+    assert!(LabeledId::nil().is_nil());
+}
+
+#[test]
+fn test_non_nil_rejects_nil() {
+    assert!(NonNilPaymentId::new(PaymentId::NIL).is_none());
+    assert_eq!(
+        NonNilPaymentId::try_from(PaymentId::nil()),
+        Err(nulid::Error::NilId)
+    );
+    assert_eq!(
+        NonNilPaymentId::try_from(Nulid::nil()),
+        Err(nulid::Error::NilId)
+    );
+    assert_eq!(
+        format!("pay_{}", Nulid::nil()).parse::<NonNilPaymentId>(),
+        Err(nulid::Error::NilId)
+    );
+}
+
+#[test]
+fn test_custom_error_type() {
+    let refund_id = RefundId::new().unwrap();
+    let parsed: Result<RefundId, AppError> = refund_id.to_string().parse();
+    assert_eq!(parsed, Ok(refund_id));
+
+    let bare = Nulid::from(refund_id).to_string();
+    assert_eq!(
+        bare.parse::<RefundId>(),
+        Err(AppError::InvalidId(nulid::Error::InvalidPrefix {
+            expected: "ref_"
+        }))
+    );
+    assert!(matches!(
+        RefundId::try_from("ref_invalid"),
+        Err(AppError::InvalidId(e)) if matches!(e.reason(), nulid::Error::InvalidLength { .. })
+    ));
+    assert!(matches!(
+        RefundId::try_from(String::from("ref_invalid")),
+        Err(AppError::InvalidId(_))
+    ));
+    assert!(matches!(
+        RefundId::try_from(&[0u8; 3][..]),
+        Err(AppError::InvalidId(_))
+    ));
+
+    assert_eq!(
+        NonNilRefundId::try_from(RefundId::NIL),
+        Err(AppError::InvalidId(nulid::Error::NilId))
+    );
+    assert_eq!(
+        format!("ref_{}", Nulid::nil()).parse::<NonNilRefundId>(),
+        Err(AppError::InvalidId(nulid::Error::NilId))
+    );
+}
+
+#[test]
+fn test_non_nil_wraps_id() {
+    let payment_id = PaymentId::new().unwrap();
+    let non_nil = NonNilPaymentId::new(payment_id).unwrap();
+
+    assert_eq!(non_nil.get(), payment_id);
+    assert_eq!(*non_nil.as_id(), payment_id);
+    assert_eq!(PaymentId::from(non_nil), payment_id);
+    assert_eq!(Nulid::from(non_nil), Nulid::from(payment_id));
+    assert_eq!(non_nil.nanos(), payment_id.nanos());
+    assert_eq!(non_nil.to_string(), payment_id.to_string());
+    assert_eq!(non_nil.to_string().parse::<NonNilPaymentId>(), Ok(non_nil));
+
+    let generated = NonNilPaymentId::generate().unwrap();
+    assert!(!generated.is_nil());
+    assert!(generated > non_nil);
+}
+
 // ============================================================================
 // Feature-gated trait tests
 // ============================================================================
@@ -698,6 +1044,20 @@ fn test_all_trait_conversions() {
 mod serde_tests {
     use super::*;
 
+    #[test]
+    fn test_serde_non_nil() {
+        let non_nil = NonNilPaymentId::generate().unwrap();
+        let json = serde_json::to_string(&non_nil).unwrap();
+        assert_eq!(json, serde_json::to_string(&non_nil.get()).unwrap());
+        assert_eq!(
+            serde_json::from_str::<NonNilPaymentId>(&json).unwrap(),
+            non_nil
+        );
+
+        let nil_json = serde_json::to_string(&PaymentId::NIL).unwrap();
+        assert!(serde_json::from_str::<NonNilPaymentId>(&nil_json).is_err());
+    }
+
     #[test]
     fn test_serde_json_roundtrip() {
         let user_id = UserId::new().unwrap();
@@ -723,6 +1083,77 @@ mod serde_tests {
         let deserialized: UserId = serde_json::from_str(&json).unwrap();
         assert_eq!(nulid, Nulid::from(deserialized));
     }
+
+    #[test]
+    fn test_serde_prefixed() {
+        let nulid = Nulid::new().unwrap();
+        let invoice_id = InvoiceId::from(nulid);
+
+        let json = serde_json::to_string(&invoice_id).unwrap();
+        assert_eq!(json, format!("\"inv_{nulid}\""));
+        assert_eq!(
+            serde_json::from_str::<InvoiceId>(&json).unwrap(),
+            invoice_id
+        );
+        assert!(serde_json::from_str::<InvoiceId>(&format!("\"{nulid}\"")).is_err());
+
+        let encoded =
+            bincode::serde::encode_to_vec(invoice_id, bincode::config::standard()).unwrap();
+        let (decoded, _): (InvoiceId, usize) =
+            bincode::serde::decode_from_slice(&encoded, bincode::config::standard()).unwrap();
+        assert_eq!(decoded, invoice_id);
+    }
+
+    #[test]
+    fn test_serde_phantom_tagged() {
+        let user_id = TypedId::<User>::new().unwrap();
+        let json = serde_json::to_string(&user_id).unwrap();
+        let deserialized: TypedId<User> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, user_id);
+    }
+
+    #[test]
+    fn test_serde_pinned_string() {
+        let account_id = AccountId::new().unwrap();
+        let config = bincode::config::standard();
+
+        let encoded = bincode::serde::encode_to_vec(account_id, config).unwrap();
+        let (as_string, _): (String, usize) =
+            bincode::serde::decode_from_slice(&encoded, config).unwrap();
+        assert_eq!(as_string, account_id.to_string());
+
+        let (decoded, _): (AccountId, usize) =
+            bincode::serde::decode_from_slice(&encoded, config).unwrap();
+        assert_eq!(decoded, account_id);
+    }
+
+    #[test]
+    fn test_serde_pinned_bytes() {
+        let blob_id = BlobId::new().unwrap();
+        let json = serde_json::to_string(&blob_id).unwrap();
+        let bytes: Vec<u8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(bytes, blob_id.to_bytes());
+        assert_eq!(serde_json::from_str::<BlobId>(&json).unwrap(), blob_id);
+    }
+
+    #[test]
+    fn test_serde_pinned_uuid() {
+        let legacy_id = LegacyId::new().unwrap();
+        let json = serde_json::to_string(&legacy_id).unwrap();
+        assert_eq!(json, format!("\"{}\"", legacy_id.to_uuid()));
+        assert_eq!(serde_json::from_str::<LegacyId>(&json).unwrap(), legacy_id);
+    }
+
+    #[test]
+    fn test_serde_pinned_u128() {
+        let counter_id = CounterId::from_u128(42);
+        let json = serde_json::to_string(&counter_id).unwrap();
+        assert_eq!(json, "42");
+        assert_eq!(
+            serde_json::from_str::<CounterId>(&json).unwrap(),
+            counter_id
+        );
+    }
 }
 
 #[cfg(feature = "uuid")]
@@ -816,3 +1247,500 @@ mod postgres_types_tests {
         assert_eq!(nulid, Nulid::from(deserialized));
     }
 }
+
+#[cfg(feature = "diesel-sqlite")]
+mod diesel_tests {
+    use super::*;
+    use diesel::prelude::*;
+    use diesel::sql_types::{Binary, Text};
+    use diesel::sqlite::SqliteConnection;
+
+    diesel::table! {
+        orders (id) {
+            id -> Binary,
+            user_id -> Nullable<Text>,
+        }
+    }
+
+    fn connection() -> SqliteConnection {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        diesel::sql_query("CREATE TABLE orders (id BLOB PRIMARY KEY, user_id TEXT)")
+            .execute(&mut conn)
+            .unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_diesel_select_round_trip() {
+        let mut conn = connection();
+        let user_id = UserId::new().unwrap();
+
+        let read: UserId = diesel::select(user_id.into_sql::<Binary>())
+            .get_result(&mut conn)
+            .unwrap();
+        assert_eq!(read, user_id);
+
+        let read: UserId = diesel::select((&user_id).into_sql::<Text>())
+            .get_result(&mut conn)
+            .unwrap();
+        assert_eq!(read, user_id);
+    }
+
+    #[test]
+    fn test_diesel_table_columns() {
+        let mut conn = connection();
+        let user_id = UserId::new().unwrap();
+        let order_ids: Vec<OrderId> = (0..3).map(|_| OrderId::new().unwrap()).collect();
+
+        for (i, order_id) in order_ids.iter().enumerate() {
+            diesel::insert_into(orders::table)
+                .values((
+                    orders::id.eq(order_id),
+                    orders::user_id.eq((i == 1).then_some(user_id)),
+                ))
+                .execute(&mut conn)
+                .unwrap();
+        }
+
+        let owned: Vec<OrderId> = orders::table
+            .filter(orders::user_id.eq(user_id))
+            .select(orders::id)
+            .load(&mut conn)
+            .unwrap();
+        assert_eq!(owned, [order_ids[1]]);
+
+        let owners: Vec<Option<UserId>> = orders::table
+            .order(orders::id)
+            .select(orders::user_id)
+            .load(&mut conn)
+            .unwrap();
+        assert_eq!(owners, [None, Some(user_id), None]);
+    }
+
+    #[test]
+    fn test_diesel_generic_wrapper() {
+        let mut conn = connection();
+        let user_id = TypedId::<User>::new().unwrap();
+        let read: TypedId<User> = diesel::select(user_id.into_sql::<Binary>())
+            .get_result(&mut conn)
+            .unwrap();
+        assert_eq!(read, user_id);
+    }
+}
+
+#[cfg(feature = "sea-orm")]
+mod sea_orm_tests {
+    use super::*;
+    use sea_orm::TryFromU64;
+    use sea_orm::sea_query::{ColumnType, Nullable, Value, ValueType};
+
+    #[test]
+    fn test_sea_orm_value_round_trip() {
+        let user_id = UserId::new().unwrap();
+        let value: Value = user_id.into();
+        assert_eq!(value, Value::from(Nulid::from(user_id)));
+        assert_eq!(<UserId as ValueType>::try_from(value).unwrap(), user_id);
+
+        let value: Value = None::<UserId>.into();
+        assert_eq!(value, UserId::null());
+        assert_eq!(
+            <Option<UserId> as ValueType>::try_from(value).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_sea_orm_value_type_metadata() {
+        assert_eq!(UserId::type_name(), "UserId");
+        assert_eq!(OrderId::type_name(), "OrderId");
+        assert_eq!(UserId::column_type(), ColumnType::Uuid);
+        assert!(<UserId as ValueType>::try_from(Value::String(None)).is_err());
+        assert!(UserId::try_from_u64(1).is_err());
+    }
+}
+
+#[cfg(feature = "utoipa")]
+mod utoipa_tests {
+    use super::*;
+    use utoipa::{PartialSchema, ToSchema};
+
+    #[test]
+    fn test_utoipa_schema_matches_nulid() {
+        let schema = serde_json::to_value(UserId::schema()).unwrap();
+        assert_eq!(schema, serde_json::to_value(Nulid::schema()).unwrap());
+        assert_eq!(schema["format"], "nulid");
+    }
+
+    #[test]
+    fn test_utoipa_schema_name() {
+        assert_eq!(UserId::name(), "UserId");
+        assert_eq!(OrderId::name(), "OrderId");
+    }
+
+    #[test]
+    fn test_utoipa_schema_description() {
+        let schema = serde_json::to_value(WarehouseId::schema()).unwrap();
+        assert_eq!(schema["description"], "Identifies a warehouse.");
+        assert_eq!(schema["format"], "nulid");
+    }
+
+    #[test]
+    fn test_utoipa_schema_prefixed() {
+        let schema = serde_json::to_value(InvoiceId::schema()).unwrap();
+        assert_eq!(
+            schema["pattern"],
+            "^(?:inv_)[0-7][0-9A-HJKMNP-TV-Za-hjkmnp-tv-z]{25}$"
+        );
+        assert_eq!(schema["minLength"], 30);
+        assert_eq!(schema["maxLength"], 30);
+
+        let schema = serde_json::to_value(CustomerId::schema()).unwrap();
+        assert_eq!(
+            schema["pattern"],
+            "^(?:cus_)?[0-7][0-9A-HJKMNP-TV-Za-hjkmnp-tv-z]{25}$"
+        );
+        assert_eq!(schema["minLength"], 26);
+        assert_eq!(schema["maxLength"], 30);
+    }
+}
+
+#[cfg(feature = "schemars")]
+mod schemars_tests {
+    use super::*;
+    use schemars::{JsonSchema, schema_for};
+
+    #[test]
+    fn test_schemars_schema_name() {
+        assert_eq!(UserId::schema_name(), "UserId");
+        assert_eq!(WarehouseId::schema_name(), "WarehouseId");
+        assert_ne!(UserId::schema_id(), OrderId::schema_id());
+    }
+
+    #[test]
+    fn test_schemars_schema_matches_nulid() {
+        let schema = schema_for!(UserId);
+        assert_eq!(schema.get("type").unwrap(), "string");
+        assert_eq!(schema.get("format").unwrap(), "nulid");
+        assert_eq!(schema.get("title").unwrap(), "UserId");
+    }
+
+    #[test]
+    fn test_schemars_schema_description() {
+        let schema = schema_for!(WarehouseId);
+        assert_eq!(
+            schema.get("description").unwrap(),
+            "Identifies a warehouse."
+        );
+    }
+
+    #[test]
+    fn test_schemars_schema_prefixed() {
+        let schema = schema_for!(InvoiceId);
+        assert_eq!(
+            schema.get("pattern").unwrap(),
+            "^(?:inv_)[0-7][0-9A-HJKMNP-TV-Za-hjkmnp-tv-z]{25}$"
+        );
+        assert_eq!(schema.get("minLength").unwrap(), 30);
+        assert_eq!(schema.get("maxLength").unwrap(), 30);
+    }
+}
+
+#[cfg(feature = "async-graphql")]
+mod async_graphql_tests {
+    use super::*;
+    use async_graphql::{InputType, OutputType, ScalarType, Value};
+
+    #[test]
+    fn test_async_graphql_round_trip() {
+        let user_id = UserId::new().unwrap();
+        let value = ScalarType::to_value(&user_id);
+        assert_eq!(value, Value::String(user_id.to_string()));
+        assert_eq!(<UserId as ScalarType>::parse(value).unwrap(), user_id);
+    }
+
+    #[test]
+    fn test_async_graphql_invalid_input() {
+        assert!(<UserId as ScalarType>::parse(Value::from("invalid")).is_err());
+        assert!(<UserId as ScalarType>::parse(Value::from(42)).is_err());
+    }
+
+    #[test]
+    fn test_async_graphql_type_name() {
+        assert_eq!(<UserId as InputType>::type_name(), "UserId");
+        assert_eq!(<WarehouseId as OutputType>::type_name(), "WarehouseId");
+    }
+
+    #[test]
+    fn test_async_graphql_prefixed() {
+        let invoice_id = InvoiceId::new().unwrap();
+        let value = ScalarType::to_value(&invoice_id);
+        assert_eq!(value, Value::String(invoice_id.to_string()));
+        assert_eq!(<InvoiceId as ScalarType>::parse(value).unwrap(), invoice_id);
+        let bare = Value::String(Nulid::from(invoice_id).to_string());
+        assert!(<InvoiceId as ScalarType>::parse(bare).is_err());
+    }
+}
+
+#[cfg(feature = "juniper")]
+mod juniper_tests {
+    use super::*;
+    use juniper::{DefaultScalarValue, FromInputValue, InputValue, ToInputValue};
+
+    #[test]
+    fn test_juniper_input_round_trip() {
+        let user_id = UserId::new().unwrap();
+        let input: InputValue<DefaultScalarValue> = user_id.to_input_value();
+        assert_eq!(input.as_string_value(), Some(user_id.to_string().as_str()));
+        assert_eq!(UserId::from_input_value(&input), Ok(user_id));
+        assert_eq!(<UserId as juniper::GraphQLType>::name(&()), Some("UserId"));
+    }
+
+    #[test]
+    fn test_juniper_invalid_input() {
+        let input: InputValue<DefaultScalarValue> = InputValue::scalar("invalid".to_string());
+        assert!(UserId::from_input_value(&input).is_err());
+    }
+
+    #[test]
+    fn test_juniper_prefixed() {
+        let invoice_id = InvoiceId::new().unwrap();
+        let input: InputValue<DefaultScalarValue> = invoice_id.to_input_value();
+        assert_eq!(
+            input.as_string_value(),
+            Some(invoice_id.to_string().as_str())
+        );
+        assert_eq!(InvoiceId::from_input_value(&input), Ok(invoice_id));
+    }
+}
+
+#[cfg(feature = "axum")]
+mod axum_tests {
+    use super::*;
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::get;
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn show(id: UserId) -> UserId {
+        id
+    }
+
+    async fn show_invoice(id: InvoiceId) -> InvoiceId {
+        id
+    }
+
+    async fn show_refund(id: RefundId) -> RefundId {
+        id
+    }
+
+    fn call(uri: &str) -> (StatusCode, String) {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let app = Router::new()
+                .route("/users/{id}", get(show))
+                .route("/invoices/{id}", get(show_invoice))
+                .route("/refunds/{id}", get(show_refund));
+            let response = app
+                .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            let status = response.status();
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            (status, String::from_utf8(body.to_vec()).unwrap())
+        })
+    }
+
+    #[test]
+    fn test_axum_extract_and_respond() {
+        let user_id = UserId::new().unwrap();
+        let (status, body) = call(&format!("/users/{user_id}"));
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, user_id.to_string());
+    }
+
+    #[test]
+    fn test_axum_invalid_id() {
+        let (status, body) = call("/users/invalid");
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body.starts_with("Invalid NULID `invalid`:"), "{body}");
+    }
+
+    #[test]
+    fn test_axum_prefixed() {
+        let invoice_id = InvoiceId::new().unwrap();
+        let (status, body) = call(&format!("/invoices/{invoice_id}"));
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, invoice_id.to_string());
+
+        let (status, _) = call(&format!("/invoices/{}", Nulid::from(invoice_id)));
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_axum_custom_error_type() {
+        let refund_id = RefundId::new().unwrap();
+        let (status, body) = call(&format!("/refunds/{refund_id}"));
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, refund_id.to_string());
+
+        let (status, body) = call("/refunds/invalid");
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body.starts_with("Invalid NULID `invalid`:"), "{body}");
+    }
+}
+
+#[cfg(feature = "actix-web")]
+mod actix_web_tests {
+    use super::*;
+    use actix_web::body::MessageBody;
+    use actix_web::http::StatusCode;
+    use actix_web::test::{TestRequest, call_service, init_service};
+    use actix_web::{App, web};
+    use nulid::features::actix_web::path_config;
+
+    async fn show(id: UserId) -> UserId {
+        id
+    }
+
+    async fn show_invoice(id: InvoiceId) -> InvoiceId {
+        id
+    }
+
+    async fn pair(ids: web::Path<(UserId, OrderId)>) -> String {
+        format!("{}{}", ids.0, ids.1)
+    }
+
+    fn call(uri: &str) -> (StatusCode, String) {
+        actix_web::rt::System::new().block_on(async {
+            let app = init_service(
+                App::new()
+                    .app_data(path_config())
+                    .route("/users/{id}", web::get().to(show))
+                    .route("/invoices/{id}", web::get().to(show_invoice))
+                    .route("/pairs/{user}/{order}", web::get().to(pair)),
+            )
+            .await;
+            let response = call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+            let status = response.status();
+            let body = response.into_body().try_into_bytes().unwrap_or_default();
+            (status, String::from_utf8(body.to_vec()).unwrap())
+        })
+    }
+
+    #[test]
+    fn test_actix_web_extract_and_respond() {
+        let user_id = UserId::new().unwrap();
+        let (status, body) = call(&format!("/users/{user_id}"));
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, user_id.to_string());
+    }
+
+    #[test]
+    fn test_actix_web_invalid_id() {
+        let (status, body) = call("/users/invalid");
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body.starts_with("Invalid NULID `invalid`:"), "{body}");
+    }
+
+    #[test]
+    fn test_actix_web_path_tuple() {
+        let user_id = UserId::new().unwrap();
+        let order_id = OrderId::new().unwrap();
+        let (status, body) = call(&format!("/pairs/{user_id}/{order_id}"));
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, format!("{user_id}{order_id}"));
+
+        let (status, _) = call(&format!("/pairs/{user_id}/invalid"));
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_actix_web_prefixed() {
+        let invoice_id = InvoiceId::new().unwrap();
+        let (status, body) = call(&format!("/invoices/{invoice_id}"));
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, invoice_id.to_string());
+
+        let (status, _) = call(&format!("/invoices/{}", Nulid::from(invoice_id)));
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
+
+#[cfg(feature = "fake")]
+mod fake_tests {
+    use super::*;
+    use fake::{Dummy, Fake, Faker};
+    use nulid::features::fake::Between;
+
+    #[derive(Debug, Dummy)]
+    struct Order {
+        id: OrderId,
+        #[dummy(faker = "Between::new(1_000, 2_000)")]
+        user_id: UserId,
+    }
+
+    #[test]
+    fn test_fake_faker() {
+        let id: UserId = Faker.fake();
+        assert!(id.nanos() <= i64::MAX as u128);
+    }
+
+    #[test]
+    fn test_fake_between() {
+        let id: UserId = Between::new(10, 20).fake();
+        assert!((10..=20).contains(&id.nanos()));
+    }
+
+    #[test]
+    fn test_fake_struct_fields() {
+        let order: Order = Faker.fake();
+        assert!((1_000..=2_000).contains(&order.user_id.nanos()));
+        assert_ne!(order.id, OrderId::nil());
+    }
+}
+
+#[cfg(feature = "rkyv")]
+mod rkyv_tests {
+    use super::*;
+    use nulid::features::rkyv::ArchivedNulid;
+    use rkyv::rancor::Error;
+
+    #[derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
+    struct Order {
+        id: OrderId,
+        user_id: UserId,
+    }
+
+    #[test]
+    fn test_rkyv_roundtrip() {
+        let user_id = UserId::new().unwrap();
+        let bytes = rkyv::to_bytes::<Error>(&user_id).unwrap();
+
+        let archived = rkyv::access::<ArchivedNulid, Error>(&bytes).unwrap();
+        assert_eq!(*archived, *user_id);
+
+        let deserialized = rkyv::from_bytes::<UserId, Error>(&bytes).unwrap();
+        assert_eq!(deserialized, user_id);
+    }
+
+    #[test]
+    fn test_rkyv_struct_fields() {
+        let order = Order {
+            id: OrderId::new().unwrap(),
+            user_id: UserId::new().unwrap(),
+        };
+        let bytes = rkyv::to_bytes::<Error>(&order).unwrap();
+
+        let archived = rkyv::access::<ArchivedOrder, Error>(&bytes).unwrap();
+        assert_eq!(archived.id, *order.id);
+        assert_eq!(archived.user_id.to_nulid(), *order.user_id);
+
+        let deserialized = rkyv::from_bytes::<Order, Error>(&bytes).unwrap();
+        assert_eq!(deserialized.id, order.id);
+        assert_eq!(deserialized.user_id, order.user_id);
+    }
+}
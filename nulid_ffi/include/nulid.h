@@ -0,0 +1,80 @@
+#ifndef NULID_H
+#define NULID_H
+
+/* This file is generated by cbindgen from nulid_ffi/src/lib.rs. Do not edit it by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Length of a NULID string, excluding the trailing NUL.
+#define NULID_STRING_LEN 26
+
+// Buffer size `nulid_format` needs, including the trailing NUL.
+#define NULID_BUFFER_LEN (NULID_STRING_LEN + 1)
+
+// Result of a fallible FFI call.
+typedef enum {
+  // The call succeeded.
+  NULID_STATUS_OK = 0,
+  // A required pointer argument was null.
+  NULID_STATUS_NULL_POINTER = 1,
+  // The input string is not 26 characters long.
+  NULID_STATUS_INVALID_LENGTH = 2,
+  // The input string contains a character outside the Base32 alphabet.
+  NULID_STATUS_INVALID_CHAR = 3,
+  // The input string is not valid UTF-8.
+  NULID_STATUS_INVALID_UTF8 = 4,
+  // The output buffer is shorter than `NULID_BUFFER_LEN`.
+  NULID_STATUS_BUFFER_TOO_SMALL = 5,
+  // The system clock could not be read.
+  NULID_STATUS_CLOCK_ERROR = 6,
+  // Any other error.
+  NULID_STATUS_ERROR = 7,
+} NulidStatus;
+
+// A NULID as 16 big-endian bytes.
+typedef struct {
+  // The 128-bit value, most significant byte first.
+  uint8_t bytes[16];
+} NulidBytes;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Generates a new NULID from the system clock and a cryptographic RNG.
+//
+// # Safety
+//
+// `out` must be null or valid for writing one `NulidBytes`.
+NulidStatus nulid_new(NulidBytes *out);
+
+// Parses a NUL-terminated NULID string.
+//
+// Accepts the canonical 26-character Crockford Base32 form in either case.
+//
+// # Safety
+//
+// `s` must be null or point to a NUL-terminated string, and `out` must be
+// null or valid for writing one `NulidBytes`.
+NulidStatus nulid_parse(const char *s, NulidBytes *out);
+
+// Formats a NULID as its canonical 26-character string followed by a NUL.
+//
+// # Safety
+//
+// `buf` must be null or valid for writing `len` bytes.
+NulidStatus nulid_format(NulidBytes id, char *buf, uintptr_t len);
+
+// Compares two NULIDs, returning exactly -1, 0 or 1.
+//
+// The order is chronological, then by random bits.
+int nulid_compare(NulidBytes a, NulidBytes b);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* NULID_H */
@@ -0,0 +1,248 @@
+//! C ABI for NULID.
+//!
+//! This crate builds `libnulid_ffi` as a shared and a static library so that
+//! C, C++ and Go (cgo) services can generate, parse and format NULIDs with
+//! the same implementation as the Rust crate. The matching header is
+//! `include/nulid.h`, generated from this file with:
+//!
+//! ```text
+//! cbindgen --config nulid_ffi/cbindgen.toml --crate nulid_ffi --output nulid_ffi/include/nulid.h
+//! ```
+//!
+//! IDs cross the boundary as [`NulidBytes`], the 16-byte big-endian layout
+//! of [`Nulid::to_bytes`], so they can be stored and compared as raw bytes.
+//! Every fallible function returns a [`NulidStatus`] and writes its result
+//! through an out-pointer.
+
+use core::cmp::Ordering;
+use core::ffi::{CStr, c_char, c_int};
+use nulid::{Error, Nulid};
+
+/// Length of a NULID string, excluding the trailing NUL.
+pub const NULID_STRING_LEN: usize = 26;
+
+/// Buffer size `nulid_format` needs, including the trailing NUL.
+pub const NULID_BUFFER_LEN: usize = NULID_STRING_LEN + 1;
+
+/// A NULID as 16 big-endian bytes.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NulidBytes {
+    /// The 128-bit value, most significant byte first.
+    pub bytes: [u8; 16],
+}
+
+impl From<Nulid> for NulidBytes {
+    fn from(nulid: Nulid) -> Self {
+        Self {
+            bytes: nulid.to_bytes(),
+        }
+    }
+}
+
+impl From<NulidBytes> for Nulid {
+    fn from(id: NulidBytes) -> Self {
+        Self::from_bytes(id.bytes)
+    }
+}
+
+/// Result of a fallible FFI call.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NulidStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = 1,
+    /// The input string is not 26 characters long.
+    InvalidLength = 2,
+    /// The input string contains a character outside the Base32 alphabet.
+    InvalidChar = 3,
+    /// The input string is not valid UTF-8.
+    InvalidUtf8 = 4,
+    /// The output buffer is shorter than `NULID_BUFFER_LEN`.
+    BufferTooSmall = 5,
+    /// The system clock could not be read.
+    ClockError = 6,
+    /// Any other error.
+    Error = 7,
+}
+
+impl From<Error> for NulidStatus {
+    fn from(error: Error) -> Self {
+        match error.reason() {
+            Error::InvalidLength { .. } => Self::InvalidLength,
+            Error::InvalidChar(..) => Self::InvalidChar,
+            Error::SystemTimeError => Self::ClockError,
+            _ => Self::Error,
+        }
+    }
+}
+
+/// Generates a new NULID from the system clock and a cryptographic RNG.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writing one `NulidBytes`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nulid_new(out: *mut NulidBytes) -> NulidStatus {
+    if out.is_null() {
+        return NulidStatus::NullPointer;
+    }
+
+    match Nulid::new() {
+        Ok(nulid) => {
+            // SAFETY: `out` is non-null and the caller guarantees it is writable.
+            unsafe { out.write(nulid.into()) };
+            NulidStatus::Ok
+        }
+        Err(error) => error.into(),
+    }
+}
+
+/// Parses a NUL-terminated NULID string.
+///
+/// Accepts the canonical 26-character Crockford Base32 form in either case.
+///
+/// # Safety
+///
+/// `s` must be null or point to a NUL-terminated string, and `out` must be
+/// null or valid for writing one `NulidBytes`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nulid_parse(s: *const c_char, out: *mut NulidBytes) -> NulidStatus {
+    if s.is_null() || out.is_null() {
+        return NulidStatus::NullPointer;
+    }
+
+    // SAFETY: `s` is non-null and the caller guarantees it is NUL-terminated.
+    let Ok(s) = unsafe { CStr::from_ptr(s) }.to_str() else {
+        return NulidStatus::InvalidUtf8;
+    };
+
+    match s.parse::<Nulid>() {
+        Ok(nulid) => {
+            // SAFETY: `out` is non-null and the caller guarantees it is writable.
+            unsafe { out.write(nulid.into()) };
+            NulidStatus::Ok
+        }
+        Err(error) => error.into(),
+    }
+}
+
+/// Formats a NULID as its canonical 26-character string followed by a NUL.
+///
+/// # Safety
+///
+/// `buf` must be null or valid for writing `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nulid_format(id: NulidBytes, buf: *mut c_char, len: usize) -> NulidStatus {
+    if buf.is_null() {
+        return NulidStatus::NullPointer;
+    }
+    if len < NULID_BUFFER_LEN {
+        return NulidStatus::BufferTooSmall;
+    }
+
+    let encoded = Nulid::from(id).to_arraystring();
+    let encoded = encoded.as_str().as_bytes();
+    // SAFETY: `buf` is non-null and the caller guarantees `len` writable
+    // bytes, which covers the 26 characters and the NUL.
+    unsafe {
+        core::ptr::copy_nonoverlapping(encoded.as_ptr(), buf.cast::<u8>(), NULID_STRING_LEN);
+        buf.add(NULID_STRING_LEN).write(0);
+    }
+    NulidStatus::Ok
+}
+
+/// Compares two NULIDs, returning exactly -1, 0 or 1.
+///
+/// The order is chronological, then by random bits.
+#[unsafe(no_mangle)]
+pub extern "C" fn nulid_compare(a: NulidBytes, b: NulidBytes) -> c_int {
+    match Nulid::from(a).cmp(&Nulid::from(b)) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ptr;
+
+    fn format(id: NulidBytes) -> String {
+        let mut buf = [0 as c_char; NULID_BUFFER_LEN];
+        let status = unsafe { nulid_format(id, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(status, NulidStatus::Ok);
+        unsafe { CStr::from_ptr(buf.as_ptr()) }
+            .to_str()
+            .unwrap()
+            .to_owned()
+    }
+
+    #[test]
+    fn test_new_format_parse_round_trip() {
+        let mut id = NulidBytes { bytes: [0; 16] };
+        assert_eq!(unsafe { nulid_new(&raw mut id) }, NulidStatus::Ok);
+
+        let string = format(id);
+        assert_eq!(string, Nulid::from(id).to_string());
+
+        let c_string = std::ffi::CString::new(string.to_lowercase()).unwrap();
+        let mut parsed = NulidBytes { bytes: [0; 16] };
+        assert_eq!(
+            unsafe { nulid_parse(c_string.as_ptr(), &raw mut parsed) },
+            NulidStatus::Ok
+        );
+        assert_eq!(parsed, id);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let mut out = NulidBytes { bytes: [0; 16] };
+        let parse = |s: &CStr, out: &mut NulidBytes| unsafe { nulid_parse(s.as_ptr(), out) };
+
+        assert_eq!(parse(c"too short", &mut out), NulidStatus::InvalidLength);
+        assert_eq!(
+            parse(c"01GZTV7EQ056J0E6N276XD6F3U", &mut out),
+            NulidStatus::InvalidChar
+        );
+        assert_eq!(
+            parse(c"\xff1GZTV7EQ056J0E6N276XD6F3D", &mut out),
+            NulidStatus::InvalidUtf8
+        );
+        assert_eq!(
+            unsafe { nulid_parse(ptr::null(), &raw mut out) },
+            NulidStatus::NullPointer
+        );
+        assert_eq!(
+            unsafe { nulid_parse(c"".as_ptr(), ptr::null_mut()) },
+            NulidStatus::NullPointer
+        );
+    }
+
+    #[test]
+    fn test_format_errors() {
+        let id = NulidBytes::from(Nulid::MAX);
+        let mut buf = [0 as c_char; NULID_STRING_LEN];
+        assert_eq!(
+            unsafe { nulid_format(id, buf.as_mut_ptr(), buf.len()) },
+            NulidStatus::BufferTooSmall
+        );
+        assert_eq!(
+            unsafe { nulid_format(id, ptr::null_mut(), NULID_BUFFER_LEN) },
+            NulidStatus::NullPointer
+        );
+        assert_eq!(format(id), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+    }
+
+    #[test]
+    fn test_compare() {
+        let earlier = NulidBytes::from(Nulid::from_nanos(1, u64::MAX));
+        let later = NulidBytes::from(Nulid::from_nanos(2, 0));
+        assert_eq!(nulid_compare(earlier, later), -1);
+        assert_eq!(nulid_compare(later, earlier), 1);
+        assert_eq!(nulid_compare(later, later), 0);
+    }
+}
@@ -0,0 +1,72 @@
+//! Compile-time validation of Base32-encoded NULID strings.
+
+/// Crockford's Base32 alphabet used by the canonical encoding.
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Length of an encoded NULID.
+const NULID_STRING_LENGTH: usize = 26;
+
+/// Validates a NULID string and returns its canonical uppercase form.
+///
+/// Accepts the same input as `Nulid::from_str`: 26 characters from
+/// Crockford's alphabet in either case. The first character must be `0`-`7`,
+/// since anything larger does not fit in 128 bits.
+pub fn canonicalize(s: &str) -> Result<String, String> {
+    if s.len() != NULID_STRING_LENGTH {
+        return Err(format!(
+            "expected a {NULID_STRING_LENGTH}-character NULID, found {} characters",
+            s.chars().count()
+        ));
+    }
+
+    let canonical = s.to_ascii_uppercase();
+    if let Some((position, ch)) = canonical
+        .char_indices()
+        .find(|&(_, ch)| !u8::try_from(ch).is_ok_and(|byte| ALPHABET.contains(&byte)))
+    {
+        return Err(format!(
+            "invalid character `{ch}` at position {position}; expected Crockford Base32"
+        ));
+    }
+    if canonical.as_bytes()[0] > b'7' {
+        return Err(format!(
+            "`{s}` overflows 128 bits; the first character must be `0` to `7`"
+        ));
+    }
+
+    Ok(canonical)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonicalize_valid() {
+        assert_eq!(
+            canonicalize("01JCQZ8ABCDEFGHJKMNPQRSTVW").as_deref(),
+            Ok("01JCQZ8ABCDEFGHJKMNPQRSTVW")
+        );
+        assert_eq!(
+            canonicalize("01jcqz8abcdefghjkmnpqrstvw").as_deref(),
+            Ok("01JCQZ8ABCDEFGHJKMNPQRSTVW")
+        );
+        assert!(canonicalize("7ZZZZZZZZZZZZZZZZZZZZZZZZZ").is_ok());
+    }
+
+    #[test]
+    fn test_canonicalize_rejects_invalid() {
+        for input in [
+            "",
+            "01JCQZ8ABCDEFGHJKMNPQRSTV",
+            "01JCQZ8ABCDEFGHJKMNPQRSTVWX",
+            "01JCQZ8ABCDEFGHJKMNPQRSTVU",
+            "01JCQZ8ABCDEFGHJKMNPQRSTVI",
+            "01JCQZ8ABCDEFGHJKMNPQRSTV-",
+            "8ZZZZZZZZZZZZZZZZZZZZZZZZZ",
+            "01JCQZ8ABCDEFGHJKMNPQRSTé",
+        ] {
+            assert!(canonicalize(input).is_err(), "{input}");
+        }
+    }
+}
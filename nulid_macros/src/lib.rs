@@ -5,17 +5,95 @@
 //! # Examples
 //!
 //! ```ignore
-//! use nulid::nulid;
+//! use nulid::{nulid, nulid_str};
 //!
 //! // Generate a new NULID (panics on error)
 //! let id = nulid!();
 //!
 //! // Generate with explicit error handling
 //! let id = nulid!(?);
+//!
+//! // Generate a typed ID
+//! let user_id = nulid!(UserId);
+//!
+//! // Anchor the timestamp at compile time
+//! let id = nulid!(at: "2024-01-01T00:00:00Z");
+//!
+//! // Validate a string constant at compile time
+//! const ADMIN: &str = nulid_str!("01JCQZ8ABCDEFGHJKMNPQRSTVW");
 //! ```
 
 use proc_macro::TokenStream;
+use proc_macro2::Literal;
 use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{LitStr, Token, Type, parse_macro_input};
+
+mod base32;
+mod timestamp;
+
+/// Parsed input of `nulid!`.
+enum NulidInput {
+    /// `nulid!()` or `nulid!(?)`.
+    Generate { fallible: bool },
+    /// `nulid!(UserId)` or `nulid!(UserId?)`.
+    Typed { ty: Box<Type>, fallible: bool },
+    /// `nulid!(at: "...")`, optionally followed by `, random` or `, random?`.
+    At {
+        timestamp: LitStr,
+        random: Option<bool>,
+    },
+}
+
+impl Parse for NulidInput {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(Self::Generate { fallible: false });
+        }
+        if input.peek(Token![?]) {
+            input.parse::<Token![?]>()?;
+            return Ok(Self::Generate { fallible: true });
+        }
+
+        if !(input.peek(syn::Ident) && input.peek2(Token![:]) && !input.peek2(Token![::])) {
+            let ty = input
+                .parse::<Type>()
+                .map_err(|error| syn::Error::new(error.span(), USAGE))?;
+            let fallible = input.parse::<Option<Token![?]>>()?.is_some();
+            if !input.is_empty() {
+                return Err(input.error(USAGE));
+            }
+            return Ok(Self::Typed {
+                ty: Box::new(ty),
+                fallible,
+            });
+        }
+
+        let keyword: syn::Ident = input.parse()?;
+        if keyword != "at" {
+            return Err(syn::Error::new(keyword.span(), USAGE));
+        }
+        input.parse::<Token![:]>()?;
+        let timestamp: LitStr = input.parse()?;
+
+        let mut random = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let mode: syn::Ident = input.parse()?;
+            if mode != "random" {
+                return Err(syn::Error::new(
+                    mode.span(),
+                    "expected `random` or `random?` after the timestamp",
+                ));
+            }
+            random = Some(input.parse::<Option<Token![?]>>()?.is_some());
+        }
+
+        Ok(Self::At { timestamp, random })
+    }
+}
+
+/// Usage summary shown for unexpected input.
+const USAGE: &str = "expected `?`, a type, or `at: \"<RFC 3339 timestamp>\"`; usage: nulid!(), nulid!(?), nulid!(UserId), nulid!(UserId?), or nulid!(at: \"2024-01-01T00:00:00Z\")";
 
 /// Generates a new NULID at compile time.
 ///
@@ -25,6 +103,16 @@ use quote::quote;
 ///
 /// - `nulid!()` - Generates a NULID, panicking on error (use in contexts where failure is acceptable)
 /// - `nulid!(?)` - Returns `Result<Nulid, Error>` for explicit error handling
+/// - `nulid!(UserId)` - Generates a NULID and converts it with `From<Nulid>`, panicking on error;
+///   `nulid!(UserId?)` returns `Result<UserId, Error>` instead
+/// - `nulid!(at: "2024-01-01T00:00:00Z")` - NULID with a fixed timestamp and zero random bits,
+///   usable in `const` items
+/// - `nulid!(at: "...", random)` - Fixed timestamp with random bits generated at runtime,
+///   panicking on error; `random?` returns `Result<Nulid, Error>` instead
+///
+/// Timestamps are RFC 3339 with `Z` or a `±HH:MM` offset and up to nine
+/// fractional second digits. They are parsed at compile time, so a malformed
+/// or pre-epoch timestamp is a compile error.
 ///
 /// # Examples
 ///
@@ -43,47 +131,139 @@ use quote::quote;
 ///
 /// // In a function that can handle errors
 /// let id = nulid!(?).expect("Failed to generate NULID");
+///
+/// // Typed IDs, e.g. wrappers deriving `Id`
+/// let user_id = nulid!(UserId);
+/// let order_id = nulid!(OrderId?)?;
+///
+/// // Time-anchored fixtures
+/// const LAUNCH: nulid::Nulid = nulid!(at: "2024-01-01T00:00:00Z");
+/// let order_id = nulid!(at: "2024-01-01T09:30:00.250+01:00", random);
+/// assert_eq!(order_id.nanos(), LAUNCH.nanos() + 30_600_250_000_000);
 /// ```
 ///
 /// # Panics
 ///
-/// The `nulid!()` variant (without `?`) will panic if NULID generation fails,
-/// which can happen if the system's random number generator is unavailable.
+/// The `nulid!()`, `nulid!(UserId)` and `nulid!(at: "...", random)` variants panic if NULID
+/// generation fails, which can happen if the system's random number
+/// generator is unavailable.
 ///
-/// Use `nulid!(?)` if you need to handle errors gracefully.
+/// Use `nulid!(?)`, `nulid!(UserId?)` or `random?` if you need to handle errors gracefully.
 #[proc_macro]
 pub fn nulid(input: TokenStream) -> TokenStream {
-    // Check if "?" was passed as an argument for fallible mode
-    let fallible_mode = if input.is_empty() {
-        false
-    } else {
-        // Parse as a single token
-        let input_str = input.to_string();
-        let trimmed = input_str.trim();
-
-        if trimmed == "?" {
-            true
-        } else {
-            return syn::Error::new(
-                proc_macro2::Span::call_site(),
-                "expected `?` or no argument; usage: nulid!() or nulid!(?)",
-            )
-            .to_compile_error()
-            .into();
-        }
-    };
-
-    let expanded = if fallible_mode {
-        // Return Result for error handling
-        quote! {
+    let expanded = match parse_macro_input!(input as NulidInput) {
+        NulidInput::Generate { fallible: true } => quote! {
             ::nulid::Nulid::new()
-        }
-    } else {
-        // Panic on error for convenience
-        quote! {
+        },
+        NulidInput::Generate { fallible: false } => quote! {
             ::nulid::Nulid::new().expect("Failed to generate NULID")
+        },
+        NulidInput::Typed { ty, fallible: true } => quote! {
+            ::nulid::Nulid::new().map(<#ty as ::core::convert::From<::nulid::Nulid>>::from)
+        },
+        NulidInput::Typed {
+            ty,
+            fallible: false,
+        } => quote! {
+            <#ty as ::core::convert::From<::nulid::Nulid>>::from(
+                ::nulid::Nulid::new().expect("Failed to generate NULID"),
+            )
+        },
+        NulidInput::At { timestamp, random } => {
+            let nanos = match timestamp::parse_rfc3339(&timestamp.value()) {
+                Ok(nanos) => nanos,
+                Err(message) => {
+                    return syn::Error::new(timestamp.span(), message)
+                        .to_compile_error()
+                        .into();
+                }
+            };
+            let secs =
+                Literal::u64_suffixed(u64::try_from(nanos / 1_000_000_000).unwrap_or(u64::MAX));
+            let subsec = Literal::u32_suffixed(u32::try_from(nanos % 1_000_000_000).unwrap_or(0));
+            let time = quote! {
+                ::std::time::UNIX_EPOCH + ::core::time::Duration::new(#secs, #subsec)
+            };
+            match random {
+                None => {
+                    let nanos = Literal::u128_suffixed(nanos);
+                    quote! { ::nulid::Nulid::from_nanos(#nanos, 0) }
+                }
+                Some(true) => quote! { ::nulid::Nulid::from_datetime(#time) },
+                Some(false) => quote! {
+                    ::nulid::Nulid::from_datetime(#time).expect("Failed to generate NULID")
+                },
+            }
         }
     };
 
     TokenStream::from(expanded)
 }
+
+/// Validates a NULID string literal at compile time.
+///
+/// Expands to a `&'static str` holding the canonical (uppercase) encoding of
+/// the literal, for config keys, match arms and documentation examples that
+/// want the string rather than the parsed value. Input is accepted in either
+/// case, as `Nulid::from_str` does; anything that would fail to parse, or
+/// would overflow 128 bits, is a compile error.
+///
+/// # Examples
+///
+/// ```ignore
+/// use nulid::{nulid_str, Nulid};
+///
+/// const SYSTEM_USER: &str = nulid_str!("01JCQZ8ABCDEFGHJKMNPQRSTVW");
+///
+/// fn role(id: &str) -> &'static str {
+///     match id {
+///         SYSTEM_USER => "system",
+///         _ => "user",
+///     }
+/// }
+///
+/// assert_eq!(nulid_str!("01jcqz8abcdefghjkmnpqrstvw"), SYSTEM_USER);
+/// let id: Nulid = SYSTEM_USER.parse()?;
+/// ```
+#[proc_macro]
+pub fn nulid_str(input: TokenStream) -> TokenStream {
+    let literal = parse_macro_input!(input as LitStr);
+    match base32::canonicalize(&literal.value()) {
+        Ok(canonical) => {
+            let canonical = LitStr::new(&canonical, literal.span());
+            TokenStream::from(quote! { #canonical })
+        }
+        Err(message) => syn::Error::new(literal.span(), message)
+            .to_compile_error()
+            .into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_typed_input() {
+        let input: NulidInput = syn::parse_str("UserId").unwrap();
+        assert!(matches!(
+            input,
+            NulidInput::Typed {
+                fallible: false,
+                ..
+            }
+        ));
+
+        let input: NulidInput = syn::parse_str("crate::ids::UserId?").unwrap();
+        assert!(matches!(input, NulidInput::Typed { fallible: true, .. }));
+
+        let input: NulidInput = syn::parse_str("at: \"2024-01-01T00:00:00Z\"").unwrap();
+        assert!(matches!(input, NulidInput::At { random: None, .. }));
+    }
+
+    #[test]
+    fn test_parse_rejects_trailing_tokens() {
+        assert!(syn::parse_str::<NulidInput>("UserId, OrderId").is_err());
+        assert!(syn::parse_str::<NulidInput>("UserId??").is_err());
+    }
+}
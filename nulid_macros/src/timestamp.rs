@@ -0,0 +1,178 @@
+//! Compile-time parsing of RFC 3339 timestamps.
+
+/// Nanoseconds per second.
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Parses an RFC 3339 timestamp into nanoseconds since the Unix epoch.
+///
+/// Accepts `YYYY-MM-DDTHH:MM:SS[.fraction]` followed by `Z` or a `±HH:MM`
+/// offset, with up to nine fractional digits. `t`, `z` and a space separator
+/// are accepted as in RFC 3339. Leap seconds and times before the epoch are
+/// rejected.
+pub fn parse_rfc3339(s: &str) -> Result<u128, String> {
+    let bytes = s.as_bytes();
+    if bytes.len() < 20 {
+        return Err(format!("expected an RFC 3339 timestamp, found `{s}`"));
+    }
+
+    let year = digits(bytes, 0, 4)?;
+    expect(bytes, 4, b"-")?;
+    let month = digits(bytes, 5, 2)?;
+    expect(bytes, 7, b"-")?;
+    let day = digits(bytes, 8, 2)?;
+    expect(bytes, 10, b"Tt ")?;
+    let hour = digits(bytes, 11, 2)?;
+    expect(bytes, 13, b":")?;
+    let minute = digits(bytes, 14, 2)?;
+    expect(bytes, 16, b":")?;
+    let second = digits(bytes, 17, 2)?;
+
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return Err(format!("invalid date `{}`", &s[..10]));
+    }
+    if hour > 23 || minute > 59 || second > 59 {
+        return Err(format!("invalid time `{}`", &s[11..19]));
+    }
+
+    let mut pos = 19;
+    let mut fraction = 0;
+    if bytes[pos] == b'.' {
+        pos += 1;
+        let start = pos;
+        while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+            pos += 1;
+        }
+        let count = pos - start;
+        if count == 0 || count > 9 {
+            return Err("expected 1 to 9 fractional second digits".to_owned());
+        }
+        let scale = 10u128.pow(u32::try_from(9 - count).unwrap_or(0));
+        fraction = u128::from(digits(bytes, start, count)?) * scale;
+    }
+
+    let offset_secs: i64 = match &bytes[pos..] {
+        [b'Z' | b'z'] => 0,
+        [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+            let hours = i64::from(digits(bytes, pos + 1, 2)?);
+            let minutes = i64::from(digits(bytes, pos + 4, 2)?);
+            if hours > 23 || minutes > 59 {
+                return Err(format!("invalid UTC offset `{}`", &s[pos..]));
+            }
+            let offset = hours * 3600 + minutes * 60;
+            if *sign == b'-' { -offset } else { offset }
+        }
+        _ => {
+            return Err(format!(
+                "expected `Z` or a `+HH:MM` offset, found `{}`",
+                &s[pos..]
+            ));
+        }
+    };
+
+    let days = days_from_civil(i64::from(year), month, day);
+    let secs = days * 86_400 + i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second)
+        - offset_secs;
+    let secs = u128::try_from(secs).map_err(|_| format!("`{s}` is before the Unix epoch"))?;
+
+    Ok(secs * NANOS_PER_SEC + fraction)
+}
+
+/// Parses `len` ASCII digits starting at `start`.
+fn digits(bytes: &[u8], start: usize, len: usize) -> Result<u32, String> {
+    let slice = bytes
+        .get(start..start + len)
+        .filter(|slice| slice.iter().all(u8::is_ascii_digit))
+        .ok_or_else(|| format!("expected {len} digits at position {start}"))?;
+    Ok(slice
+        .iter()
+        .fold(0, |acc, digit| acc * 10 + u32::from(digit - b'0')))
+}
+
+/// Checks that the byte at `pos` is one of `allowed`.
+fn expect(bytes: &[u8], pos: usize, allowed: &[u8]) -> Result<(), String> {
+    if allowed.contains(&bytes[pos]) {
+        Ok(())
+    } else {
+        Err(format!(
+            "expected `{}` at position {pos}",
+            char::from(allowed[0])
+        ))
+    }
+}
+
+/// Returns the number of days in `month` of `year`.
+const fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the days since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_epoch() {
+        assert_eq!(parse_rfc3339("1970-01-01T00:00:00Z"), Ok(0));
+        assert_eq!(parse_rfc3339("1970-01-01T00:00:01z"), Ok(NANOS_PER_SEC));
+    }
+
+    #[test]
+    fn test_parse_known_timestamps() {
+        assert_eq!(
+            parse_rfc3339("2024-01-01T00:00:00Z"),
+            Ok(1_704_067_200 * NANOS_PER_SEC)
+        );
+        assert_eq!(
+            parse_rfc3339("2000-02-29T12:34:56.789Z"),
+            Ok(951_827_696 * NANOS_PER_SEC + 789_000_000)
+        );
+        assert_eq!(
+            parse_rfc3339("2024-01-01 00:00:00.000000001Z"),
+            Ok(1_704_067_200 * NANOS_PER_SEC + 1)
+        );
+    }
+
+    #[test]
+    fn test_parse_offsets() {
+        let utc = parse_rfc3339("2024-06-01T12:00:00Z").unwrap();
+        assert_eq!(parse_rfc3339("2024-06-01T14:30:00+02:30"), Ok(utc));
+        assert_eq!(parse_rfc3339("2024-06-01T07:00:00-05:00"), Ok(utc));
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid() {
+        for input in [
+            "2024-01-01",
+            "2024-01-01T00:00:00",
+            "2024-13-01T00:00:00Z",
+            "2023-02-29T00:00:00Z",
+            "2024-01-01T24:00:00Z",
+            "2024-01-01T00:00:60Z",
+            "2024-01-01T00:00:00.Z",
+            "2024-01-01T00:00:00.0123456789Z",
+            "2024-01-01T00:00:00+0200",
+            "1969-12-31T23:59:59Z",
+            "1970-01-01T00:00:00+01:00",
+        ] {
+            assert!(parse_rfc3339(input).is_err(), "{input}");
+        }
+    }
+}
@@ -0,0 +1,306 @@
+//! Python bindings for NULID.
+//!
+//! This crate builds the `nulid` Python extension module with PyO3, so that
+//! notebooks and scripts can generate and inspect NULIDs without
+//! re-implementing the bit layout:
+//!
+//! ```python
+//! import nulid
+//!
+//! id = nulid.Nulid.parse("01GZTV7EQ056J0E6N276XD6F3D")
+//! id.nanos, id.random, id.datetime   # timestamp parts and an aware UTC datetime
+//! id.to_uuid()                       # uuid.UUID with the same 128 bits
+//! sorted(events, key=lambda e: nulid.Nulid.parse(e["id"]))
+//! ```
+//!
+//! Build the wheel with `maturin build --release` from this directory; see
+//! `pyproject.toml`.
+
+use core::fmt;
+use nulid::{DistributedGenerator, Error, Generator, Nulid};
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use std::time::SystemTime;
+use uuid::Uuid;
+
+/// Maps parsing and conversion errors to `ValueError`.
+fn value_error(error: Error) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// Maps generation errors to `RuntimeError`.
+fn runtime_error(error: Error) -> PyErr {
+    PyRuntimeError::new_err(error.to_string())
+}
+
+/// A NULID: 68-bit nanosecond timestamp and 60 random bits.
+///
+/// Instances are immutable, hashable, and compare in chronological order.
+#[pyclass(name = "Nulid", module = "nulid", frozen, eq, ord, hash, str)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PyNulid(Nulid);
+
+impl fmt::Display for PyNulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl From<Nulid> for PyNulid {
+    fn from(nulid: Nulid) -> Self {
+        Self(nulid)
+    }
+}
+
+impl From<PyNulid> for Nulid {
+    fn from(nulid: PyNulid) -> Self {
+        nulid.0
+    }
+}
+
+#[pymethods]
+impl PyNulid {
+    /// Generates a new NULID from the current time.
+    #[new]
+    fn new() -> PyResult<Self> {
+        Nulid::new().map(Self).map_err(runtime_error)
+    }
+
+    /// Parses a 26-character Base32 string (any case).
+    #[staticmethod]
+    fn parse(s: &str) -> PyResult<Self> {
+        s.parse().map(Self).map_err(value_error)
+    }
+
+    /// Creates a NULID from a `uuid.UUID` with the same 128 bits.
+    #[staticmethod]
+    fn from_uuid(uuid: Uuid) -> Self {
+        Self(Nulid::from_uuid(uuid))
+    }
+
+    /// Creates a NULID from 16 big-endian bytes.
+    #[staticmethod]
+    fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        Nulid::try_from(data).map(Self).map_err(value_error)
+    }
+
+    /// Creates a NULID from a 128-bit integer.
+    #[staticmethod]
+    const fn from_int(value: u128) -> Self {
+        Self(Nulid::from_u128(value))
+    }
+
+    /// Creates a NULID from nanoseconds since the Unix epoch and random bits.
+    #[staticmethod]
+    #[pyo3(signature = (nanos, random = 0))]
+    const fn from_nanos(nanos: u128, random: u64) -> Self {
+        Self(Nulid::from_nanos(nanos, random))
+    }
+
+    /// Converts to a `uuid.UUID` with the same 128 bits.
+    #[pyo3(name = "to_uuid")]
+    fn uuid(&self) -> Uuid {
+        self.0.to_uuid()
+    }
+
+    /// Nanoseconds since the Unix epoch.
+    #[getter]
+    const fn nanos(&self) -> u128 {
+        self.0.nanos()
+    }
+
+    /// Milliseconds since the Unix epoch.
+    #[getter]
+    const fn millis(&self) -> u128 {
+        self.0.millis()
+    }
+
+    /// The 60 random bits.
+    #[getter]
+    const fn random(&self) -> u64 {
+        self.0.random()
+    }
+
+    /// The timestamp as an aware UTC `datetime` (microsecond precision).
+    #[getter]
+    fn datetime(&self) -> SystemTime {
+        self.0.datetime()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Nulid('{}')", self.0)
+    }
+
+    const fn __int__(&self) -> u128 {
+        self.0.as_u128()
+    }
+
+    fn __bytes__<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.0.to_bytes())
+    }
+}
+
+/// Generator state: a single node, or a node ID embedded in every ID.
+enum GeneratorKind {
+    Local(Generator),
+    Distributed(DistributedGenerator),
+}
+
+/// Monotonic NULID generator.
+///
+/// IDs from one generator are strictly increasing, even within the same
+/// nanosecond or across clock regressions. Pass `node_id` to embed a 16-bit
+/// node identifier in the random bits.
+#[pyclass(name = "Generator", module = "nulid", frozen)]
+pub struct PyGenerator(GeneratorKind);
+
+#[pymethods]
+impl PyGenerator {
+    #[new]
+    #[pyo3(signature = (node_id = None))]
+    fn new(node_id: Option<u16>) -> Self {
+        Self(node_id.map_or_else(
+            || GeneratorKind::Local(Generator::new()),
+            |node_id| GeneratorKind::Distributed(DistributedGenerator::with_node_id(node_id)),
+        ))
+    }
+
+    /// Generates the next NULID.
+    fn generate(&self) -> PyResult<PyNulid> {
+        match &self.0 {
+            GeneratorKind::Local(generator) => generator.generate(),
+            GeneratorKind::Distributed(generator) => generator.generate(),
+        }
+        .map(PyNulid)
+        .map_err(runtime_error)
+    }
+
+    /// The last generated NULID, or `None`.
+    #[getter]
+    fn last(&self) -> Option<PyNulid> {
+        match &self.0 {
+            GeneratorKind::Local(generator) => generator.last(),
+            GeneratorKind::Distributed(generator) => generator.last(),
+        }
+        .map(PyNulid)
+    }
+
+    /// The embedded node ID, or `None`.
+    #[getter]
+    fn node_id(&self) -> Option<u16> {
+        match &self.0 {
+            GeneratorKind::Local(generator) => generator.node_id(),
+            GeneratorKind::Distributed(generator) => generator.node_id(),
+        }
+    }
+}
+
+/// Generates a new NULID.
+#[pyfunction]
+fn generate() -> PyResult<PyNulid> {
+    PyNulid::new()
+}
+
+/// Parses a 26-character Base32 string (any case).
+#[pyfunction]
+fn parse(s: &str) -> PyResult<PyNulid> {
+    PyNulid::parse(s)
+}
+
+/// The `nulid` Python module.
+#[pymodule]
+#[pyo3(name = "nulid")]
+fn nulid_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyNulid>()?;
+    m.add_class::<PyGenerator>()?;
+    m.add_function(wrap_pyfunction!(generate, m)?)?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::py_run;
+
+    fn with_module(code: &str) {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "nulid").unwrap();
+            nulid_py(&module).unwrap();
+            py_run!(py, module, &format!("nulid = module\n{code}"));
+        });
+    }
+
+    #[test]
+    fn test_parse_and_properties() {
+        with_module(
+            r#"
+import datetime, uuid
+id = nulid.parse("01gztv7eq056j0e6n276xd6f3d")
+assert str(id) == "01GZTV7EQ056J0E6N276XD6F3D"
+assert repr(id) == "Nulid('01GZTV7EQ056J0E6N276XD6F3D')"
+assert id.nanos == int(id) >> 60
+assert id.millis == id.nanos // 1_000_000
+assert id.random == int(id) & ((1 << 60) - 1)
+assert id.datetime.tzinfo is not None
+assert isinstance(id.to_uuid(), uuid.UUID)
+assert nulid.Nulid.from_uuid(id.to_uuid()) == id
+assert nulid.Nulid.from_bytes(bytes(id)) == id
+assert nulid.Nulid.from_int(int(id)) == id
+"#,
+        );
+    }
+
+    #[test]
+    fn test_comparisons_and_hash() {
+        with_module(
+            r"
+a = nulid.Nulid.from_nanos(1, random=5)
+b = nulid.Nulid.from_nanos(2)
+assert a < b and b > a and a != b and a <= a
+assert len({a, nulid.Nulid.from_nanos(1, 5), b}) == 2
+assert sorted([b, a]) == [a, b]
+",
+        );
+    }
+
+    #[test]
+    fn test_generator() {
+        with_module(
+            r"
+g = nulid.Generator()
+assert g.last is None and g.node_id is None
+ids = []
+for _ in range(100):
+    ids.append(g.generate())
+assert ids == sorted(ids) and len(set(ids)) == 100
+assert g.last == ids[-1]
+assert nulid.Generator(node_id=7).generate().random >> 44 == 7
+assert nulid.generate() < nulid.Nulid()
+",
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        with_module(
+            r#"
+for bad in ["short", "01GZTV7EQ056J0E6N276XD6F3U"]:
+    try:
+        nulid.parse(bad)
+    except ValueError:
+        pass
+    else:
+        raise AssertionError(bad)
+try:
+    nulid.Nulid.from_bytes(b"abc")
+except ValueError:
+    pass
+else:
+    raise AssertionError("from_bytes")
+"#,
+        );
+    }
+}
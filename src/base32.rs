@@ -18,7 +18,7 @@
 //! The encoding preserves lexicographic ordering, making NULID strings naturally
 //! sortable by their timestamp component.
 
-use crate::{Error, Result};
+use crate::{Error, Nulid, Result};
 
 /// Crockford's Base32 alphabet (32 characters, 5 bits each)
 const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
@@ -26,6 +26,12 @@ const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
 /// Length of a NULID string representation (26 characters)
 pub const NULID_STRING_LENGTH: usize = 26;
 
+/// Length of a NULID string with a trailing check symbol (27 characters)
+pub const NULID_CHECKED_STRING_LENGTH: usize = NULID_STRING_LENGTH + 1;
+
+/// Crockford's check symbols: the 32 data symbols followed by `*~$=U`
+const CHECK_ALPHABET: &[u8; 37] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+
 /// Lookup table for decoding Base32 characters
 /// Invalid characters are marked with 0xFF
 const DECODE_TABLE: [u8; 256] = {
@@ -87,6 +93,19 @@ const DECODE_TABLE: [u8; 256] = {
     table
 };
 
+/// [`DECODE_TABLE`] extended with Crockford's aliases: `I`/`L` decode as `1`
+/// and `O` as `0`. `U` remains invalid.
+const ALIAS_DECODE_TABLE: [u8; 256] = {
+    let mut table = DECODE_TABLE;
+    table[b'I' as usize] = 1;
+    table[b'i' as usize] = 1;
+    table[b'L' as usize] = 1;
+    table[b'l' as usize] = 1;
+    table[b'O' as usize] = 0;
+    table[b'o' as usize] = 0;
+    table
+};
+
 /// Encodes a 128-bit value into a 26-character Base32 string.
 ///
 /// The encoding is written directly into the provided buffer for zero-allocation encoding.
@@ -118,8 +137,35 @@ const DECODE_TABLE: [u8; 256] = {
 /// # Ok(())
 /// # }
 /// ```
+///
+/// Encoding also works in `const` contexts. For `static` string forms of
+/// well-known IDs, see [`NulidString`].
+///
+/// ```
+/// use nulid::base32::encode_u128;
+///
+/// const ENCODED: [u8; 26] = {
+///     let mut buf = [0u8; 26];
+///     assert!(encode_u128(42, &mut buf).is_ok());
+///     buf
+/// };
+/// assert_eq!(&ENCODED, b"0000000000000000000000001A");
+/// ```
 #[inline]
-pub fn encode_u128(mut value: u128, buf: &mut [u8; 26]) -> Result<&str> {
+pub const fn encode_u128(value: u128, buf: &mut [u8; 26]) -> Result<&str> {
+    encode_into(value, buf);
+
+    // ALPHABET contains only ASCII characters (0-9, A-Z), so this conversion
+    // should never fail.
+    match core::str::from_utf8(buf) {
+        Ok(s) => Ok(s),
+        Err(_) => Err(Error::EncodingError),
+    }
+}
+
+/// Writes the 26 Base32 characters of `value` into `buf`.
+#[inline]
+const fn encode_into(mut value: u128, buf: &mut [u8; 26]) {
     buf[25] = ALPHABET[(value & 0x1F) as usize];
     value >>= 5;
     buf[24] = ALPHABET[(value & 0x1F) as usize];
@@ -171,18 +217,6 @@ pub fn encode_u128(mut value: u128, buf: &mut [u8; 26]) -> Result<&str> {
     buf[1] = ALPHABET[(value & 0x1F) as usize];
     value >>= 5;
     buf[0] = ALPHABET[(value & 0x1F) as usize];
-
-    // Safety: ALPHABET contains only ASCII characters (0-9, A-Z), so this conversion
-    // should never fail. We include a debug assertion to catch any potential issues
-    // during development.
-    core::str::from_utf8(buf).map_err(|utf8_err| {
-        // This should be unreachable since ALPHABET is guaranteed to be valid ASCII
-        debug_assert!(
-            false,
-            "UTF-8 conversion failed unexpectedly. This indicates a bug in the encoding logic. Error: {utf8_err}"
-        );
-        Error::EncodingError
-    })
 }
 
 /// Decodes a 26-character Base32 string into a 128-bit value.
@@ -216,27 +250,513 @@ pub fn encode_u128(mut value: u128, buf: &mut [u8; 26]) -> Result<&str> {
 /// ```
 #[inline]
 pub fn decode_u128(s: &str) -> Result<u128> {
-    // Validate length
-    if s.len() != NULID_STRING_LENGTH {
+    decode_ascii(s.as_bytes())
+}
+
+/// Decodes 26 Base32 ASCII bytes into a 128-bit value.
+///
+/// Works directly on bytes through a 256-entry lookup table, with no UTF-8
+/// handling or per-character branching. Validity is checked once after the
+/// whole input has been folded in; the position of an invalid character is
+/// only searched for on the error path.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidLength` if the input is not 26 bytes.
+/// Returns `Error::InvalidChar` if the input contains invalid characters.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::base32::decode_ascii;
+///
+/// # fn main() -> nulid::Result<()> {
+/// let value = decode_ascii(b"0000000000000000000000000Z")?;
+/// assert_eq!(value, 31);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn decode_ascii(bytes: &[u8]) -> Result<u128> {
+    decode_with_table(bytes, &DECODE_TABLE)
+}
+
+/// Decodes a 26-character Base32 string, accepting Crockford's aliases.
+///
+/// Crockford Base32 maps the easily confused `I` and `L` to `1`, and `O` to
+/// `0`, so IDs read back by humans (support tickets, over the phone) still
+/// parse. `U` is still rejected. The strict decoders remain the default.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidLength` if the string is not 26 characters.
+/// Returns `Error::InvalidChar` if the string contains invalid characters.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::base32::{decode_crockford_aliases, decode_u128};
+///
+/// # fn main() -> nulid::Result<()> {
+/// let value = decode_crockford_aliases("OIGZTV7EQ056JOE6N276XD6F3D")?;
+/// assert_eq!(value, decode_u128("01GZTV7EQ056J0E6N276XD6F3D")?);
+/// assert!(decode_crockford_aliases("U1GZTV7EQ056J0E6N276XD6F3D").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn decode_crockford_aliases(s: &str) -> Result<u128> {
+    decode_with_table(s.as_bytes(), &ALIAS_DECODE_TABLE)
+}
+
+/// Decodes 26 ASCII bytes through the given lookup table.
+#[inline]
+fn decode_with_table(bytes: &[u8], table: &[u8; 256]) -> Result<u128> {
+    let Ok(bytes) = <&[u8; NULID_STRING_LENGTH]>::try_from(bytes) else {
         return Err(Error::InvalidLength {
             expected: NULID_STRING_LENGTH,
-            found: s.len(),
+            found: bytes.len(),
         });
-    }
+    };
 
     let mut result: u128 = 0;
+    let mut seen: u8 = 0;
+    for &byte in bytes {
+        let value = table[byte as usize];
+        seen |= value;
+        result = (result << 5) | u128::from(value & 0x1F);
+    }
 
-    for (i, byte) in s.bytes().enumerate() {
-        let value = DECODE_TABLE[byte as usize];
-        if value == 0xFF {
-            return Err(Error::InvalidChar(byte as char, i));
-        }
-        result = (result << 5) | u128::from(value);
+    // Valid digits never exceed 31, so any high bit marks an invalid byte.
+    if seen & !0x1F != 0 {
+        return Err(first_invalid_char(bytes, table));
     }
 
     Ok(result)
 }
 
+/// Decodes a canonical 26-character Base32 string: uppercase only, no separators.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidLength` if the string is not 26 characters.
+/// Returns `Error::InvalidChar` if the string contains lowercase or invalid characters.
+/// Returns `Error::Overflow` if the first character is above `'7'`, which
+/// would encode more than 128 bits.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::base32::decode_strict;
+///
+/// assert!(decode_strict("0000000000000000000000000Z").is_ok());
+/// assert!(decode_strict("0000000000000000000000000z").is_err());
+/// assert!(decode_strict("80000000000000000000000000").is_err());
+/// ```
+#[inline]
+pub fn decode_strict(s: &str) -> Result<u128> {
+    if let Some(i) = s.bytes().position(|byte| byte.is_ascii_lowercase()) {
+        return Err(Error::InvalidChar(char::from(s.as_bytes()[i]), i));
+    }
+    let value = decode_ascii(s.as_bytes())?;
+
+    // The 2 padding bits cap the first character at '7'.
+    if DECODE_TABLE[s.as_bytes()[0] as usize] > 7 {
+        return Err(Error::Overflow);
+    }
+    Ok(value)
+}
+
+/// Decodes a human-entered Base32 string.
+///
+/// Accepts lowercase, surrounding whitespace, and `-` or `_` separators
+/// anywhere inside the ID (e.g. `01GZTV7E-Q056J0E6-N276XD6F3D`).
+///
+/// # Errors
+///
+/// Returns `Error::InvalidLength` if the string does not contain exactly 26
+/// Base32 characters once separators are removed.
+/// Returns `Error::InvalidChar` if the string contains invalid characters;
+/// the position refers to the trimmed input.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::base32::{decode_lenient, decode_u128};
+///
+/// # fn main() -> nulid::Result<()> {
+/// let value = decode_lenient("  01gztv7e-q056j0e6-n276xd6f3d\n")?;
+/// assert_eq!(value, decode_u128("01GZTV7EQ056J0E6N276XD6F3D")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn decode_lenient(s: &str) -> Result<u128> {
+    let trimmed = s.trim();
+    let mut buf = [0u8; NULID_STRING_LENGTH];
+    let mut len = 0;
+
+    for (i, byte) in trimmed.bytes().enumerate() {
+        if byte == b'-' || byte == b'_' {
+            continue;
+        }
+        if DECODE_TABLE[byte as usize] == 0xFF {
+            return Err(Error::InvalidChar(char::from(byte), i));
+        }
+        if let Some(slot) = buf.get_mut(len) {
+            *slot = byte;
+        }
+        len += 1;
+    }
+
+    if len != NULID_STRING_LENGTH {
+        return Err(Error::InvalidLength {
+            expected: NULID_STRING_LENGTH,
+            found: len,
+        });
+    }
+
+    decode_ascii(&buf)
+}
+
+/// Builds the `InvalidChar` error for the first byte not in the table.
+#[cold]
+fn first_invalid_char(bytes: &[u8], table: &[u8; 256]) -> Error {
+    bytes
+        .iter()
+        .position(|&byte| table[byte as usize] == 0xFF)
+        .map_or(Error::EncodingError, |i| {
+            Error::InvalidChar(char::from(bytes[i]), i)
+        })
+}
+
+/// Decodes a partial Base32 string into the range of NULIDs it prefixes.
+///
+/// Returns the smallest and largest NULIDs (inclusive) whose encoding starts
+/// with `prefix`, compared case-insensitively. This turns an ID fragment
+/// pasted into a search box into a range query. An empty prefix matches
+/// every NULID.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidLength` if the prefix is longer than 26 characters.
+/// Returns `Error::InvalidChar` if the prefix contains invalid characters, or
+/// starts with a character above `7` (no NULID encoding does).
+///
+/// # Examples
+///
+/// ```
+/// use nulid::Nulid;
+/// use nulid::base32::decode_prefix;
+///
+/// # fn main() -> nulid::Result<()> {
+/// let (min, max) = decode_prefix("01GZTV7EQ0")?;
+/// assert_eq!(min.to_string(), "01GZTV7EQ00000000000000000");
+/// assert_eq!(max.to_string(), "01GZTV7EQ0ZZZZZZZZZZZZZZZZ");
+///
+/// let id: Nulid = "01GZTV7EQ056J0E6N276XD6F3D".parse()?;
+/// assert!((min..=max).contains(&id));
+/// # Ok(())
+/// # }
+/// ```
+pub fn decode_prefix(prefix: &str) -> Result<(Nulid, Nulid)> {
+    let bytes = prefix.as_bytes();
+    if bytes.len() > NULID_STRING_LENGTH {
+        return Err(Error::InvalidLength {
+            expected: NULID_STRING_LENGTH,
+            found: bytes.len(),
+        });
+    }
+
+    // The 2 padding bits cap the first character at '7'.
+    if let Some(&first) = bytes.first()
+        && DECODE_TABLE[first as usize] > 7
+        && DECODE_TABLE[first as usize] != 0xFF
+    {
+        return Err(Error::InvalidChar(char::from(first), 0));
+    }
+
+    let mut min = [b'0'; NULID_STRING_LENGTH];
+    let mut max = [b'Z'; NULID_STRING_LENGTH];
+    max[0] = b'7';
+    min[..bytes.len()].copy_from_slice(bytes);
+    max[..bytes.len()].copy_from_slice(bytes);
+
+    Ok((
+        Nulid::from_u128(decode_ascii(&min)?),
+        Nulid::from_u128(decode_ascii(&max)?),
+    ))
+}
+
+/// Computes the Crockford check symbol (value mod 37) for a 128-bit value.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::base32::check_symbol;
+///
+/// assert_eq!(check_symbol(0), '0');
+/// assert_eq!(check_symbol(36), 'U');
+/// assert_eq!(check_symbol(37), '0');
+/// ```
+#[must_use]
+pub const fn check_symbol(value: u128) -> char {
+    CHECK_ALPHABET[(value % 37) as usize] as char
+}
+
+/// Decodes a 27-character Base32 string whose last character is a check symbol.
+///
+/// The 26 data characters are decoded like [`decode_u128`]; the check symbol
+/// is case-insensitive.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidLength` if the string is not 27 characters.
+/// Returns `Error::InvalidChar` if the string contains invalid characters.
+/// Returns `Error::InvalidChecksum` if the check symbol does not match.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::base32::decode_checked;
+/// use nulid::Error;
+///
+/// # fn main() -> nulid::Result<()> {
+/// assert_eq!(decode_checked("0000000000000000000000001ZT")?, 63);
+/// assert!(matches!(
+///     decode_checked("0000000000000000000000001YT"),
+///     Err(Error::InvalidChecksum { .. })
+/// ));
+/// # Ok(())
+/// # }
+/// ```
+pub fn decode_checked(s: &str) -> Result<u128> {
+    let bytes = s.as_bytes();
+    let Some((&check, data)) = bytes
+        .split_last()
+        .filter(|_| bytes.len() == NULID_CHECKED_STRING_LENGTH)
+    else {
+        return Err(Error::InvalidLength {
+            expected: NULID_CHECKED_STRING_LENGTH,
+            found: bytes.len(),
+        });
+    };
+
+    let value = decode_ascii(data)?;
+    let found = check.to_ascii_uppercase();
+    if !CHECK_ALPHABET.contains(&found) {
+        return Err(Error::InvalidChar(char::from(check), NULID_STRING_LENGTH));
+    }
+
+    let expected = check_symbol(value);
+    if char::from(found) != expected {
+        return Err(Error::InvalidChecksum {
+            expected,
+            found: char::from(check),
+        });
+    }
+
+    Ok(value)
+}
+
+/// Encodes many NULIDs as newline-terminated lines, appending to `out`.
+///
+/// Reserves the full output size up front and encodes each ID in place, so
+/// bulk exports avoid one heap allocation per ID.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::Nulid;
+/// use nulid::base32::{decode_batch, encode_batch};
+///
+/// # fn main() -> nulid::Result<()> {
+/// let ids = vec![Nulid::new()?, Nulid::new()?];
+/// let mut out = String::new();
+/// encode_batch(&ids, &mut out);
+/// assert_eq!(out.lines().count(), 2);
+/// assert_eq!(decode_batch(&out)?, ids);
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_batch(ids: &[Nulid], out: &mut String) {
+    out.reserve(ids.len() * (NULID_STRING_LENGTH + 1));
+    for id in ids {
+        out.push_str(&id.to_arraystring());
+        out.push('\n');
+    }
+}
+
+/// Decodes one NULID per line.
+///
+/// Accepts `\n` or `\r\n` line endings and skips empty lines, so the output
+/// of [`encode_batch`] round-trips. The result is allocated once.
+///
+/// # Errors
+///
+/// Returns the first error encountered, as reported by [`decode_u128`] for
+/// the offending line.
+pub fn decode_batch(lines: &str) -> Result<Vec<Nulid>> {
+    let mut ids = Vec::with_capacity(lines.len() / (NULID_STRING_LENGTH + 1));
+    for line in lines.lines().filter(|line| !line.is_empty()) {
+        ids.push(Nulid::from_u128(decode_u128(line)?));
+    }
+    Ok(ids)
+}
+
+/// Parallel version of [`encode_batch`], returning a new string.
+///
+/// Each line has a fixed width, so every worker writes directly into its own
+/// slice of the output buffer.
+///
+/// # Errors
+///
+/// Returns `Error::EncodingError` if the output is not valid UTF-8 (should
+/// never occur with valid ALPHABET).
+#[cfg(feature = "rayon")]
+pub fn encode_batch_par(ids: &[Nulid]) -> Result<String> {
+    use rayon::prelude::*;
+
+    const LINE_LENGTH: usize = NULID_STRING_LENGTH + 1;
+    let mut buf = vec![b'\n'; ids.len() * LINE_LENGTH];
+    buf.par_chunks_mut(LINE_LENGTH)
+        .zip(ids.par_iter())
+        .for_each(|(line, id)| {
+            line[..NULID_STRING_LENGTH].copy_from_slice(id.to_arraystring().as_bytes());
+        });
+    String::from_utf8(buf).map_err(|_| Error::EncodingError)
+}
+
+/// Parallel version of [`decode_batch`]. Output order matches input order.
+///
+/// # Errors
+///
+/// Returns an error if any line fails to decode, as reported by [`decode_u128`].
+#[cfg(feature = "rayon")]
+pub fn decode_batch_par(lines: &str) -> Result<Vec<Nulid>> {
+    use rayon::prelude::*;
+
+    lines
+        .par_lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| decode_u128(line).map(Nulid::from_u128))
+        .collect()
+}
+
+/// A NULID encoded as 26 Base32 characters, stored inline on the stack.
+///
+/// Returned by [`Nulid::to_arraystring`](crate::Nulid::to_arraystring) for hot
+/// paths that need an owned string value without a heap allocation.
+/// Dereferences to `str`.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::Nulid;
+///
+/// let id = Nulid::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+/// let s = id.to_arraystring();
+/// assert_eq!(s.len(), 26);
+/// assert_eq!(s, id.to_string().as_str());
+/// ```
+///
+/// Construction is `const`, so well-known IDs can have static string forms
+/// computed at compile time:
+///
+/// ```
+/// use nulid::{Nulid, NulidString};
+///
+/// const ROOT_ID: Nulid = Nulid::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+/// static ROOT: NulidString = ROOT_ID.to_arraystring();
+/// static ROOT_STR: &str = ROOT.as_str();
+///
+/// assert_eq!(ROOT_STR, ROOT_ID.to_string());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NulidString([u8; NULID_STRING_LENGTH]);
+
+impl NulidString {
+    /// Encodes a 128-bit value into an inline string.
+    #[must_use]
+    pub const fn from_u128(value: u128) -> Self {
+        let mut buf = [0u8; NULID_STRING_LENGTH];
+        encode_into(value, &mut buf);
+        Self(buf)
+    }
+
+    /// Returns the encoded string slice.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        // ALPHABET is pure ASCII, so this never falls back.
+        match core::str::from_utf8(&self.0) {
+            Ok(s) => s,
+            Err(_) => "",
+        }
+    }
+
+    /// Returns the encoded ASCII bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; NULID_STRING_LENGTH] {
+        &self.0
+    }
+}
+
+/// The encoding of the nil ID, `00000000000000000000000000`.
+impl Default for NulidString {
+    fn default() -> Self {
+        Self::from_u128(0)
+    }
+}
+
+impl core::ops::Deref for NulidString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for NulidString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl core::borrow::Borrow<str> for NulidString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl core::fmt::Display for NulidString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::fmt::Debug for NulidString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq<str> for NulidString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for NulidString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl From<NulidString> for String {
+    fn from(s: NulidString) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -353,7 +873,7 @@ mod tests {
     #[test]
     fn test_alphabet_valid() {
         // Verify that ALPHABET contains only valid ASCII/UTF-8 characters
-        // This test ensures the debug_assert in encode_u128 should never trigger
+        // This test ensures the UTF-8 conversion in encode_u128 never fails
         for &byte in ALPHABET {
             assert!(
                 byte.is_ascii(),
@@ -487,4 +1007,219 @@ mod tests {
             let _ = decode_u128(&s).unwrap();
         }
     }
+
+    #[test]
+    fn test_nulid_string_matches_encode() {
+        let value = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+        let mut buf = [0u8; 26];
+        let encoded = encode_u128(value, &mut buf).unwrap();
+
+        let inline = NulidString::from_u128(value);
+        assert_eq!(inline, encoded);
+        assert_eq!(inline.as_bytes(), encoded.as_bytes());
+        assert_eq!(decode_u128(&inline).unwrap(), value);
+        assert_eq!(format!("{inline}"), encoded);
+        assert_eq!(format!("{inline:?}"), format!("{encoded:?}"));
+        assert_eq!(String::from(inline), encoded);
+    }
+
+    #[test]
+    fn test_decode_ascii_matches_decode_u128() {
+        let value = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+        let mut buf = [0u8; 26];
+        encode_u128(value, &mut buf).unwrap();
+        assert_eq!(decode_ascii(&buf).unwrap(), value);
+        assert_eq!(decode_ascii(&buf[..25]), decode_u128(&"0".repeat(25)));
+        assert!(matches!(
+            decode_ascii(b"00000000000I0000000000000U"),
+            Err(Error::InvalidChar('I', 11))
+        ));
+    }
+
+    #[test]
+    fn test_decode_strict() {
+        let canonical = "01GZTV7EQ056J0E6N276XD6F3D";
+        assert_eq!(
+            decode_strict(canonical).unwrap(),
+            decode_u128(canonical).unwrap()
+        );
+        assert!(matches!(
+            decode_strict("01GZTV7EQ056J0E6N276XD6f3D"),
+            Err(Error::InvalidChar('f', 23))
+        ));
+        assert!(matches!(
+            decode_strict(" 01GZTV7EQ056J0E6N276XD6F3D"),
+            Err(Error::InvalidLength { .. })
+        ));
+        assert_eq!(
+            decode_strict("7ZZZZZZZZZZZZZZZZZZZZZZZZZ").unwrap(),
+            u128::MAX
+        );
+        assert!(matches!(
+            decode_strict("8ZZZZZZZZZZZZZZZZZZZZZZZZZ"),
+            Err(Error::Overflow)
+        ));
+        assert!(matches!(
+            decode_strict("Z0000000000000000000000000"),
+            Err(Error::Overflow)
+        ));
+    }
+
+    #[test]
+    fn test_decode_lenient() {
+        let expected = decode_u128("01GZTV7EQ056J0E6N276XD6F3D").unwrap();
+        for input in [
+            "01GZTV7EQ056J0E6N276XD6F3D",
+            "01gztv7eq056j0e6n276xd6f3d",
+            "\t01GZTV7EQ056J0E6N276XD6F3D \n",
+            "01GZTV7E-Q056J0E6-N276XD6F3D",
+            "01GZ_TV7E_Q056_J0E6_N276_XD6F_3D",
+        ] {
+            assert_eq!(decode_lenient(input).unwrap(), expected, "{input:?}");
+        }
+
+        assert!(matches!(
+            decode_lenient("01GZTV7E-Q056J0E6-N276XD6F3"),
+            Err(Error::InvalidLength {
+                expected: 26,
+                found: 25
+            })
+        ));
+        assert!(matches!(
+            decode_lenient("01GZTV7E Q056J0E6N276XD6F3D"),
+            Err(Error::InvalidChar(' ', 8))
+        ));
+    }
+
+    #[test]
+    fn test_decode_crockford_aliases() {
+        let expected = decode_u128("01GZTV7EQ056J0E6N276XD6F3D").unwrap();
+        for input in [
+            "01GZTV7EQ056J0E6N276XD6F3D",
+            "OIGZTV7EQ056J0E6N276XD6F3D",
+            "oLGZTV7EQ056J0E6N276XD6F3D",
+            "0lgztv7eq056jOe6n276xd6f3d",
+        ] {
+            assert_eq!(
+                decode_crockford_aliases(input).unwrap(),
+                expected,
+                "{input}"
+            );
+        }
+
+        assert!(matches!(
+            decode_crockford_aliases("01GZTV7EQ056J0E6N276XD6F3U"),
+            Err(Error::InvalidChar('U', 25))
+        ));
+        // Strict decoding still rejects the aliases
+        assert!(decode_u128("OIGZTV7EQ056J0E6N276XD6F3D").is_err());
+    }
+
+    #[test]
+    fn test_check_symbol_covers_alphabet() {
+        for (value, &symbol) in CHECK_ALPHABET.iter().enumerate() {
+            assert_eq!(check_symbol(value as u128), char::from(symbol));
+        }
+        assert_eq!(check_symbol(u128::MAX), check_symbol(u128::MAX % 37));
+    }
+
+    #[test]
+    fn test_decode_checked() {
+        let value = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+        let mut buf = [0u8; 26];
+        let encoded = encode_u128(value, &mut buf).unwrap();
+        let checked = format!("{encoded}{}", check_symbol(value));
+
+        assert_eq!(decode_checked(&checked).unwrap(), value);
+        assert_eq!(decode_checked(&checked.to_lowercase()).unwrap(), value);
+
+        // Single-character substitution is caught
+        let mut corrupted = checked.into_bytes();
+        corrupted[10] = if corrupted[10] == b'0' { b'1' } else { b'0' };
+        let corrupted = String::from_utf8(corrupted).unwrap();
+        assert!(matches!(
+            decode_checked(&corrupted),
+            Err(Error::InvalidChecksum { .. })
+        ));
+
+        assert!(matches!(
+            decode_checked(encoded),
+            Err(Error::InvalidLength {
+                expected: 27,
+                found: 26
+            })
+        ));
+        assert!(matches!(
+            decode_checked("0000000000000000000000000000!"),
+            Err(Error::InvalidLength { .. })
+        ));
+        assert!(matches!(
+            decode_checked("00000000000000000000000000!"),
+            Err(Error::InvalidChar('!', 26))
+        ));
+    }
+
+    #[test]
+    fn test_batch_round_trip() {
+        let ids: Vec<Nulid> = (0..1000).map(|_| Nulid::new().unwrap()).collect();
+        let mut out = String::new();
+        encode_batch(&ids, &mut out);
+
+        assert_eq!(out.len(), ids.len() * 27);
+        assert_eq!(decode_batch(&out).unwrap(), ids);
+        assert_eq!(decode_batch(&out.replace('\n', "\r\n\n")).unwrap(), ids);
+        assert!(decode_batch("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_decode_batch_error() {
+        let input = "01GZTV7EQ056J0E6N276XD6F3D\n01GZTV7EQ056J0E6N276XD6F3U\n";
+        assert!(matches!(
+            decode_batch(input),
+            Err(Error::InvalidChar('U', 25))
+        ));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_batch_par_matches_sequential() {
+        let ids: Vec<Nulid> = (0..1000).map(|_| Nulid::new().unwrap()).collect();
+        let mut sequential = String::new();
+        encode_batch(&ids, &mut sequential);
+
+        let parallel = encode_batch_par(&ids).unwrap();
+        assert_eq!(parallel, sequential);
+        assert_eq!(decode_batch_par(&parallel).unwrap(), ids);
+    }
+
+    #[test]
+    fn test_decode_prefix() {
+        let (min, max) = decode_prefix("").unwrap();
+        assert_eq!(min, Nulid::min());
+        assert_eq!(max, Nulid::max());
+
+        let id: Nulid = "01GZTV7EQ056J0E6N276XD6F3D".parse().unwrap();
+        let (min, max) = decode_prefix("01gztv7eq056j0e6n276xd6f3d").unwrap();
+        assert_eq!((min, max), (id, id));
+
+        for len in 1..26 {
+            let (min, max) = decode_prefix(&id.to_string()[..len]).unwrap();
+            assert!(min <= id && id <= max);
+            assert!(min.to_string().starts_with(&id.to_string()[..len]));
+            assert!(max.to_string().starts_with(&id.to_string()[..len]));
+        }
+
+        assert!(matches!(
+            decode_prefix("8"),
+            Err(Error::InvalidChar('8', 0))
+        ));
+        assert!(matches!(
+            decode_prefix("01U"),
+            Err(Error::InvalidChar('U', 2))
+        ));
+        assert!(matches!(
+            decode_prefix(&"0".repeat(27)),
+            Err(Error::InvalidLength { .. })
+        ));
+    }
 }
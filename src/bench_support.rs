@@ -0,0 +1,165 @@
+//! Inputs and helpers for benchmarking code built on NULID.
+//!
+//! The crate's own Criterion benches are not published, so downstream
+//! crates that wrap [`Nulid`] (typed IDs, database columns, codecs) have no
+//! shared baseline to measure their overhead against. This module exposes
+//! the inputs those benchmarks should agree on:
+//!
+//! - [`corpus`] and [`string_corpus`]: reproducible, realistic ID sets
+//! - [`shuffled_corpus`]: the same IDs out of order, for sort and index benches
+//! - [`VALID_EDGE_STRINGS`] and [`INVALID_STRINGS`]: worst-case parser inputs
+//! - [`time_per_op`]: a minimal timing loop for quick comparisons without a
+//!   benchmark framework; the `nulid bench` CLI command is built on it
+//!
+//! Corpora are deterministic for a given seed, so numbers from different
+//! crates and machines are measured over identical inputs.
+//!
+//! # Examples
+//!
+//! Comparing a wrapper type's parser against the baseline with Criterion:
+//!
+//! ```ignore
+//! use nulid::{Nulid, bench_support};
+//!
+//! fn bench_parse(c: &mut Criterion) {
+//!     let inputs = bench_support::string_corpus(10_000, bench_support::DEFAULT_SEED);
+//!     c.bench_function("nulid", |b| b.iter(|| inputs.iter().map(|s| s.parse::<Nulid>()).count()));
+//!     c.bench_function("user_id", |b| b.iter(|| inputs.iter().map(|s| s.parse::<UserId>()).count()));
+//! }
+//! ```
+
+use crate::Nulid;
+use core::hint::black_box;
+use core::time::Duration;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::time::Instant;
+
+/// Seed used by the `nulid bench` command; use it to compare against its output.
+pub const DEFAULT_SEED: u64 = 0x006E_756C_6964;
+
+/// Timestamp of the first corpus ID: 2024-01-01T00:00:00Z in nanoseconds.
+pub const CORPUS_START_NANOS: u128 = 1_704_067_200_000_000_000;
+
+/// Mean spacing between corpus IDs, in nanoseconds (about 1M IDs per second).
+pub const CORPUS_MEAN_GAP_NANOS: u64 = 1_000;
+
+/// Valid strings that exercise the parser's edge cases.
+///
+/// Every entry parses successfully: the minimum and maximum values, and
+/// all-lowercase and mixed-case input.
+pub const VALID_EDGE_STRINGS: &[&str] = &[
+    "00000000000000000000000000",
+    "7ZZZZZZZZZZZZZZZZZZZZZZZZZ",
+    "7zzzzzzzzzzzzzzzzzzzzzzzzz",
+    "01gztv7eq056j0e6n276xd6f3d",
+    "01GzTv7Eq056J0e6N276xD6f3D",
+];
+
+/// Strings the parser must reject, each as late as possible.
+///
+/// Includes an invalid character in the last position, which forces a full
+/// scan before failing, and lengths one short and one long of 26.
+pub const INVALID_STRINGS: &[&str] = &[
+    "01GZTV7EQ056J0E6N276XD6F3U",
+    "01GZTV7EQ056J0E6N276XD6F3!",
+    "01GZTV7EQ056J0E6N276XD6F3",
+    "01GZTV7EQ056J0E6N276XD6F3DD",
+    "",
+];
+
+/// Returns `n` distinct NULIDs in ascending order, reproducible from `seed`.
+///
+/// Timestamps start at [`CORPUS_START_NANOS`] and advance by a random gap
+/// averaging [`CORPUS_MEAN_GAP_NANOS`], with fully random low bits, which
+/// resembles the output of a busy [`Generator`](crate::Generator).
+///
+/// # Examples
+///
+/// ```
+/// use nulid::bench_support::{corpus, DEFAULT_SEED};
+///
+/// let ids = corpus(1_000, DEFAULT_SEED);
+/// assert_eq!(ids, corpus(1_000, DEFAULT_SEED));
+/// assert!(ids.windows(2).all(|w| w[0] < w[1]));
+/// ```
+#[must_use]
+pub fn corpus(n: usize, seed: u64) -> Vec<Nulid> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut nanos = CORPUS_START_NANOS;
+    (0..n)
+        .map(|_| {
+            nanos += u128::from(rng.random_range(1..2 * CORPUS_MEAN_GAP_NANOS));
+            Nulid::from_nanos(nanos, rng.random())
+        })
+        .collect()
+}
+
+/// Returns [`corpus`] encoded as canonical 26-character strings.
+#[must_use]
+pub fn string_corpus(n: usize, seed: u64) -> Vec<String> {
+    corpus(n, seed).iter().map(Nulid::to_string).collect()
+}
+
+/// Returns the IDs of [`corpus`] in a random order, reproducible from `seed`.
+#[must_use]
+pub fn shuffled_corpus(n: usize, seed: u64) -> Vec<Nulid> {
+    let mut ids = corpus(n, seed);
+    ids.shuffle(&mut StdRng::seed_from_u64(seed.rotate_left(32)));
+    ids
+}
+
+/// Runs `op` `iterations` times and returns the mean time per call.
+///
+/// The result of each call is passed through [`black_box`] so the work is
+/// not optimized away. There is no warm-up or outlier rejection; use a
+/// benchmark framework for publishable numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::Nulid;
+/// use nulid::bench_support::time_per_op;
+///
+/// let per_op = time_per_op(1_000, || Nulid::from_u128(42).to_arraystring());
+/// assert!(per_op.as_secs() < 1);
+/// ```
+pub fn time_per_op<T>(iterations: u32, mut op: impl FnMut() -> T) -> Duration {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(op());
+    }
+    start.elapsed() / iterations.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corpus_is_sorted_unique_and_seeded() {
+        let ids = corpus(10_000, DEFAULT_SEED);
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+        assert!(ids[0].nanos() > CORPUS_START_NANOS);
+        assert_ne!(ids, corpus(10_000, DEFAULT_SEED + 1));
+
+        let mut shuffled = shuffled_corpus(10_000, DEFAULT_SEED);
+        assert_ne!(shuffled, ids);
+        shuffled.sort_unstable();
+        assert_eq!(shuffled, ids);
+
+        let strings = string_corpus(10, DEFAULT_SEED);
+        assert_eq!(strings[3].parse::<Nulid>().unwrap(), ids[3]);
+    }
+
+    #[test]
+    fn test_edge_strings() {
+        for s in VALID_EDGE_STRINGS {
+            assert!(s.parse::<Nulid>().is_ok(), "{s}");
+        }
+        for s in INVALID_STRINGS {
+            assert!(s.parse::<Nulid>().is_err(), "{s}");
+        }
+    }
+}
@@ -1,468 +1,2016 @@
 //! NULID CLI - Command-line interface for NULID generation and manipulation
 
-use core::fmt::Write;
-use std::io::{self, BufRead};
-use std::process;
+use core::fmt::{self, Write as _};
+use core::time::Duration;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
 
+use clap::{ArgGroup, Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
 use nulid::Nulid;
-
-#[cfg(feature = "uuid")]
-use uuid::Uuid;
+use nulid::bench_support::{self, DEFAULT_SEED};
+use nulid::generator::{Clock, CryptoRng, Generator, NoNodeId, Rng, SeededRng, SystemClock};
+use serde::Serialize;
 
 #[cfg(feature = "chrono")]
 use chrono::{DateTime, Utc};
 
-#[allow(clippy::too_many_lines)]
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
+const EXIT_CODES: &str = "\
+Exit codes:
+  0  Success
+  1  Invalid input, or the operation failed
+  2  Usage error (unknown command, bad arguments)";
 
-    if args.len() < 2 {
-        print_help();
-        process::exit(0);
-    }
+const EXAMPLES: &str = "\
+Examples:
+  nulid generate                      Generate a single NULID
+  nulid gen --count 10 -o uuid        Generate 10 NULIDs as UUIDs
+  nulid gen --at 2024-01-01T00:00:00Z --seed 42
+                                      Reproducible NULID for a fixed time
+  nulid gen --follow --rate 500/s -f ndjson | consumer
+                                      Stream IDs until interrupted
+  nulid inspect 01GZWQ22K2MNDR0GAQTE834QRV
+  nulid inspect -f json 01GZWQ22K2MNDR0GAQTE834QRV | jq .datetime
+  nulid inspect --diff 01GZWQ22K2MNDR0GAQTE834QRV 01GZWQ22K2TKVGHH1Z1G0AK1EK
+  cat nulids.txt | nulid validate -q  Check a file through the exit code
+  cat export.txt | nulid check        Find duplicates and out-of-order IDs
+  cat dump.txt | nulid stats          Summarize timestamps, rates, and nodes
+  tail -f app.log | nulid grep --annotate
+                                      Show the timestamp next to each NULID
+  cat nulids.txt | nulid sort
+  nulid convert --to uuid 01GZWQ22K2MNDR0GAQTE834QRV
+  cat uuids.txt | nulid convert --from uuid --to b58
+  cat nulids.txt | nulid filter --after 2024-01-01T00:00:00Z
+  nulid range --from 2024-01-01T00:00:00Z --to 2024-01-31T23:59:59Z --sql
+                                      WHERE clause for a time window
+  nulid bench -n 1000000              Time generation, encoding, and parsing";
 
-    match args[1].as_str() {
-        "generate" | "gen" | "g" => {
-            let count = if args.len() > 2 {
-                args[2].parse::<usize>().unwrap_or_else(|_| {
-                    eprintln!("Error: Invalid count '{}'", args[2]);
-                    process::exit(1);
-                })
-            } else {
-                1
-            };
-            generate(count);
+/// NULID CLI - Nanosecond-Precision Universally Lexicographically Sortable Identifier
+#[derive(Debug, Parser)]
+#[command(
+    name = "nulid",
+    version,
+    after_help = format!("{EXAMPLES}\n\n{EXIT_CODES}"),
+    after_long_help = format!(
+        "{EXAMPLES}\n\n{EXIT_CODES}\n\nFor more information, visit: https://github.com/kakilangit/nulid"
+    ),
+)]
+struct Cli {
+    /// Suppress error messages and summaries; rely on the exit code
+    #[arg(short, long, global = true)]
+    quiet: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Generate NULID(s)
+    #[command(visible_aliases = ["gen", "g"])]
+    Generate {
+        /// Number of NULIDs to generate
+        #[arg(value_name = "COUNT", conflicts_with = "count")]
+        positional_count: Option<usize>,
+
+        /// Number of NULIDs to generate [default: 1]
+        #[arg(short = 'n', long)]
+        count: Option<usize>,
+
+        /// Keep generating until interrupted
+        #[arg(long, conflicts_with_all = ["count", "positional_count"])]
+        follow: bool,
+
+        /// Delay between IDs with --follow, e.g. 100ms, 2s, 1m [default: 1s]
+        #[arg(long, value_name = "DURATION", value_parser = parse_interval, requires = "follow")]
+        interval: Option<Duration>,
+
+        /// IDs per second (N or N/s) or per minute (N/m) with --follow
+        #[arg(
+            long,
+            value_name = "RATE",
+            value_parser = parse_rate,
+            requires = "follow",
+            conflicts_with = "interval"
+        )]
+        rate: Option<Duration>,
+
+        /// Timestamp to mint IDs at instead of now: RFC 3339 or nanoseconds
+        /// since Unix epoch
+        #[arg(long, value_name = "TIME", value_parser = parse_timestamp)]
+        at: Option<u128>,
+
+        /// Seed for the random bits, making the output reproducible
+        #[arg(long, value_name = "SEED")]
+        seed: Option<u64>,
+
+        #[command(flatten)]
+        output: OutputArgs,
+
+        #[command(flatten)]
+        format: FormatArgs,
+    },
+
+    /// Parse and validate a NULID string
+    #[command(visible_alias = "p")]
+    Parse {
+        /// NULID string
+        nulid: String,
+
+        #[command(flatten)]
+        output: OutputArgs,
+
+        #[command(flatten)]
+        format: FormatArgs,
+    },
+
+    /// Inspect NULID components in detail, or diff two NULIDs
+    #[command(visible_alias = "i")]
+    Inspect {
+        /// NULID string
+        nulid: String,
+
+        /// Second NULID string, diffed against the first with --diff
+        #[arg(requires = "diff")]
+        other: Option<String>,
+
+        /// Show component-wise differences between two NULIDs
+        #[arg(long, requires = "other")]
+        diff: bool,
+
+        #[command(flatten)]
+        format: FormatArgs,
+    },
+
+    /// Decode a NULID to hex bytes
+    #[command(visible_alias = "d")]
+    Decode {
+        /// NULID string
+        nulid: String,
+    },
+
+    /// Validate NULID(s) from arguments or stdin
+    #[command(visible_alias = "v")]
+    Validate {
+        /// NULID strings; read from stdin, one per line, if omitted
+        nulids: Vec<String>,
+
+        /// Stop at the first invalid input
+        #[arg(long)]
+        fail_fast: bool,
+
+        /// Print only the total, valid, and invalid counts
+        #[arg(long)]
+        summary_only: bool,
+
+        #[command(flatten)]
+        format: FormatArgs,
+    },
+
+    /// Check a NULID stream for duplicates, ordering, and time gaps
+    ///
+    /// Reports every duplicate, out-of-order, and invalid entry, followed by
+    /// gap statistics. Exits with code 1 if any violation was found.
+    Check {
+        /// NULID strings; read from stdin, one per line, if omitted
+        nulids: Vec<String>,
+
+        /// Also report consecutive IDs further apart than this, e.g. 5s
+        #[arg(long, value_name = "DURATION", value_parser = parse_interval)]
+        max_gap: Option<Duration>,
+    },
+
+    /// Find NULIDs embedded in text, such as application logs
+    ///
+    /// A match is a valid NULID forming a whole alphanumeric token, so IDs
+    /// inside `user_<id>` or `"id":"<id>"` are found, while 26-character
+    /// windows of longer tokens are not. Exits with code 1 if nothing matched.
+    Grep {
+        /// Files to scan; read stdin if omitted
+        files: Vec<PathBuf>,
+
+        /// Print only the matched NULIDs, one per line
+        #[arg(short = 'o', long, conflicts_with = "annotate")]
+        only_matching: bool,
+
+        /// Print every line, with each NULID followed by its RFC 3339 timestamp
+        #[arg(short, long)]
+        annotate: bool,
+    },
+
+    /// Summarize a NULID corpus: time range, rate histogram, nodes, entropy
+    ///
+    /// Node prefixes are the top 16 random bits, where generators configured
+    /// with a node ID store it; for IDs without one they are just random.
+    /// Invalid lines are counted and skipped.
+    Stats {
+        /// NULID strings; read from stdin, one per line, if omitted
+        nulids: Vec<String>,
+
+        /// Width of the histogram buckets, e.g. 1s, 100ms, 1m
+        #[arg(long, value_name = "DURATION", value_parser = parse_interval, default_value = "1s")]
+        bucket: Duration,
+
+        /// Number of most frequent node prefixes to list
+        #[arg(long, value_name = "N", default_value_t = 10)]
+        top: usize,
+    },
+
+    /// Compare two NULIDs; see `inspect --diff` for a detailed diff
+    #[command(visible_aliases = ["cmp", "c"])]
+    Compare {
+        /// First NULID string
+        first: String,
+
+        /// Second NULID string
+        second: String,
+    },
+
+    /// Sort NULIDs from arguments or stdin
+    #[command(visible_alias = "s")]
+    Sort {
+        /// NULID strings; read from stdin, one per line, if omitted
+        nulids: Vec<String>,
+
+        #[command(flatten)]
+        options: SortArgs,
+
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+
+    /// Print only the NULIDs whose timestamp falls within a time window
+    ///
+    /// The window is half-open: `--after` is inclusive and `--before` is
+    /// exclusive, so adjacent windows never select the same ID.
+    #[command(visible_alias = "f")]
+    #[command(group(ArgGroup::new("window").args(["after", "before"]).required(true).multiple(true)))]
+    Filter {
+        /// NULID strings; read from stdin, one per line, if omitted
+        nulids: Vec<String>,
+
+        /// Keep IDs at or after this time: RFC 3339 or nanoseconds since Unix epoch
+        #[arg(long, value_name = "TIME", value_parser = parse_timestamp)]
+        after: Option<u128>,
+
+        /// Keep IDs strictly before this time: RFC 3339 or nanoseconds since Unix epoch
+        #[arg(long, value_name = "TIME", value_parser = parse_timestamp)]
+        before: Option<u128>,
+
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+
+    /// Print the lowest and highest NULIDs for a time window
+    ///
+    /// Every NULID minted between `--from` and `--to` (both inclusive) sorts
+    /// between the two printed bounds, so they can be used directly in range
+    /// queries against NULID-keyed tables.
+    #[command(visible_alias = "r")]
+    Range {
+        /// Start of the window: RFC 3339 or nanoseconds since Unix epoch
+        #[arg(long, value_name = "TIME", value_parser = parse_timestamp)]
+        from: u128,
+
+        /// End of the window (inclusive): RFC 3339 or nanoseconds since Unix epoch
+        #[arg(long, value_name = "TIME", value_parser = parse_timestamp)]
+        to: u128,
+
+        /// Print a SQL `BETWEEN` clause instead of the bare bounds
+        #[arg(long)]
+        sql: bool,
+
+        /// Column name used in the `--sql` clause
+        #[arg(long, value_name = "NAME", default_value = "id", requires = "sql")]
+        column: String,
+
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+
+    /// Convert IDs between encodings
+    ///
+    /// `ulid` is the order-preserving ULID form of `Nulid::to_ulid_string`;
+    /// the other encodings carry the same 128 bits.
+    #[command(visible_alias = "conv")]
+    Convert {
+        /// IDs to convert; read from stdin, one per line, if omitted
+        ids: Vec<String>,
+
+        /// Encoding of the input IDs
+        #[arg(long, value_enum, default_value_t = Encoding::Nulid)]
+        from: Encoding,
+
+        /// Encoding to print
+        #[arg(long, value_enum)]
+        to: Encoding,
+    },
+
+    /// Convert a NULID to a UUID (shortcut for `convert --to uuid`)
+    #[command(visible_alias = "u", hide = true)]
+    Uuid {
+        /// NULID string
+        nulid: String,
+    },
+
+    /// Convert a UUID to a NULID (shortcut for `convert --from uuid`)
+    #[command(visible_alias = "fu", hide = true)]
+    FromUuid {
+        /// UUID string
+        uuid: String,
+
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+
+    /// Convert a NULID to an ISO 8601 datetime (requires the `chrono` feature)
+    #[command(visible_alias = "dt")]
+    Datetime {
+        /// NULID string
+        nulid: String,
+    },
+
+    /// Create a NULID from an ISO 8601 datetime (requires the `chrono` feature)
+    #[command(visible_alias = "fdt")]
+    FromDatetime {
+        /// ISO 8601 datetime, e.g. 2024-01-01T00:00:00Z
+        datetime: String,
+
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+
+    /// Measure generation, encoding, and parsing speed on this machine
+    ///
+    /// Runs over the seeded corpus from `nulid::bench_support`, so results
+    /// are comparable with downstream benchmarks that use the same inputs.
+    Bench {
+        /// Operations to time per benchmark
+        #[arg(short = 'n', long, value_name = "N", default_value_t = 100_000)]
+        count: u32,
+    },
+
+    /// Print a shell completion script to stdout
+    ///
+    /// For example, `nulid completions bash > /etc/bash_completion.d/nulid`.
+    Completions {
+        /// Target shell
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+
+    /// Print the man page (roff) to stdout
+    ///
+    /// For example, `nulid man > /usr/local/share/man/man1/nulid.1`.
+    Man,
+
+    /// Print version information
+    Version,
+}
+
+/// Flags selecting how IDs are printed.
+#[derive(Debug, Clone, Copy, Args)]
+struct OutputArgs {
+    /// Representation of printed IDs
+    #[arg(short, long, visible_alias = "as", value_enum, default_value_t = IdFormat::Base32)]
+    output: IdFormat,
+}
+
+/// Flags controlling `sort`.
+#[derive(Debug, Clone, Copy, Args)]
+struct SortArgs {
+    /// Sort newest first
+    #[arg(short, long)]
+    reverse: bool,
+
+    /// Print each distinct NULID once
+    #[arg(short, long)]
+    unique: bool,
+
+    /// Warn about invalid lines on stderr and sort the rest, instead of failing
+    #[arg(long)]
+    skip_invalid: bool,
+}
+
+/// Representation of a printed ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum IdFormat {
+    /// 26-character Crockford Base32 string
+    Base32,
+    /// 32 lowercase hex digits (big-endian bytes)
+    Hex,
+    /// Decimal `u128` value
+    U128,
+    /// Hyphenated UUID carrying the same 128 bits
+    Uuid,
+    /// Hyphenated RFC 9562 version 7 UUID (millisecond timestamp, lossy)
+    Uuid7,
+    /// 26-character ULID with a millisecond timestamp (order-preserving)
+    Ulid,
+}
+
+/// Flags selecting the output layout.
+#[derive(Debug, Clone, Copy, Args)]
+struct FormatArgs {
+    /// Output layout; structured layouts ignore `--output`
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Plain)]
+    format: OutputFormat,
+}
+
+/// Layout of command output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// Human-readable text
+    Plain,
+    /// A JSON object, or an array for commands printing several records
+    Json,
+    /// One JSON object per line
+    Ndjson,
+    /// Comma-separated values with a header row
+    Csv,
+}
+
+/// Structured view of a NULID for `--format json|ndjson|csv`.
+#[derive(Debug, Serialize)]
+struct IdRecord {
+    id: String,
+    /// Nanoseconds since Unix epoch.
+    timestamp: u128,
+    /// RFC 3339 UTC datetime with nanosecond precision.
+    datetime: String,
+    random: u64,
+    uuid: String,
+}
+
+impl IdRecord {
+    fn new(nulid: Nulid) -> Self {
+        Self {
+            id: nulid.to_string(),
+            timestamp: nulid.nanos(),
+            datetime: rfc3339(nulid),
+            random: nulid.random(),
+            uuid: uuid_string(nulid),
         }
-        "parse" | "p" => {
-            if args.len() < 3 {
-                eprintln!("Error: NULID string required for parse command");
-                eprintln!("Usage: nulid parse <nulid-string>");
-                process::exit(1);
-            }
-            parse(&args[2]);
-        }
-        "uuid" | "u" => {
-            #[cfg(feature = "uuid")]
-            {
-                if args.len() < 3 {
-                    eprintln!("Error: NULID string required for uuid command");
-                    eprintln!("Usage: nulid uuid <nulid-string>");
-                    process::exit(1);
-                }
-                to_uuid(&args[2]);
-            }
-            #[cfg(not(feature = "uuid"))]
-            {
-                eprintln!("Error: uuid feature not enabled");
-                eprintln!("Rebuild with: cargo build --features uuid");
-                process::exit(1);
+    }
+}
+
+/// Structured result of validating one input for `--format json|ndjson|csv`.
+#[derive(Debug, Serialize)]
+struct ValidationRecord {
+    /// 1-based input line.
+    line: usize,
+    input: String,
+    valid: bool,
+    error: Option<String>,
+    /// 0-based character index of the offending character, if there is one.
+    position: Option<usize>,
+}
+
+impl ValidationRecord {
+    fn new(line: usize, input: &str) -> Self {
+        // The record already carries the input, so report the bare reason.
+        let error = input.parse::<Nulid>().err().map(|e| e.reason());
+        let position = match error {
+            // The parser reports byte offsets; convert to a character index.
+            Some(nulid::Error::InvalidChar(_, byte)) => {
+                Some(input.char_indices().take_while(|&(i, _)| i < byte).count())
             }
+            _ => None,
+        };
+        Self {
+            line,
+            input: input.to_string(),
+            valid: error.is_none(),
+            error: error.map(|e| e.to_string()),
+            position,
         }
-        "from-uuid" | "fu" => {
-            #[cfg(feature = "uuid")]
-            {
-                if args.len() < 3 {
-                    eprintln!("Error: UUID string required for from-uuid command");
-                    eprintln!("Usage: nulid from-uuid <uuid-string>");
-                    process::exit(1);
-                }
-                from_uuid(&args[2]);
-            }
-            #[cfg(not(feature = "uuid"))]
-            {
-                eprintln!("Error: uuid feature not enabled");
-                eprintln!("Rebuild with: cargo build --features uuid");
-                process::exit(1);
+    }
+}
+
+/// Totals of a `validate` run.
+#[derive(Debug, Clone, Copy, Serialize)]
+struct ValidationSummary {
+    total: usize,
+    valid: usize,
+    invalid: usize,
+}
+
+/// A record that can be written as a CSV row.
+trait CsvRecord {
+    /// Header row naming the columns.
+    const HEADER: &'static str;
+
+    /// Unescaped column values, in header order.
+    fn fields(&self) -> Vec<String>;
+}
+
+impl CsvRecord for IdRecord {
+    const HEADER: &'static str = "id,timestamp,datetime,random,uuid";
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.timestamp.to_string(),
+            self.datetime.clone(),
+            self.random.to_string(),
+            self.uuid.clone(),
+        ]
+    }
+}
+
+impl CsvRecord for ValidationRecord {
+    const HEADER: &'static str = "line,input,valid,error,position";
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.line.to_string(),
+            self.input.clone(),
+            self.valid.to_string(),
+            self.error.clone().unwrap_or_default(),
+            self.position.map(|p| p.to_string()).unwrap_or_default(),
+        ]
+    }
+}
+
+impl CsvRecord for ValidationSummary {
+    const HEADER: &'static str = "total,valid,invalid";
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.total.to_string(),
+            self.valid.to_string(),
+            self.invalid.to_string(),
+        ]
+    }
+}
+
+/// Failure that ends the process with exit code 1.
+enum Failure {
+    /// Printed to stderr unless `--quiet`.
+    Message(String),
+    /// Reported through the exit code only, e.g. when `validate` finds
+    /// invalid IDs and has already printed per-ID results.
+    Silent,
+    /// Writing to stdout failed; see [`ignore_broken_pipe`].
+    Io(io::Error),
+}
+
+impl Failure {
+    fn new(message: impl fmt::Display) -> Self {
+        Self::Message(message.to_string())
+    }
+
+    const fn silent() -> Self {
+        Self::Silent
+    }
+}
+
+impl From<io::Error> for Failure {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+type CliResult = Result<(), Failure>;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match run(cli.command, cli.quiet, &mut io::stdout().lock()) {
+        Err(Failure::Io(e)) => ignore_broken_pipe(Err(e)),
+        result => result,
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(Failure::Message(message)) => {
+            if !cli.quiet {
+                eprintln!("Error: {message}");
             }
+            ExitCode::FAILURE
         }
-        "datetime" | "dt" => {
-            #[cfg(feature = "chrono")]
-            {
-                if args.len() < 3 {
-                    eprintln!("Error: NULID string required for datetime command");
-                    eprintln!("Usage: nulid datetime <nulid-string>");
-                    process::exit(1);
-                }
-                to_datetime(&args[2]);
-            }
-            #[cfg(not(feature = "chrono"))]
-            {
-                eprintln!("Error: chrono feature not enabled");
-                eprintln!("Rebuild with: cargo build --features chrono");
-                process::exit(1);
+        Err(Failure::Silent | Failure::Io(_)) => ExitCode::FAILURE,
+    }
+}
+
+/// Runs `command`, writing its output to `out`.
+#[allow(clippy::too_many_lines)]
+fn run(command: Command, quiet: bool, out: &mut impl Write) -> CliResult {
+    match command {
+        Command::Generate {
+            positional_count,
+            count,
+            follow,
+            interval,
+            rate,
+            at,
+            seed,
+            output,
+            format,
+        } => {
+            let clock = at.map_or(CliClock::System, CliClock::Fixed);
+            let rng = seed.map_or(CliRng::Os, |seed| {
+                CliRng::Seeded(Box::new(SeededRng::new(seed)))
+            });
+            let generator = Generator::with_deps(clock, rng);
+            if follow {
+                let interval = interval.or(rate).unwrap_or(Duration::from_secs(1));
+                generate_follow(&generator, interval, output.output, format.format, out)
+            } else {
+                generate(
+                    &generator,
+                    count.or(positional_count).unwrap_or(1),
+                    output.output,
+                    format.format,
+                    out,
+                )
             }
         }
-        "from-datetime" | "fdt" => {
-            #[cfg(feature = "chrono")]
-            {
-                if args.len() < 3 {
-                    eprintln!("Error: ISO 8601 datetime string required for from-datetime command");
-                    eprintln!("Usage: nulid from-datetime <iso8601-datetime>");
-                    process::exit(1);
-                }
-                from_datetime(&args[2]);
-            }
-            #[cfg(not(feature = "chrono"))]
-            {
-                eprintln!("Error: chrono feature not enabled");
-                eprintln!("Rebuild with: cargo build --features chrono");
-                process::exit(1);
-            }
+        Command::Parse {
+            nulid,
+            output,
+            format,
+        } => parse(&nulid, output.output, format.format, out),
+        Command::Inspect {
+            nulid,
+            other: Some(other),
+            format,
+            ..
+        } => inspect_diff(&nulid, &other, format.format, out),
+        Command::Inspect { nulid, format, .. } => inspect(&nulid, format.format, out),
+        Command::Decode { nulid } => decode(&nulid, out),
+        Command::Validate {
+            nulids,
+            fail_fast,
+            summary_only,
+            format,
+        } => {
+            let report = ValidationReport::new(&read_inputs(nulids)?, fail_fast);
+            validate(&report, format.format, summary_only, quiet, out)
+        }
+        Command::Check { nulids, max_gap } => check(&read_inputs(nulids)?, max_gap, quiet, out),
+        Command::Grep {
+            files,
+            only_matching,
+            annotate,
+        } => {
+            let mode = if annotate {
+                GrepMode::Annotate
+            } else if only_matching {
+                GrepMode::OnlyMatching
+            } else {
+                GrepMode::Lines
+            };
+            grep(&files, mode, out)
         }
-        "compare" | "cmp" | "c" => {
-            if args.len() < 4 {
-                eprintln!("Error: Two NULID strings required for compare command");
-                eprintln!("Usage: nulid compare <nulid1> <nulid2>");
-                process::exit(1);
+        Command::Stats {
+            nulids,
+            bucket,
+            top,
+        } => stats(&read_inputs(nulids)?, bucket, top, out),
+        Command::Compare { first, second } => compare(&first, &second, quiet, out),
+        Command::Sort {
+            nulids,
+            options,
+            output,
+        } => {
+            for nulid in sorted(&read_inputs(nulids)?, options, quiet)? {
+                writeln!(out, "{}", format_id(nulid, output.output)?)?;
             }
-            compare(&args[2], &args[3]);
+            Ok(())
         }
-        "sort" | "s" => {
-            if args.len() > 2 {
-                sort_args(&args[2..]);
-            } else {
-                sort_stdin();
+        Command::Filter {
+            nulids,
+            after,
+            before,
+            output,
+        } => filter(
+            &read_inputs(nulids)?,
+            after.unwrap_or(0)..before.unwrap_or(u128::MAX),
+            output.output,
+            out,
+        ),
+        Command::Range {
+            from,
+            to,
+            sql,
+            column,
+            output,
+        } => range(from, to, sql.then_some(column.as_str()), output.output, out),
+        Command::Convert { ids, from, to } => convert(&read_inputs(ids)?, from, to, out),
+        Command::Uuid { nulid } => convert(&[nulid], Encoding::Nulid, Encoding::Uuid, out),
+        Command::FromUuid { uuid, output } => {
+            convert(&[uuid], Encoding::Uuid, output.output.into(), out)
+        }
+        Command::Datetime { nulid } => to_datetime(&nulid, out),
+        Command::FromDatetime { datetime, output } => from_datetime(&datetime, output.output, out),
+        Command::Bench { count } => {
+            for (name, per_op) in bench_rows(count) {
+                writeln!(out, "{name:<16} {:>8} ns/op", per_op.as_nanos())?;
             }
+            Ok(())
+        }
+        Command::Completions { shell } => {
+            clap_complete::generate(shell, &mut Cli::command(), "nulid", out);
+            Ok(())
+        }
+        Command::Man => {
+            clap_mangen::Man::new(Cli::command()).render(out)?;
+            Ok(())
         }
-        "inspect" | "i" => {
-            if args.len() < 3 {
-                eprintln!("Error: NULID string required for inspect command");
-                eprintln!("Usage: nulid inspect <nulid-string>");
-                process::exit(1);
+        Command::Version => {
+            writeln!(out, "nulid {}", env!("CARGO_PKG_VERSION"))?;
+            Ok(())
+        }
+    }
+}
+
+/// Returns `args`, or the non-empty trimmed lines of stdin if `args` is empty.
+fn read_inputs(args: Vec<String>) -> Result<Vec<String>, Failure> {
+    if !args.is_empty() {
+        return Ok(args);
+    }
+
+    let mut inputs = Vec::new();
+    for line in io::stdin().lock().lines() {
+        let line = line.map_err(|e| Failure::new(format_args!("reading stdin: {e}")))?;
+        let trimmed = line.trim();
+        if !trimmed.is_empty() {
+            inputs.push(trimmed.to_string());
+        }
+    }
+    Ok(inputs)
+}
+
+fn parse_nulid(nulid_str: &str) -> Result<Nulid, Failure> {
+    nulid_str
+        .parse::<Nulid>()
+        .map_err(|e| Failure::new(format_args!("parsing NULID '{nulid_str}': {e}")))
+}
+
+fn format_id(nulid: Nulid, format: IdFormat) -> Result<String, Failure> {
+    Encoding::from(format)
+        .encode(nulid)
+        .map_err(|e| Failure::new(format_args!("formatting {nulid} as {format:?}: {e}")))
+}
+
+/// Writes structured records to `out` in the given layout.
+///
+/// Commands print `plain` output themselves, so it is a no-op here. With
+/// `single`, JSON output is a bare object instead of a one-element array.
+fn emit<T: Serialize + CsvRecord>(
+    records: &[T],
+    format: OutputFormat,
+    single: bool,
+    out: &mut impl Write,
+) -> CliResult {
+    match (format, records) {
+        (OutputFormat::Plain, _) => {}
+        (OutputFormat::Json, [record]) if single => writeln!(out, "{}", to_json(record)?)?,
+        (OutputFormat::Json, _) => writeln!(out, "{}", to_json(records)?)?,
+        (OutputFormat::Ndjson, _) => {
+            for record in records {
+                writeln!(out, "{}", to_json(record)?)?;
             }
-            inspect(&args[2]);
         }
-        "decode" | "d" => {
-            if args.len() < 3 {
-                eprintln!("Error: NULID string required for decode command");
-                eprintln!("Usage: nulid decode <nulid-string>");
-                process::exit(1);
+        (OutputFormat::Csv, _) => {
+            writeln!(out, "{}", T::HEADER)?;
+            for record in records {
+                writeln!(out, "{}", csv_row(&record.fields()))?;
             }
-            decode(&args[2]);
         }
-        "validate" | "v" => {
-            if args.len() > 2 {
-                validate_args(&args[2..]);
+    }
+    Ok(())
+}
+
+fn to_json<T: Serialize + ?Sized>(value: &T) -> Result<String, Failure> {
+    serde_json::to_string(value).map_err(|e| Failure::new(format_args!("encoding JSON: {e}")))
+}
+
+/// Joins fields into a CSV row, quoting fields that need it (RFC 4180).
+fn csv_row(fields: &[String]) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
             } else {
-                validate_stdin();
+                field.clone()
             }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(not(feature = "chrono"))]
+fn feature_disabled(feature: &str) -> Failure {
+    Failure::new(format_args!(
+        "{feature} feature not enabled\nRebuild with: cargo build --features cli,{feature}"
+    ))
+}
+
+/// Time source for `generate`: the system clock, or a fixed `--at` timestamp.
+enum CliClock {
+    System,
+    Fixed(u128),
+}
+
+impl Clock for CliClock {
+    fn now_nanos(&self) -> nulid::Result<u128> {
+        match self {
+            Self::System => SystemClock.now_nanos(),
+            Self::Fixed(nanos) => Ok(*nanos),
         }
-        "help" | "-h" | "--help" => {
-            print_help();
+    }
+}
+
+/// Random source for `generate`: OS randomness, or a `--seed`ed RNG.
+enum CliRng {
+    Os,
+    Seeded(Box<SeededRng>),
+}
+
+impl Rng for CliRng {
+    fn random_u64(&self) -> u64 {
+        match self {
+            Self::Os => CryptoRng.random_u64(),
+            Self::Seeded(rng) => rng.random_u64(),
         }
-        "version" | "-v" | "--version" => {
-            println!("nulid {}", env!("CARGO_PKG_VERSION"));
+    }
+}
+
+/// Parses an `--at` value: decimal nanoseconds since Unix epoch, or RFC 3339.
+fn parse_timestamp(value: &str) -> Result<u128, String> {
+    let nanos = if value.bytes().all(|b| b.is_ascii_digit()) {
+        value.parse::<u128>().map_err(|e| e.to_string())?
+    } else {
+        parse_rfc3339(value).ok_or_else(|| {
+            "expected nanoseconds since Unix epoch or an RFC 3339 datetime, \
+             e.g. 2024-01-01T00:00:00Z"
+                .to_string()
+        })?
+    };
+
+    if nanos >> Nulid::TIMESTAMP_BITS != 0 {
+        return Err(format!(
+            "timestamp exceeds the {}-bit NULID range",
+            Nulid::TIMESTAMP_BITS
+        ));
+    }
+    Ok(nanos)
+}
+
+/// Parses `YYYY-MM-DDTHH:MM:SS[.fraction](Z|+HH:MM|-HH:MM)` into nanoseconds
+/// since Unix epoch. Returns `None` for malformed or pre-epoch datetimes.
+fn parse_rfc3339(value: &str) -> Option<u128> {
+    fn number(digits: &str) -> Option<i64> {
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
         }
+        digits.parse().ok()
+    }
+
+    let (date, rest) = value.split_at_checked(10)?;
+    let rest = rest.strip_prefix(['T', 't', ' '])?;
+    let (time, zone) = rest.split_at_checked(8)?;
+
+    if date.get(4..5)? != "-" || date.get(7..8)? != "-" {
+        return None;
+    }
+    if time.get(2..3)? != ":" || time.get(5..6)? != ":" {
+        return None;
+    }
+    let (year, month, day) = (
+        number(date.get(..4)?)?,
+        number(date.get(5..7)?)?,
+        number(date.get(8..)?)?,
+    );
+    let (hour, minute, second) = (
+        number(time.get(..2)?)?,
+        number(time.get(3..5)?)?,
+        number(time.get(6..)?)?,
+    );
+
+    let (subsec_nanos, zone) = match zone.strip_prefix('.') {
+        Some(zone) => {
+            let end = zone
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(zone.len());
+            let (fraction, zone) = zone.split_at(end);
+            let scale = 9_u32.checked_sub(u32::try_from(fraction.len()).ok()?)?;
+            (number(fraction)? * 10_i64.pow(scale), zone)
+        }
+        None => (0, zone),
+    };
+
+    let offset_secs = match zone {
+        "Z" | "z" => 0,
         _ => {
-            eprintln!("Error: Unknown command '{}'", args[1]);
-            eprintln!();
-            print_help();
-            process::exit(1);
+            let (sign, offset) = match zone.split_at_checked(1)? {
+                ("+", offset) => (1, offset),
+                ("-", offset) => (-1, offset),
+                _ => return None,
+            };
+            let (hours, minutes) = offset.split_once(':')?;
+            if hours.len() != 2 || minutes.len() != 2 {
+                return None;
+            }
+            let (hours, minutes) = (number(hours)?, number(minutes)?);
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            sign * (hours * 3600 + minutes * 60)
         }
+    };
+
+    let days_in_month = match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return None,
+    };
+    if !(1..=days_in_month).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+        return None;
     }
+
+    let secs = days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second
+        - offset_secs;
+    let secs = u128::try_from(secs).ok()?;
+    Some(secs * 1_000_000_000 + u128::try_from(subsec_nanos).ok()?)
 }
 
-fn generate(count: usize) {
+fn generate<C: Clock, R: Rng>(
+    generator: &Generator<C, R, NoNodeId>,
+    count: usize,
+    output: IdFormat,
+    format: OutputFormat,
+    out: &mut impl Write,
+) -> CliResult {
+    let mut records = Vec::new();
     for _ in 0..count {
-        match Nulid::new() {
-            Ok(nulid) => println!("{nulid}"),
-            Err(e) => {
-                eprintln!("Error generating NULID: {e}");
-                process::exit(1);
+        let nulid = generator
+            .generate()
+            .map_err(|e| Failure::new(format_args!("generating NULID: {e}")))?;
+        if format == OutputFormat::Plain {
+            writeln!(out, "{}", format_id(nulid, output)?)?;
+        } else {
+            records.push(IdRecord::new(nulid));
+        }
+    }
+    emit(&records, format, false, out)
+}
+
+/// Emits one ID per `interval` until interrupted or stdout is closed.
+///
+/// Ticks are scheduled from the start time, so slow writes do not make the
+/// rate drift. Ends quietly when the reader goes away (e.g. `| head`).
+fn generate_follow<C: Clock, R: Rng>(
+    generator: &Generator<C, R, NoNodeId>,
+    interval: Duration,
+    output: IdFormat,
+    format: OutputFormat,
+    out: &mut impl Write,
+) -> CliResult {
+    if format == OutputFormat::Json {
+        return Err(Failure::new(
+            "--format json cannot be streamed; use --format ndjson with --follow",
+        ));
+    }
+
+    if format == OutputFormat::Csv {
+        writeln!(out, "{}", IdRecord::HEADER)?;
+    }
+
+    let mut next = Instant::now();
+    loop {
+        let nulid = generator
+            .generate()
+            .map_err(|e| Failure::new(format_args!("generating NULID: {e}")))?;
+        match format {
+            OutputFormat::Plain => writeln!(out, "{}", format_id(nulid, output)?)?,
+            OutputFormat::Json | OutputFormat::Ndjson => {
+                writeln!(out, "{}", to_json(&IdRecord::new(nulid))?)?;
+            }
+            OutputFormat::Csv => {
+                writeln!(out, "{}", csv_row(&IdRecord::new(nulid).fields()))?;
             }
         }
+        out.flush()?;
+
+        next += interval;
+        std::thread::sleep(next.saturating_duration_since(Instant::now()));
+    }
+}
+
+/// Treats a closed stdout (e.g. `| head`) as a normal end of output.
+fn ignore_broken_pipe(written: io::Result<()>) -> CliResult {
+    match written {
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => Err(Failure::new(e)),
+        _ => Ok(()),
     }
 }
 
-fn parse(nulid_str: &str) {
-    match nulid_str.parse::<Nulid>() {
-        Ok(nulid) => {
-            println!("{nulid}");
+/// Parses a `--interval` value such as `250ms`, `2s`, or `1m`.
+fn parse_interval(value: &str) -> Result<Duration, String> {
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(|| format!("missing unit in '{value}'; use ns, us, ms, s, or m"))?;
+    let (amount, unit) = value.split_at(split);
+    let amount = amount
+        .parse::<u64>()
+        .map_err(|_| format!("invalid duration '{value}'"))?;
+
+    let interval = match unit {
+        "ns" => Duration::from_nanos(amount),
+        "us" | "µs" => Duration::from_micros(amount),
+        "ms" => Duration::from_millis(amount),
+        "s" => Duration::from_secs(amount),
+        "m" => Duration::from_secs(amount.saturating_mul(60)),
+        _ => return Err(format!("unknown unit '{unit}'; use ns, us, ms, s, or m")),
+    };
+    if interval.is_zero() {
+        return Err("interval must be greater than zero".to_string());
+    }
+    Ok(interval)
+}
+
+/// Parses a `--rate` value (`N`, `N/s`, or `N/m`) into the interval between IDs.
+fn parse_rate(value: &str) -> Result<Duration, String> {
+    let (amount, per) = match value.split_once('/') {
+        Some((amount, "s")) => (amount, 1.0),
+        Some((amount, "m")) => (amount, 60.0),
+        Some((_, unit)) => return Err(format!("unknown rate unit '{unit}'; use s or m")),
+        None => (value, 1.0),
+    };
+    let amount = amount
+        .parse::<f64>()
+        .map_err(|_| format!("invalid rate '{value}'"))?;
+    if !amount.is_finite() || amount <= 0.0 {
+        return Err("rate must be greater than zero".to_string());
+    }
+    Duration::try_from_secs_f64(per / amount).map_err(|e| format!("invalid rate '{value}': {e}"))
+}
+
+fn parse(
+    nulid_str: &str,
+    output: IdFormat,
+    format: OutputFormat,
+    out: &mut impl Write,
+) -> CliResult {
+    let nulid = parse_nulid(nulid_str)?;
+    if format == OutputFormat::Plain {
+        writeln!(out, "{}", format_id(nulid, output)?)?;
+        return Ok(());
+    }
+    emit(&[IdRecord::new(nulid)], format, true, out)
+}
+
+fn inspect(nulid_str: &str, format: OutputFormat, out: &mut impl Write) -> CliResult {
+    let nulid = parse_nulid(nulid_str)?;
+    if format != OutputFormat::Plain {
+        return emit(&[IdRecord::new(nulid)], format, true, out);
+    }
+
+    let nanos = nulid.nanos();
+    let random = nulid.random();
+    let bytes = nulid.to_bytes();
+    let datetime = nulid.datetime();
+
+    writeln!(out, "NULID:       {nulid}")?;
+    writeln!(out, "Timestamp:   {nanos} ns since epoch")?;
+    writeln!(out, "Seconds:     {} s", nulid.seconds())?;
+    writeln!(out, "Subsec:      {} ns", nulid.subsec_nanos())?;
+    writeln!(out, "Randomness:  {random} (60-bit)")?;
+    writeln!(out, "Bytes:       {}", hex_encode(&bytes))?;
+    writeln!(out, "DateTime:    {datetime:?}")?;
+    writeln!(out, "u128 value:  0x{:032X}", nulid.as_u128())?;
+
+    #[cfg(feature = "uuid")]
+    {
+        let uuid = nulid.to_uuid();
+        writeln!(out, "UUID:        {uuid}")?;
+    }
+
+    #[cfg(feature = "chrono")]
+    {
+        match nulid.chrono_datetime() {
+            Ok(chrono_dt) => writeln!(out, "Chrono DT:   {chrono_dt}")?,
+            Err(e) => writeln!(out, "Chrono DT:   Error: {e}")?,
         }
-        Err(e) => {
-            eprintln!("Error parsing NULID: {e}");
-            process::exit(1);
+    }
+
+    Ok(())
+}
+
+/// Component-wise difference between two NULIDs, for `inspect --diff`.
+#[derive(Debug, Serialize)]
+struct DiffRecord {
+    a: String,
+    b: String,
+    /// `less`, `equal`, or `greater`: how `a` orders relative to `b`.
+    ordering: &'static str,
+    /// Timestamp of `b` minus timestamp of `a`, in nanoseconds.
+    delta_nanos: i128,
+    /// `delta_nanos` in human units.
+    delta: String,
+    /// Number of the 60 random bits that differ.
+    differing_random_bits: u32,
+    /// Indices of the differing bytes (big-endian).
+    differing_bytes: Vec<usize>,
+}
+
+impl DiffRecord {
+    fn new(a: Nulid, b: Nulid) -> Self {
+        let delta_nanos = if b.nanos() >= a.nanos() {
+            i128::try_from(b.nanos() - a.nanos()).unwrap_or(i128::MAX)
+        } else {
+            -i128::try_from(a.nanos() - b.nanos()).unwrap_or(i128::MAX)
+        };
+        let sign = if delta_nanos < 0 { "-" } else { "+" };
+        Self {
+            a: a.to_string(),
+            b: b.to_string(),
+            ordering: match a.cmp(&b) {
+                core::cmp::Ordering::Less => "less",
+                core::cmp::Ordering::Equal => "equal",
+                core::cmp::Ordering::Greater => "greater",
+            },
+            delta_nanos,
+            delta: format!("{sign}{}", human_duration(delta_nanos.unsigned_abs())),
+            differing_random_bits: (a.random() ^ b.random()).count_ones(),
+            differing_bytes: a
+                .to_bytes()
+                .iter()
+                .zip(b.to_bytes())
+                .enumerate()
+                .filter(|&(_, (x, y))| *x != y)
+                .map(|(i, _)| i)
+                .collect(),
         }
     }
 }
 
-fn inspect(nulid_str: &str) {
-    match nulid_str.parse::<Nulid>() {
-        Ok(nulid) => {
-            let nanos = nulid.nanos();
-            let random = nulid.random();
-            let bytes = nulid.to_bytes();
-            let datetime = nulid.datetime();
-
-            println!("NULID:       {nulid}");
-            println!("Timestamp:   {nanos} ns since epoch");
-            println!("Seconds:     {} s", nulid.seconds());
-            println!("Subsec:      {} ns", nulid.subsec_nanos());
-            println!("Randomness:  {random} (60-bit)");
-            println!("Bytes:       {}", hex_encode(&bytes));
-            println!("DateTime:    {datetime:?}");
-            println!("u128 value:  0x{:032X}", nulid.as_u128());
-
-            #[cfg(feature = "uuid")]
-            {
-                let uuid = nulid.to_uuid();
-                println!("UUID:        {uuid}");
+impl CsvRecord for DiffRecord {
+    const HEADER: &'static str =
+        "a,b,ordering,delta_nanos,delta,differing_random_bits,differing_bytes";
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.a.clone(),
+            self.b.clone(),
+            self.ordering.to_string(),
+            self.delta_nanos.to_string(),
+            self.delta.clone(),
+            self.differing_random_bits.to_string(),
+            self.differing_bytes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(";"),
+        ]
+    }
+}
+
+fn inspect_diff(a_str: &str, b_str: &str, format: OutputFormat, out: &mut impl Write) -> CliResult {
+    let (a, b) = (parse_nulid(a_str)?, parse_nulid(b_str)?);
+    let diff = DiffRecord::new(a, b);
+    if format != OutputFormat::Plain {
+        return emit(&[diff], format, true, out);
+    }
+
+    let relation = match diff.ordering {
+        "less" => "A < B (A is earlier)",
+        "greater" => "A > B (A is later)",
+        _ => "A == B",
+    };
+    writeln!(out, "A:           {a}  {}", rfc3339(a))?;
+    writeln!(out, "B:           {b}  {}", rfc3339(b))?;
+    writeln!(out, "Order:       {relation}")?;
+    writeln!(out, "Time delta:  {} ({} ns)", diff.delta, diff.delta_nanos)?;
+    writeln!(
+        out,
+        "Random:      {} of {} bits differ",
+        diff.differing_random_bits,
+        Nulid::RANDOM_BITS
+    )?;
+    writeln!(out, "  A:         {:015x}", a.random())?;
+    writeln!(out, "  B:         {:015x}", b.random())?;
+    writeln!(out, "  XOR:       {:015x}", a.random() ^ b.random())?;
+
+    let markers: String = (0..16)
+        .map(|i| {
+            if diff.differing_bytes.contains(&i) {
+                "^^ "
+            } else {
+                "   "
             }
+        })
+        .collect();
+    writeln!(
+        out,
+        "Bytes:       {} of 16 differ",
+        diff.differing_bytes.len()
+    )?;
+    writeln!(out, "  A:         {}", spaced_hex(&a.to_bytes()))?;
+    writeln!(out, "  B:         {}", spaced_hex(&b.to_bytes()))?;
+    writeln!(out, "             {}", markers.trim_end())?;
+    Ok(())
+}
 
-            #[cfg(feature = "chrono")]
-            {
-                match nulid.chrono_datetime() {
-                    Ok(chrono_dt) => println!("Chrono DT:   {chrono_dt}"),
-                    Err(e) => println!("Chrono DT:   Error: {e}"),
-                }
+/// Formats bytes as space-separated hex pairs.
+fn spaced_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Formats a nanosecond span with the largest fitting units, e.g.
+/// `1d 2h 3m 4.000000005s`, `12.5ms`, or `800ns`.
+fn human_duration(nanos: u128) -> String {
+    /// Trims trailing zeros from a fixed-width fraction.
+    fn fraction(value: u128, width: usize) -> String {
+        let digits = format!("{value:0width$}");
+        let digits = digits.trim_end_matches('0');
+        if digits.is_empty() {
+            String::new()
+        } else {
+            format!(".{digits}")
+        }
+    }
+
+    match nanos {
+        0..1_000 => format!("{nanos}ns"),
+        1_000..1_000_000 => format!("{}{}µs", nanos / 1_000, fraction(nanos % 1_000, 3)),
+        1_000_000..1_000_000_000 => {
+            format!("{}{}ms", nanos / 1_000_000, fraction(nanos % 1_000_000, 6))
+        }
+        _ => {
+            let secs = nanos / 1_000_000_000;
+            let (days, hours, minutes) = (secs / 86_400, secs / 3600 % 24, secs / 60 % 60);
+            let mut parts = Vec::new();
+            if days > 0 {
+                parts.push(format!("{days}d"));
+            }
+            if days > 0 || hours > 0 {
+                parts.push(format!("{hours}h"));
             }
+            if days > 0 || hours > 0 || minutes > 0 {
+                parts.push(format!("{minutes}m"));
+            }
+            parts.push(format!(
+                "{}{}s",
+                secs % 60,
+                fraction(nanos % 1_000_000_000, 9)
+            ));
+            parts.join(" ")
         }
-        Err(e) => {
-            eprintln!("Error inspecting NULID: {e}");
-            process::exit(1);
+    }
+}
+
+fn decode(nulid_str: &str, out: &mut impl Write) -> CliResult {
+    let nulid = parse_nulid(nulid_str)?;
+    writeln!(out, "{}", hex_encode(&nulid.to_bytes()))?;
+    Ok(())
+}
+
+/// Per-input results of a `validate` run.
+struct ValidationReport {
+    records: Vec<ValidationRecord>,
+}
+
+impl ValidationReport {
+    /// Validates each input in order; with `fail_fast`, stops after the
+    /// first invalid one.
+    fn new(nulid_strs: &[String], fail_fast: bool) -> Self {
+        let mut records = Vec::new();
+        for (index, nulid_str) in nulid_strs.iter().enumerate() {
+            let record = ValidationRecord::new(index + 1, nulid_str);
+            let stop = fail_fast && !record.valid;
+            records.push(record);
+            if stop {
+                break;
+            }
+        }
+        Self { records }
+    }
+
+    fn summary(&self) -> ValidationSummary {
+        let valid = self.records.iter().filter(|record| record.valid).count();
+        ValidationSummary {
+            total: self.records.len(),
+            valid,
+            invalid: self.records.len() - valid,
         }
     }
 }
 
-fn decode(nulid_str: &str) {
-    match nulid_str.parse::<Nulid>() {
-        Ok(nulid) => {
-            let bytes = nulid.to_bytes();
-            println!("{}", hex_encode(&bytes));
+fn validate(
+    report: &ValidationReport,
+    format: OutputFormat,
+    summary_only: bool,
+    quiet: bool,
+    out: &mut impl Write,
+) -> CliResult {
+    let summary = report.summary();
+
+    if quiet {
+        // Report through the exit code only.
+    } else if format != OutputFormat::Plain {
+        if summary_only {
+            emit(&[summary], format, true, out)?;
+        } else {
+            emit(&report.records, format, false, out)?;
         }
-        Err(e) => {
-            eprintln!("Error decoding NULID: {e}");
-            process::exit(1);
+    } else {
+        if !summary_only {
+            for record in &report.records {
+                match &record.error {
+                    None => writeln!(out, "{}: valid", record.input)?,
+                    Some(e) => writeln!(out, "{}: invalid ({e})", record.input)?,
+                }
+            }
+            writeln!(out)?;
         }
+        writeln!(out, "Valid:   {}", summary.valid)?;
+        writeln!(out, "Invalid: {}", summary.invalid)?;
     }
+
+    if summary.invalid > 0 {
+        return Err(Failure::silent());
+    }
+    Ok(())
 }
 
-fn validate_args(nulid_strs: &[String]) {
-    let mut valid_count = 0;
-    let mut invalid_count = 0;
+/// Textual encoding of a 128-bit ID, for `nulid convert`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Encoding {
+    /// 26-character Crockford Base32 NULID
+    #[value(alias = "base32")]
+    Nulid,
+    /// 26-character ULID with a millisecond timestamp (order-preserving)
+    Ulid,
+    /// Hyphenated UUID
+    Uuid,
+    /// Hyphenated RFC 9562 version 7 UUID (output only: drops sub-61 ns precision)
+    Uuid7,
+    /// 32 hex digits (big-endian bytes)
+    Hex,
+    /// Decimal `u128` value
+    U128,
+    /// Base58 (Bitcoin alphabet) of the 16 big-endian bytes
+    #[value(alias = "base58")]
+    B58,
+}
 
-    for nulid_str in nulid_strs {
-        match nulid_str.parse::<Nulid>() {
-            Ok(_) => {
-                println!("{nulid_str}: valid");
-                valid_count += 1;
+impl From<IdFormat> for Encoding {
+    fn from(format: IdFormat) -> Self {
+        match format {
+            IdFormat::Base32 => Self::Nulid,
+            IdFormat::Hex => Self::Hex,
+            IdFormat::U128 => Self::U128,
+            IdFormat::Uuid => Self::Uuid,
+            IdFormat::Uuid7 => Self::Uuid7,
+            IdFormat::Ulid => Self::Ulid,
+        }
+    }
+}
+
+impl Encoding {
+    /// Decodes `input` in this encoding.
+    fn decode(self, input: &str) -> Result<Nulid, String> {
+        match self {
+            Self::Nulid => input.parse().map_err(|e| format!("{e}")),
+            Self::Ulid => Nulid::from_ulid_string(input).map_err(|e| format!("{e}")),
+            Self::Uuid => {
+                let bytes = input.as_bytes();
+                let hyphenated =
+                    bytes.len() == 36 && [8, 13, 18, 23].iter().all(|&i| bytes[i] == b'-');
+                if !hyphenated && bytes.len() != 32 {
+                    return Err("expected a hyphenated or 32-digit UUID".to_string());
+                }
+                hex_decode(&input.replace('-', ""))
             }
-            Err(e) => {
-                println!("{nulid_str}: invalid ({e})");
-                invalid_count += 1;
+            Self::Uuid7 => {
+                Err("a UUIDv7 cannot be converted back; its timestamp is truncated".to_string())
             }
+            Self::Hex => hex_decode(input.strip_prefix("0x").unwrap_or(input)),
+            Self::U128 => input
+                .parse::<u128>()
+                .map(Nulid::from_u128)
+                .map_err(|e| format!("{e}")),
+            Self::B58 => base58_decode(input),
+        }
+    }
+
+    /// Encodes `nulid` in this encoding.
+    fn encode(self, nulid: Nulid) -> Result<String, String> {
+        match self {
+            Self::Nulid => Ok(nulid.to_string()),
+            Self::Ulid => nulid
+                .to_ulid_string()
+                .map(|ulid| ulid.to_string())
+                .map_err(|e| format!("{e}")),
+            Self::Uuid => Ok(uuid_string(nulid)),
+            Self::Uuid7 => nulid
+                .to_uuid_v7()
+                .map(|uuid| uuid.to_string())
+                .map_err(|e| format!("{e}")),
+            Self::Hex => Ok(hex_encode(&nulid.to_bytes())),
+            Self::U128 => Ok(nulid.as_u128().to_string()),
+            Self::B58 => Ok(base58_encode(nulid)),
         }
     }
+}
+
+/// Converts each input from one encoding to another, one per line.
+fn convert(inputs: &[String], from: Encoding, to: Encoding, out: &mut impl Write) -> CliResult {
+    for input in inputs {
+        let nulid = from
+            .decode(input)
+            .map_err(|e| Failure::new(format_args!("parsing '{input}' as {from:?}: {e}")))?;
+        let output = to
+            .encode(nulid)
+            .map_err(|e| Failure::new(format_args!("encoding {nulid} as {to:?}: {e}")))?;
+        writeln!(out, "{output}")?;
+    }
+    Ok(())
+}
+
+/// Bitcoin Base58 alphabet.
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Encodes the 16 big-endian bytes in Base58, one `1` per leading zero byte.
+fn base58_encode(nulid: Nulid) -> String {
+    let bytes = nulid.to_bytes();
+    let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+
+    let mut digits = Vec::new();
+    let mut value = nulid.as_u128();
+    while value > 0 {
+        digits.push(BASE58_ALPHABET[(value % 58) as usize]);
+        value /= 58;
+    }
+    digits.extend(core::iter::repeat_n(b'1', zeros));
+    digits.iter().rev().map(|&b| char::from(b)).collect()
+}
 
-    println!();
-    println!("Valid:   {valid_count}");
-    println!("Invalid: {invalid_count}");
+/// Decodes Base58 produced by [`base58_encode`], requiring exactly 16 bytes.
+fn base58_decode(input: &str) -> Result<Nulid, String> {
+    let zeros = input.bytes().take_while(|&b| b == b'1').count();
+    let mut value: u128 = 0;
+    for c in input.chars() {
+        let digit = BASE58_ALPHABET
+            .iter()
+            .position(|&b| char::from(b) == c)
+            .ok_or_else(|| format!("invalid Base58 character '{c}'"))?;
+        value = value
+            .checked_mul(58)
+            .and_then(|value| value.checked_add(digit as u128))
+            .ok_or("value exceeds 128 bits")?;
+    }
 
-    if invalid_count > 0 {
-        process::exit(1);
+    let significant = 16 - (value.leading_zeros() / 8) as usize;
+    if zeros + significant != 16 {
+        return Err("expected the Base58 encoding of exactly 16 bytes".to_string());
     }
+    Ok(Nulid::from_u128(value))
 }
 
-fn validate_stdin() {
-    let stdin = io::stdin();
-    let mut valid_count = 0;
-    let mut invalid_count = 0;
+/// Decodes exactly 32 hex digits into a NULID.
+fn hex_decode(hex: &str) -> Result<Nulid, String> {
+    if hex.len() != 32 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err("expected 32 hex digits".to_string());
+    }
+    u128::from_str_radix(hex, 16)
+        .map(Nulid::from_u128)
+        .map_err(|e| format!("{e}"))
+}
 
-    for line in stdin.lock().lines() {
-        match line {
-            Ok(nulid_str) => {
-                let trimmed = nulid_str.trim();
-                if trimmed.is_empty() {
+#[cfg(feature = "chrono")]
+fn to_datetime(nulid_str: &str, out: &mut impl Write) -> CliResult {
+    let nulid = parse_nulid(nulid_str)?;
+    let dt = nulid
+        .chrono_datetime()
+        .map_err(|e| Failure::new(format_args!("converting to datetime: {e}")))?;
+    writeln!(out, "{}", dt.to_rfc3339())?;
+    Ok(())
+}
+
+#[cfg(not(feature = "chrono"))]
+fn to_datetime(_nulid_str: &str, _out: &mut impl Write) -> CliResult {
+    Err(feature_disabled("chrono"))
+}
+
+#[cfg(feature = "chrono")]
+fn from_datetime(datetime_str: &str, format: IdFormat, out: &mut impl Write) -> CliResult {
+    let dt = datetime_str.parse::<DateTime<Utc>>().map_err(|e| {
+        Failure::new(format_args!(
+            "parsing datetime: {e}\nExpected ISO 8601 format, e.g., 2024-01-01T00:00:00Z"
+        ))
+    })?;
+    let nulid = Nulid::from_chrono_datetime(dt)
+        .map_err(|e| Failure::new(format_args!("creating NULID: {e}")))?;
+    writeln!(out, "{}", format_id(nulid, format)?)?;
+    Ok(())
+}
+
+#[cfg(not(feature = "chrono"))]
+fn from_datetime(_datetime_str: &str, _format: IdFormat, _out: &mut impl Write) -> CliResult {
+    Err(feature_disabled("chrono"))
+}
+
+/// Result of checking a NULID stream with `nulid check`.
+#[derive(Debug, Default)]
+struct CheckReport {
+    /// One message per violation, prefixed with its 1-based line number.
+    violations: Vec<String>,
+    total: usize,
+    duplicates: usize,
+    out_of_order: usize,
+    invalid: usize,
+    /// Smallest, largest, and summed timestamp gap between consecutive
+    /// in-order IDs, in nanoseconds.
+    gaps: Option<(u128, u128, u128)>,
+    gap_count: usize,
+    /// Earliest and latest timestamp seen.
+    span: Option<(u128, u128)>,
+}
+
+impl CheckReport {
+    fn new(nulid_strs: &[String], max_gap: Option<Duration>) -> Self {
+        let mut report = Self::default();
+        let mut seen = std::collections::HashMap::new();
+        let mut previous: Option<(usize, Nulid)> = None;
+
+        for (index, nulid_str) in nulid_strs.iter().enumerate() {
+            let line = index + 1;
+            report.total += 1;
+
+            let nulid = match nulid_str.parse::<Nulid>() {
+                Ok(nulid) => nulid,
+                Err(e) => {
+                    report.invalid += 1;
+                    report
+                        .violations
+                        .push(format!("line {line}: invalid '{nulid_str}' ({e})"));
                     continue;
                 }
-                match trimmed.parse::<Nulid>() {
-                    Ok(_) => {
-                        println!("{trimmed}: valid");
-                        valid_count += 1;
-                    }
-                    Err(e) => {
-                        println!("{trimmed}: invalid ({e})");
-                        invalid_count += 1;
+            };
+
+            let nanos = nulid.nanos();
+            report.span = Some(report.span.map_or((nanos, nanos), |(min, max)| {
+                (min.min(nanos), max.max(nanos))
+            }));
+
+            if let Some(first) = seen.get(&nulid) {
+                report.duplicates += 1;
+                report
+                    .violations
+                    .push(format!("line {line}: duplicate of line {first}: {nulid}"));
+            } else {
+                seen.insert(nulid, line);
+            }
+
+            if let Some((previous_line, previous)) = previous {
+                if nulid < previous {
+                    report.out_of_order += 1;
+                    report.violations.push(format!(
+                        "line {line}: out of order: {nulid} < {previous} (line {previous_line})"
+                    ));
+                } else {
+                    let gap = nanos - previous.nanos();
+                    report.gap_count += 1;
+                    report.gaps = Some(report.gaps.map_or((gap, gap, gap), |(min, max, sum)| {
+                        (min.min(gap), max.max(gap), sum + gap)
+                    }));
+                    if max_gap.is_some_and(|max_gap| gap > max_gap.as_nanos()) {
+                        report.violations.push(format!(
+                            "line {line}: gap of {gap} ns since line {previous_line} exceeds --max-gap"
+                        ));
                     }
                 }
             }
-            Err(e) => {
-                eprintln!("Error reading stdin: {e}");
-                process::exit(1);
-            }
+            previous = Some((line, nulid));
         }
-    }
-
-    println!();
-    println!("Valid:   {valid_count}");
-    println!("Invalid: {invalid_count}");
 
-    if invalid_count > 0 {
-        process::exit(1);
+        report
     }
 }
 
-#[cfg(feature = "uuid")]
-fn to_uuid(nulid_str: &str) {
-    match nulid_str.parse::<Nulid>() {
-        Ok(nulid) => {
-            let uuid = nulid.to_uuid();
-            println!("{uuid}");
+fn check(
+    nulid_strs: &[String],
+    max_gap: Option<Duration>,
+    quiet: bool,
+    out: &mut impl Write,
+) -> CliResult {
+    let report = CheckReport::new(nulid_strs, max_gap);
+
+    if !quiet {
+        for violation in &report.violations {
+            writeln!(out, "{violation}")?;
+        }
+        if !report.violations.is_empty() {
+            writeln!(out)?;
+        }
+
+        writeln!(out, "IDs:          {}", report.total)?;
+        writeln!(out, "Duplicates:   {}", report.duplicates)?;
+        writeln!(out, "Out of order: {}", report.out_of_order)?;
+        writeln!(out, "Invalid:      {}", report.invalid)?;
+        if let Some((min, max)) = report.span {
+            writeln!(out, "Time span:    {} ns", max - min)?;
         }
-        Err(e) => {
-            eprintln!("Error parsing NULID: {e}");
-            process::exit(1);
+        if let Some((min, max, sum)) = report.gaps {
+            writeln!(out, "Min gap:      {min} ns")?;
+            writeln!(out, "Mean gap:     {} ns", sum / report.gap_count as u128)?;
+            writeln!(out, "Max gap:      {max} ns")?;
         }
     }
+
+    if report.violations.is_empty() {
+        Ok(())
+    } else {
+        Err(Failure::silent())
+    }
 }
 
-#[cfg(feature = "uuid")]
-fn from_uuid(uuid_str: &str) {
-    match uuid_str.parse::<Uuid>() {
-        Ok(uuid) => {
-            let nulid = Nulid::from_uuid(uuid);
-            println!("{nulid}");
+/// What `nulid grep` prints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GrepMode {
+    /// Lines containing at least one NULID.
+    Lines,
+    /// Each matched NULID on its own line.
+    OnlyMatching,
+    /// Every line, with matches annotated inline.
+    Annotate,
+}
+
+/// Finds the valid NULIDs that form whole alphanumeric tokens in `line`.
+fn find_nulids(line: &str) -> Vec<(core::ops::Range<usize>, Nulid)> {
+    let mut matches = Vec::new();
+    let bytes = line.as_bytes();
+    let mut start = 0;
+    while start < bytes.len() {
+        if !bytes[start].is_ascii_alphanumeric() {
+            start += 1;
+            continue;
         }
-        Err(e) => {
-            eprintln!("Error parsing UUID: {e}");
-            process::exit(1);
+        let end = bytes[start..]
+            .iter()
+            .position(|b| !b.is_ascii_alphanumeric())
+            .map_or(bytes.len(), |len| start + len);
+        // A leading digit above 7 would overflow 128 bits.
+        if bytes[start] <= b'7'
+            && let Ok(nulid) = line[start..end].parse::<Nulid>()
+        {
+            matches.push((start..end, nulid));
         }
+        start = end;
     }
+    matches
 }
 
-#[cfg(feature = "chrono")]
-fn to_datetime(nulid_str: &str) {
-    match nulid_str.parse::<Nulid>() {
-        Ok(nulid) => match nulid.chrono_datetime() {
-            Ok(dt) => println!("{}", dt.to_rfc3339()),
-            Err(e) => {
-                eprintln!("Error converting to datetime: {e}");
-                process::exit(1);
+/// Scans `files` (or stdin) for NULIDs and prints them according to `mode`.
+fn grep(files: &[PathBuf], mode: GrepMode, out: &mut impl Write) -> CliResult {
+    let mut readers: Vec<Box<dyn BufRead>> = Vec::new();
+    if files.is_empty() {
+        readers.push(Box::new(io::stdin().lock()));
+    }
+    for path in files {
+        let file = std::fs::File::open(path)
+            .map_err(|e| Failure::new(format_args!("opening {}: {e}", path.display())))?;
+        readers.push(Box::new(io::BufReader::new(file)));
+    }
+
+    let mut any_match = false;
+    for reader in readers {
+        for line in reader.lines() {
+            let line = line.map_err(|e| Failure::new(format_args!("reading input: {e}")))?;
+            let matches = find_nulids(&line);
+            any_match |= !matches.is_empty();
+
+            match mode {
+                GrepMode::Lines if matches.is_empty() => {}
+                GrepMode::Lines => writeln!(out, "{line}")?,
+                GrepMode::OnlyMatching => {
+                    for (_, nulid) in &matches {
+                        writeln!(out, "{nulid}")?;
+                    }
+                }
+                GrepMode::Annotate => writeln!(out, "{}", annotate(&line, &matches))?,
             }
-        },
-        Err(e) => {
-            eprintln!("Error parsing NULID: {e}");
-            process::exit(1);
         }
     }
+
+    if any_match {
+        Ok(())
+    } else {
+        Err(Failure::silent())
+    }
 }
 
-#[cfg(feature = "chrono")]
-fn from_datetime(datetime_str: &str) {
-    match datetime_str.parse::<DateTime<Utc>>() {
-        Ok(dt) => match Nulid::from_chrono_datetime(dt) {
-            Ok(nulid) => println!("{nulid}"),
-            Err(e) => {
-                eprintln!("Error creating NULID: {e}");
-                process::exit(1);
-            }
-        },
-        Err(e) => {
-            eprintln!("Error parsing datetime: {e}");
-            eprintln!("Expected ISO 8601 format, e.g., 2024-01-01T00:00:00Z");
-            process::exit(1);
-        }
+/// Inserts `[<rfc3339>]` after each match in `line`.
+fn annotate(line: &str, matches: &[(core::ops::Range<usize>, Nulid)]) -> String {
+    let mut annotated = String::with_capacity(line.len() + matches.len() * 34);
+    let mut copied = 0;
+    for (range, nulid) in matches {
+        annotated.push_str(&line[copied..range.end]);
+        annotated.push('[');
+        annotated.push_str(&rfc3339(*nulid));
+        annotated.push(']');
+        copied = range.end;
     }
+    annotated.push_str(&line[copied..]);
+    annotated
 }
 
-fn compare(nulid_str1: &str, nulid_str2: &str) {
-    let nulid1 = match nulid_str1.parse::<Nulid>() {
-        Ok(n) => n,
-        Err(e) => {
-            eprintln!("Error parsing first NULID: {e}");
-            process::exit(1);
+/// Shift of the 16-bit node ID within the random field, when one is configured.
+const NODE_ID_SHIFT: u32 = 44;
+
+/// Summary of a NULID corpus for `nulid stats`.
+#[derive(Debug, Default)]
+struct CorpusStats {
+    total: usize,
+    invalid: usize,
+    /// Earliest and latest timestamp, in nanoseconds.
+    span: Option<(u128, u128)>,
+    /// ID count per bucket, keyed by bucket start in nanoseconds.
+    histogram: std::collections::BTreeMap<u128, usize>,
+    /// ID count per 16-bit node prefix.
+    node_prefixes: std::collections::HashMap<u16, usize>,
+    /// How often each of the 60 random bits is set, least significant first.
+    ones: Vec<usize>,
+}
+
+impl CorpusStats {
+    fn new(nulid_strs: &[String], bucket: Duration) -> Self {
+        let bucket = bucket.as_nanos().max(1);
+        let mut stats = Self {
+            ones: vec![0; Nulid::RANDOM_BITS as usize],
+            ..Self::default()
+        };
+
+        for nulid_str in nulid_strs {
+            stats.total += 1;
+            let Ok(nulid) = nulid_str.parse::<Nulid>() else {
+                stats.invalid += 1;
+                continue;
+            };
+
+            let (nanos, random) = nulid.parts();
+            stats.span = Some(stats.span.map_or((nanos, nanos), |(min, max)| {
+                (min.min(nanos), max.max(nanos))
+            }));
+            *stats.histogram.entry(nanos - nanos % bucket).or_default() += 1;
+            #[allow(clippy::cast_possible_truncation)]
+            let prefix = (random >> NODE_ID_SHIFT) as u16;
+            *stats.node_prefixes.entry(prefix).or_default() += 1;
+            for (bit, ones) in stats.ones.iter_mut().enumerate() {
+                *ones += usize::from(random >> bit & 1 == 1);
+            }
         }
-    };
 
-    let nulid2 = match nulid_str2.parse::<Nulid>() {
-        Ok(n) => n,
-        Err(e) => {
-            eprintln!("Error parsing second NULID: {e}");
-            process::exit(1);
+        stats
+    }
+
+    /// Number of successfully parsed IDs.
+    const fn valid(&self) -> usize {
+        self.total - self.invalid
+    }
+
+    /// Estimated entropy of the random field in bits: the sum of each bit's
+    /// Shannon entropy. An upper bound; 60 for perfectly random bits.
+    #[allow(clippy::cast_precision_loss)]
+    fn entropy_bits(&self) -> f64 {
+        let valid = self.valid();
+        if valid == 0 {
+            return 0.0;
         }
+        self.ones
+            .iter()
+            .map(|&ones| {
+                let p = ones as f64 / valid as f64;
+                if p <= 0.0 || p >= 1.0 {
+                    0.0
+                } else {
+                    -p.mul_add(p.log2(), (1.0 - p) * (1.0 - p).log2())
+                }
+            })
+            .sum()
+    }
+
+    /// Random bits that have the same value in every ID.
+    fn constant_bits(&self) -> usize {
+        let valid = self.valid();
+        self.ones
+            .iter()
+            .filter(|&&ones| ones == 0 || ones == valid)
+            .count()
+    }
+}
+
+fn stats(nulid_strs: &[String], bucket: Duration, top: usize, out: &mut impl Write) -> CliResult {
+    /// Width of the longest histogram bar, in characters.
+    const BAR_WIDTH: usize = 40;
+
+    let stats = CorpusStats::new(nulid_strs, bucket);
+    writeln!(out, "IDs:          {}", stats.total)?;
+    writeln!(out, "Invalid:      {}", stats.invalid)?;
+    let Some((earliest, latest)) = stats.span else {
+        return Ok(());
     };
 
-    println!("NULID 1:     {nulid1}");
-    println!("  Timestamp: {} ns", nulid1.nanos());
-    println!("  Random:    {}", nulid1.random());
-    println!();
-    println!("NULID 2:     {nulid2}");
-    println!("  Timestamp: {} ns", nulid2.nanos());
-    println!("  Random:    {}", nulid2.random());
-    println!();
+    let datetime = |nanos| rfc3339(Nulid::from_nanos(nanos, 0));
+    writeln!(out, "Earliest:     {} ({earliest} ns)", datetime(earliest))?;
+    writeln!(out, "Latest:       {} ({latest} ns)", datetime(latest))?;
+    writeln!(out, "Time span:    {} ns", latest - earliest)?;
+
+    writeln!(out)?;
+    writeln!(out, "IDs per {bucket:?}:")?;
+    let peak = stats.histogram.values().copied().max().unwrap_or(1);
+    for (&start, &count) in &stats.histogram {
+        let bar = "#".repeat((count * BAR_WIDTH).div_ceil(peak));
+        writeln!(out, "  {}  {count:>8}  {bar}", datetime(start))?;
+    }
+
+    writeln!(out)?;
+    writeln!(out, "Node prefixes (top 16 random bits):")?;
+    writeln!(out, "  Distinct:   {}", stats.node_prefixes.len())?;
+    let mut prefixes: Vec<_> = stats.node_prefixes.iter().collect();
+    prefixes.sort_unstable_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+    for (prefix, count) in prefixes.into_iter().take(top) {
+        writeln!(out, "  {prefix:>5}       {count}")?;
+    }
 
-    match nulid1.cmp(&nulid2) {
+    writeln!(out)?;
+    writeln!(
+        out,
+        "Entropy:      {:.2} of {} random bits (per-bit estimate)",
+        stats.entropy_bits(),
+        Nulid::RANDOM_BITS
+    )?;
+    writeln!(out, "Constant:     {} bits", stats.constant_bits())?;
+    Ok(())
+}
+
+fn compare(nulid_str1: &str, nulid_str2: &str, quiet: bool, out: &mut impl Write) -> CliResult {
+    let nulid1 = parse_nulid(nulid_str1)?;
+    let nulid2 = parse_nulid(nulid_str2)?;
+    let ordering = nulid1.cmp(&nulid2);
+
+    if quiet {
+        let symbol = match ordering {
+            core::cmp::Ordering::Less => "<",
+            core::cmp::Ordering::Equal => "=",
+            core::cmp::Ordering::Greater => ">",
+        };
+        writeln!(out, "{symbol}")?;
+        return Ok(());
+    }
+
+    writeln!(out, "NULID 1:     {nulid1}")?;
+    writeln!(out, "  Timestamp: {} ns", nulid1.nanos())?;
+    writeln!(out, "  Random:    {}", nulid1.random())?;
+    writeln!(out)?;
+    writeln!(out, "NULID 2:     {nulid2}")?;
+    writeln!(out, "  Timestamp: {} ns", nulid2.nanos())?;
+    writeln!(out, "  Random:    {}", nulid2.random())?;
+    writeln!(out)?;
+
+    match ordering {
         core::cmp::Ordering::Less => {
-            println!("Result:      NULID 1 < NULID 2 (earlier)");
+            writeln!(out, "Result:      NULID 1 < NULID 2 (earlier)")?;
             let diff = nulid2.nanos().saturating_sub(nulid1.nanos());
-            println!("Time diff:   {diff} ns");
+            writeln!(out, "Time diff:   {diff} ns")?;
         }
         core::cmp::Ordering::Equal => {
-            println!("Result:      NULID 1 == NULID 2 (equal)");
+            writeln!(out, "Result:      NULID 1 == NULID 2 (equal)")?;
         }
         core::cmp::Ordering::Greater => {
-            println!("Result:      NULID 1 > NULID 2 (later)");
+            writeln!(out, "Result:      NULID 1 > NULID 2 (later)")?;
             let diff = nulid1.nanos().saturating_sub(nulid2.nanos());
-            println!("Time diff:   {diff} ns");
+            writeln!(out, "Time diff:   {diff} ns")?;
         }
     }
-}
 
-fn sort_args(nulid_strs: &[String]) {
-    let mut nulids: Vec<(String, Nulid)> = Vec::new();
+    Ok(())
+}
 
-    for nulid_str in nulid_strs {
-        match nulid_str.parse::<Nulid>() {
-            Ok(nulid) => {
-                nulids.push((nulid_str.clone(), nulid));
-            }
-            Err(e) => {
-                eprintln!("Error parsing NULID '{nulid_str}': {e}");
-                process::exit(1);
+/// Parses and sorts `nulid_strs` according to `options`.
+///
+/// With `skip_invalid`, unparseable lines are reported on stderr (unless
+/// `quiet`) and left out; otherwise the first one is an error.
+fn sorted(nulid_strs: &[String], options: SortArgs, quiet: bool) -> Result<Vec<Nulid>, Failure> {
+    let mut nulids = Vec::with_capacity(nulid_strs.len());
+    for (index, nulid_str) in nulid_strs.iter().enumerate() {
+        match parse_nulid(nulid_str) {
+            Ok(nulid) => nulids.push(nulid),
+            Err(Failure::Message(message)) if options.skip_invalid => {
+                if !quiet {
+                    eprintln!("Warning: line {}: {}", index + 1, message);
+                }
             }
+            Err(failure) => return Err(failure),
         }
     }
 
-    nulids.sort_by_key(|(_, nulid)| *nulid);
+    nulids.sort_unstable();
+    if options.unique {
+        nulids.dedup();
+    }
+    if options.reverse {
+        nulids.reverse();
+    }
+    Ok(nulids)
+}
 
-    for (original, _) in nulids {
-        println!("{original}");
+/// Prints the NULIDs whose timestamp lies in `window`, preserving input order.
+fn filter(
+    nulid_strs: &[String],
+    window: core::ops::Range<u128>,
+    format: IdFormat,
+    out: &mut impl Write,
+) -> CliResult {
+    for nulid_str in nulid_strs {
+        let nulid = parse_nulid(nulid_str)?;
+        if window.contains(&nulid.nanos()) {
+            writeln!(out, "{}", format_id(nulid, format)?)?;
+        }
     }
+    Ok(())
 }
 
-fn sort_stdin() {
-    let stdin = io::stdin();
-    let mut nulids: Vec<(String, Nulid)> = Vec::new();
+/// Prints the NULID bounds of `[from, to]`, or a `BETWEEN` clause on `column`.
+fn range(
+    from: u128,
+    to: u128,
+    column: Option<&str>,
+    format: IdFormat,
+    out: &mut impl Write,
+) -> CliResult {
+    if from > to {
+        return Err(Failure::new("--from must not be later than --to"));
+    }
 
-    for line in stdin.lock().lines() {
-        match line {
-            Ok(nulid_str) => {
-                let trimmed = nulid_str.trim();
-                if trimmed.is_empty() {
-                    continue;
-                }
-                match trimmed.parse::<Nulid>() {
-                    Ok(nulid) => {
-                        nulids.push((trimmed.to_string(), nulid));
-                    }
-                    Err(e) => {
-                        eprintln!("Error parsing NULID '{trimmed}': {e}");
-                        process::exit(1);
-                    }
-                }
-            }
-            Err(e) => {
-                eprintln!("Error reading stdin: {e}");
-                process::exit(1);
-            }
+    let (lower, upper) = range_bounds(from, to);
+    let (lower, upper) = (format_id(lower, format)?, format_id(upper, format)?);
+    match column {
+        Some(column) if format == IdFormat::U128 => {
+            writeln!(out, "{column} BETWEEN {lower} AND {upper}")?;
+        }
+        Some(column) => writeln!(out, "{column} BETWEEN '{lower}' AND '{upper}'")?,
+        None => {
+            writeln!(out, "{lower}")?;
+            writeln!(out, "{upper}")?;
         }
     }
+    Ok(())
+}
 
-    nulids.sort_by_key(|(_, nulid)| *nulid);
+/// Returns the zero-filled and one-filled NULIDs bounding `[from, to]`.
+const fn range_bounds(from: u128, to: u128) -> (Nulid, Nulid) {
+    (Nulid::from_nanos(from, 0), Nulid::from_nanos(to, u64::MAX))
+}
 
-    for (original, _) in nulids {
-        println!("{original}");
-    }
+/// Times each core operation `count` times, cycling over the bench corpus.
+fn bench_rows(count: u32) -> Vec<(&'static str, Duration)> {
+    let corpus_len = usize::try_from(count)
+        .unwrap_or(usize::MAX)
+        .clamp(1, 100_000);
+    let ids = bench_support::corpus(corpus_len, DEFAULT_SEED);
+    let strings = bench_support::string_corpus(corpus_len, DEFAULT_SEED);
+    let generator = Generator::new();
+
+    let mut ids_cycle = ids.iter().copied().cycle();
+    let mut strings_cycle = strings.iter().cycle();
+    let mut edge_cycle = bench_support::VALID_EDGE_STRINGS.iter().cycle();
+    let mut invalid_cycle = bench_support::INVALID_STRINGS.iter().cycle();
+    vec![
+        (
+            "generate",
+            bench_support::time_per_op(count, || generator.generate()),
+        ),
+        (
+            "encode",
+            bench_support::time_per_op(count, || ids_cycle.next().map(Nulid::to_arraystring)),
+        ),
+        (
+            "parse",
+            bench_support::time_per_op(count, || strings_cycle.next().map(|s| s.parse::<Nulid>())),
+        ),
+        (
+            "parse_edge",
+            bench_support::time_per_op(count, || edge_cycle.next().map(|s| s.parse::<Nulid>())),
+        ),
+        (
+            "parse_invalid",
+            bench_support::time_per_op(count, || invalid_cycle.next().map(|s| s.parse::<Nulid>())),
+        ),
+    ]
 }
 
 fn hex_encode(bytes: &[u8]) -> String {
@@ -472,87 +2020,631 @@ fn hex_encode(bytes: &[u8]) -> String {
     })
 }
 
-fn print_help() {
-    println!("NULID CLI - Nanosecond-Precision Universally Lexicographically Sortable Identifier");
-    println!();
-    println!("USAGE:");
-    println!("    nulid <COMMAND> [OPTIONS]");
-    println!();
-    println!("COMMANDS:");
-    println!("    generate, gen, g [COUNT]       Generate NULID(s) (default: 1)");
-    println!("    parse, p <NULID>               Parse and validate a NULID string");
-    println!("    inspect, i <NULID>             Inspect NULID components in detail");
-    println!("    decode, d <NULID>              Decode NULID to hex bytes");
-    println!("    validate, v [NULID...]         Validate NULID(s) from args or stdin");
-    println!("    compare, cmp, c <N1> <N2>      Compare two NULIDs");
-    println!("    sort, s [NULID...]             Sort NULIDs from args or stdin");
-    println!();
-    #[cfg(feature = "uuid")]
-    println!("UUID COMMANDS (requires --features uuid):");
-    #[cfg(not(feature = "uuid"))]
-    println!("UUID COMMANDS (disabled - rebuild with --features uuid):");
-    println!("    uuid, u <NULID>                Convert NULID to UUID");
-    println!("    from-uuid, fu <UUID>           Convert UUID to NULID");
-    println!();
-    #[cfg(feature = "chrono")]
-    println!("DATETIME COMMANDS (requires --features chrono):");
-    #[cfg(not(feature = "chrono"))]
-    println!("DATETIME COMMANDS (disabled - rebuild with --features chrono):");
-    println!("    datetime, dt <NULID>           Convert NULID to ISO 8601 datetime");
-    println!("    from-datetime, fdt <DATETIME>  Create NULID from ISO 8601 datetime");
-    println!();
-    println!("OTHER COMMANDS:");
-    println!("    help, -h, --help               Print this help message");
-    println!("    version, -v, --version         Print version information");
-    println!();
-    println!("EXAMPLES:");
-    println!("    # Generate a single NULID");
-    println!("    nulid generate");
-    println!();
-    println!("    # Generate 10 NULIDs");
-    println!("    nulid gen 10");
-    println!();
-    println!("    # Parse a NULID string");
-    println!("    nulid parse 01GZWQ22K2MNDR0GAQTE834QRV");
-    println!();
-    println!("    # Inspect NULID details");
-    println!("    nulid inspect 01GZWQ22K2MNDR0GAQTE834QRV");
-    println!();
-    println!("    # Decode to hex");
-    println!("    nulid decode 01GZWQ22K2MNDR0GAQTE834QRV");
-    println!();
-    println!("    # Validate multiple NULIDs");
-    println!("    nulid validate 01GZWQ22K2MNDR0GAQTE834QRV 01GZWQ22K2TKVGHH1Z1G0AK1EK");
-    println!();
-    println!("    # Validate from stdin");
-    println!("    cat nulids.txt | nulid validate");
-    println!();
-    println!("    # Compare two NULIDs");
-    println!("    nulid compare 01GZWQ22K2MNDR0GAQTE834QRV 01GZWQ22K2TKVGHH1Z1G0AK1EK");
-    println!();
-    println!("    # Sort NULIDs");
-    println!("    nulid sort 01GZWQ22K2TKVGHH1Z1G0AK1EK 01GZWQ22K2MNDR0GAQTE834QRV");
-    println!();
-    println!("    # Sort from stdin");
-    println!("    cat nulids.txt | nulid sort");
-    println!();
-    #[cfg(feature = "uuid")]
-    {
-        println!("    # Convert NULID to UUID");
-        println!("    nulid uuid 01GZWQ22K2MNDR0GAQTE834QRV");
-        println!();
-        println!("    # Convert UUID to NULID");
-        println!("    nulid from-uuid 018d3f9c-5a2e-7b4d-8f1c-3e6a9d2c5b7e");
-        println!();
+/// Formats a NULID as a hyphenated UUID, matching `Nulid::to_uuid`.
+fn uuid_string(nulid: Nulid) -> String {
+    let hex = hex_encode(&nulid.to_bytes());
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+/// Formats the NULID timestamp as an RFC 3339 UTC datetime with nanoseconds.
+fn rfc3339(nulid: Nulid) -> String {
+    let secs = nulid.seconds();
+    let (year, month, day) = civil_from_days(secs / 86_400);
+    let secs_of_day = secs % 86_400;
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:09}Z",
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60,
+        nulid.subsec_nanos()
+    )
+}
+
+/// Converts days since Unix epoch to a proleptic Gregorian `(year, month, day)`.
+///
+/// Howard Hinnant's `civil_from_days`, restricted to non-negative days.
+const fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Converts a proleptic Gregorian date to days since Unix epoch.
+///
+/// Howard Hinnant's `days_from_civil`, the inverse of [`civil_from_days`].
+const fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_completions_and_man() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+            let mut script = Vec::new();
+            clap_complete::generate(shell, &mut Cli::command(), "nulid", &mut script);
+            let script = String::from_utf8(script).unwrap();
+            assert!(script.contains("inspect"), "{shell}");
+        }
+
+        let mut page = Vec::new();
+        clap_mangen::Man::new(Cli::command())
+            .render(&mut page)
+            .unwrap();
+        let page = String::from_utf8(page).unwrap();
+        assert!(page.starts_with(".ie"));
+        assert!(page.contains("nulid"));
+    }
+
+    #[test]
+    fn test_bench_rows() {
+        let rows = bench_rows(100);
+        let names: Vec<_> = rows.iter().map(|(name, _)| *name).collect();
+        assert_eq!(
+            names,
+            ["generate", "encode", "parse", "parse_edge", "parse_invalid"]
+        );
+    }
+
+    #[test]
+    fn test_generate_count_forms() {
+        for args in [
+            &["nulid", "gen", "3"][..],
+            &["nulid", "generate", "--count", "3"],
+            &["nulid", "g", "-n", "3"],
+        ] {
+            let cli = Cli::try_parse_from(args).unwrap();
+            let Command::Generate {
+                positional_count,
+                count,
+                ..
+            } = cli.command
+            else {
+                panic!("expected generate");
+            };
+            assert_eq!(count.or(positional_count), Some(3));
+        }
+
+        assert!(Cli::try_parse_from(["nulid", "gen", "3", "--count", "4"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(parse_timestamp("0"), Ok(0));
+        assert_eq!(
+            parse_timestamp("1704067200123456789"),
+            Ok(1_704_067_200_123_456_789)
+        );
+        assert_eq!(
+            parse_timestamp("2024-01-01T00:00:00Z"),
+            Ok(1_704_067_200_000_000_000)
+        );
+        assert_eq!(
+            parse_timestamp("2024-01-01T00:00:00.123456789Z"),
+            Ok(1_704_067_200_123_456_789)
+        );
+        assert_eq!(
+            parse_timestamp("2024-01-01T02:00:00.5+02:00"),
+            Ok(1_704_067_200_500_000_000)
+        );
+        assert_eq!(
+            parse_timestamp("2000-02-29 11:59:59.000000001z"),
+            Ok(951_825_599_000_000_001)
+        );
+
+        for invalid in [
+            "",
+            "now",
+            "2024-01-01",
+            "2024-01-01T00:00:00",
+            "2024-01-01T00:00:00.Z",
+            "2024-01-01T00:00:00.1234567890Z",
+            "2023-02-29T00:00:00Z",
+            "2024-13-01T00:00:00Z",
+            "2024-01-01T24:00:00Z",
+            "1969-12-31T23:59:59Z",
+            "2024-01-01T00:00:00+2:00",
+            "2024-1-011T00:00:00Z",
+            "295147905179352825856",
+        ] {
+            assert!(parse_timestamp(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_generate_at_with_seed_is_reproducible() {
+        let cli = Cli::try_parse_from([
+            "nulid",
+            "gen",
+            "--at",
+            "2024-01-01T00:00:00Z",
+            "--seed",
+            "42",
+        ])
+        .unwrap();
+        let Command::Generate { at, seed, .. } = cli.command else {
+            panic!("expected generate");
+        };
+        assert_eq!(at, Some(1_704_067_200_000_000_000));
+        assert_eq!(seed, Some(42));
+
+        let mint = || {
+            let generator = Generator::<_, _, NoNodeId>::with_deps(
+                CliClock::Fixed(1_704_067_200_000_000_000),
+                CliRng::Seeded(Box::new(SeededRng::new(42))),
+            );
+            (0..3)
+                .map(|_| generator.generate().unwrap())
+                .collect::<Vec<_>>()
+        };
+        let ids = mint();
+        assert_eq!(ids, mint());
+        assert!(ids.iter().all(|id| id.nanos() == 1_704_067_200_000_000_000));
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_parse_interval_and_rate() {
+        assert_eq!(parse_interval("100ms"), Ok(Duration::from_millis(100)));
+        assert_eq!(parse_interval("250us"), Ok(Duration::from_micros(250)));
+        assert_eq!(parse_interval("2s"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_interval("1m"), Ok(Duration::from_secs(60)));
+        for invalid in ["", "100", "ms", "0s", "1h", "-1s", "1.5s"] {
+            assert!(parse_interval(invalid).is_err(), "{invalid}");
+        }
+
+        assert_eq!(parse_rate("10"), Ok(Duration::from_millis(100)));
+        assert_eq!(parse_rate("4/s"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_rate("2/m"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_rate("0.5/s"), Ok(Duration::from_secs(2)));
+        for invalid in ["", "0", "-1/s", "inf", "NaN", "10/h", "fast"] {
+            assert!(parse_rate(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_follow_args() {
+        let cli = Cli::try_parse_from(["nulid", "gen", "--follow", "--rate", "100/s"]).unwrap();
+        let Command::Generate {
+            follow,
+            interval,
+            rate,
+            ..
+        } = cli.command
+        else {
+            panic!("expected generate");
+        };
+        assert!(follow);
+        assert_eq!((interval, rate), (None, Some(Duration::from_millis(10))));
+
+        for args in [
+            &["nulid", "gen", "--interval", "1s"][..],
+            &[
+                "nulid",
+                "gen",
+                "--follow",
+                "--interval",
+                "1s",
+                "--rate",
+                "1",
+            ],
+            &["nulid", "gen", "--follow", "-n", "5"],
+            &["nulid", "gen", "5", "--follow"],
+        ] {
+            assert!(Cli::try_parse_from(args).is_err(), "{args:?}");
+        }
+    }
+
+    #[test]
+    fn test_filter_window_args() {
+        let cli = Cli::try_parse_from(["nulid", "filter", "--after", "1000", "--before", "2000"])
+            .unwrap();
+        let Command::Filter { after, before, .. } = cli.command else {
+            panic!("expected filter");
+        };
+        assert_eq!((after, before), (Some(1_000), Some(2_000)));
+
+        assert!(Cli::try_parse_from(["nulid", "filter", "--before", "2000"]).is_ok());
+        assert!(Cli::try_parse_from(["nulid", "filter"]).is_err());
+        assert!(Cli::try_parse_from(["nulid", "filter", "--after", "yesterday"]).is_err());
+    }
+
+    #[test]
+    fn test_check_report() {
+        let ids = [
+            Nulid::from_nanos(1_000, 1),
+            Nulid::from_nanos(1_500, 1),
+            Nulid::from_nanos(1_500, 1),
+            Nulid::from_nanos(1_200, 1),
+            Nulid::from_nanos(9_000, 1),
+        ];
+        let mut inputs: Vec<String> = ids.iter().map(ToString::to_string).collect();
+        inputs.push("not-a-nulid".to_string());
+
+        let report = CheckReport::new(&inputs, Some(Duration::from_micros(5)));
+        assert_eq!(report.total, 6);
+        assert_eq!(report.duplicates, 1);
+        assert_eq!(report.out_of_order, 1);
+        assert_eq!(report.invalid, 1);
+        assert_eq!(report.span, Some((1_000, 9_000)));
+        assert_eq!(report.gaps, Some((0, 7_800, 8_300)));
+        assert_eq!(report.gap_count, 3);
+
+        assert_eq!(report.violations.len(), 4);
+        assert!(report.violations[0].starts_with("line 3: duplicate of line 2"));
+        assert!(report.violations[1].starts_with("line 4: out of order"));
+        assert!(report.violations[2].starts_with("line 5: gap of 7800 ns"));
+        assert!(report.violations[3].starts_with("line 6: invalid"));
+
+        let sorted: Vec<String> = [1, 2, 3]
+            .map(|random| Nulid::from_nanos(1_000, random).to_string())
+            .to_vec();
+        assert!(CheckReport::new(&sorted, None).violations.is_empty());
+    }
+
+    #[test]
+    fn test_corpus_stats() {
+        let mut inputs: Vec<String> = [
+            Nulid::from_nanos(1_000_000_000, 1 << 44),
+            Nulid::from_nanos(1_200_000_000, (1 << 44) | 0xFFF),
+            Nulid::from_nanos(3_500_000_000, 2 << 44),
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+        inputs.push("garbage".to_string());
+
+        let stats = CorpusStats::new(&inputs, Duration::from_secs(1));
+        assert_eq!((stats.total, stats.invalid, stats.valid()), (4, 1, 3));
+        assert_eq!(stats.span, Some((1_000_000_000, 3_500_000_000)));
+        assert_eq!(
+            stats.histogram.into_iter().collect::<Vec<_>>(),
+            [(1_000_000_000, 2), (3_000_000_000, 1)]
+        );
+        assert_eq!(stats.node_prefixes.get(&1), Some(&2));
+        assert_eq!(stats.node_prefixes.get(&2), Some(&1));
+
+        let constant = CorpusStats::new(&inputs[..1], Duration::from_secs(1));
+        assert!(constant.entropy_bits().abs() < f64::EPSILON);
+        assert_eq!(constant.constant_bits(), 60);
+
+        let random: Vec<String> = (0..2_000)
+            .map(|_| Nulid::new().unwrap().to_string())
+            .collect();
+        let random = CorpusStats::new(&random, Duration::from_secs(1));
+        assert!(random.entropy_bits() > 59.0);
+        assert_eq!(random.constant_bits(), 0);
+    }
+
+    #[test]
+    fn test_convert_round_trips() {
+        let encodings = [
+            Encoding::Nulid,
+            Encoding::Ulid,
+            Encoding::Uuid,
+            Encoding::Hex,
+            Encoding::U128,
+            Encoding::B58,
+        ];
+        for nulid in [
+            Nulid::nil(),
+            Nulid::from_u128(1),
+            Nulid::from_nanos(1_704_067_200_123_456_789, 0x0FED_CBA9_8765_4321),
+            Nulid::from_u128(u128::MAX >> 8),
+        ] {
+            for encoding in encodings {
+                let encoded = encoding.encode(nulid).unwrap();
+                assert_eq!(encoding.decode(&encoded), Ok(nulid), "{encoding:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_encodings() {
+        let nulid = Nulid::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+        assert_eq!(
+            Encoding::Uuid.decode("0123456789abcdeffedcba9876543210"),
+            Ok(nulid)
+        );
+        assert_eq!(
+            Encoding::Hex.decode("0x0123456789ABCDEFFEDCBA9876543210"),
+            Ok(nulid)
+        );
+
+        assert_eq!(base58_encode(Nulid::nil()), "1".repeat(16));
+        assert_eq!(
+            base58_encode(Nulid::from_u128(57)),
+            format!("{}z", "1".repeat(15))
+        );
+        assert_eq!(
+            base58_encode(Nulid::from_u128(u128::MAX)),
+            "YcVfxkQb6JRzqk5kF2tNLv"
+        );
+
+        assert!(
+            Encoding::Uuid
+                .decode("01234567-89ab-cdef-fedc-ba987654321")
+                .is_err()
+        );
+        assert!(
+            Encoding::Uuid
+                .decode("0123456789ab-cdef-fedc-ba9876543210-")
+                .is_err()
+        );
+        assert!(Encoding::Hex.decode("0123").is_err());
+        assert!(Encoding::B58.decode("0OIl").is_err());
+        assert!(Encoding::B58.decode("2").is_err());
+        assert!(Encoding::B58.decode(&"z".repeat(30)).is_err());
+        assert!(Encoding::Ulid.encode(Nulid::MAX).is_err());
+    }
+
+    #[test]
+    fn test_validation_report() {
+        let inputs = [
+            Nulid::from_u128(1).to_string(),
+            "01GZWQ22K2MNDR0GAQTE834QRU".to_string(),
+            "short".to_string(),
+            "é1GZWQ22K2MNDR0GAQTE834QU".to_string(),
+        ];
+
+        let report = ValidationReport::new(&inputs, false);
+        let positions: Vec<_> = report
+            .records
+            .iter()
+            .map(|record| (record.line, record.valid, record.position))
+            .collect();
+        assert_eq!(
+            positions,
+            [
+                (1, true, None),
+                (2, false, Some(25)),
+                (3, false, None),
+                (4, false, Some(0)),
+            ]
+        );
+        let summary = report.summary();
+        assert_eq!((summary.total, summary.valid, summary.invalid), (4, 1, 3));
+
+        let report = ValidationReport::new(&inputs, true);
+        assert_eq!(report.records.len(), 2);
+        assert_eq!(report.summary().invalid, 1);
+    }
+
+    #[test]
+    fn test_diff_record() {
+        let a = Nulid::from_nanos(1_000, 0b1010);
+        let b = Nulid::from_nanos(1_500_000_000, 0b0110);
+        let diff = DiffRecord::new(a, b);
+        assert_eq!(diff.ordering, "less");
+        assert_eq!(diff.delta_nanos, 1_499_999_000);
+        assert_eq!(diff.delta, "+1.499999s");
+        assert_eq!(diff.differing_random_bits, 2);
+        assert_eq!(diff.differing_bytes, [4, 5, 6, 7, 8, 15]);
+
+        let reversed = DiffRecord::new(b, a);
+        assert_eq!(reversed.ordering, "greater");
+        assert_eq!(reversed.delta_nanos, -1_499_999_000);
+        assert_eq!(reversed.delta, "-1.499999s");
+
+        let same = DiffRecord::new(a, a);
+        assert_eq!((same.ordering, same.delta.as_str()), ("equal", "+0ns"));
+        assert!(same.differing_bytes.is_empty());
+
+        assert!(Cli::try_parse_from(["nulid", "inspect", "--diff", "A", "B"]).is_ok());
+        assert!(Cli::try_parse_from(["nulid", "inspect", "--diff", "A"]).is_err());
+        assert!(Cli::try_parse_from(["nulid", "inspect", "A", "B"]).is_err());
+    }
+
+    #[test]
+    fn test_human_duration() {
+        assert_eq!(human_duration(0), "0ns");
+        assert_eq!(human_duration(999), "999ns");
+        assert_eq!(human_duration(1_500), "1.5µs");
+        assert_eq!(human_duration(12_000_000), "12ms");
+        assert_eq!(human_duration(1_000_000_001), "1.000000001s");
+        assert_eq!(human_duration(61_000_000_000), "1m 1s");
+        assert_eq!(human_duration(90_061_500_000_000), "1d 1h 1m 1.5s");
+        assert_eq!(human_duration(3_600_000_000_000), "1h 0m 0s");
+    }
+
+    #[test]
+    fn test_find_and_annotate_nulids() {
+        let id = Nulid::from_nanos(1_704_067_200_000_000_000, 42);
+        let line = format!(
+            r#"GET /users/user_{id} ok {{"id":"{}"}} {id}X 8ZZZZZZZZZZZZZZZZZZZZZZZZZ"#,
+            id.to_string().to_lowercase()
+        );
+
+        let matches = find_nulids(&line);
+        assert_eq!(matches.len(), 2);
+        assert!(matches.iter().all(|(_, nulid)| *nulid == id));
+        assert_eq!(&line[matches[0].0.clone()], id.to_string());
+
+        let annotated = annotate(&line, &matches);
+        assert!(annotated.starts_with(&format!(
+            "GET /users/user_{id}[2024-01-01T00:00:00.000000000Z] ok"
+        )));
+        assert_eq!(annotated.matches("[2024-01-01T").count(), 2);
+
+        assert!(find_nulids("no ids here").is_empty());
+        assert_eq!(annotate("plain", &[]), "plain");
+    }
+
+    #[test]
+    fn test_uuid7_layout() {
+        // 2024-01-01T00:00:00.000500000Z: half a millisecond past the second.
+        let nulid = Nulid::from_nanos(1_704_067_200_000_500_000, 0x0ABC_DEF0_1234_5678);
+        let uuid = format_id(nulid, IdFormat::Uuid7).ok().unwrap();
+        assert_eq!(uuid, "018cc251-f400-7800-8abc-def012345678");
+        assert_eq!(uuid, nulid.to_uuid_v7().unwrap().to_string());
+
+        assert!(Encoding::Uuid7.decode(&uuid).is_err());
+        let cli = Cli::try_parse_from(["nulid", "gen", "--as", "uuid7"]).unwrap();
+        let Command::Generate { output, .. } = cli.command else {
+            panic!("expected generate");
+        };
+        assert_eq!(output.output, IdFormat::Uuid7);
+    }
+
+    #[test]
+    fn test_sort_options() {
+        let (a, b, c) = (
+            Nulid::from_nanos(1, 0),
+            Nulid::from_nanos(2, 0),
+            Nulid::from_nanos(3, 0),
+        );
+        let inputs = [c, a, b, a]
+            .iter()
+            .map(ToString::to_string)
+            .chain(["garbage".to_string()])
+            .collect::<Vec<_>>();
+        let options = |reverse, unique, skip_invalid| SortArgs {
+            reverse,
+            unique,
+            skip_invalid,
+        };
+
+        assert!(sorted(&inputs, options(false, false, false), true).is_err());
+        assert_eq!(
+            sorted(&inputs, options(false, false, true), true).ok(),
+            Some(vec![a, a, b, c])
+        );
+        assert_eq!(
+            sorted(&inputs, options(true, true, true), true).ok(),
+            Some(vec![c, b, a])
+        );
+    }
+
+    #[test]
+    fn test_range_bounds() {
+        let (lower, upper) = range_bounds(1_000, 2_000);
+        assert_eq!(lower.parts(), (1_000, 0));
+        assert_eq!(upper.parts(), (2_000, (1 << Nulid::RANDOM_BITS) - 1));
+
+        for id in [
+            Nulid::from_nanos(1_000, 0),
+            Nulid::from_nanos(1_500, 12_345),
+            Nulid::from_nanos(2_000, u64::MAX),
+        ] {
+            assert!((lower..=upper).contains(&id));
+        }
+        assert!(Nulid::from_nanos(999, u64::MAX) < lower);
+        assert!(Nulid::from_nanos(2_001, 0) > upper);
+
+        assert!(Cli::try_parse_from(["nulid", "range", "--from", "0", "--to", "1"]).is_ok());
+        assert!(
+            Cli::try_parse_from([
+                "nulid", "range", "--from", "0", "--to", "1", "--column", "x"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_output_and_quiet_flags() {
+        let cli = Cli::try_parse_from(["nulid", "parse", "-q", "X", "-o", "hex"]).unwrap();
+        assert!(cli.quiet);
+        let Command::Parse { output, .. } = cli.command else {
+            panic!("expected parse");
+        };
+        assert_eq!(output.output, IdFormat::Hex);
+
+        assert!(Cli::try_parse_from(["nulid", "parse", "X", "-o", "nope"]).is_err());
+    }
+
+    #[test]
+    fn test_format_id() {
+        let nulid = Nulid::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+        assert_eq!(
+            format_id(nulid, IdFormat::Base32).ok().unwrap(),
+            nulid.to_string()
+        );
+        assert_eq!(
+            format_id(nulid, IdFormat::Hex).ok().unwrap(),
+            "0123456789abcdeffedcba9876543210"
+        );
+        assert_eq!(
+            format_id(nulid, IdFormat::U128).ok().unwrap(),
+            nulid.as_u128().to_string()
+        );
+        assert_eq!(
+            format_id(nulid, IdFormat::Uuid).ok().unwrap(),
+            "01234567-89ab-cdef-fedc-ba9876543210"
+        );
+        assert_eq!(
+            format_id(nulid, IdFormat::Ulid).ok().unwrap(),
+            nulid.to_ulid_string().unwrap().to_string()
+        );
+        assert!(format_id(Nulid::MAX, IdFormat::Uuid7).is_err());
+    }
+
+    #[test]
+    fn test_rfc3339() {
+        assert_eq!(
+            rfc3339(Nulid::from_nanos(0, 0)),
+            "1970-01-01T00:00:00.000000000Z"
+        );
+        assert_eq!(
+            rfc3339(Nulid::from_nanos(1_704_067_200_123_456_789, 0)),
+            "2024-01-01T00:00:00.123456789Z"
+        );
+        assert_eq!(
+            rfc3339(Nulid::from_nanos(951_825_599_000_000_001, 0)),
+            "2000-02-29T11:59:59.000000001Z"
+        );
+        assert_eq!(
+            rfc3339(Nulid::from_nanos(4_107_542_399_999_999_999, 0)),
+            "2100-02-28T23:59:59.999999999Z"
+        );
+    }
+
+    #[test]
+    fn test_id_record_json() {
+        let nulid = Nulid::from_nanos(1_704_067_200_000_000_000, 42);
+        let json = to_json(&IdRecord::new(nulid)).ok().unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"id":"{nulid}","timestamp":1704067200000000000,"datetime":"2024-01-01T00:00:00.000000000Z","random":42,"uuid":"{}"}}"#,
+                uuid_string(nulid)
+            )
+        );
+    }
+
+    #[test]
+    fn test_csv_row_quoting() {
+        let fields = [
+            "plain".to_string(),
+            "a,b".to_string(),
+            "say \"hi\"".to_string(),
+        ];
+        assert_eq!(csv_row(&fields), r#"plain,"a,b","say ""hi""""#);
     }
-    #[cfg(feature = "chrono")]
-    {
-        println!("    # Convert NULID to datetime");
-        println!("    nulid datetime 01GZWQ22K2MNDR0GAQTE834QRV");
-        println!();
-        println!("    # Create NULID from datetime");
-        println!("    nulid from-datetime 2024-01-01T00:00:00Z");
-        println!();
-    }
-    println!("For more information, visit: https://github.com/kakilangit/nulid");
 }
@@ -0,0 +1,341 @@
+//! Approximate membership for NULIDs.
+//!
+//! [`NulidBloom`] is a Bloom filter for "have we probably seen this ID?"
+//! checks at the edge of a pipeline, where an exact set of hundreds of
+//! millions of IDs would not fit in memory. It never reports a false
+//! negative; the false-positive rate is chosen at construction.
+//!
+//! NULIDs already carry 60 random bits, so the filter does not run them
+//! through a general-purpose hasher. The probe positions are derived from
+//! the two 64-bit halves of the ID with one multiply each (Kirsch-Mitzenmacher
+//! double hashing), which keeps `insert` and `contains` to a handful of
+//! arithmetic operations plus the memory accesses.
+//!
+//! With the `serde` feature the filter can be serialized, e.g. to persist it
+//! across restarts or ship it to another node.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use nulid::bloom::NulidBloom;
+//!
+//! # fn main() -> nulid::Result<()> {
+//! // Sized for 1M IDs at a 1% false-positive rate (about 1.2 MB)
+//! let mut seen = NulidBloom::new(1_000_000, 0.01)?;
+//!
+//! let id = Nulid::new()?;
+//! assert!(seen.insert(id)); // not seen before
+//! assert!(!seen.insert(id)); // probably a duplicate
+//! assert!(seen.contains(id));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{Error, Nulid, Result};
+
+/// Odd multiplier (2^64 / golden ratio) used to spread the timestamp half.
+const MIX_HIGH: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Odd multiplier used to derive the probe step from the random half.
+const MIX_LOW: u64 = 0xC2B2_AE3D_27D4_EB4F;
+
+/// A Bloom filter keyed by NULID.
+///
+/// The bit array is rounded up to whole 64-bit words. Inserting and
+/// querying are O(k) for k hash functions; memory is fixed at construction.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::Nulid;
+/// use nulid::bloom::NulidBloom;
+///
+/// # fn main() -> nulid::Result<()> {
+/// let mut filter = NulidBloom::new(1_000, 0.001)?;
+/// filter.insert(Nulid::from_nanos(1, 42));
+///
+/// assert!(filter.contains(Nulid::from_nanos(1, 42)));
+/// assert!(filter.estimated_false_positive_rate() < 0.001);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(try_from = "BloomParts")
+)]
+pub struct NulidBloom {
+    num_hashes: u32,
+    bits: Vec<u64>,
+}
+
+/// Unvalidated serialized form of [`NulidBloom`].
+#[cfg(feature = "serde")]
+#[derive(::serde::Deserialize)]
+struct BloomParts {
+    num_hashes: u32,
+    bits: Vec<u64>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<BloomParts> for NulidBloom {
+    type Error = Error;
+
+    fn try_from(parts: BloomParts) -> Result<Self> {
+        check_size(parts.bits.len() as u64 * 64, parts.num_hashes)?;
+        Ok(Self {
+            num_hashes: parts.num_hashes,
+            bits: parts.bits,
+        })
+    }
+}
+
+/// Rejects filters without bits or hash functions, which could not hold an ID.
+const fn check_size(num_bits: u64, num_hashes: u32) -> Result<()> {
+    if num_bits == 0 {
+        return Err(Error::InvalidArgument {
+            reason: "bloom filter must have at least one bit",
+        });
+    }
+    if num_hashes == 0 {
+        return Err(Error::InvalidArgument {
+            reason: "bloom filter must use at least one hash function",
+        });
+    }
+    Ok(())
+}
+
+impl NulidBloom {
+    /// Creates a filter sized for `expected_items` at the given false-positive rate.
+    ///
+    /// Uses the optimal bit count `m = -n ln(p) / ln(2)^2` and hash count
+    /// `k = (m / n) ln(2)`. Inserting more than `expected_items` IDs raises
+    /// the false-positive rate above the target.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidArgument`: If `false_positive_rate` is not strictly between 0 and 1
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )] // sizes are positive and far below 2^52
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Result<Self> {
+        if !(false_positive_rate > 0.0 && false_positive_rate < 1.0) {
+            return Err(Error::InvalidArgument {
+                reason: "false-positive rate must be between 0 and 1",
+            });
+        }
+        let items = expected_items.max(1) as f64;
+        let ln2 = core::f64::consts::LN_2;
+
+        let num_bits = (-items * false_positive_rate.ln() / (ln2 * ln2)).ceil();
+        let num_hashes = (num_bits / items * ln2).round().max(1.0);
+        Self::with_num_bits(num_bits as u64, num_hashes as u32)
+    }
+
+    /// Creates a filter with an explicit size and number of hash functions.
+    ///
+    /// `num_bits` is rounded up to a multiple of 64.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidArgument`: If `num_bits` or `num_hashes` is zero
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bit array cannot be allocated.
+    pub fn with_num_bits(num_bits: u64, num_hashes: u32) -> Result<Self> {
+        check_size(num_bits, num_hashes)?;
+        let words = usize::try_from(num_bits.div_ceil(64)).unwrap_or(usize::MAX);
+        Ok(Self {
+            num_hashes,
+            bits: vec![0; words],
+        })
+    }
+
+    /// Adds an ID, returning `true` if it was definitely not present before.
+    ///
+    /// A `false` return means the ID was probably inserted already, or is a
+    /// false positive.
+    pub fn insert(&mut self, id: Nulid) -> bool {
+        let num_bits = self.num_bits();
+        let mut added = false;
+        for index in probes(id, self.num_hashes, num_bits) {
+            let (word, mask) = locate(index);
+            added |= self.bits[word] & mask == 0;
+            self.bits[word] |= mask;
+        }
+        added
+    }
+
+    /// Returns `true` if the ID was probably inserted, `false` if it definitely was not.
+    #[must_use]
+    pub fn contains(&self, id: Nulid) -> bool {
+        probes(id, self.num_hashes, self.num_bits()).all(|index| {
+            let (word, mask) = locate(index);
+            self.bits[word] & mask != 0
+        })
+    }
+
+    /// Removes all IDs, keeping the size and hash count.
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+    }
+
+    /// Returns the number of bits in the filter.
+    #[must_use]
+    pub const fn num_bits(&self) -> u64 {
+        self.bits.len() as u64 * 64
+    }
+
+    /// Returns the number of hash functions (bits set per ID).
+    #[must_use]
+    pub const fn num_hashes(&self) -> u32 {
+        self.num_hashes
+    }
+
+    /// Returns the size of the bit array in bytes.
+    #[must_use]
+    pub const fn size_in_bytes(&self) -> usize {
+        self.bits.len() * 8
+    }
+
+    /// Estimates the current false-positive rate from the fraction of set bits.
+    ///
+    /// Counts every bit, so this is O(m); call it for monitoring, not per
+    /// lookup.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // an estimate
+    pub fn estimated_false_positive_rate(&self) -> f64 {
+        let set: u64 = self
+            .bits
+            .iter()
+            .map(|word| u64::from(word.count_ones()))
+            .sum();
+        let fill = set as f64 / self.num_bits() as f64;
+        fill.powf(f64::from(self.num_hashes))
+    }
+}
+
+/// Returns the `num_hashes` bit indices probed for `id`.
+#[allow(clippy::cast_possible_truncation)] // splitting into halves
+fn probes(id: Nulid, num_hashes: u32, num_bits: u64) -> impl Iterator<Item = u64> {
+    let value = id.as_u128();
+    let high = (value >> 64) as u64;
+    let low = value as u64;
+
+    // `low` holds the random bits, `high` the timestamp; mix each into the
+    // other so IDs that share either half still probe different positions.
+    let h1 = low ^ high.wrapping_mul(MIX_HIGH);
+    let h2 = (high ^ low.wrapping_mul(MIX_LOW)) | 1;
+    (0..u64::from(num_hashes)).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % num_bits)
+}
+
+/// Splits a bit index into a word index and a bit mask.
+#[allow(clippy::cast_possible_truncation)] // bounded by the word count
+const fn locate(index: u64) -> (usize, u64) {
+    ((index / 64) as usize, 1 << (index % 64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Generator;
+    use crate::generator::{MockClock, NoNodeId, SequentialRng};
+
+    fn false_positives(filter: &NulidBloom, ids: impl Iterator<Item = Nulid>) -> usize {
+        ids.filter(|&id| filter.contains(id)).count()
+    }
+
+    #[test]
+    fn test_sizing() {
+        let filter = NulidBloom::new(1_000_000, 0.01).unwrap();
+        // ~9.59 bits per item and 7 hashes for 1%
+        assert_eq!(filter.num_hashes(), 7);
+        assert!((9_585_000..9_586_000).contains(&filter.num_bits()));
+        assert_eq!(filter.size_in_bytes() as u64 * 8, filter.num_bits());
+        assert_eq!(NulidBloom::with_num_bits(65, 3).unwrap().num_bits(), 128);
+    }
+
+    #[test]
+    fn test_no_false_negatives() {
+        let generator = Generator::new();
+        let ids: Vec<Nulid> = (0..10_000).map(|_| generator.generate().unwrap()).collect();
+        let mut filter = NulidBloom::new(ids.len(), 0.01).unwrap();
+        for &id in &ids {
+            filter.insert(id);
+        }
+        assert!(ids.iter().all(|&id| filter.contains(id)));
+
+        filter.clear();
+        assert!(!filter.contains(ids[0]));
+        assert!(filter.estimated_false_positive_rate() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_false_positive_rate_with_random_ids() {
+        let generator = Generator::new();
+        let mut filter = NulidBloom::new(10_000, 0.01).unwrap();
+        for _ in 0..10_000 {
+            filter.insert(generator.generate().unwrap());
+        }
+        let fp = false_positives(&filter, (0..10_000).map(|_| generator.generate().unwrap()));
+        assert!(fp < 200, "{fp} false positives");
+    }
+
+    #[test]
+    fn test_false_positive_rate_with_sequential_ids() {
+        // A frozen clock and sequential RNG make every ID the previous one
+        // plus one, the least random input the filter can see.
+        let generator = Generator::<_, _, NoNodeId>::with_deps(
+            MockClock::new(1_000_000),
+            SequentialRng::starting_at(0),
+        );
+        let mut filter = NulidBloom::new(10_000, 0.01).unwrap();
+        for _ in 0..10_000 {
+            filter.insert(generator.generate().unwrap());
+        }
+        let fp = false_positives(&filter, (0..10_000).map(|_| generator.generate().unwrap()));
+        assert!(fp < 200, "{fp} false positives");
+
+        let mut filter = NulidBloom::new(10_000, 0.01).unwrap();
+        for n in 0..10_000 {
+            filter.insert(Nulid::from_nanos(n, 0));
+        }
+        let fp = false_positives(&filter, (10_000..20_000).map(|n| Nulid::from_nanos(n, 0)));
+        assert!(fp < 200, "{fp} false positives");
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        for rate in [0.0, 1.0, -0.5, f64::NAN] {
+            assert!(matches!(
+                NulidBloom::new(10, rate),
+                Err(Error::InvalidArgument { .. })
+            ));
+        }
+        assert!(NulidBloom::with_num_bits(0, 3).is_err());
+        assert_eq!(
+            NulidBloom::with_num_bits(64, 0).unwrap_err().to_string(),
+            "Invalid argument: bloom filter must use at least one hash function"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut filter = NulidBloom::new(100, 0.01).unwrap();
+        filter.insert(Nulid::from_nanos(7, 7));
+
+        let json = serde_json::to_string(&filter).unwrap();
+        let decoded: NulidBloom = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, filter);
+        assert!(decoded.contains(Nulid::from_nanos(7, 7)));
+
+        assert!(serde_json::from_str::<NulidBloom>(r#"{"num_hashes":0,"bits":[0]}"#).is_err());
+        assert!(serde_json::from_str::<NulidBloom>(r#"{"num_hashes":3,"bits":[]}"#).is_err());
+    }
+}
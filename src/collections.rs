@@ -0,0 +1,519 @@
+//! Sorted collections keyed by NULID.
+//!
+//! NULIDs from a generator arrive in increasing order, so a sorted vector
+//! is both the most compact and the fastest index for them: an insertion
+//! that is greater than every existing key is a single `push`, lookups are
+//! a binary search over contiguous 16-byte keys, and time-range queries are
+//! two binary searches returning a slice. Out-of-order keys are still
+//! supported and cost one `Vec::insert`.
+//!
+//! - [`NulidSet`] deduplicates IDs, e.g. to drop redelivered events during
+//!   ingestion.
+//! - [`NulidMap`] associates a value with each ID.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use nulid::collections::NulidSet;
+//!
+//! let ids = [1, 2, 3, 2, 4].map(|n| Nulid::from_nanos(n, 0));
+//!
+//! let mut seen = NulidSet::new();
+//! let fresh: Vec<Nulid> = ids.into_iter().filter(|&id| seen.insert(id)).collect();
+//! assert_eq!(fresh.len(), 4);
+//!
+//! let window = seen.range(Nulid::from_nanos(2, 0)..Nulid::from_nanos(4, 0));
+//! assert_eq!(window, &ids[1..3]);
+//! ```
+
+use crate::Nulid;
+use core::ops::{Bound, RangeBounds};
+
+/// Returns the index range of `keys` falling within `range`.
+pub(crate) fn range_indices(
+    keys: &[Nulid],
+    range: &impl RangeBounds<Nulid>,
+) -> core::ops::Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(start) => keys.partition_point(|key| key < start),
+        Bound::Excluded(start) => keys.partition_point(|key| key <= start),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => keys.partition_point(|key| key <= end),
+        Bound::Excluded(end) => keys.partition_point(|key| key < end),
+        Bound::Unbounded => keys.len(),
+    };
+    start..end.max(start)
+}
+
+/// Binary-searches `keys` for `id`, with the same result as [`slice::binary_search`].
+///
+/// Returns `Err(len)` without searching when `id` sorts after every key, the
+/// common case for freshly generated IDs.
+fn search(keys: &[Nulid], id: Nulid) -> Result<usize, usize> {
+    match keys.last() {
+        Some(&last) if id > last => Err(keys.len()),
+        None => Err(0),
+        Some(_) => keys.binary_search(&id),
+    }
+}
+
+/// A set of NULIDs stored as a sorted vector.
+///
+/// Appending an ID newer than every existing one is O(1); other insertions
+/// and removals are O(n). Lookups are O(log n). Iteration is in ascending
+/// (chronological) order.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::Nulid;
+/// use nulid::collections::NulidSet;
+///
+/// # fn main() -> nulid::Result<()> {
+/// let mut set = NulidSet::new();
+/// let id = Nulid::new()?;
+/// assert!(set.insert(id));
+/// assert!(!set.insert(id));
+/// assert!(set.contains(id));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NulidSet {
+    ids: Vec<Nulid>,
+}
+
+impl NulidSet {
+    /// Creates an empty set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { ids: Vec::new() }
+    }
+
+    /// Creates an empty set with room for `capacity` IDs.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            ids: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Builds a set from IDs in ascending order.
+    ///
+    /// Duplicates are removed. Input that turns out not to be sorted is
+    /// sorted first, so the result is always correct; sorted input is
+    /// collected in a single pass.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    /// use nulid::collections::NulidSet;
+    ///
+    /// let set = NulidSet::from_sorted_iter((0..1000).map(|n| Nulid::from_nanos(n, 0)));
+    /// assert_eq!(set.len(), 1000);
+    /// ```
+    #[must_use]
+    pub fn from_sorted_iter<I: IntoIterator<Item = Nulid>>(iter: I) -> Self {
+        let mut ids: Vec<Nulid> = iter.into_iter().collect();
+        if !ids.is_sorted() {
+            ids.sort_unstable();
+        }
+        ids.dedup();
+        Self { ids }
+    }
+
+    /// Adds an ID, returning `true` if it was not already present.
+    pub fn insert(&mut self, id: Nulid) -> bool {
+        match search(&self.ids, id) {
+            Ok(_) => false,
+            Err(index) => {
+                self.ids.insert(index, id);
+                true
+            }
+        }
+    }
+
+    /// Returns `true` if the set contains `id`.
+    #[must_use]
+    pub fn contains(&self, id: Nulid) -> bool {
+        self.ids.binary_search(&id).is_ok()
+    }
+
+    /// Removes an ID, returning `true` if it was present.
+    pub fn remove(&mut self, id: Nulid) -> bool {
+        self.ids
+            .binary_search(&id)
+            .map(|index| self.ids.remove(index))
+            .is_ok()
+    }
+
+    /// Returns the IDs within `range`, in ascending order.
+    ///
+    /// Bounds such as `Nulid::from_nanos(start, 0)..Nulid::from_nanos(end, 0)`
+    /// select a time window.
+    #[must_use]
+    pub fn range(&self, range: impl RangeBounds<Nulid>) -> &[Nulid] {
+        &self.ids[range_indices(&self.ids, &range)]
+    }
+
+    /// Returns the oldest ID, if any.
+    #[must_use]
+    pub fn first(&self) -> Option<Nulid> {
+        self.ids.first().copied()
+    }
+
+    /// Returns the newest ID, if any.
+    #[must_use]
+    pub fn last(&self) -> Option<Nulid> {
+        self.ids.last().copied()
+    }
+
+    /// Returns the number of IDs in the set.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns `true` if the set is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Removes all IDs.
+    pub fn clear(&mut self) {
+        self.ids.clear();
+    }
+
+    /// Returns an iterator over the IDs in ascending order.
+    pub fn iter(&self) -> core::iter::Copied<core::slice::Iter<'_, Nulid>> {
+        self.ids.iter().copied()
+    }
+
+    /// Returns the IDs as a sorted slice.
+    #[must_use]
+    pub fn as_slice(&self) -> &[Nulid] {
+        &self.ids
+    }
+}
+
+impl FromIterator<Nulid> for NulidSet {
+    fn from_iter<I: IntoIterator<Item = Nulid>>(iter: I) -> Self {
+        Self::from_sorted_iter(iter)
+    }
+}
+
+impl Extend<Nulid> for NulidSet {
+    fn extend<I: IntoIterator<Item = Nulid>>(&mut self, iter: I) {
+        for id in iter {
+            self.insert(id);
+        }
+    }
+}
+
+impl IntoIterator for NulidSet {
+    type Item = Nulid;
+    type IntoIter = std::vec::IntoIter<Nulid>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ids.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a NulidSet {
+    type Item = Nulid;
+    type IntoIter = core::iter::Copied<core::slice::Iter<'a, Nulid>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A map from NULIDs to values, stored as sorted parallel vectors.
+///
+/// Has the same complexity as [`NulidSet`]: appending a newer key is O(1),
+/// lookups are O(log n), and iteration is in ascending key order.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::Nulid;
+/// use nulid::collections::NulidMap;
+///
+/// let mut events = NulidMap::new();
+/// events.insert(Nulid::from_nanos(1, 0), "created");
+/// events.insert(Nulid::from_nanos(2, 0), "updated");
+///
+/// assert_eq!(events.get(Nulid::from_nanos(2, 0)), Some(&"updated"));
+/// let recent: Vec<_> = events.range(Nulid::from_nanos(2, 0)..).map(|(_, v)| *v).collect();
+/// assert_eq!(recent, ["updated"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NulidMap<V> {
+    keys: Vec<Nulid>,
+    values: Vec<V>,
+}
+
+impl<V> Default for NulidMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> NulidMap<V> {
+    /// Creates an empty map.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            keys: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Creates an empty map with room for `capacity` entries.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            keys: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Builds a map from entries in ascending key order.
+    ///
+    /// Input that turns out not to be sorted is sorted first. For duplicate
+    /// keys the last value wins, as with repeated [`insert`](Self::insert).
+    #[must_use]
+    pub fn from_sorted_iter<I: IntoIterator<Item = (Nulid, V)>>(iter: I) -> Self {
+        let mut entries: Vec<(Nulid, V)> = iter.into_iter().collect();
+        if !entries.is_sorted_by_key(|(key, _)| *key) {
+            // Stable, so later duplicates stay after earlier ones
+            entries.sort_by_key(|(key, _)| *key);
+        }
+
+        let mut map = Self::with_capacity(entries.len());
+        for (key, value) in entries {
+            if map.keys.last() == Some(&key) {
+                if let Some(last) = map.values.last_mut() {
+                    *last = value;
+                }
+            } else {
+                map.keys.push(key);
+                map.values.push(value);
+            }
+        }
+        map
+    }
+
+    /// Inserts a value, returning the previous value for `key` if any.
+    pub fn insert(&mut self, key: Nulid, value: V) -> Option<V> {
+        match search(&self.keys, key) {
+            Ok(index) => Some(core::mem::replace(&mut self.values[index], value)),
+            Err(index) => {
+                self.keys.insert(index, key);
+                self.values.insert(index, value);
+                None
+            }
+        }
+    }
+
+    /// Returns the value for `key`.
+    #[must_use]
+    pub fn get(&self, key: Nulid) -> Option<&V> {
+        self.keys
+            .binary_search(&key)
+            .ok()
+            .map(|index| &self.values[index])
+    }
+
+    /// Returns a mutable reference to the value for `key`.
+    pub fn get_mut(&mut self, key: Nulid) -> Option<&mut V> {
+        self.keys
+            .binary_search(&key)
+            .ok()
+            .map(|index| &mut self.values[index])
+    }
+
+    /// Returns `true` if the map contains `key`.
+    #[must_use]
+    pub fn contains_key(&self, key: Nulid) -> bool {
+        self.keys.binary_search(&key).is_ok()
+    }
+
+    /// Removes `key`, returning its value if it was present.
+    pub fn remove(&mut self, key: Nulid) -> Option<V> {
+        let index = self.keys.binary_search(&key).ok()?;
+        self.keys.remove(index);
+        Some(self.values.remove(index))
+    }
+
+    /// Returns an iterator over the entries with keys within `range`, in ascending order.
+    pub fn range(
+        &self,
+        range: impl RangeBounds<Nulid>,
+    ) -> core::iter::Zip<core::slice::Iter<'_, Nulid>, core::slice::Iter<'_, V>> {
+        let indices = range_indices(&self.keys, &range);
+        self.keys[indices.clone()].iter().zip(&self.values[indices])
+    }
+
+    /// Returns the number of entries.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if the map is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.keys.clear();
+        self.values.clear();
+    }
+
+    /// Splits the map at `key`, returning the entries with keys `>= key`.
+    ///
+    /// Entries with smaller keys stay in `self`, as with `BTreeMap::split_off`.
+    #[must_use]
+    pub fn split_off(&mut self, key: Nulid) -> Self {
+        let index = self.keys.partition_point(|k| *k < key);
+        Self {
+            keys: self.keys.split_off(index),
+            values: self.values.split_off(index),
+        }
+    }
+
+    /// Returns an iterator over the entries in ascending key order.
+    pub fn iter(&self) -> core::iter::Zip<core::slice::Iter<'_, Nulid>, core::slice::Iter<'_, V>> {
+        self.keys.iter().zip(&self.values)
+    }
+
+    /// Returns the keys as a sorted slice.
+    #[must_use]
+    pub fn keys(&self) -> &[Nulid] {
+        &self.keys
+    }
+
+    /// Returns the values in ascending key order.
+    #[must_use]
+    pub fn values(&self) -> &[V] {
+        &self.values
+    }
+}
+
+impl<V> FromIterator<(Nulid, V)> for NulidMap<V> {
+    fn from_iter<I: IntoIterator<Item = (Nulid, V)>>(iter: I) -> Self {
+        Self::from_sorted_iter(iter)
+    }
+}
+
+impl<V> Extend<(Nulid, V)> for NulidMap<V> {
+    fn extend<I: IntoIterator<Item = (Nulid, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<V> IntoIterator for NulidMap<V> {
+    type Item = (Nulid, V);
+    type IntoIter = core::iter::Zip<std::vec::IntoIter<Nulid>, std::vec::IntoIter<V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.into_iter().zip(self.values)
+    }
+}
+
+impl<'a, V> IntoIterator for &'a NulidMap<V> {
+    type Item = (&'a Nulid, &'a V);
+    type IntoIter = core::iter::Zip<core::slice::Iter<'a, Nulid>, core::slice::Iter<'a, V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeMap, BTreeSet};
+
+    fn id(n: u128) -> Nulid {
+        Nulid::from_nanos(n, 0)
+    }
+
+    #[test]
+    fn test_set_matches_btreeset() {
+        let mut set = NulidSet::new();
+        let mut reference = BTreeSet::new();
+        // Mostly increasing with some late and duplicate arrivals
+        for n in [5, 6, 7, 3, 8, 8, 1, 9, 6, 10, 2, 11] {
+            assert_eq!(set.insert(id(n)), reference.insert(id(n)));
+        }
+        assert!(set.iter().eq(reference.iter().copied()));
+        assert!(set.contains(id(3)) && !set.contains(id(4)));
+        assert!(set.remove(id(3)) && !set.remove(id(3)));
+        assert_eq!(set.first(), Some(id(1)));
+        assert_eq!(set.last(), Some(id(11)));
+        assert_eq!(set.len(), 9);
+    }
+
+    #[test]
+    fn test_set_range_bounds() {
+        let set: NulidSet = (0..10).map(id).collect();
+        assert_eq!(set.range(id(2)..id(5)), &[id(2), id(3), id(4)]);
+        assert_eq!(set.range(id(2)..=id(5)).len(), 4);
+        assert_eq!(set.range(..id(2)), &[id(0), id(1)]);
+        assert_eq!(set.range(id(8)..).len(), 2);
+        assert_eq!(
+            set.range((Bound::Excluded(id(8)), Bound::Unbounded)),
+            &[id(9)]
+        );
+        assert!(set.range(id(5)..id(2)).is_empty());
+        assert_eq!(set.range(..).len(), 10);
+    }
+
+    #[test]
+    fn test_from_sorted_iter_handles_unsorted_and_duplicates() {
+        let set = NulidSet::from_sorted_iter([3, 1, 2, 3, 1].map(id));
+        assert_eq!(set.as_slice(), &[id(1), id(2), id(3)]);
+
+        let map = NulidMap::from_sorted_iter([(id(2), 'a'), (id(1), 'b'), (id(2), 'c')]);
+        assert_eq!(map.keys(), &[id(1), id(2)]);
+        assert_eq!(map.values(), &['b', 'c']);
+    }
+
+    #[test]
+    fn test_map_matches_btreemap() {
+        let mut map = NulidMap::new();
+        let mut reference = BTreeMap::new();
+        for (n, value) in [(5, 'a'), (6, 'b'), (2, 'c'), (6, 'd'), (9, 'e'), (1, 'f')] {
+            assert_eq!(map.insert(id(n), value), reference.insert(id(n), value));
+        }
+        assert!(map.iter().eq(reference.iter()));
+        assert_eq!(map.get(id(6)), Some(&'d'));
+        if let Some(value) = map.get_mut(id(6)) {
+            *value = 'x';
+        }
+        assert_eq!(map.remove(id(6)), Some('x'));
+        assert_eq!(map.remove(id(6)), None);
+        assert!(!map.contains_key(id(6)));
+
+        let window: Vec<_> = map.range(id(2)..id(9)).map(|(k, v)| (*k, *v)).collect();
+        assert_eq!(window, [(id(2), 'c'), (id(5), 'a')]);
+
+        let mut reference_tail = reference.split_off(&id(5));
+        reference_tail.remove(&id(6));
+        let tail = map.split_off(id(5));
+        assert!(tail.iter().eq(reference_tail.iter()));
+        assert_eq!(map.keys(), &[id(1), id(2)]);
+        assert_eq!(map.into_iter().count(), 2);
+    }
+}
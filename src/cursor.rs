@@ -0,0 +1,394 @@
+//! Opaque pagination cursors for keyset pagination.
+//!
+//! Keyset pagination over NULID-ordered tables (`WHERE id > $1 ORDER BY id`)
+//! needs to hand clients a token for "where to continue". [`Cursor`] packs
+//! the boundary NULID, the paging [`Direction`], and an optional secondary
+//! sort key into a URL-safe string, optionally signed with HMAC-SHA256 so
+//! clients cannot forge or tamper with it.
+//!
+//! Cursors are opaque: the format is versioned, but clients should not rely
+//! on its contents.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use nulid::cursor::{Cursor, Direction};
+//!
+//! # fn main() -> nulid::Result<()> {
+//! let last_seen = Nulid::new()?;
+//! let key = b"server-side secret";
+//!
+//! let token = Cursor::after(last_seen).encode_signed(key);
+//!
+//! let cursor = Cursor::decode_signed(&token, key)?;
+//! assert_eq!(cursor.id(), last_seen);
+//! assert_eq!(cursor.direction(), Direction::Forward);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{Error, Nulid, Result};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use core::fmt;
+use core::str::FromStr;
+use hmac::digest::{Key, KeyInit};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+/// Current cursor format version.
+const VERSION: u8 = 1;
+
+/// Flag bit: the cursor pages backward.
+const FLAG_BACKWARD: u8 = 0b001;
+
+/// Flag bit: the cursor carries a secondary key.
+const FLAG_SECONDARY: u8 = 0b010;
+
+/// Flag bit: the cursor ends with a signature.
+const FLAG_SIGNED: u8 = 0b100;
+
+/// Length of the version, flags, and NULID prefix.
+const HEADER_LEN: usize = 2 + 16;
+
+/// Length of the truncated HMAC-SHA256 tag.
+const TAG_LEN: usize = 16;
+
+/// Direction in which a cursor continues paging.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Continue with IDs after the cursor (`id > cursor`).
+    #[default]
+    Forward,
+    /// Continue with IDs before the cursor (`id < cursor`).
+    Backward,
+}
+
+/// A keyset pagination cursor.
+///
+/// See the [module documentation](self) for details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cursor {
+    id: Nulid,
+    direction: Direction,
+    secondary: Option<Vec<u8>>,
+}
+
+impl Cursor {
+    /// Creates a cursor at `id` paging in `direction`.
+    #[must_use]
+    pub const fn new(id: Nulid, direction: Direction) -> Self {
+        Self {
+            id,
+            direction,
+            secondary: None,
+        }
+    }
+
+    /// Creates a cursor continuing with IDs after `id`.
+    #[must_use]
+    pub const fn after(id: Nulid) -> Self {
+        Self::new(id, Direction::Forward)
+    }
+
+    /// Creates a cursor continuing with IDs before `id`.
+    #[must_use]
+    pub const fn before(id: Nulid) -> Self {
+        Self::new(id, Direction::Backward)
+    }
+
+    /// Attaches a secondary sort key, e.g. the value of a non-unique column
+    /// the page is ordered by, with the NULID as tie-breaker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    /// use nulid::cursor::Cursor;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let cursor = Cursor::after(Nulid::new()?).with_secondary(42i64.to_be_bytes());
+    /// let decoded = Cursor::decode(&cursor.encode())?;
+    /// assert_eq!(decoded.secondary(), Some(&42i64.to_be_bytes()[..]));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_secondary(mut self, key: impl Into<Vec<u8>>) -> Self {
+        self.secondary = Some(key.into());
+        self
+    }
+
+    /// Returns the boundary NULID.
+    #[must_use]
+    pub const fn id(&self) -> Nulid {
+        self.id
+    }
+
+    /// Returns the paging direction.
+    #[must_use]
+    pub const fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Returns the secondary sort key, if any.
+    #[must_use]
+    pub fn secondary(&self) -> Option<&[u8]> {
+        self.secondary.as_deref()
+    }
+
+    /// Encodes the cursor as an unsigned, URL-safe string.
+    #[must_use]
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(self.to_payload(0))
+    }
+
+    /// Encodes the cursor as a URL-safe string signed with `key`.
+    #[must_use]
+    pub fn encode_signed(&self, key: &[u8]) -> String {
+        let mut payload = self.to_payload(FLAG_SIGNED);
+        let tag = mac(key, &payload).finalize().into_bytes();
+        payload.extend_from_slice(&tag[..TAG_LEN]);
+        URL_SAFE_NO_PAD.encode(payload)
+    }
+
+    /// Decodes an unsigned cursor.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidCursor`: If the string is not a valid unsigned cursor
+    pub fn decode(s: &str) -> Result<Self> {
+        let payload = decode_payload(s)?;
+        if payload[1] & FLAG_SIGNED != 0 {
+            return Err(Error::InvalidCursor);
+        }
+        Self::from_payload(&payload)
+    }
+
+    /// Decodes a cursor signed with `key`, verifying its signature.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidCursor`: If the string is not a valid cursor
+    /// - `InvalidCursorSignature`: If the cursor is unsigned or was not
+    ///   signed with `key`
+    pub fn decode_signed(s: &str, key: &[u8]) -> Result<Self> {
+        let payload = decode_payload(s)?;
+        if payload[1] & FLAG_SIGNED == 0 {
+            return Err(Error::InvalidCursorSignature);
+        }
+        let split = payload
+            .len()
+            .checked_sub(TAG_LEN)
+            .filter(|&split| split >= HEADER_LEN)
+            .ok_or(Error::InvalidCursor)?;
+        let (body, tag) = payload.split_at(split);
+        mac(key, body)
+            .verify_truncated_left(tag)
+            .map_err(|_| Error::InvalidCursorSignature)?;
+        Self::from_payload(body)
+    }
+
+    /// Serializes the cursor to its binary form, without signature.
+    fn to_payload(&self, extra_flags: u8) -> Vec<u8> {
+        let secondary = self.secondary.as_deref().unwrap_or_default();
+        let mut flags = extra_flags;
+        if self.direction == Direction::Backward {
+            flags |= FLAG_BACKWARD;
+        }
+        if self.secondary.is_some() {
+            flags |= FLAG_SECONDARY;
+        }
+
+        let mut payload = Vec::with_capacity(HEADER_LEN + secondary.len() + TAG_LEN);
+        payload.push(VERSION);
+        payload.push(flags);
+        payload.extend_from_slice(&self.id.to_bytes());
+        payload.extend_from_slice(secondary);
+        payload
+    }
+
+    /// Parses the binary form, without signature.
+    fn from_payload(payload: &[u8]) -> Result<Self> {
+        let (header, secondary) = payload.split_at(HEADER_LEN);
+        let flags = header[1];
+        if flags & !(FLAG_BACKWARD | FLAG_SECONDARY | FLAG_SIGNED) != 0 {
+            return Err(Error::InvalidCursor);
+        }
+        if flags & FLAG_SECONDARY == 0 && !secondary.is_empty() {
+            return Err(Error::InvalidCursor);
+        }
+
+        let id = Nulid::try_from(&header[2..]).map_err(|_| Error::InvalidCursor)?;
+        let direction = if flags & FLAG_BACKWARD == 0 {
+            Direction::Forward
+        } else {
+            Direction::Backward
+        };
+        let secondary = (flags & FLAG_SECONDARY != 0).then(|| secondary.to_vec());
+
+        Ok(Self {
+            id,
+            direction,
+            secondary,
+        })
+    }
+}
+
+/// Decodes the Base64 text and checks the version and minimum length.
+fn decode_payload(s: &str) -> Result<Vec<u8>> {
+    let payload = URL_SAFE_NO_PAD
+        .decode(s)
+        .map_err(|_| Error::InvalidCursor)?;
+    if payload.len() < HEADER_LEN || payload[0] != VERSION {
+        return Err(Error::InvalidCursor);
+    }
+    Ok(payload)
+}
+
+/// Returns an HMAC-SHA256 instance over `data` keyed with `key`.
+fn mac(key: &[u8], data: &[u8]) -> Hmac<Sha256> {
+    // Normalize the key to one block exactly as HMAC does (hash long keys,
+    // zero-pad short ones), which makes construction infallible.
+    let mut block = Key::<Hmac<Sha256>>::default();
+    if key.len() > block.len() {
+        let digest = Sha256::digest(key);
+        block[..digest.len()].copy_from_slice(&digest);
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+    let mut mac = <Hmac<Sha256> as KeyInit>::new(&block);
+    mac.update(data);
+    mac
+}
+
+impl fmt::Display for Cursor {
+    /// Formats the cursor as its unsigned encoding.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
+impl FromStr for Cursor {
+    type Err = Error;
+
+    /// Parses an unsigned cursor.
+    fn from_str(s: &str) -> Result<Self> {
+        Self::decode(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &[u8] = b"test key";
+
+    #[test]
+    fn test_unsigned_round_trip() {
+        let id = Nulid::new().unwrap();
+        for cursor in [Cursor::after(id), Cursor::before(id)] {
+            let token = cursor.encode();
+            assert!(
+                token
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+            );
+            assert_eq!(Cursor::decode(&token).unwrap(), cursor);
+            assert_eq!(token.parse::<Cursor>().unwrap(), cursor);
+            assert_eq!(cursor.to_string(), token);
+        }
+    }
+
+    #[test]
+    fn test_secondary_key() {
+        let id = Nulid::new().unwrap();
+        let cursor = Cursor::before(id).with_secondary("2024-01-01");
+        let decoded = Cursor::decode(&cursor.encode()).unwrap();
+        assert_eq!(decoded.secondary(), Some(&b"2024-01-01"[..]));
+        assert_eq!(decoded.direction(), Direction::Backward);
+
+        let empty = Cursor::after(id).with_secondary(Vec::new());
+        let decoded = Cursor::decode(&empty.encode()).unwrap();
+        assert_eq!(decoded.secondary(), Some(&[][..]));
+        assert_ne!(decoded, Cursor::after(id));
+    }
+
+    #[test]
+    fn test_signed_round_trip() {
+        let cursor = Cursor::after(Nulid::new().unwrap()).with_secondary([1, 2, 3]);
+        let token = cursor.encode_signed(KEY);
+        assert_eq!(Cursor::decode_signed(&token, KEY).unwrap(), cursor);
+    }
+
+    #[test]
+    fn test_mac_matches_hmac() {
+        for key in [&b""[..], KEY, &[7u8; 64], &[9u8; 100]] {
+            let mut expected = <Hmac<Sha256> as Mac>::new_from_slice(key).unwrap();
+            expected.update(b"payload");
+            assert_eq!(
+                mac(key, b"payload").finalize().into_bytes(),
+                expected.finalize().into_bytes()
+            );
+        }
+    }
+
+    #[test]
+    fn test_signed_wrong_key() {
+        let token = Cursor::after(Nulid::new().unwrap()).encode_signed(KEY);
+        assert_eq!(
+            Cursor::decode_signed(&token, b"other key"),
+            Err(Error::InvalidCursorSignature)
+        );
+    }
+
+    #[test]
+    fn test_signed_tampered() {
+        let cursor = Cursor::after(Nulid::new().unwrap());
+        let mut payload = URL_SAFE_NO_PAD.decode(cursor.encode_signed(KEY)).unwrap();
+        payload[5] ^= 1;
+        let token = URL_SAFE_NO_PAD.encode(payload);
+        assert_eq!(
+            Cursor::decode_signed(&token, KEY),
+            Err(Error::InvalidCursorSignature)
+        );
+    }
+
+    #[test]
+    fn test_signature_required() {
+        let cursor = Cursor::after(Nulid::new().unwrap());
+        assert_eq!(
+            Cursor::decode_signed(&cursor.encode(), KEY),
+            Err(Error::InvalidCursorSignature)
+        );
+        assert_eq!(
+            Cursor::decode(&cursor.encode_signed(KEY)),
+            Err(Error::InvalidCursor)
+        );
+    }
+
+    #[test]
+    fn test_invalid_cursor() {
+        assert_eq!(Cursor::decode(""), Err(Error::InvalidCursor));
+        assert_eq!(Cursor::decode("not base64!"), Err(Error::InvalidCursor));
+        assert_eq!(
+            Cursor::decode(&URL_SAFE_NO_PAD.encode([VERSION, 0, 1, 2])),
+            Err(Error::InvalidCursor)
+        );
+
+        let mut payload = vec![2, 0];
+        payload.extend_from_slice(&[0; 16]);
+        assert_eq!(
+            Cursor::decode(&URL_SAFE_NO_PAD.encode(&payload)),
+            Err(Error::InvalidCursor)
+        );
+
+        payload[0] = VERSION;
+        payload.push(7);
+        assert_eq!(
+            Cursor::decode(&URL_SAFE_NO_PAD.encode(&payload)),
+            Err(Error::InvalidCursor)
+        );
+    }
+}
@@ -0,0 +1,180 @@
+//! Health checks for NULID generation in production.
+//!
+//! A generator with a broken entropy source still produces valid, sortable
+//! IDs; it just stops producing unique ones. Containers without a seeded
+//! RNG, stubbed-out `getrandom` backends and misconfigured test doubles all
+//! show up the same way: the random field carries far fewer than its 60
+//! bits. [`entropy_estimate`] measures that from a sample of recent IDs, so
+//! operators can alert on it before collisions appear.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use nulid::diagnostics::entropy_estimate;
+//!
+//! # fn main() -> nulid::Result<()> {
+//! let sample: Vec<Nulid> = (0..256).map(|_| Nulid::new()).collect::<nulid::Result<_>>()?;
+//! let estimate = entropy_estimate(&sample);
+//! assert_eq!(estimate.varying_bits, 60);
+//!
+//! // An RNG stuck at zero
+//! let broken: Vec<Nulid> = (0..256).map(|n| Nulid::from_nanos(n, 0)).collect();
+//! let estimate = entropy_estimate(&broken);
+//! assert_eq!(estimate.varying_bits, 0);
+//! assert_eq!(estimate.repeated_values, 255);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::Nulid;
+
+/// What a sample of IDs reveals about the entropy of their random fields.
+///
+/// Returned by [`entropy_estimate`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EntropyEstimate {
+    /// Number of IDs in the sample.
+    pub samples: usize,
+    /// Bits of the random field that took both values across the sample.
+    pub varying_bits: u32,
+    /// Mask of the random-field bits that had the same value in every ID.
+    pub stuck_mask: u64,
+    /// Sum of the per-bit Shannon entropies, in bits.
+    ///
+    /// Unlike [`varying_bits`](Self::varying_bits), this also counts biased
+    /// bits as partial. It is `60.0` for a perfect source and large sample,
+    /// and is an upper bound: correlations between bits are not detected.
+    pub entropy_bits: f64,
+    /// IDs whose random field equals that of an earlier ID in the sample.
+    ///
+    /// With 60 random bits this stays `0` for any realistic sample, so a
+    /// nonzero value means the source repeats itself, even if every bit
+    /// varies.
+    pub repeated_values: usize,
+}
+
+impl EntropyEstimate {
+    /// Returns the number of random-field bits that never changed.
+    #[must_use]
+    pub const fn stuck_bits(&self) -> u32 {
+        self.stuck_mask.count_ones()
+    }
+}
+
+/// Estimates how many of the 60 random bits vary across `ids`.
+///
+/// A bit of a healthy source is constant across `n` IDs with probability
+/// `2^(1 - n)`, so a few dozen IDs are enough for
+/// [`varying_bits`](EntropyEstimate::varying_bits) to reach 60; smaller
+/// samples underestimate it, and an empty or single-ID sample reports every
+/// bit as stuck. IDs from a generator with a node ID carry that ID in the
+/// top 16 random bits, so a sample from one node reports them as stuck:
+/// expect at most 44 varying bits there.
+///
+/// IDs generated within one nanosecond are increments of the previous ID
+/// rather than fresh draws, which lowers
+/// [`entropy_bits`](EntropyEstimate::entropy_bits); sample IDs spread over
+/// time, such as one per request, rather than a burst.
+///
+/// The sample should come from one source, in any order. Runs in
+/// `O(n log n)` time and allocates a copy of the random fields.
+#[must_use]
+pub fn entropy_estimate(ids: &[Nulid]) -> EntropyEstimate {
+    let mut ones = [0usize; Nulid::RANDOM_BITS as usize];
+    let mut randoms: Vec<u64> = ids.iter().map(|id| id.random()).collect();
+    for random in &randoms {
+        for (bit, count) in ones.iter_mut().enumerate() {
+            *count += usize::from(random >> bit & 1 == 1);
+        }
+    }
+
+    let samples = ids.len();
+    let mut stuck_mask = 0u64;
+    let mut entropy_bits = 0.0;
+    for (bit, &count) in ones.iter().enumerate() {
+        if count == 0 || count == samples {
+            stuck_mask |= 1 << bit;
+        } else {
+            #[allow(clippy::cast_precision_loss)] // only a ratio is needed
+            let p = count as f64 / samples as f64;
+            entropy_bits -= p.mul_add(p.log2(), (1.0 - p) * (1.0 - p).log2());
+        }
+    }
+
+    randoms.sort_unstable();
+    randoms.dedup();
+
+    EntropyEstimate {
+        samples,
+        varying_bits: Nulid::RANDOM_BITS - stuck_mask.count_ones(),
+        stuck_mask,
+        entropy_bits,
+        repeated_values: samples - randoms.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::{Generator, MockClock, NoNodeId, SeededRng, SequentialRng, WithNodeId};
+    use core::time::Duration;
+
+    #[test]
+    fn test_entropy_of_seeded_source() {
+        let clock = MockClock::new(1_000);
+        let generator = Generator::<_, _, NoNodeId>::with_deps(&clock, SeededRng::new(42));
+        let ids: Vec<Nulid> = (0..1_000)
+            .map(|_| {
+                clock.advance(Duration::from_nanos(1));
+                generator.generate().unwrap()
+            })
+            .collect();
+
+        let estimate = entropy_estimate(&ids);
+        assert_eq!(estimate.samples, 1_000);
+        assert_eq!(estimate.varying_bits, 60);
+        assert_eq!(estimate.stuck_bits(), 0);
+        assert_eq!(estimate.repeated_values, 0);
+        assert!(estimate.entropy_bits > 59.0 && estimate.entropy_bits <= 60.0);
+    }
+
+    #[test]
+    fn test_entropy_of_broken_sources() {
+        let clock = MockClock::new(1_000);
+
+        // A counter only varies its low bits
+        let generator = Generator::<_, _, NoNodeId>::with_deps(&clock, SequentialRng::new());
+        let ids: Vec<Nulid> = (0..256)
+            .map(|_| {
+                clock.advance(Duration::from_nanos(1));
+                generator.generate().unwrap()
+            })
+            .collect();
+        let estimate = entropy_estimate(&ids);
+        assert_eq!(estimate.varying_bits, 8);
+        assert_eq!(estimate.stuck_mask, !0xFF & ((1 << 60) - 1));
+        assert!((estimate.entropy_bits - 8.0).abs() < 1e-9);
+
+        // A node ID pins the top 16 bits
+        let generator =
+            Generator::with_deps_and_node_id(&clock, SeededRng::new(7), WithNodeId::new(3));
+        let ids: Vec<Nulid> = (0..256).map(|_| generator.generate().unwrap()).collect();
+        assert_eq!(entropy_estimate(&ids).stuck_mask, 0xFFFF << 44);
+
+        // Two alternating values vary every bit but repeat
+        let ids: Vec<Nulid> = (0..100)
+            .map(|n| Nulid::from_nanos(n, if n % 2 == 0 { 0 } else { (1 << 60) - 1 }))
+            .collect();
+        let estimate = entropy_estimate(&ids);
+        assert_eq!(estimate.varying_bits, 60);
+        assert_eq!(estimate.repeated_values, 98);
+    }
+
+    #[test]
+    fn test_entropy_of_tiny_samples() {
+        assert_eq!(entropy_estimate(&[]).varying_bits, 0);
+        assert_eq!(entropy_estimate(&[]).stuck_bits(), 60);
+        assert_eq!(entropy_estimate(&[Nulid::MAX]).varying_bits, 0);
+    }
+}
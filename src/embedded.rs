@@ -0,0 +1,293 @@
+//! Interrupt-safe NULID generation for embedded targets.
+//!
+//! [`Generator`](crate::Generator) guards its state with a `std` mutex,
+//! which would deadlock if an interrupt handler or higher-priority task
+//! preempted a thread holding it. This module provides:
+//!
+//! - [`CriticalSectionGenerator`], which keeps the last ID in a
+//!   [`critical_section::Mutex`] and updates it inside a critical section,
+//!   so it can live in a `static` and be called from tasks and interrupt
+//!   handlers alike
+//! - [`TickClock`], a [`Clock`] built from a user-provided monotonic tick
+//!   counter, its frequency and the wall-clock time at tick zero
+//!
+//! The crate itself still requires `std`, so this targets firmware built
+//! on a `std` platform such as ESP-IDF or embedded Linux. Bare-metal
+//! `no_std` frameworks such as RTIC and Embassy need a `no_std` build of the
+//! crate first and are not supported yet; neither is a `portable-atomic`
+//! backend, so `critical-section` is the only option. The firmware must link a
+//! `critical-section` implementation, usually provided by its HAL. The
+//! clock and RNG are read outside the critical section, so interrupts are
+//! only masked for the monotonic comparison.
+//!
+//! # Examples
+//!
+//! ```
+//! use core::sync::atomic::{AtomicU64, Ordering};
+//! use nulid::embedded::{CriticalSectionGenerator, TickClock};
+//! use nulid::generator::Rng;
+//!
+//! // Stand-ins for a hardware timer and RNG peripheral.
+//! static TICKS: AtomicU64 = AtomicU64::new(0);
+//! fn ticks() -> u64 {
+//!     TICKS.fetch_add(1, Ordering::Relaxed)
+//! }
+//!
+//! struct HardwareRng;
+//! impl Rng for HardwareRng {
+//!     fn random_u64(&self) -> u64 {
+//!         0x0123_4567_89AB_CDEF
+//!     }
+//! }
+//!
+//! // 1 MHz timer started at 2024-01-01T00:00:00Z. The clock is built at
+//! // compile time, so a zero frequency fails the build rather than panicking.
+//! static GENERATOR: CriticalSectionGenerator<TickClock, HardwareRng> = CriticalSectionGenerator::new(
+//!     TickClock::new(ticks, 1_000_000)
+//!         .expect("non-zero tick frequency")
+//!         .with_epoch_nanos(1_704_067_200_000_000_000),
+//!     HardwareRng,
+//! );
+//!
+//! # fn main() -> nulid::Result<()> {
+//! let id1 = GENERATOR.generate()?;
+//! let id2 = GENERATOR.generate()?;
+//! assert!(id2 > id1);
+//! assert_eq!(id1.seconds(), 1_704_067_200);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::generator::{Clock, NoNodeId, NodeId, Rng, WithNodeId, next_monotonic, random_bits};
+use crate::{Nulid, Result};
+use core::cell::Cell;
+
+/// Nanoseconds per second.
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Clock derived from a monotonic tick counter.
+///
+/// Readings are `epoch_nanos + ticks * 1e9 / ticks_per_sec`, where `ticks`
+/// is read from the user-provided function. The counter must never go
+/// backwards; set `epoch_nanos` from an RTC, GPS or network time at boot
+/// so that IDs from different devices sort by wall-clock time.
+#[derive(Debug, Clone, Copy)]
+pub struct TickClock {
+    ticks: fn() -> u64,
+    ticks_per_sec: u64,
+    epoch_nanos: u128,
+}
+
+impl TickClock {
+    /// Creates a clock reading `ticks` at `ticks_per_sec` Hz, starting at the Unix epoch.
+    ///
+    /// Returns `None` if `ticks_per_sec` is zero.
+    #[must_use]
+    pub const fn new(ticks: fn() -> u64, ticks_per_sec: u64) -> Option<Self> {
+        if ticks_per_sec == 0 {
+            return None;
+        }
+        Some(Self {
+            ticks,
+            ticks_per_sec,
+            epoch_nanos: 0,
+        })
+    }
+
+    /// Sets the Unix time, in nanoseconds, that tick zero corresponds to.
+    #[must_use]
+    pub const fn with_epoch_nanos(mut self, epoch_nanos: u128) -> Self {
+        self.epoch_nanos = epoch_nanos;
+        self
+    }
+
+    /// Returns the tick frequency in Hz.
+    #[must_use]
+    pub const fn ticks_per_sec(&self) -> u64 {
+        self.ticks_per_sec
+    }
+
+    /// Returns the Unix time, in nanoseconds, of tick zero.
+    #[must_use]
+    pub const fn epoch_nanos(&self) -> u128 {
+        self.epoch_nanos
+    }
+}
+
+impl Clock for TickClock {
+    fn now_nanos(&self) -> Result<u128> {
+        let ticks = u128::from((self.ticks)());
+        Ok(self.epoch_nanos + ticks * NANOS_PER_SEC / u128::from(self.ticks_per_sec))
+    }
+}
+
+/// Monotonic NULID generator synchronized with critical sections.
+///
+/// Uses the same increment-on-skew algorithm as
+/// [`Generator`](crate::Generator), but its constructors are `const` and its
+/// state is guarded by a [`critical_section::Mutex`] instead of a `std`
+/// mutex, so it can be stored in a `static` and shared with interrupt
+/// handlers. There is no default clock or RNG: embedded targets supply
+/// their own timer and entropy source.
+pub struct CriticalSectionGenerator<C: Clock, R: Rng, N: NodeId = NoNodeId> {
+    clock: C,
+    rng: R,
+    node_id: N,
+    state: critical_section::Mutex<Cell<Option<Nulid>>>,
+}
+
+impl<C: Clock, R: Rng> CriticalSectionGenerator<C, R, NoNodeId> {
+    /// Creates a generator from a clock and RNG (single node).
+    #[must_use]
+    pub const fn new(clock: C, rng: R) -> Self {
+        Self::with_deps_and_node_id(clock, rng, NoNodeId)
+    }
+}
+
+impl<C: Clock, R: Rng> CriticalSectionGenerator<C, R, WithNodeId> {
+    /// Creates a generator from a clock and RNG with an embedded node ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - Unique node identifier (0-65535, 16 bits)
+    #[must_use]
+    pub const fn with_node_id(clock: C, rng: R, node_id: u16) -> Self {
+        Self::with_deps_and_node_id(clock, rng, WithNodeId::new(node_id))
+    }
+}
+
+impl<C: Clock, R: Rng, N: NodeId> CriticalSectionGenerator<C, R, N> {
+    /// Creates a generator with custom clock, RNG, and node ID.
+    #[must_use]
+    pub const fn with_deps_and_node_id(clock: C, rng: R, node_id: N) -> Self {
+        Self {
+            clock,
+            rng,
+            node_id,
+            state: critical_section::Mutex::new(Cell::new(None)),
+        }
+    }
+
+    /// Generates a new NULID with monotonicity guarantee.
+    ///
+    /// Follows the same increment-on-skew algorithm as
+    /// [`Generator::generate`](crate::Generator::generate). Only the
+    /// comparison with the last ID runs inside the critical section.
+    ///
+    /// # Errors
+    ///
+    /// - `Overflow`: If increment would overflow 128-bit space
+    /// - `SystemTimeError`: If clock read fails
+    pub fn generate(&self) -> Result<Nulid> {
+        let timestamp = self.clock.now_nanos()?;
+        let candidate = Nulid::from_nanos(timestamp, random_bits(&self.rng, &self.node_id));
+
+        critical_section::with(|cs| {
+            let state = self.state.borrow(cs);
+            let next = next_monotonic(state.get(), candidate)?;
+            state.set(Some(next));
+            Ok(next)
+        })
+    }
+
+    /// Returns the last generated NULID, if any.
+    #[must_use]
+    pub fn last(&self) -> Option<Nulid> {
+        critical_section::with(|cs| self.state.borrow(cs).get())
+    }
+
+    /// Resets the generator state.
+    ///
+    /// This clears the last generated NULID, allowing the generator
+    /// to start fresh. This is primarily useful for testing.
+    pub fn reset(&self) {
+        critical_section::with(|cs| self.state.borrow(cs).set(None));
+    }
+
+    /// Returns the node ID if configured.
+    #[must_use]
+    pub fn node_id(&self) -> Option<u16> {
+        self.node_id.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::{MockClock, SequentialRng};
+    use core::sync::atomic::{AtomicU64, Ordering};
+    use core::time::Duration;
+    use std::sync::Arc;
+
+    static TEST_TICKS: AtomicU64 = AtomicU64::new(0);
+
+    fn test_ticks() -> u64 {
+        TEST_TICKS.load(Ordering::Relaxed)
+    }
+
+    #[test]
+    fn test_tick_clock() {
+        let clock = TickClock::new(test_ticks, 32_768)
+            .unwrap()
+            .with_epoch_nanos(1_000_000_000);
+        TEST_TICKS.store(32_768 * 3 + 16_384, Ordering::Relaxed);
+        assert_eq!(clock.now_nanos().unwrap(), 4_500_000_000);
+        assert_eq!(clock.ticks_per_sec(), 32_768);
+        assert_eq!(clock.epoch_nanos(), 1_000_000_000);
+    }
+
+    #[test]
+    fn test_tick_clock_zero_frequency() {
+        assert!(TickClock::new(test_ticks, 0).is_none());
+    }
+
+    #[test]
+    fn test_monotonic_with_clock_regression() {
+        let clock = MockClock::new(1_000_000_000);
+        let generator = CriticalSectionGenerator::new(&clock, SequentialRng::starting_at(100));
+
+        let id1 = generator.generate().unwrap();
+        clock.regress(Duration::from_millis(10));
+        let id2 = generator.generate().unwrap();
+        assert_eq!(id2.as_u128(), id1.as_u128() + 1);
+        assert_eq!(generator.last(), Some(id2));
+
+        generator.reset();
+        assert!(generator.last().is_none());
+    }
+
+    #[test]
+    fn test_node_id() {
+        let generator =
+            CriticalSectionGenerator::with_node_id(MockClock::new(1), SequentialRng::new(), 7);
+        assert_eq!(generator.node_id(), Some(7));
+        let id = generator.generate().unwrap();
+        assert_eq!(id.random() >> 44, 7);
+    }
+
+    #[test]
+    fn test_shared_between_threads() {
+        let generator = Arc::new(CriticalSectionGenerator::new(
+            MockClock::new(1_000),
+            SequentialRng::new(),
+        ));
+
+        let mut handles = vec![];
+        for _ in 0..4 {
+            let generator = Arc::clone(&generator);
+            handles.push(std::thread::spawn(move || {
+                (0..250)
+                    .map(|_| generator.generate().unwrap())
+                    .collect::<Vec<_>>()
+            }));
+        }
+
+        let mut ids = Vec::new();
+        for handle in handles {
+            ids.extend(handle.join().unwrap());
+        }
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), 1_000);
+    }
+}
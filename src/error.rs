@@ -3,7 +3,12 @@
 use core::fmt;
 
 /// Errors that can occur during NULID operations.
+///
+/// New variants may be added as features grow, so matches outside this
+/// crate need a wildcard arm. To handle errors by category instead, match on
+/// [`Error::kind`]; [`Error::code`] gives a stable number for logs and APIs.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
     /// Failed to generate random data.
     RandomError,
@@ -22,7 +27,8 @@ pub enum Error {
     /// System time is before Unix epoch.
     SystemTimeError,
 
-    /// Overflow occurred during NULID increment.
+    /// Overflow occurred during NULID increment, or a strictly parsed string
+    /// encodes more than 128 bits.
     Overflow,
 
     /// Mutex was poisoned (another thread panicked while holding the lock).
@@ -30,6 +36,456 @@ pub enum Error {
 
     /// UTF-8 encoding error (should never occur with valid ALPHABET).
     EncodingError,
+
+    /// A replay generator has no recorded entries left.
+    ReplayExhausted,
+
+    /// Check symbol does not match the decoded value.
+    InvalidChecksum {
+        /// Check symbol computed from the decoded value.
+        expected: char,
+        /// Check symbol found in the input.
+        found: char,
+    },
+
+    /// Pagination cursor is malformed.
+    InvalidCursor,
+
+    /// Pagination cursor signature is missing or does not match.
+    InvalidCursorSignature,
+
+    /// String is missing the prefix required by a prefixed ID type.
+    InvalidPrefix {
+        /// Prefix the type requires.
+        expected: &'static str,
+    },
+
+    /// A nil ID was given where a non-nil ID is required.
+    NilId,
+
+    /// UUID is not an RFC 9562 version 7 UUID.
+    InvalidUuidVersion {
+        /// Version number found in the UUID.
+        found: usize,
+    },
+
+    /// A constructor or builder argument is outside its valid range.
+    InvalidArgument {
+        /// Which constraint the argument violates.
+        reason: &'static str,
+    },
+
+    /// A string could not be parsed; carries the input and what it was parsed as.
+    ///
+    /// Returned by the string parsers on [`Nulid`](crate::Nulid), such as
+    /// `FromStr` and [`parse_any`](crate::Nulid::parse_any). The low-level
+    /// [`base32`](crate::base32) decoders return the underlying
+    /// `InvalidChar`/`InvalidLength` errors directly.
+    Parse(ParseError),
+}
+
+impl Error {
+    /// Wraps `reason` in a [`ParseError`] recording `input` and `format`.
+    ///
+    /// Errors that already carry parse context, or that are not about the
+    /// input (such as `SystemTimeError`), are returned unchanged.
+    #[cold]
+    pub(crate) fn parse(format: ParseFormat, input: &str, reason: Self) -> Self {
+        ParseErrorKind::from_error(&reason).map_or(reason, |kind| {
+            Self::Parse(ParseError::new(format, input, kind))
+        })
+    }
+
+    /// Returns the underlying error, looking through parse context.
+    ///
+    /// For [`Error::Parse`] this is the error for its [`ParseErrorKind`];
+    /// any other error is returned as is. Use it to match on
+    /// `InvalidChar`/`InvalidLength` regardless of which parser produced them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::{Error, Nulid};
+    ///
+    /// let error = "01GZTV7EQ056J0E6N276XD6F3U".parse::<Nulid>().unwrap_err();
+    /// assert_eq!(error.reason(), Error::InvalidChar('U', 25));
+    /// ```
+    #[must_use]
+    pub fn reason(&self) -> Self {
+        match self {
+            Self::Parse(error) => error.kind().into(),
+            other => other.clone(),
+        }
+    }
+
+    /// Returns the category of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::{ErrorKind, Nulid};
+    ///
+    /// let error = "not a nulid".parse::<Nulid>().unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    /// ```
+    #[must_use]
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidChar(..)
+            | Self::InvalidLength { .. }
+            | Self::InvalidChecksum { .. }
+            | Self::InvalidCursor
+            | Self::InvalidCursorSignature
+            | Self::InvalidPrefix { .. }
+            | Self::NilId
+            | Self::InvalidUuidVersion { .. }
+            | Self::InvalidArgument { .. }
+            | Self::Parse(_) => ErrorKind::InvalidInput,
+            Self::SystemTimeError => ErrorKind::Clock,
+            Self::Overflow | Self::ReplayExhausted => ErrorKind::Exhausted,
+            Self::RandomError | Self::MutexPoisoned | Self::EncodingError => ErrorKind::Internal,
+        }
+    }
+
+    /// Returns a stable numeric code identifying the error.
+    ///
+    /// Codes are never reused or renumbered, so they are safe to store, send
+    /// to clients, or alert on. [`Error::Parse`] reports the code of its
+    /// [`reason`](Self::reason).
+    ///
+    /// | Code | Variant |
+    /// |------|---------|
+    /// | 1 | `RandomError` |
+    /// | 2 | `InvalidChar` |
+    /// | 3 | `InvalidLength` |
+    /// | 4 | `SystemTimeError` |
+    /// | 5 | `Overflow` |
+    /// | 6 | `MutexPoisoned` |
+    /// | 7 | `EncodingError` |
+    /// | 8 | `ReplayExhausted` |
+    /// | 9 | `InvalidChecksum` |
+    /// | 10 | `InvalidCursor` |
+    /// | 11 | `InvalidCursorSignature` |
+    /// | 12 | `InvalidPrefix` |
+    /// | 13 | `NilId` |
+    /// | 14 | `InvalidUuidVersion` |
+    /// | 15 | `InvalidArgument` |
+    #[must_use]
+    pub const fn code(&self) -> u16 {
+        match self {
+            Self::RandomError => 1,
+            Self::InvalidChar(..) => 2,
+            Self::InvalidLength { .. } => 3,
+            Self::SystemTimeError => 4,
+            Self::Overflow => 5,
+            Self::MutexPoisoned => 6,
+            Self::EncodingError => 7,
+            Self::ReplayExhausted => 8,
+            Self::InvalidChecksum { .. } => 9,
+            Self::InvalidCursor => 10,
+            Self::InvalidCursorSignature => 11,
+            Self::InvalidPrefix { .. } => 12,
+            Self::NilId => 13,
+            Self::InvalidUuidVersion { .. } => 14,
+            Self::InvalidArgument { .. } => 15,
+            Self::Parse(error) => match error.kind() {
+                ParseErrorKind::InvalidChar { .. } => 2,
+                ParseErrorKind::InvalidLength { .. } => 3,
+                ParseErrorKind::Overflow => 5,
+                ParseErrorKind::InvalidChecksum { .. } => 9,
+            },
+        }
+    }
+}
+
+/// Broad category of an [`Error`], for handling errors without matching
+/// every variant.
+///
+/// Maps naturally onto HTTP statuses: `InvalidInput` is the caller's fault
+/// (`400 Bad Request`), `Exhausted` is retryable (`503 Service Unavailable`),
+/// and the rest are server faults (`500 Internal Server Error`).
+///
+/// # Examples
+///
+/// ```
+/// use nulid::{Error, ErrorKind};
+///
+/// fn status(error: &Error) -> u16 {
+///     match error.kind() {
+///         ErrorKind::InvalidInput => 400,
+///         ErrorKind::Exhausted => 503,
+///         _ => 500,
+///     }
+/// }
+///
+/// assert_eq!(status(&Error::NilId), 400);
+/// assert_eq!(status(&Error::SystemTimeError), 500);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The input was malformed or not acceptable: unparseable strings, wrong
+    /// lengths, bad prefixes or cursors, nil IDs.
+    InvalidInput,
+    /// The system clock could not provide a usable time.
+    Clock,
+    /// No more IDs or values are available: a generator's increment
+    /// overflowed, or a replay log ran out.
+    Exhausted,
+    /// An unexpected internal failure, such as a poisoned lock or a failing
+    /// random source.
+    Internal,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidInput => "invalid input",
+            Self::Clock => "clock error",
+            Self::Exhausted => "exhausted",
+            Self::Internal => "internal error",
+        })
+    }
+}
+
+/// What a string was being parsed as when a [`ParseError`] occurred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseFormat {
+    /// The 26-character Base32 text form (also used for ULIDs).
+    Text,
+    /// 32 hexadecimal digits, optionally prefixed with `0x`.
+    Hex,
+    /// A UUID string.
+    Uuid,
+    /// A decimal `u128`.
+    Decimal,
+    /// An input whose shape matched none of the supported formats.
+    Unknown,
+}
+
+impl fmt::Display for ParseFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Text => "NULID text",
+            Self::Hex => "hex",
+            Self::Uuid => "UUID",
+            Self::Decimal => "decimal",
+            Self::Unknown => "ID",
+        })
+    }
+}
+
+/// Why a string failed to parse.
+///
+/// Mirrors the corresponding [`Error`] variants, which it converts into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+    /// Invalid character at a byte offset.
+    InvalidChar {
+        /// The offending character.
+        ch: char,
+        /// Byte offset of the character in the input.
+        position: usize,
+    },
+
+    /// Wrong number of characters.
+    InvalidLength {
+        /// Expected length.
+        expected: usize,
+        /// Actual length found.
+        found: usize,
+    },
+
+    /// Check symbol does not match the decoded value.
+    InvalidChecksum {
+        /// Check symbol computed from the decoded value.
+        expected: char,
+        /// Check symbol found in the input.
+        found: char,
+    },
+
+    /// The value does not fit in 128 bits.
+    Overflow,
+}
+
+impl ParseErrorKind {
+    /// Returns the kind for errors that describe bad input.
+    const fn from_error(error: &Error) -> Option<Self> {
+        match *error {
+            Error::InvalidChar(ch, position) => Some(Self::InvalidChar { ch, position }),
+            Error::InvalidLength { expected, found } => {
+                Some(Self::InvalidLength { expected, found })
+            }
+            Error::InvalidChecksum { expected, found } => {
+                Some(Self::InvalidChecksum { expected, found })
+            }
+            Error::Overflow => Some(Self::Overflow),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseErrorKind> for Error {
+    fn from(kind: ParseErrorKind) -> Self {
+        match kind {
+            ParseErrorKind::InvalidChar { ch, position } => Self::InvalidChar(ch, position),
+            ParseErrorKind::InvalidLength { expected, found } => {
+                Self::InvalidLength { expected, found }
+            }
+            ParseErrorKind::InvalidChecksum { expected, found } => {
+                Self::InvalidChecksum { expected, found }
+            }
+            ParseErrorKind::Overflow => Self::Overflow,
+        }
+    }
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&Error::from(*self), f)
+    }
+}
+
+impl core::error::Error for ParseErrorKind {}
+
+/// A failed string parse, with the offending input for diagnostics.
+///
+/// The first [`ParseError::MAX_INPUT_LEN`] bytes of the input are kept
+/// inline, so a logged error shows what was actually received rather than
+/// just a position, and creating one does not allocate. The cause is
+/// available from [`kind`](Self::kind) and
+/// [`source`](core::error::Error::source).
+///
+/// # Examples
+///
+/// ```
+/// use nulid::{Error, Nulid, ParseFormat};
+///
+/// let Err(Error::Parse(error)) = "01GZTV7EQ056J0E6N276XD6F3U".parse::<Nulid>() else {
+///     panic!("expected a parse error");
+/// };
+/// assert_eq!(error.format(), ParseFormat::Text);
+/// assert_eq!(error.input(), "01GZTV7EQ056J0E6N276XD6F3U");
+/// assert_eq!(error.position(), Some(25));
+/// assert_eq!(
+///     error.to_string(),
+///     r#"Failed to parse NULID text "01GZTV7EQ056J0E6N276XD6F3U": Invalid character 'U' at position 25"#
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    format: ParseFormat,
+    kind: ParseErrorKind,
+    input: [u8; Self::MAX_INPUT_LEN],
+    input_len: u8,
+    truncated: bool,
+}
+
+impl ParseError {
+    /// Maximum number of input bytes kept in the error.
+    ///
+    /// Long enough for every supported format, including a `urn:uuid:` UUID.
+    pub const MAX_INPUT_LEN: usize = 48;
+
+    /// Creates a parse error for `input`, truncating it if necessary.
+    ///
+    /// For an invalid character at a byte offset that starts a multi-byte
+    /// character, the character is replaced by the one actually found there.
+    #[must_use]
+    pub fn new(format: ParseFormat, input: &str, kind: ParseErrorKind) -> Self {
+        let kind = match kind {
+            ParseErrorKind::InvalidChar { position, .. } => input
+                .get(position..)
+                .and_then(|rest| rest.chars().next())
+                .map_or(kind, |ch| ParseErrorKind::InvalidChar { ch, position }),
+            kind => kind,
+        };
+
+        let mut len = input.len().min(Self::MAX_INPUT_LEN);
+        while !input.is_char_boundary(len) {
+            len -= 1;
+        }
+        let mut buf = [0; Self::MAX_INPUT_LEN];
+        buf[..len].copy_from_slice(&input.as_bytes()[..len]);
+        Self {
+            format,
+            kind,
+            input: buf,
+            #[allow(clippy::cast_possible_truncation)] // at most MAX_INPUT_LEN
+            input_len: len as u8,
+            truncated: len < input.len(),
+        }
+    }
+
+    /// Returns what the input was being parsed as.
+    #[must_use]
+    pub const fn format(&self) -> ParseFormat {
+        self.format
+    }
+
+    /// Returns why parsing failed.
+    #[must_use]
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the input, truncated to [`MAX_INPUT_LEN`](Self::MAX_INPUT_LEN) bytes.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        // Always cut at a character boundary of the original `&str`
+        core::str::from_utf8(&self.input[..usize::from(self.input_len)]).unwrap_or_default()
+    }
+
+    /// Returns `true` if [`input`](Self::input) was truncated.
+    #[must_use]
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the byte offset of the invalid character, if that was the cause.
+    #[must_use]
+    pub const fn position(&self) -> Option<usize> {
+        match self.kind {
+            ParseErrorKind::InvalidChar { position, .. } => Some(position),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Debug for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParseError")
+            .field("format", &self.format)
+            .field("kind", &self.kind)
+            .field("input", &self.input())
+            .field("truncated", &self.truncated)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ellipsis = if self.truncated { "…" } else { "" };
+        write!(
+            f,
+            "Failed to parse {} {:?}{ellipsis}: {}",
+            self.format,
+            self.input(),
+            self.kind
+        )
+    }
+}
+
+impl core::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.kind)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
 }
 
 impl fmt::Display for Error {
@@ -49,11 +505,37 @@ impl fmt::Display for Error {
             Self::Overflow => write!(f, "Overflow occurred during NULID increment"),
             Self::MutexPoisoned => write!(f, "Mutex poisoned (thread panic)"),
             Self::EncodingError => write!(f, "UTF-8 encoding error"),
+            Self::ReplayExhausted => write!(f, "Replay log exhausted"),
+            Self::InvalidChecksum { expected, found } => {
+                write!(
+                    f,
+                    "Invalid check symbol: expected '{expected}', found '{found}'"
+                )
+            }
+            Self::InvalidCursor => write!(f, "Invalid pagination cursor"),
+            Self::InvalidCursorSignature => write!(f, "Invalid pagination cursor signature"),
+            Self::InvalidPrefix { expected } => {
+                write!(f, "Invalid prefix: expected '{expected}'")
+            }
+            Self::NilId => write!(f, "Nil ID where a non-nil ID is required"),
+            Self::InvalidUuidVersion { found } => {
+                write!(f, "Invalid UUID version: expected 7, found {found}")
+            }
+            Self::InvalidArgument { reason } => write!(f, "Invalid argument: {reason}"),
+            Self::Parse(error) => fmt::Display::fmt(error, f),
         }
     }
 }
 
-impl core::error::Error for Error {}
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            // Displayed transparently, so the cause comes next in the chain
+            Self::Parse(error) => core::error::Error::source(error),
+            _ => None,
+        }
+    }
+}
 
 /// A specialized `Result` type for NULID operations.
 pub type Result<T> = core::result::Result<T, Error>;
@@ -97,6 +579,128 @@ mod tests {
             Error::MutexPoisoned.to_string(),
             "Mutex poisoned (thread panic)"
         );
+
+        assert_eq!(Error::ReplayExhausted.to_string(), "Replay log exhausted");
+
+        assert_eq!(
+            Error::InvalidCursor.to_string(),
+            "Invalid pagination cursor"
+        );
+
+        assert_eq!(
+            Error::InvalidChecksum {
+                expected: '*',
+                found: '5'
+            }
+            .to_string(),
+            "Invalid check symbol: expected '*', found '5'"
+        );
+
+        assert_eq!(
+            Error::InvalidPrefix { expected: "user_" }.to_string(),
+            "Invalid prefix: expected 'user_'"
+        );
+
+        assert_eq!(
+            Error::NilId.to_string(),
+            "Nil ID where a non-nil ID is required"
+        );
+
+        assert_eq!(
+            Error::InvalidUuidVersion { found: 4 }.to_string(),
+            "Invalid UUID version: expected 7, found 4"
+        );
+
+        assert_eq!(
+            Error::InvalidArgument {
+                reason: "must be non-zero"
+            }
+            .to_string(),
+            "Invalid argument: must be non-zero"
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Error::parse(
+            ParseFormat::Hex,
+            "0x018cc251f40078008abcdef01234567g",
+            Error::InvalidChar('g', 33),
+        );
+        assert_eq!(
+            error.to_string(),
+            r#"Failed to parse hex "0x018cc251f40078008abcdef01234567g": Invalid character 'g' at position 33"#
+        );
+        assert_eq!(error.reason(), Error::InvalidChar('g', 33));
+        let source = core::error::Error::source(&error).unwrap();
+        assert_eq!(source.to_string(), "Invalid character 'g' at position 33");
+
+        // Context is only added once
+        assert_eq!(
+            Error::parse(ParseFormat::Text, "other", error.clone()),
+            error
+        );
+        assert!(core::error::Error::source(&Error::Overflow).is_none());
+
+        // Errors unrelated to the input are not wrapped
+        assert_eq!(
+            Error::parse(ParseFormat::Text, "x", Error::SystemTimeError),
+            Error::SystemTimeError
+        );
+    }
+
+    #[test]
+    fn test_parse_error_input() {
+        let long = "x".repeat(100);
+        let error = ParseError::new(ParseFormat::Unknown, &long, ParseErrorKind::Overflow);
+        assert_eq!(error.input().len(), ParseError::MAX_INPUT_LEN);
+        assert!(error.is_truncated());
+        assert!(error.to_string().contains(r#"x"…: Overflow"#));
+        assert_eq!(error.position(), None);
+
+        // Truncation respects character boundaries
+        let wide = "é".repeat(40);
+        let error = ParseError::new(ParseFormat::Text, &wide, ParseErrorKind::Overflow);
+        assert_eq!(error.input().len(), ParseError::MAX_INPUT_LEN);
+
+        // The real character is reported for a non-ASCII byte
+        let error = ParseError::new(
+            ParseFormat::Text,
+            "01GZTV7EQ056J0E6N276XD6F3é",
+            ParseErrorKind::InvalidChar {
+                ch: '\u{c3}',
+                position: 25,
+            },
+        );
+        assert_eq!(
+            error.kind(),
+            ParseErrorKind::InvalidChar {
+                ch: 'é',
+                position: 25
+            }
+        );
+        assert_eq!(error.position(), Some(25));
+
+        let control = ParseError::new(ParseFormat::Text, "a\nb", ParseErrorKind::Overflow);
+        assert!(control.to_string().contains(r#""a\nb""#));
+    }
+
+    #[test]
+    fn test_error_kind_and_code() {
+        assert_eq!(Error::InvalidChar('U', 0).kind(), ErrorKind::InvalidInput);
+        assert_eq!(Error::SystemTimeError.kind(), ErrorKind::Clock);
+        assert_eq!(Error::ReplayExhausted.kind(), ErrorKind::Exhausted);
+        assert_eq!(Error::MutexPoisoned.kind(), ErrorKind::Internal);
+        assert_eq!(ErrorKind::InvalidInput.to_string(), "invalid input");
+
+        assert_eq!(Error::RandomError.code(), 1);
+        assert_eq!(Error::InvalidUuidVersion { found: 4 }.code(), 14);
+        assert_eq!(Error::InvalidArgument { reason: "" }.code(), 15);
+
+        // Parse context reports the kind and code of the underlying error
+        let error = Error::parse(ParseFormat::Decimal, "9", Error::Overflow);
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert_eq!(error.code(), Error::Overflow.code());
     }
 
     #[test]
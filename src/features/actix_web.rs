@@ -0,0 +1,262 @@
+//! actix-web support for NULID.
+//!
+//! This module implements:
+//!
+//! - `FromRequest` for `Nulid`, extracting it from the route's single path
+//!   segment. Invalid IDs are rejected with `400 Bad Request` and a message
+//!   naming the offending value
+//! - `Responder` for `Nulid`, returning the ID as a `text/plain` body
+//! - `ResponseError` for [`Error`], with the status chosen by
+//!   [`Error::kind`]: `400` for invalid input, `503` when exhausted, and
+//!   `500` otherwise
+//!
+//! `web::Path<T>` and `web::Query<T>` deserialize NULIDs through serde, but
+//! actix-web answers path deserialization failures with `404 Not Found`.
+//! Register [`path_config`] (and [`query_config`] for a matching message)
+//! to answer them with `400 Bad Request` instead.
+//!
+//! Types deriving `Id` get the same implementations.
+//!
+//! # Examples
+//!
+//! ```
+//! use actix_web::{App, web};
+//! use nulid::Nulid;
+//! use nulid::features::actix_web::{path_config, query_config};
+//!
+//! async fn show(id: Nulid) -> String {
+//!     format!("user {id}")
+//! }
+//!
+//! async fn pair(ids: web::Path<(Nulid, Nulid)>) -> String {
+//!     format!("{} {}", ids.0, ids.1)
+//! }
+//!
+//! async fn create() -> Result<Nulid, nulid::Error> {
+//!     Nulid::new()
+//! }
+//!
+//! let app = App::new()
+//!     .app_data(path_config())
+//!     .app_data(query_config())
+//!     .route("/users", web::post().to(create))
+//!     .route("/users/{id}", web::get().to(show))
+//!     .route("/pairs/{a}/{b}", web::get().to(pair));
+//! ```
+
+use crate::{Error, ErrorKind, Nulid};
+use actix_web::body::BoxBody;
+use actix_web::dev::Payload;
+use actix_web::error::{InternalError, PathError, QueryPayloadError};
+use actix_web::http::StatusCode;
+use actix_web::http::header::ContentType;
+use actix_web::web::{PathConfig, QueryConfig};
+use actix_web::{FromRequest, HttpRequest, HttpResponse, Responder, ResponseError};
+use core::fmt;
+use core::future::{Ready, ready};
+
+/// Error returned when a `Nulid` cannot be extracted from the path.
+#[derive(Debug)]
+pub enum NulidRejection {
+    /// The route does not have exactly one path segment to extract from.
+    PathSegments(usize),
+    /// The path segment is not a valid NULID.
+    Invalid {
+        /// The raw path segment.
+        value: String,
+        /// Why parsing failed.
+        error: Error,
+    },
+}
+
+impl fmt::Display for NulidRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PathSegments(count) => write!(
+                f,
+                "Expected exactly one path segment to extract a NULID from, found {count}"
+            ),
+            Self::Invalid { value, error } => write!(f, "Invalid NULID `{value}`: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for NulidRejection {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::PathSegments(_) => None,
+            Self::Invalid { error, .. } => Some(error),
+        }
+    }
+}
+
+impl ResponseError for NulidRejection {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::PathSegments(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::Invalid { .. } => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl ResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        match self.kind() {
+            ErrorKind::InvalidInput => StatusCode::BAD_REQUEST,
+            ErrorKind::Exhausted => StatusCode::SERVICE_UNAVAILABLE,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl FromRequest for Nulid {
+    type Error = NulidRejection;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let info = req.match_info();
+        let mut segments = info.iter();
+        let result = match (segments.next(), segments.next()) {
+            (Some((_, value)), None) => value.parse().map_err(|error| NulidRejection::Invalid {
+                value: value.to_owned(),
+                error,
+            }),
+            _ => Err(NulidRejection::PathSegments(info.segment_count())),
+        };
+        ready(result)
+    }
+}
+
+impl Responder for Nulid {
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+        HttpResponse::Ok()
+            .content_type(ContentType::plaintext())
+            .body(self.to_string())
+    }
+}
+
+/// Returns a `PathConfig` that answers path deserialization failures with
+/// `400 Bad Request` instead of actix-web's default `404 Not Found`.
+#[must_use]
+pub fn path_config() -> PathConfig {
+    PathConfig::default().error_handler(|err: PathError, _req| {
+        let message = format!("Invalid path parameter: {err}");
+        InternalError::new(message, StatusCode::BAD_REQUEST).into()
+    })
+}
+
+/// Returns a `QueryConfig` that answers query deserialization failures with
+/// `400 Bad Request` and a message matching [`path_config`].
+#[must_use]
+pub fn query_config() -> QueryConfig {
+    QueryConfig::default().error_handler(|err: QueryPayloadError, _req| {
+        let message = format!("Invalid query parameter: {err}");
+        InternalError::new(message, StatusCode::BAD_REQUEST).into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::body::MessageBody;
+    use actix_web::test::{TestRequest, call_service, init_service};
+    use actix_web::{App, web};
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct Filter {
+        after: Nulid,
+    }
+
+    async fn show(id: Nulid) -> Nulid {
+        id
+    }
+
+    async fn pair(ids: web::Path<(Nulid, Nulid)>) -> String {
+        format!("{}{}", ids.0, ids.1)
+    }
+
+    async fn list(filter: web::Query<Filter>) -> Nulid {
+        filter.after
+    }
+
+    fn call(uri: &str) -> (StatusCode, String) {
+        actix_web::rt::System::new().block_on(async {
+            let app = init_service(
+                App::new()
+                    .app_data(path_config())
+                    .app_data(query_config())
+                    .route("/items", web::get().to(list))
+                    .route("/items/{id}", web::get().to(show))
+                    .route("/pairs/{a}/{b}", web::get().to(pair))
+                    .route("/bad/{a}/{b}", web::get().to(show)),
+            )
+            .await;
+            let response = call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+            let status = response.status();
+            let body = response.into_body().try_into_bytes().unwrap_or_default();
+            (status, String::from_utf8(body.to_vec()).unwrap())
+        })
+    }
+
+    #[test]
+    fn test_extract_valid() {
+        let id = Nulid::new().unwrap();
+        let (status, body) = call(&format!("/items/{id}"));
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, id.to_string());
+    }
+
+    #[test]
+    fn test_extract_invalid() {
+        let (status, body) = call("/items/not-a-nulid");
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body.starts_with("Invalid NULID `not-a-nulid`:"), "{body}");
+    }
+
+    #[test]
+    fn test_extract_too_many_segments() {
+        let id = Nulid::new().unwrap();
+        let (status, _) = call(&format!("/bad/{id}/{id}"));
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn test_path_config_bad_request() {
+        let id = Nulid::new().unwrap();
+        let (status, body) = call(&format!("/pairs/{id}/{id}"));
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, format!("{id}{id}"));
+
+        let (status, body) = call(&format!("/pairs/{id}/invalid"));
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body.starts_with("Invalid path parameter:"), "{body}");
+    }
+
+    #[test]
+    fn test_query_config_bad_request() {
+        let id = Nulid::new().unwrap();
+        let (status, body) = call(&format!("/items?after={id}"));
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, id.to_string());
+
+        let (status, body) = call("/items?after=invalid");
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body.starts_with("Invalid query parameter:"), "{body}");
+    }
+
+    #[test]
+    fn test_error_response() {
+        assert_eq!(
+            Error::SystemTimeError.status_code(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+        assert_eq!(
+            Error::Overflow.status_code(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+        assert_eq!(Error::NilId.status_code(), StatusCode::BAD_REQUEST);
+    }
+}
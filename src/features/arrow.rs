@@ -0,0 +1,276 @@
+//! Apache Arrow support for NULID.
+//!
+//! NULIDs are stored in Arrow as `FixedSizeBinary(16)` columns holding the
+//! big-endian bytes, so sorting the column sorts by creation time. Columns can
+//! be tagged with the [`NulidExtension`] extension type (name `"nulid"`) so
+//! readers can recover the logical type. Parquet writers map these columns to
+//! `FIXED_LEN_BYTE_ARRAY(16)`.
+//!
+//! # Examples
+//!
+//! ```
+//! use arrow_array::Array;
+//! use nulid::Nulid;
+//! use nulid::features::arrow::{field, from_array, to_array};
+//!
+//! # fn main() -> nulid::Result<()> {
+//! let ids = vec![Nulid::new()?, Nulid::new()?];
+//!
+//! let schema_field = field("id", false);
+//! let array = to_array(&ids);
+//! assert_eq!(schema_field.data_type(), array.data_type());
+//!
+//! let decoded: Vec<Nulid> = from_array(&array)?.into_iter().flatten().collect();
+//! assert_eq!(decoded, ids);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{Error, Nulid, Result};
+use arrow_array::array::FixedSizeBinaryArray;
+use arrow_array::builder::FixedSizeBinaryBuilder;
+use arrow_buffer::{Buffer, NullBuffer};
+use arrow_schema::extension::ExtensionType;
+use arrow_schema::{ArrowError, DataType, Field};
+
+/// Byte width of a NULID in a `FixedSizeBinary` column.
+pub const BYTE_WIDTH: i32 = 16;
+
+/// Arrow extension type for NULID columns.
+///
+/// Registered under the name `"nulid"` with no metadata. The storage type
+/// must be `FixedSizeBinary(16)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NulidExtension;
+
+impl ExtensionType for NulidExtension {
+    const NAME: &'static str = "nulid";
+
+    type Metadata = ();
+
+    fn metadata(&self) -> &Self::Metadata {
+        &()
+    }
+
+    fn serialize_metadata(&self) -> Option<String> {
+        None
+    }
+
+    fn deserialize_metadata(_metadata: Option<&str>) -> core::result::Result<(), ArrowError> {
+        Ok(())
+    }
+
+    fn supports_data_type(&self, data_type: &DataType) -> core::result::Result<(), ArrowError> {
+        match data_type {
+            DataType::FixedSizeBinary(BYTE_WIDTH) => Ok(()),
+            other => Err(ArrowError::InvalidArgumentError(format!(
+                "nulid extension type requires FixedSizeBinary(16), found {other}"
+            ))),
+        }
+    }
+
+    fn try_new(data_type: &DataType, (): Self::Metadata) -> core::result::Result<Self, ArrowError> {
+        Self.supports_data_type(data_type)?;
+        Ok(Self)
+    }
+}
+
+/// Creates a `FixedSizeBinary(16)` field tagged with the [`NulidExtension`] type.
+#[must_use]
+pub fn field(name: impl Into<String>, nullable: bool) -> Field {
+    Field::new(name, DataType::FixedSizeBinary(BYTE_WIDTH), nullable)
+        .with_extension_type(NulidExtension)
+}
+
+/// Converts NULIDs into a `FixedSizeBinaryArray` without nulls.
+#[must_use]
+pub fn to_array(ids: &[Nulid]) -> FixedSizeBinaryArray {
+    let mut values = Vec::with_capacity(ids.len() * 16);
+    for id in ids {
+        values.extend_from_slice(&id.to_bytes());
+    }
+    FixedSizeBinaryArray::new(BYTE_WIDTH, Buffer::from_vec(values), None)
+}
+
+/// Converts optional NULIDs into a `FixedSizeBinaryArray`, mapping `None` to null.
+#[must_use]
+pub fn to_nullable_array(ids: &[Option<Nulid>]) -> FixedSizeBinaryArray {
+    let mut values = Vec::with_capacity(ids.len() * 16);
+    for id in ids {
+        values.extend_from_slice(&id.unwrap_or_default().to_bytes());
+    }
+    let nulls: NullBuffer = ids.iter().map(Option::is_some).collect();
+    let nulls = (nulls.null_count() > 0).then_some(nulls);
+    FixedSizeBinaryArray::new(BYTE_WIDTH, Buffer::from_vec(values), nulls)
+}
+
+/// Converts a `FixedSizeBinaryArray` back into NULIDs, mapping nulls to `None`.
+///
+/// # Errors
+///
+/// Returns `InvalidLength` if the array's byte width is not 16.
+pub fn from_array(array: &FixedSizeBinaryArray) -> Result<Vec<Option<Nulid>>> {
+    if array.value_length() != BYTE_WIDTH {
+        return Err(Error::InvalidLength {
+            expected: 16,
+            found: usize::try_from(array.value_length()).unwrap_or_default(),
+        });
+    }
+    array
+        .iter()
+        .map(|value| value.map(Nulid::try_from).transpose())
+        .collect()
+}
+
+/// Creates a `FixedSizeBinaryBuilder` for NULIDs with the given capacity.
+#[must_use]
+pub fn builder(capacity: usize) -> FixedSizeBinaryBuilder {
+    FixedSizeBinaryBuilder::with_capacity(capacity, BYTE_WIDTH)
+}
+
+/// Extension methods for appending NULIDs to a `FixedSizeBinaryBuilder`.
+///
+/// # Examples
+///
+/// ```
+/// use arrow_array::Array;
+/// use nulid::Nulid;
+/// use nulid::features::arrow::{NulidBuilderExt, builder};
+///
+/// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+/// let mut ids = builder(2);
+/// ids.append_nulid(Nulid::new()?)?;
+/// ids.append_option_nulid(None)?;
+/// let array = ids.finish();
+/// assert_eq!(array.len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+pub trait NulidBuilderExt {
+    /// Appends a NULID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the builder's byte width is not 16.
+    fn append_nulid(&mut self, id: Nulid) -> core::result::Result<(), ArrowError>;
+
+    /// Appends a NULID, or a null for `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the builder's byte width is not 16.
+    fn append_option_nulid(&mut self, id: Option<Nulid>) -> core::result::Result<(), ArrowError>;
+}
+
+impl NulidBuilderExt for FixedSizeBinaryBuilder {
+    fn append_nulid(&mut self, id: Nulid) -> core::result::Result<(), ArrowError> {
+        self.append_value(id.to_bytes())
+    }
+
+    fn append_option_nulid(&mut self, id: Option<Nulid>) -> core::result::Result<(), ArrowError> {
+        if let Some(id) = id {
+            return self.append_nulid(id);
+        }
+        self.append_null();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::Array;
+
+    fn ids(n: usize) -> Vec<Nulid> {
+        (0..n).map(|_| Nulid::new().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_array_round_trip() {
+        let ids = ids(10);
+        let array = to_array(&ids);
+
+        assert_eq!(array.len(), 10);
+        assert_eq!(array.null_count(), 0);
+        assert_eq!(array.value(3), ids[3].to_bytes());
+
+        let decoded = from_array(&array).unwrap();
+        assert_eq!(decoded, ids.into_iter().map(Some).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_nullable_array_round_trip() {
+        let id = Nulid::new().unwrap();
+        let values = vec![Some(id), None, Some(Nulid::nil())];
+        let array = to_nullable_array(&values);
+
+        assert_eq!(array.null_count(), 1);
+        assert!(array.is_null(1));
+        assert_eq!(from_array(&array).unwrap(), values);
+    }
+
+    #[test]
+    fn test_from_array_wrong_width() {
+        let array = FixedSizeBinaryArray::try_from_iter([[0u8; 8]].iter()).unwrap();
+        assert_eq!(
+            from_array(&array),
+            Err(Error::InvalidLength {
+                expected: 16,
+                found: 8
+            })
+        );
+    }
+
+    #[test]
+    fn test_builder() {
+        let ids = ids(3);
+        let mut builder = builder(4);
+        for id in &ids {
+            builder.append_nulid(*id).unwrap();
+        }
+        builder.append_option_nulid(None).unwrap();
+        let array = builder.finish();
+
+        let decoded = from_array(&array).unwrap();
+        assert_eq!(decoded.len(), 4);
+        assert_eq!(
+            decoded[..3],
+            ids.iter().copied().map(Some).collect::<Vec<_>>()
+        );
+        assert_eq!(decoded[3], None);
+    }
+
+    #[test]
+    fn test_builder_wrong_width() {
+        let mut builder = FixedSizeBinaryBuilder::new(8);
+        assert!(builder.append_nulid(Nulid::nil()).is_err());
+    }
+
+    #[test]
+    fn test_extension_field() {
+        let field = field("id", true);
+        assert_eq!(field.name(), "id");
+        assert!(field.is_nullable());
+        assert_eq!(field.extension_type_name(), Some("nulid"));
+        assert_eq!(
+            field.try_extension_type::<NulidExtension>().unwrap(),
+            NulidExtension
+        );
+    }
+
+    #[test]
+    fn test_extension_rejects_other_types() {
+        assert!(NulidExtension::try_new(&DataType::FixedSizeBinary(8), ()).is_err());
+        assert!(NulidExtension::try_new(&DataType::Utf8, ()).is_err());
+        assert!(NulidExtension::try_new(&DataType::FixedSizeBinary(16), ()).is_ok());
+    }
+
+    #[test]
+    fn test_sort_order_preserved() {
+        let ids = ids(50);
+        let array = to_array(&ids);
+        for i in 1..array.len() {
+            assert!(array.value(i) > array.value(i - 1));
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! GraphQL scalar support for NULID via `async-graphql`.
+//!
+//! This module exposes `Nulid` as a custom `Nulid` GraphQL scalar, transferred
+//! as its 26-character Base32 string, so async-graphql schemas can accept and
+//! return NULIDs directly.
+//!
+//! # Examples
+//!
+//! ```
+//! use async_graphql::{ScalarType, Value};
+//! use nulid::Nulid;
+//!
+//! # fn main() -> nulid::Result<()> {
+//! let id = Nulid::new()?;
+//! let value = id.to_value();
+//! assert_eq!(value, Value::String(id.to_string()));
+//! assert_eq!(Nulid::parse(value).ok(), Some(id));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::Nulid;
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// NULID: 26-character Crockford Base32, nanosecond-precision sortable identifier
+#[Scalar(name = "Nulid")]
+impl ScalarType for Nulid {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => s.parse().map_err(InputValueError::custom),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(s) if s.parse::<Self>().is_ok())
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scalar_round_trip() {
+        let id = Nulid::new().expect("Failed to create NULID");
+        let value = id.to_value();
+        assert_eq!(value, Value::String(id.to_string()));
+        assert!(Nulid::is_valid(&value));
+        assert_eq!(Nulid::parse(value).ok(), Some(id));
+    }
+
+    #[test]
+    fn test_scalar_invalid_input() {
+        let invalid = Value::String("not-a-nulid".to_string());
+        assert!(!Nulid::is_valid(&invalid));
+        assert!(Nulid::parse(invalid).is_err());
+
+        let wrong_type = Value::Number(42.into());
+        assert!(!Nulid::is_valid(&wrong_type));
+        assert!(Nulid::parse(wrong_type).is_err());
+    }
+}
@@ -0,0 +1,338 @@
+//! Avro support for NULID via [`apache-avro`](https://docs.rs/apache-avro).
+//!
+//! Two representations are supported:
+//!
+//! - [`FIXED_SCHEMA`] with the [`fixed`] adapter: a named `fixed` of 16
+//!   big-endian bytes, the compact choice for Kafka topics
+//! - [`STRING_SCHEMA`] with the [`string`] adapter: an Avro `string` carrying
+//!   the 26-character Base32 form, annotated with a `"nulid"` logical type
+//!   that readers without NULID support fall back to plain strings for
+//!
+//! Both preserve NULID ordering under Avro's sort order.
+//!
+//! `Nulid` implements [`AvroSchemaComponent`] (and so [`AvroSchema`]) with
+//! the `fixed` schema, and converts to and from [`Value`]. `Nulid`'s own
+//! serde impls write a 16-element tuple in binary formats, which Avro cannot
+//! map to `fixed`, so serde-based records must annotate NULID fields with one
+//! of the adapters. Each adapter also provides the `get_schema_in_ctxt`
+//! functions that `apache-avro`'s `#[avro(with)]` derive attribute expects.
+//!
+//! [`AvroSchema`]: apache_avro::AvroSchema
+//!
+//! # Examples
+//!
+//! ```
+//! use apache_avro::{Reader, Schema, Writer, from_value};
+//! use nulid::Nulid;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "nulid::features::avro::fixed")]
+//!     id: Nulid,
+//!     name: String,
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let schema = Schema::parse_str(
+//!     r#"{
+//!         "type": "record",
+//!         "name": "Event",
+//!         "fields": [
+//!             { "name": "id", "type": { "type": "fixed", "name": "Nulid", "namespace": "nulid", "size": 16 } },
+//!             { "name": "name", "type": "string" }
+//!         ]
+//!     }"#,
+//! )?;
+//!
+//! let event = Event { id: Nulid::new()?, name: "created".to_string() };
+//! let mut writer = Writer::new(&schema, Vec::new())?;
+//! writer.append_ser(&event)?;
+//! let bytes = writer.into_inner()?;
+//!
+//! for value in Reader::new(&bytes[..])? {
+//!     assert_eq!(from_value::<Event>(&value?)?, event);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::Nulid;
+use apache_avro::AvroSchemaComponent;
+use apache_avro::error::Details;
+use apache_avro::schema::{FixedSchema, Name, NamespaceRef, RecordField, Schema};
+use apache_avro::types::Value;
+use std::collections::HashSet;
+
+/// Avro schema for a NULID stored as `fixed(16)`.
+///
+/// Pair with the [`fixed`] serde adapter.
+pub const FIXED_SCHEMA: &str = r#"{"type":"fixed","name":"Nulid","namespace":"nulid","size":16}"#;
+
+/// Avro schema for a NULID stored as its Base32 string.
+///
+/// Pair with the [`string`] serde adapter.
+pub const STRING_SCHEMA: &str = r#"{"type":"string","logicalType":"nulid"}"#;
+
+/// Fully qualified name of the `fixed` schema.
+const FIXED_NAME: &str = "nulid.Nulid";
+
+/// Returns the `fixed(16)` schema, or a reference to it if already defined.
+fn fixed_schema(named_schemas: &mut HashSet<Name>) -> Schema {
+    let name = Name::new(FIXED_NAME).unwrap_or_else(|_| unreachable!("name is valid"));
+    if named_schemas.contains(&name) {
+        Schema::Ref { name }
+    } else {
+        named_schemas.insert(name.clone());
+        Schema::Fixed(FixedSchema::builder().name(name).size(16).build())
+    }
+}
+
+impl AvroSchemaComponent for Nulid {
+    /// The schema is [`FIXED_SCHEMA`]: a `fixed` of size 16 named `nulid.Nulid`.
+    fn get_schema_in_ctxt(named_schemas: &mut HashSet<Name>, _: NamespaceRef) -> Schema {
+        fixed_schema(named_schemas)
+    }
+
+    fn get_record_fields_in_ctxt(
+        _: &mut HashSet<Name>,
+        _: NamespaceRef,
+    ) -> Option<Vec<RecordField>> {
+        None
+    }
+}
+
+impl From<Nulid> for Value {
+    /// Converts a NULID to an Avro `fixed(16)` value of its big-endian bytes.
+    fn from(id: Nulid) -> Self {
+        Self::Fixed(16, id.to_bytes().to_vec())
+    }
+}
+
+impl TryFrom<&Value> for Nulid {
+    type Error = apache_avro::Error;
+
+    /// Converts an Avro value to a NULID.
+    ///
+    /// Accepts `fixed` and `bytes` values of 16 bytes, `uuid` values, and
+    /// `string` values holding the Base32 form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use apache_avro::types::Value;
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let id = Nulid::new()?;
+    /// assert_eq!(Nulid::try_from(&Value::from(id))?, id);
+    /// assert_eq!(Nulid::try_from(&Value::String(id.to_string()))?, id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        let invalid = |err: crate::Error| Details::DeserializeValue(err.to_string()).into();
+        match value {
+            Value::Fixed(_, bytes) | Value::Bytes(bytes) => {
+                Self::try_from(bytes.as_slice()).map_err(invalid)
+            }
+            Value::Uuid(uuid) => Ok(Self::from_bytes(*uuid.as_bytes())),
+            Value::String(s) => s.parse().map_err(invalid),
+            other => Err(Details::GetString(other.clone()).into()),
+        }
+    }
+}
+
+impl TryFrom<Value> for Nulid {
+    type Error = apache_avro::Error;
+
+    /// Converts an Avro value to a NULID; see [`TryFrom<&Value>`](#impl-TryFrom<%26Value>-for-Nulid).
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+/// Serializes a NULID as 16 bytes for an Avro `fixed(16)` field.
+///
+/// Use with `#[serde(with = "nulid::features::avro::fixed")]`. `apache-avro`
+/// resolves the byte buffer against [`FIXED_SCHEMA`]. With `apache-avro`'s
+/// derive, add `#[avro(with)]` to pick up [`fixed::get_schema_in_ctxt`].
+// The signatures must match what `#[avro(with)]` calls.
+#[allow(clippy::implicit_hasher)]
+pub mod fixed {
+    use super::{HashSet, Name, NamespaceRef, RecordField, Schema};
+    pub use crate::serde::as_bytes::{deserialize, serialize};
+
+    /// Returns the `fixed(16)` schema named `nulid.Nulid`.
+    pub fn get_schema_in_ctxt(named_schemas: &mut HashSet<Name>, _: NamespaceRef) -> Schema {
+        super::fixed_schema(named_schemas)
+    }
+
+    /// Returns `None`; the schema is not a record.
+    #[must_use]
+    pub const fn get_record_fields_in_ctxt(
+        _: &mut HashSet<Name>,
+        _: NamespaceRef,
+    ) -> Option<Vec<RecordField>> {
+        None
+    }
+}
+
+/// Serializes a NULID as its Base32 string for an Avro `string` field.
+///
+/// Use with `#[serde(with = "nulid::features::avro::string")]`. With
+/// `apache-avro`'s derive, add `#[avro(with)]` to pick up
+/// [`string::get_schema_in_ctxt`].
+#[allow(clippy::implicit_hasher)]
+pub mod string {
+    use super::{HashSet, Name, NamespaceRef, RecordField, Schema};
+    pub use crate::serde::as_string::{deserialize, serialize};
+
+    /// Returns the `string` schema.
+    #[must_use]
+    pub const fn get_schema_in_ctxt(_: &mut HashSet<Name>, _: NamespaceRef) -> Schema {
+        Schema::String
+    }
+
+    /// Returns `None`; the schema is not a record.
+    #[must_use]
+    pub const fn get_record_fields_in_ctxt(
+        _: &mut HashSet<Name>,
+        _: NamespaceRef,
+    ) -> Option<Vec<RecordField>> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apache_avro::{AvroSchema, Reader, Writer, from_value};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        #[serde(with = "fixed")]
+        id: Nulid,
+        #[serde(with = "string")]
+        parent: Nulid,
+    }
+
+    fn event_schema() -> Schema {
+        Schema::parse_str(&format!(
+            r#"{{"type":"record","name":"Event","fields":[
+                {{"name":"id","type":{FIXED_SCHEMA}}},
+                {{"name":"parent","type":{STRING_SCHEMA}}}
+            ]}}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_fixed_schema() {
+        let schema: serde_json::Value = serde_json::from_str(FIXED_SCHEMA).unwrap();
+        assert_eq!(schema["type"], "fixed");
+        assert_eq!(schema["name"], "Nulid");
+        assert_eq!(schema["size"], 16);
+
+        assert_eq!(
+            Schema::parse_str(FIXED_SCHEMA).unwrap(),
+            Nulid::get_schema()
+        );
+    }
+
+    #[test]
+    fn test_string_schema() {
+        let schema: serde_json::Value = serde_json::from_str(STRING_SCHEMA).unwrap();
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["logicalType"], "nulid");
+    }
+
+    #[test]
+    fn test_schema_ref_when_named() {
+        let mut named = HashSet::new();
+        assert!(matches!(
+            Nulid::get_schema_in_ctxt(&mut named, None),
+            Schema::Fixed(_)
+        ));
+        assert!(matches!(
+            fixed::get_schema_in_ctxt(&mut named, None),
+            Schema::Ref { .. }
+        ));
+    }
+
+    #[test]
+    fn test_value_conversions() {
+        let id = Nulid::new().unwrap();
+        let value = Value::from(id);
+        assert_eq!(value, Value::Fixed(16, id.to_bytes().to_vec()));
+        assert_eq!(Nulid::try_from(value).unwrap(), id);
+        assert_eq!(
+            Nulid::try_from(Value::Bytes(id.to_bytes().to_vec())).unwrap(),
+            id
+        );
+        assert_eq!(Nulid::try_from(Value::String(id.to_string())).unwrap(), id);
+
+        assert!(Nulid::try_from(Value::Bytes(vec![1, 2, 3])).is_err());
+        assert!(Nulid::try_from(Value::String("invalid".to_string())).is_err());
+        assert!(Nulid::try_from(Value::Long(42)).is_err());
+    }
+
+    #[test]
+    fn test_writer_reader_round_trip() {
+        let schema = event_schema();
+        let events: Vec<Event> = (0..3)
+            .map(|_| Event {
+                id: Nulid::new().unwrap(),
+                parent: Nulid::new().unwrap(),
+            })
+            .collect();
+
+        let mut writer = Writer::new(&schema, Vec::new()).unwrap();
+        for event in &events {
+            writer.append_ser(event).unwrap();
+        }
+        let bytes = writer.into_inner().unwrap();
+
+        let decoded: Vec<Event> = Reader::new(&bytes[..])
+            .unwrap()
+            .map(|value| from_value(&value.unwrap()).unwrap())
+            .collect();
+        assert_eq!(decoded, events);
+    }
+
+    #[test]
+    fn test_writer_reader_values() {
+        let schema = Schema::parse_str(FIXED_SCHEMA).unwrap();
+        let ids: Vec<Nulid> = (0..3).map(|_| Nulid::new().unwrap()).collect();
+
+        let mut writer = Writer::new(&schema, Vec::new()).unwrap();
+        for id in &ids {
+            writer.append_value(*id).unwrap();
+        }
+        let bytes = writer.into_inner().unwrap();
+
+        let decoded: Vec<Nulid> = Reader::new(&bytes[..])
+            .unwrap()
+            .map(|value| Nulid::try_from(value.unwrap()).unwrap())
+            .collect();
+        assert_eq!(decoded, ids);
+    }
+
+    #[test]
+    fn test_adapters_round_trip() {
+        let event = Event {
+            id: Nulid::new().unwrap(),
+            parent: Nulid::new().unwrap(),
+        };
+
+        let bytes = rmp_serde::to_vec(&event).unwrap();
+        let decoded: Event = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(decoded, event);
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains(&format!("\"{}\"", event.parent)));
+        let decoded: Event = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, event);
+    }
+}
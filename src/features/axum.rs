@@ -0,0 +1,193 @@
+//! axum support for NULID.
+//!
+//! This module implements:
+//!
+//! - `FromRequestParts` for `Nulid`, extracting it from the route's single
+//!   path parameter. Invalid IDs are rejected with `400 Bad Request` and a
+//!   message naming the offending value
+//! - `IntoResponse` for `Nulid`, returning the ID as a `text/plain` body
+//! - `IntoResponse` for [`Error`], with the status chosen by
+//!   [`Error::kind`]: `400` for invalid input, `503` when exhausted, and
+//!   `500` otherwise
+//!
+//! Types deriving `Id` get the same implementations.
+//!
+//! # Examples
+//!
+//! ```
+//! use axum::Router;
+//! use axum::routing::{get, post};
+//! use nulid::Nulid;
+//!
+//! async fn show(id: Nulid) -> String {
+//!     format!("user {id}")
+//! }
+//!
+//! async fn create() -> Result<Nulid, nulid::Error> {
+//!     Nulid::new()
+//! }
+//!
+//! let app: Router = Router::new()
+//!     .route("/users", post(create))
+//!     .route("/users/{id}", get(show));
+//! ```
+//!
+//! Routes with several path parameters can use `Path<(Nulid, Nulid)>`
+//! with the `serde` feature instead.
+
+use crate::{Error, ErrorKind, Nulid};
+use axum::extract::rejection::PathRejection;
+use axum::extract::{FromRequestParts, Path};
+use axum::http::StatusCode;
+use axum::http::request::Parts;
+use axum::response::{IntoResponse, Response};
+use core::fmt;
+
+/// Rejection returned when a `Nulid` cannot be extracted from the path.
+#[derive(Debug)]
+pub enum NulidRejection {
+    /// The path parameter could not be read, e.g. the route has no or
+    /// several parameters.
+    Path(PathRejection),
+    /// The path parameter is not a valid NULID.
+    Invalid {
+        /// The raw path parameter.
+        value: String,
+        /// Why parsing failed.
+        error: Error,
+    },
+}
+
+impl fmt::Display for NulidRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Path(rejection) => write!(f, "{rejection}"),
+            Self::Invalid { value, error } => write!(f, "Invalid NULID `{value}`: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for NulidRejection {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Path(rejection) => Some(rejection),
+            Self::Invalid { error, .. } => Some(error),
+        }
+    }
+}
+
+impl IntoResponse for NulidRejection {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Path(rejection) => rejection.into_response(),
+            invalid @ Self::Invalid { .. } => {
+                (StatusCode::BAD_REQUEST, invalid.to_string()).into_response()
+            }
+        }
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for Nulid {
+    type Rejection = NulidRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Path(value) = Path::<String>::from_request_parts(parts, state)
+            .await
+            .map_err(NulidRejection::Path)?;
+        value
+            .parse()
+            .map_err(|error| NulidRejection::Invalid { value, error })
+    }
+}
+
+impl IntoResponse for Nulid {
+    fn into_response(self) -> Response {
+        self.to_string().into_response()
+    }
+}
+
+impl IntoResponse for Error {
+    fn into_response(self) -> Response {
+        let status = match self.kind() {
+            ErrorKind::InvalidInput => StatusCode::BAD_REQUEST,
+            ErrorKind::Exhausted => StatusCode::SERVICE_UNAVAILABLE,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        (status, self.to_string()).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::get;
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn show(id: Nulid) -> String {
+        id.to_string()
+    }
+
+    async fn create() -> Nulid {
+        Nulid::from_u128(42)
+    }
+
+    fn app() -> Router {
+        Router::new()
+            .route("/new", get(create))
+            .route("/items/{id}", get(show))
+            .route("/items/{id}/{other}", get(show))
+    }
+
+    async fn call(uri: &str) -> (StatusCode, String) {
+        let response = app()
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_extract_valid() {
+        let id = Nulid::new().unwrap();
+        let (status, body) = call(&format!("/items/{id}")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, id.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_extract_invalid() {
+        let (status, body) = call("/items/not-a-nulid").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body.starts_with("Invalid NULID `not-a-nulid`:"), "{body}");
+    }
+
+    #[tokio::test]
+    async fn test_extract_too_many_params() {
+        let id = Nulid::new().unwrap();
+        let (status, _) = call(&format!("/items/{id}/{id}")).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[tokio::test]
+    async fn test_into_response() {
+        let (status, body) = call("/new").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, Nulid::from_u128(42).to_string());
+    }
+
+    #[test]
+    fn test_error_into_response() {
+        let response = Error::SystemTimeError.into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        let response = Error::Overflow.into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let response = Error::NilId.into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
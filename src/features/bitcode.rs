@@ -0,0 +1,62 @@
+//! `bitcode` serialization support for NULID.
+//!
+//! `Nulid` derives `bitcode::Encode` and `bitcode::Decode` directly, using
+//! `#[cfg_attr(feature = "bitcode", derive(...))]` in `nulid.rs`. The value
+//! is encoded as its `u128`. bitcode packs integer columns, so each real
+//! NULID takes the full 16 bytes plus a one-byte packing header per column;
+//! only columns of small values, such as nil IDs, shrink further.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let id = Nulid::new()?;
+//!
+//! let bytes = bitcode::encode(&id);
+//! assert_eq!(bytes.len(), 17);
+//!
+//! let decoded: Nulid = bitcode::decode(&bytes)?;
+//! assert_eq!(decoded, id);
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(test)]
+mod tests {
+    use crate::Nulid;
+
+    #[derive(Debug, PartialEq, Eq, bitcode::Encode, bitcode::Decode)]
+    struct Packet {
+        id: Nulid,
+        sequence: u32,
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for id in [Nulid::nil(), Nulid::max(), Nulid::new().unwrap()] {
+            let bytes = bitcode::encode(&id);
+            assert_eq!(bitcode::decode::<Nulid>(&bytes).unwrap(), id);
+        }
+        assert_eq!(bitcode::encode(&Nulid::new().unwrap()).len(), 17);
+    }
+
+    #[test]
+    fn test_derived_struct() {
+        let packets: Vec<Packet> = (0..10)
+            .map(|sequence| Packet {
+                id: Nulid::new().unwrap(),
+                sequence,
+            })
+            .collect();
+        let bytes = bitcode::encode(&packets);
+        assert!(bytes.len() >= 16 * packets.len());
+        assert_eq!(bitcode::decode::<Vec<Packet>>(&bytes).unwrap(), packets);
+    }
+
+    #[test]
+    fn test_short_buffer() {
+        assert!(bitcode::decode::<Nulid>(&[0u8; 15]).is_err());
+    }
+}
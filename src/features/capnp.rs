@@ -0,0 +1,195 @@
+//! Cap'n Proto support for NULID.
+//!
+//! Helpers for the common ways of storing a NULID in a Cap'n Proto struct:
+//!
+//! - A 16-byte `Data` field holding the big-endian bytes, written with
+//!   [`write_data`] and read with [`from_data`]
+//! - Two `UInt64` fields holding the high and low halves, as in the bundled
+//!   [`CAPNP_FILE`], set from [`Nulid::to_u64_pair`] and read with
+//!   [`Nulid::from_u64_pair`]
+//! - A two-element `List(UInt64)`, written with [`write_u64_list`] and read
+//!   with [`from_u64_list`]
+//!
+//! ```text
+//! struct Event {
+//!   id @0 :Data;          # 16-byte blob
+//!   parentHigh @1 :UInt64; # or two UInt64 fields
+//!   parentLow @2 :UInt64;
+//! }
+//! ```
+//!
+//! # Examples
+//!
+//! ```
+//! use capnp::{data, message, serialize};
+//! use nulid::Nulid;
+//! use nulid::features::capnp::{from_data, write_data};
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let id = Nulid::new()?;
+//!
+//! let mut builder = message::Builder::new_default();
+//! write_data(builder.initn_root::<data::Builder>(16), id)?;
+//! let words = serialize::write_message_to_words(&builder);
+//!
+//! let reader = serialize::read_message_from_flat_slice(&mut &words[..], Default::default())?;
+//! assert_eq!(from_data(reader.get_root::<data::Reader>()?)?, id);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{Error, Nulid, Result};
+use capnp::{data, primitive_list};
+
+/// Contents of `proto/nulid.capnp`, defining the `Nulid` struct.
+///
+/// Write it out from a build script to import it from other `.capnp` files.
+pub const CAPNP_FILE: &str = include_str!("../../proto/nulid.capnp");
+
+/// Writes a NULID into a 16-byte `Data` field.
+///
+/// `data` is the builder returned by the generated `init_<field>(16)`.
+///
+/// # Errors
+///
+/// Returns `InvalidLength` if `data` is not exactly 16 bytes.
+pub const fn write_data(data: data::Builder<'_>, id: Nulid) -> Result<()> {
+    if data.len() != 16 {
+        return Err(Error::InvalidLength {
+            expected: 16,
+            found: data.len(),
+        });
+    }
+    data.copy_from_slice(&id.to_bytes());
+    Ok(())
+}
+
+/// Reads a NULID from a 16-byte `Data` field.
+///
+/// # Errors
+///
+/// Returns `InvalidLength` if the data is not exactly 16 bytes.
+pub fn from_data(data: data::Reader<'_>) -> Result<Nulid> {
+    Nulid::try_from(data)
+}
+
+/// Writes a NULID into a two-element `List(UInt64)` as `[high, low]`.
+///
+/// `list` is the builder returned by the generated `init_<field>(2)`.
+///
+/// # Errors
+///
+/// Returns `InvalidLength` if `list` does not have exactly 2 elements.
+pub fn write_u64_list(mut list: primitive_list::Builder<'_, u64>, id: Nulid) -> Result<()> {
+    if list.len() != 2 {
+        return Err(Error::InvalidLength {
+            expected: 2,
+            found: list.len() as usize,
+        });
+    }
+    let (high, low) = id.to_u64_pair();
+    list.set(0, high);
+    list.set(1, low);
+    Ok(())
+}
+
+/// Reads a NULID from a two-element `List(UInt64)` holding `[high, low]`.
+///
+/// # Errors
+///
+/// Returns `InvalidLength` if the list does not have exactly 2 elements.
+pub fn from_u64_list(list: primitive_list::Reader<'_, u64>) -> Result<Nulid> {
+    if list.len() != 2 {
+        return Err(Error::InvalidLength {
+            expected: 2,
+            found: list.len() as usize,
+        });
+    }
+    Ok(Nulid::from_u64_pair(list.get(0), list.get(1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use capnp::message::{self, ReaderOptions};
+    use capnp::serialize;
+
+    fn to_words(builder: &message::Builder<message::HeapAllocator>) -> Vec<u8> {
+        serialize::write_message_to_words(builder)
+    }
+
+    #[test]
+    fn test_data_round_trip() {
+        let id = Nulid::new().unwrap();
+        let mut builder = message::Builder::new_default();
+        write_data(builder.initn_root::<data::Builder>(16), id).unwrap();
+
+        let words = to_words(&builder);
+        let reader =
+            serialize::read_message_from_flat_slice(&mut &words[..], ReaderOptions::new()).unwrap();
+        let data = reader.get_root::<data::Reader>().unwrap();
+        assert_eq!(data, id.to_bytes());
+        assert_eq!(from_data(data).unwrap(), id);
+    }
+
+    #[test]
+    fn test_data_wrong_length() {
+        let mut builder = message::Builder::new_default();
+        assert_eq!(
+            write_data(builder.initn_root::<data::Builder>(3), Nulid::MAX),
+            Err(Error::InvalidLength {
+                expected: 16,
+                found: 3
+            })
+        );
+        assert_eq!(
+            from_data(&[1, 2, 3]),
+            Err(Error::InvalidLength {
+                expected: 16,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_u64_list_round_trip() {
+        let id = Nulid::new().unwrap();
+        let mut builder = message::Builder::new_default();
+        write_u64_list(builder.initn_root::<primitive_list::Builder<u64>>(2), id).unwrap();
+
+        let words = to_words(&builder);
+        let reader =
+            serialize::read_message_from_flat_slice(&mut &words[..], ReaderOptions::new()).unwrap();
+        let list = reader.get_root::<primitive_list::Reader<u64>>().unwrap();
+        let (high, low) = id.to_u64_pair();
+        assert_eq!((list.get(0), list.get(1)), (high, low));
+        assert_eq!(from_u64_list(list).unwrap(), id);
+    }
+
+    #[test]
+    fn test_u64_list_wrong_length() {
+        let mut builder = message::Builder::new_default();
+        let list = builder.initn_root::<primitive_list::Builder<u64>>(3);
+        assert_eq!(
+            write_u64_list(list, Nulid::MAX),
+            Err(Error::InvalidLength {
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            from_u64_list(builder.get_root_as_reader().unwrap()),
+            Err(Error::InvalidLength {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_capnp_file() {
+        assert!(CAPNP_FILE.contains("struct Nulid"));
+        assert!(CAPNP_FILE.contains("high @0 :UInt64;"));
+        assert!(CAPNP_FILE.contains("low @1 :UInt64;"));
+    }
+}
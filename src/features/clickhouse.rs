@@ -0,0 +1,168 @@
+//! `ClickHouse` `RowBinary` support for NULID.
+//!
+//! The [`clickhouse`](https://docs.rs/clickhouse) crate reads and writes rows
+//! through serde, so this feature only needs serde adapters; it does not
+//! depend on the `clickhouse` crate itself. Two column types are supported:
+//!
+//! - [`uuid`]: `UUID` columns, written as two little-endian `u64` halves
+//!   (high half first), the same wire format as `clickhouse::serde::uuid`
+//! - [`uint128`]: `UInt128` columns, written as a little-endian `u128`
+//!
+//! # Ordering
+//!
+//! `UInt128` preserves NULID ordering in `ClickHouse`; `UUID` does not.
+//! `ClickHouse` compares `UUID` values by their low 64-bit half first, which
+//! holds the random bits, so `ORDER BY` on a `UUID` column does not sort by
+//! creation time. Use `UInt128` (or `FixedString(16)` holding the big-endian
+//! [`Nulid::to_bytes`](crate::Nulid::to_bytes)) for sort and primary keys, and
+//! `UUID` only where interoperability with other UUID columns matters more
+//! than ordering.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "nulid::features::clickhouse::uint128")]
+//!     id: Nulid,
+//!     #[serde(with = "nulid::features::clickhouse::uuid")]
+//!     trace_id: Nulid,
+//!     name: String,
+//! }
+//! ```
+//!
+//! With the table defined as:
+//!
+//! ```sql
+//! CREATE TABLE events (id UInt128, trace_id UUID, name String)
+//! ENGINE = MergeTree ORDER BY id
+//! ```
+
+/// Serializes a NULID for a `ClickHouse` `UUID` column.
+///
+/// Use with `#[serde(with = "nulid::features::clickhouse::uuid")]`. Binary
+/// formats receive a `(u64, u64)` tuple of the high and low halves, matching
+/// `RowBinary`'s `UUID` encoding. Human-readable formats receive a hyphenated
+/// UUID string, as `clickhouse::serde::uuid` does.
+///
+/// `ClickHouse` does not sort `UUID` columns in NULID order; see the
+/// [module documentation](self) before using one as a sort key.
+pub mod uuid {
+    use crate::Nulid;
+    use crate::serde::as_uuid_string;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes `id` as a `ClickHouse` `UUID`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the serializer.
+    pub fn serialize<S: Serializer>(id: &Nulid, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return as_uuid_string::serialize(id, serializer);
+        }
+        let (high, low) = split(id.as_u128());
+        (high, low).serialize(serializer)
+    }
+
+    /// Deserializes a NULID from a `ClickHouse` `UUID`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a `(u64, u64)` pair, or not a
+    /// UUID string in human-readable formats.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Nulid, D::Error> {
+        if deserializer.is_human_readable() {
+            return as_uuid_string::deserialize(deserializer);
+        }
+        let (high, low) = <(u64, u64)>::deserialize(deserializer)?;
+        Ok(Nulid::from_u128((u128::from(high) << 64) | u128::from(low)))
+    }
+
+    /// Splits a `u128` into its high and low `u64` halves.
+    #[allow(clippy::cast_possible_truncation)]
+    const fn split(value: u128) -> (u64, u64) {
+        ((value >> 64) as u64, value as u64)
+    }
+}
+
+/// Serializes a NULID for a `ClickHouse` `UInt128` column.
+///
+/// Use with `#[serde(with = "nulid::features::clickhouse::uint128")]`. The
+/// NULID is written as a `u128`, which `RowBinary` encodes little-endian.
+/// `UInt128` columns sort in NULID order, so this is the representation to
+/// use in `ORDER BY` and primary keys.
+pub mod uint128 {
+    pub use crate::serde::as_u128::{deserialize, serialize};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Nulid;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Row {
+        #[serde(with = "super::uuid")]
+        id: Nulid,
+        #[serde(with = "super::uint128")]
+        trace_id: Nulid,
+    }
+
+    const VALUE: u128 = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210;
+
+    fn row_binary<T: Serialize>(value: &T) -> Vec<u8> {
+        // Fixed-width little-endian integers, as in RowBinary.
+        bincode::serde::encode_to_vec(value, bincode::config::legacy()).unwrap()
+    }
+
+    #[test]
+    fn test_uuid_row_binary_layout() {
+        let row = Row {
+            id: Nulid::from_u128(VALUE),
+            trace_id: Nulid::nil(),
+        };
+        let bytes = row_binary(&row);
+
+        assert_eq!(&bytes[..8], &0x0123_4567_89AB_CDEF_u64.to_le_bytes());
+        assert_eq!(&bytes[8..16], &0xFEDC_BA98_7654_3210_u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_uint128_row_binary_layout() {
+        let row = Row {
+            id: Nulid::nil(),
+            trace_id: Nulid::from_u128(VALUE),
+        };
+        let bytes = row_binary(&row);
+
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(&bytes[16..], &VALUE.to_le_bytes());
+    }
+
+    #[test]
+    fn test_row_binary_round_trip() {
+        let row = Row {
+            id: Nulid::new().unwrap(),
+            trace_id: Nulid::new().unwrap(),
+        };
+        let bytes = row_binary(&row);
+        let (decoded, _): (Row, usize) =
+            bincode::serde::decode_from_slice(&bytes, bincode::config::legacy()).unwrap();
+        assert_eq!(decoded, row);
+    }
+
+    #[test]
+    fn test_uuid_human_readable() {
+        let id = Nulid::from_u128(VALUE);
+        let row = Row { id, trace_id: id };
+        let json = serde_json::to_string(&row).unwrap();
+        assert!(json.contains("\"01234567-89ab-cdef-fedc-ba9876543210\""));
+
+        let decoded: Row = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, row);
+    }
+}
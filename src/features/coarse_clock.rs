@@ -0,0 +1,158 @@
+//! Coarse system clock for high-throughput generation.
+//!
+//! [`CoarseClock`] reads the operating system's cached wall-clock time
+//! instead of a high-resolution counter: `CLOCK_REALTIME_COARSE` on Linux
+//! and Android, and `GetSystemTimeAsFileTime` on Windows. A reading is a
+//! memory load rather than a counter read and conversion, but it only
+//! advances once per kernel tick, about 1 ms (1-4 ms on Linux depending on
+//! `CONFIG_HZ`, up to 15.6 ms on Windows unless the timer period is
+//! raised).
+//!
+//! IDs generated between ticks share a timestamp, so a
+//! [`Generator`](crate::Generator) orders them by incrementing the previous
+//! ID. The generator still draws random bits for every ID, so this only
+//! saves the cost of the clock read, with timestamps accurate to the tick
+//! instead of the nanosecond. Combine it with
+//! [`with_timestamp_batch`](crate::Generator::with_timestamp_batch) to
+//! also skip the RNG between clock reads.
+//!
+//! On other platforms the clock falls back to
+//! [`SystemTime::now`](std::time::SystemTime::now).
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Generator;
+//! use nulid::coarse_clock::CoarseClock;
+//!
+//! # fn main() -> nulid::Result<()> {
+//! let generator = Generator::with_clock(CoarseClock);
+//! let ids: Vec<_> = (0..1_000).map(|_| generator.generate()).collect::<Result<_, _>>()?;
+//! assert!(ids.windows(2).all(|w| w[0] < w[1]));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::generator::Clock;
+use crate::{Error, Result};
+
+/// Wall clock with tick resolution, read without a hardware counter.
+///
+/// See the [module documentation](self) for the resolution per platform.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CoarseClock;
+
+impl Clock for CoarseClock {
+    fn now_nanos(&self) -> Result<u128> {
+        now_nanos_coarse()
+    }
+}
+
+/// Returns the coarse wall-clock time as nanoseconds since Unix epoch.
+///
+/// # Errors
+///
+/// Returns `SystemTimeError` if the clock cannot be read or is before Unix
+/// epoch.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::coarse_clock::now_nanos_coarse;
+///
+/// # fn main() -> nulid::Result<()> {
+/// let coarse = now_nanos_coarse()?;
+/// let precise = nulid::time::now_nanos()?;
+/// assert!(coarse.abs_diff(precise) < 1_000_000_000);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn now_nanos_coarse() -> Result<u128> {
+    let mut ts = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `ts` is a valid, writable `timespec` for the duration of the call.
+    let rc = unsafe { libc::clock_gettime(libc::CLOCK_REALTIME_COARSE, &raw mut ts) };
+    if rc != 0 {
+        return Err(Error::SystemTimeError);
+    }
+
+    let secs = u64::try_from(ts.tv_sec).map_err(|_| Error::SystemTimeError)?;
+    let nanos = u64::try_from(ts.tv_nsec).map_err(|_| Error::SystemTimeError)?;
+    Ok(u128::from(secs) * 1_000_000_000 + u128::from(nanos))
+}
+
+/// Returns the coarse wall-clock time as nanoseconds since Unix epoch.
+///
+/// # Errors
+///
+/// Returns `SystemTimeError` if the clock is before Unix epoch.
+#[cfg(windows)]
+pub fn now_nanos_coarse() -> Result<u128> {
+    use windows_sys::Win32::Foundation::FILETIME;
+    use windows_sys::Win32::System::SystemInformation::GetSystemTimeAsFileTime;
+
+    /// 100 ns intervals between 1601-01-01 and the Unix epoch.
+    const UNIX_EPOCH_INTERVALS: u64 = 116_444_736_000_000_000;
+
+    let mut ft = FILETIME {
+        dwLowDateTime: 0,
+        dwHighDateTime: 0,
+    };
+    // SAFETY: `ft` is a valid, writable `FILETIME` for the duration of the call.
+    unsafe { GetSystemTimeAsFileTime(&raw mut ft) };
+
+    let intervals = (u64::from(ft.dwHighDateTime) << 32) | u64::from(ft.dwLowDateTime);
+    let since_unix = intervals
+        .checked_sub(UNIX_EPOCH_INTERVALS)
+        .ok_or(Error::SystemTimeError)?;
+    Ok(u128::from(since_unix) * 100)
+}
+
+/// Returns the coarse wall-clock time as nanoseconds since Unix epoch.
+///
+/// No coarse clock is available on this platform, so this reads
+/// [`SystemTime::now`](std::time::SystemTime::now).
+///
+/// # Errors
+///
+/// Returns `SystemTimeError` if the clock is before Unix epoch.
+#[cfg(not(any(target_os = "linux", target_os = "android", windows)))]
+pub fn now_nanos_coarse() -> Result<u128> {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .map_err(|_| Error::SystemTimeError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Generator;
+
+    #[test]
+    fn test_coarse_clock_tracks_wall_clock() {
+        let coarse = CoarseClock.now_nanos().unwrap();
+        let wall = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        // Behind by at most a few ticks, never ahead
+        assert!(coarse <= wall);
+        assert!(wall - coarse < 100_000_000, "{}", wall - coarse);
+    }
+
+    #[test]
+    fn test_generator_with_coarse_clock() {
+        let generator = Generator::with_clock(CoarseClock);
+        let ids: Vec<_> = (0..10_000).map(|_| generator.generate().unwrap()).collect();
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+
+        // Far more IDs than ticks, so most share a timestamp
+        let mut nanos: Vec<u128> = ids.iter().map(|id| id.nanos()).collect();
+        nanos.dedup();
+        assert!(nanos.len() < ids.len() / 2);
+    }
+}
@@ -0,0 +1,35 @@
+//! `defmt` logging support for NULID.
+//!
+//! This module implements `defmt::Format` for [`Nulid`], so firmware can log
+//! IDs over RTT or other `defmt` transports with `{}` or `{:?}`:
+//!
+//! ```ignore
+//! defmt::info!("accepted job {}", id);
+//! ```
+//!
+//! The ID is encoded to its 26-character Base32 form on a stack buffer and
+//! sent as a `{=str}` argument. The format string itself is interned, so
+//! each log line carries only the 26 bytes of the ID and no `core::fmt`
+//! machinery is linked.
+
+use crate::Nulid;
+
+impl defmt::Format for Nulid {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=str}", self.to_arraystring().as_str());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn assert_format<T: defmt::Format>() {}
+
+    #[test]
+    fn test_nulid_implements_format() {
+        assert_format::<Nulid>();
+        assert_format::<Option<Nulid>>();
+        assert_format::<[Nulid; 2]>();
+    }
+}
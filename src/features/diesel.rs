@@ -0,0 +1,192 @@
+//! Diesel support for NULID.
+//!
+//! This module implements Diesel's `ToSql` and `FromSql` for NULID, one
+//! backend per feature so only the backends you use are compiled:
+//!
+//! - `diesel-postgres`: `UUID` columns (16 bytes), plus `TEXT` columns holding the Base32 form
+//! - `diesel-sqlite`: `BLOB` columns (`Binary`, 16 bytes) and `TEXT` columns (Base32 form)
+//!
+//! `Nulid` also derives `AsExpression` and `FromSqlRow` for these SQL types,
+//! so it can be used directly in Diesel schemas and queries. The `diesel`
+//! feature on its own enables no backend.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "diesel-sqlite")]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! use diesel::prelude::*;
+//! use diesel::sql_types::Binary;
+//! use diesel::sqlite::SqliteConnection;
+//! use nulid::Nulid;
+//!
+//! let mut conn = SqliteConnection::establish(":memory:")?;
+//! let id = Nulid::new()?;
+//! let read: Nulid = diesel::select(id.into_sql::<Binary>()).get_result(&mut conn)?;
+//! assert_eq!(read, id);
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "diesel-sqlite"))]
+//! # fn main() {}
+//! ```
+
+use crate::Nulid;
+#[cfg(feature = "diesel-sqlite")]
+use core::str::FromStr;
+use diesel::deserialize::{self, FromSql};
+#[cfg(feature = "diesel-postgres")]
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{self, IsNull, Output, ToSql};
+#[cfg(feature = "diesel-sqlite")]
+use diesel::sql_types::Binary;
+use diesel::sql_types::Text;
+#[cfg(feature = "diesel-postgres")]
+use diesel::sql_types::Uuid;
+#[cfg(feature = "diesel-sqlite")]
+use diesel::sqlite::{Sqlite, SqliteValue};
+#[cfg(feature = "diesel-postgres")]
+use std::io::Write;
+
+#[cfg(feature = "diesel-postgres")]
+impl ToSql<Uuid, Pg> for Nulid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(&self.to_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "diesel-postgres")]
+impl FromSql<Uuid, Pg> for Nulid {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        Ok(Self::try_from(value.as_bytes())?)
+    }
+}
+
+#[cfg(feature = "diesel-postgres")]
+impl ToSql<Text, Pg> for Nulid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_arraystring().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "diesel-postgres")]
+impl FromSql<Text, Pg> for Nulid {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        Ok(Self::from_ascii_slice(value.as_bytes())?)
+    }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+impl ToSql<Binary, Sqlite> for Nulid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.to_bytes().to_vec());
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+impl FromSql<Binary, Sqlite> for Nulid {
+    fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        Ok(Self::try_from(value.read_blob())?)
+    }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+impl ToSql<Text, Sqlite> for Nulid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.to_string());
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+impl FromSql<Text, Sqlite> for Nulid {
+    fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        Ok(Self::from_str(value.read_text())?)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "diesel-sqlite")]
+mod tests {
+    use super::*;
+    use diesel::prelude::*;
+    use diesel::sqlite::SqliteConnection;
+
+    diesel::table! {
+        events (id) {
+            id -> Binary,
+            label -> Text,
+        }
+    }
+
+    fn connection() -> SqliteConnection {
+        SqliteConnection::establish(":memory:").expect("Failed to open SQLite")
+    }
+
+    #[test]
+    fn test_sqlite_binary_round_trip() {
+        let mut conn = connection();
+        let id = Nulid::new().expect("Failed to create NULID");
+        let read: Nulid = diesel::select(id.into_sql::<Binary>())
+            .get_result(&mut conn)
+            .expect("Query failed");
+        assert_eq!(read, id);
+    }
+
+    #[test]
+    fn test_sqlite_text_round_trip() {
+        let mut conn = connection();
+        let id = Nulid::new().expect("Failed to create NULID");
+        let read: Nulid = diesel::select(id.into_sql::<Text>())
+            .get_result(&mut conn)
+            .expect("Query failed");
+        assert_eq!(read, id);
+
+        let raw: String = diesel::select(id.into_sql::<Text>())
+            .get_result(&mut conn)
+            .expect("Query failed");
+        assert_eq!(raw, id.to_string());
+    }
+
+    #[test]
+    fn test_sqlite_table_insert_and_filter() {
+        let mut conn = connection();
+        diesel::sql_query("CREATE TABLE events (id BLOB PRIMARY KEY, label TEXT NOT NULL)")
+            .execute(&mut conn)
+            .expect("Failed to create table");
+
+        let ids: Vec<Nulid> = (0..3)
+            .map(|_| Nulid::new().expect("Failed to create NULID"))
+            .collect();
+        for (i, id) in ids.iter().enumerate() {
+            diesel::insert_into(events::table)
+                .values((events::id.eq(id), events::label.eq(format!("event {i}"))))
+                .execute(&mut conn)
+                .expect("Insert failed");
+        }
+
+        let label: String = events::table
+            .filter(events::id.eq(ids[1]))
+            .select(events::label)
+            .first(&mut conn)
+            .expect("Query failed");
+        assert_eq!(label, "event 1");
+
+        let all: Vec<Nulid> = events::table
+            .select(events::id)
+            .order(events::id)
+            .load(&mut conn)
+            .expect("Query failed");
+        assert_eq!(all, ids);
+    }
+
+    #[test]
+    fn test_sqlite_invalid_blob() {
+        let mut conn = connection();
+        let result: QueryResult<Nulid> =
+            diesel::select(vec![1u8, 2, 3].into_sql::<Binary>()).get_result(&mut conn);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,184 @@
+//! `fake` dummy data support for NULID.
+//!
+//! This module implements `Dummy` for [`Nulid`], so fixture builders using
+//! `fake` can fill ID fields automatically:
+//!
+//! - `Faker`: a NULID with a random timestamp between the Unix epoch and
+//!   `i64::MAX` nanoseconds (about year 2262), so the value still converts
+//!   to `chrono`, `jiff`, and other `i64`-based time types
+//! - [`Between`]: a NULID with a timestamp in a configured range, e.g.
+//!   [`Between::recent`] for IDs from the last hour
+//!
+//! Both fill the 60-bit random part at random. Types deriving `Id` get the
+//! same implementations when the `derive` feature is also enabled.
+//!
+//! # Examples
+//!
+//! ```
+//! use core::time::Duration;
+//! use fake::{Fake, Faker};
+//! use nulid::Nulid;
+//! use nulid::features::fake::Between;
+//!
+//! # fn main() -> nulid::Result<()> {
+//! let id: Nulid = Faker.fake();
+//! assert!(id.nanos() <= i64::MAX as u128);
+//!
+//! let window = Between::new(1_000, 2_000);
+//! let id: Nulid = window.fake();
+//! assert!((1_000..=2_000).contains(&id.nanos()));
+//!
+//! let recent: Nulid = Between::recent(Duration::from_secs(3600))?.fake();
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::generator::{Clock, SystemClock};
+use crate::{Error, Nulid, Result};
+use core::time::Duration;
+use fake::{Dummy, Faker};
+use rand::Rng;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Upper timestamp bound used for `Faker`, in nanoseconds since Unix epoch.
+const FAKER_MAX_NANOS: u128 = i64::MAX as u128;
+
+impl Dummy<Faker> for Nulid {
+    fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+        Self::from_nanos(rng.random_range(0..=FAKER_MAX_NANOS), rng.random())
+    }
+}
+
+/// Dummy configuration for NULIDs with a timestamp in an inclusive range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Between {
+    start: u128,
+    end: u128,
+}
+
+impl Between {
+    /// Creates a range from two timestamps in nanoseconds since Unix epoch.
+    ///
+    /// The bounds are swapped if `start` is after `end`.
+    #[must_use]
+    pub const fn new(start: u128, end: u128) -> Self {
+        if start <= end {
+            Self { start, end }
+        } else {
+            Self {
+                start: end,
+                end: start,
+            }
+        }
+    }
+
+    /// Creates a range from two system times.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SystemTimeError` if either time is before Unix epoch.
+    pub fn from_system_times(start: SystemTime, end: SystemTime) -> Result<Self> {
+        let nanos = |time: SystemTime| {
+            time.duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_nanos())
+                .map_err(|_| Error::SystemTimeError)
+        };
+        Ok(Self::new(nanos(start)?, nanos(end)?))
+    }
+
+    /// Creates a range covering the last `window` up to the current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SystemTimeError` if the system time is before Unix epoch.
+    pub fn recent(window: Duration) -> Result<Self> {
+        let now = SystemClock.now_nanos()?;
+        Ok(Self::new(now.saturating_sub(window.as_nanos()), now))
+    }
+
+    /// Returns the lower bound, in nanoseconds since Unix epoch.
+    #[must_use]
+    pub const fn start(self) -> u128 {
+        self.start
+    }
+
+    /// Returns the upper bound, in nanoseconds since Unix epoch.
+    #[must_use]
+    pub const fn end(self) -> u128 {
+        self.end
+    }
+}
+
+impl Dummy<Between> for Nulid {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &Between, rng: &mut R) -> Self {
+        Self::from_nanos(rng.random_range(config.start..=config.end), rng.random())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fake::Fake;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_faker_in_i64_range() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..1000 {
+            let id: Nulid = Faker.fake_with_rng(&mut rng);
+            assert!(id.nanos() <= FAKER_MAX_NANOS);
+        }
+    }
+
+    #[test]
+    fn test_faker_deterministic_with_seed() {
+        let a: Nulid = Faker.fake_with_rng(&mut StdRng::seed_from_u64(42));
+        let b: Nulid = Faker.fake_with_rng(&mut StdRng::seed_from_u64(42));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_between_bounds() {
+        let config = Between::new(2_000, 1_000);
+        assert_eq!((config.start(), config.end()), (1_000, 2_000));
+
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..1000 {
+            let id: Nulid = config.fake_with_rng(&mut rng);
+            assert!((1_000..=2_000).contains(&id.nanos()));
+        }
+
+        let id: Nulid = Between::new(5, 5).fake();
+        assert_eq!(id.nanos(), 5);
+    }
+
+    #[test]
+    fn test_between_system_times() {
+        let start = UNIX_EPOCH + Duration::from_secs(1);
+        let end = UNIX_EPOCH + Duration::from_secs(2);
+        let config = Between::from_system_times(start, end).unwrap();
+        assert_eq!(config, Between::new(1_000_000_000, 2_000_000_000));
+
+        let before_epoch = UNIX_EPOCH - Duration::from_secs(1);
+        assert_eq!(
+            Between::from_system_times(before_epoch, end),
+            Err(Error::SystemTimeError)
+        );
+    }
+
+    #[test]
+    fn test_between_recent() {
+        let config = Between::recent(Duration::from_secs(60)).unwrap();
+        assert_eq!(config.end() - config.start(), 60_000_000_000);
+
+        let id: Nulid = config.fake();
+        assert!(id.nanos() >= config.start() && id.nanos() <= config.end());
+    }
+
+    #[test]
+    fn test_collections() {
+        let ids: Vec<Nulid> = (Faker, 3..5).fake();
+        assert!((3..5).contains(&ids.len()));
+    }
+}
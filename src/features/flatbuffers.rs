@@ -0,0 +1,132 @@
+//! `FlatBuffers` support for NULID.
+//!
+//! Helpers for the two common ways of storing a NULID in a `FlatBuffers`
+//! table:
+//!
+//! - A 16-byte `[ubyte]` vector holding the big-endian bytes, written with
+//!   [`create_bytes_vector`] and read with [`from_bytes_vector`]
+//! - Two `ulong` fields holding the high and low halves, written with
+//!   [`push_u64_pair`] and read with [`Nulid::from_u64_pair`]
+//!
+//! ```text
+//! table Event {
+//!   id: [ubyte];     // 16-byte blob
+//!   parent_hi: ulong; // or two u64 fields
+//!   parent_lo: ulong;
+//! }
+//! ```
+//!
+//! # Examples
+//!
+//! ```
+//! use flatbuffers::FlatBufferBuilder;
+//! use nulid::Nulid;
+//! use nulid::features::flatbuffers::{create_bytes_vector, from_bytes_vector};
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let id = Nulid::new()?;
+//!
+//! let mut builder = FlatBufferBuilder::new();
+//! let bytes = create_bytes_vector(&mut builder, id);
+//! builder.finish_minimal(bytes);
+//!
+//! let vector = flatbuffers::root::<flatbuffers::Vector<u8>>(builder.finished_data())?;
+//! assert_eq!(from_bytes_vector(vector)?, id);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{Nulid, Result};
+use flatbuffers::{Allocator, FlatBufferBuilder, VOffsetT, Vector, WIPOffset};
+
+/// Writes a NULID as a 16-byte `[ubyte]` vector.
+pub fn create_bytes_vector<'fbb, A: Allocator>(
+    builder: &mut FlatBufferBuilder<'fbb, A>,
+    id: Nulid,
+) -> WIPOffset<Vector<'fbb, u8>> {
+    builder.create_vector(&id.to_bytes())
+}
+
+/// Reads a NULID from a 16-byte `[ubyte]` vector.
+///
+/// # Errors
+///
+/// Returns `InvalidLength` if the vector is not exactly 16 bytes.
+pub fn from_bytes_vector(bytes: Vector<'_, u8>) -> Result<Nulid> {
+    Nulid::try_from(bytes.bytes())
+}
+
+/// Writes a NULID into two `ulong` slots of the table being built.
+///
+/// `high_slot` and `low_slot` are the vtable offsets of the fields, as
+/// exposed by the generated code (e.g. `Event::VT_PARENT_HI`).
+pub fn push_u64_pair<A: Allocator>(
+    builder: &mut FlatBufferBuilder<'_, A>,
+    high_slot: VOffsetT,
+    low_slot: VOffsetT,
+    id: Nulid,
+) {
+    let (high, low) = id.to_u64_pair();
+    builder.push_slot_always(high_slot, high);
+    builder.push_slot_always(low_slot, low);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use flatbuffers::Table;
+
+    const VT_HIGH: VOffsetT = 4;
+    const VT_LOW: VOffsetT = 6;
+
+    #[test]
+    fn test_bytes_vector_round_trip() {
+        let id = Nulid::new().unwrap();
+        let mut builder = FlatBufferBuilder::new();
+        let bytes = create_bytes_vector(&mut builder, id);
+        builder.finish_minimal(bytes);
+
+        let vector = flatbuffers::root::<Vector<u8>>(builder.finished_data()).unwrap();
+        assert_eq!(vector.bytes(), id.to_bytes());
+        assert_eq!(from_bytes_vector(vector).unwrap(), id);
+    }
+
+    #[test]
+    fn test_bytes_vector_wrong_length() {
+        let mut builder = FlatBufferBuilder::new();
+        let bytes = builder.create_vector(&[1u8, 2, 3]);
+        builder.finish_minimal(bytes);
+
+        let vector = flatbuffers::root::<Vector<u8>>(builder.finished_data()).unwrap();
+        assert_eq!(
+            from_bytes_vector(vector),
+            Err(Error::InvalidLength {
+                expected: 16,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_u64_pair_round_trip() {
+        let id = Nulid::new().unwrap();
+        let mut builder = FlatBufferBuilder::new();
+        let start = builder.start_table();
+        push_u64_pair(&mut builder, VT_HIGH, VT_LOW, id);
+        let table = builder.end_table(start);
+        builder.finish_minimal(table);
+
+        let data = builder.finished_data();
+        // SAFETY: the buffer was just built above with a table at its root
+        // holding `ulong` fields at both slots.
+        let (high, low) = unsafe {
+            let table = flatbuffers::root_unchecked::<Table>(data);
+            (
+                table.get::<u64>(VT_HIGH, None).unwrap(),
+                table.get::<u64>(VT_LOW, None).unwrap(),
+            )
+        };
+        assert_eq!(Nulid::from_u64_pair(high, low), id);
+    }
+}
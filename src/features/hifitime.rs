@@ -0,0 +1,236 @@
+//! Hifitime integration for NULID.
+//!
+//! This module provides conversion between NULID and `hifitime::Epoch`, and
+//! a [`HifitimeClock`] for the [`Generator`](crate::Generator).
+//!
+//! NULID timestamps count Unix nanoseconds, which skip leap seconds. The
+//! conversions go through hifitime's UTC time scale, so an `Epoch` built
+//! from a NULID is correct in TAI, GPST and the other scales hifitime
+//! supports, and durations between epochs include the leap seconds that
+//! Unix time leaves out.
+
+use hifitime::{Duration, Epoch, TimeScale, UNIX_REF_EPOCH};
+use rand::Rng;
+
+use crate::generator::Clock;
+use crate::{Error, Nulid, Result};
+
+impl Nulid {
+    /// Converts this NULID to a `hifitime::Epoch`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hifitime::{Epoch, TimeScale};
+    /// use nulid::Nulid;
+    ///
+    /// // 2017-01-01T00:00:00Z, just after the last leap second
+    /// let id = Nulid::from_nanos(1_483_228_800_000_000_000, 0);
+    /// let epoch = id.to_hifitime_epoch();
+    /// assert_eq!(epoch, Epoch::from_gregorian_utc_at_midnight(2017, 1, 1));
+    ///
+    /// // Half a second earlier in Unix time is 1.5 seconds earlier in TAI
+    /// let before = Nulid::from_nanos(1_483_228_799_500_000_000, 0).to_hifitime_epoch();
+    /// assert_eq!((epoch - before).to_seconds(), 1.5);
+    /// ```
+    #[must_use]
+    pub fn to_hifitime_epoch(self) -> Epoch {
+        Epoch::from_unix_duration(Duration::from_total_nanoseconds(self.nanos().cast_signed()))
+    }
+
+    /// Creates a NULID from a `hifitime::Epoch` with random bits.
+    ///
+    /// The epoch is read in UTC, so the timestamp is the Unix time of the
+    /// epoch regardless of the time scale it was created in. An epoch inside
+    /// a leap second maps to the second after it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::SystemTimeError` if the epoch is before the Unix
+    /// epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hifitime::Epoch;
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let epoch = Epoch::from_gregorian_utc_at_midnight(2024, 1, 1);
+    /// let id = Nulid::from_hifitime_epoch(epoch)?;
+    /// assert_eq!(id.nanos(), 1_704_067_200_000_000_000);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_hifitime_epoch(epoch: Epoch) -> Result<Self> {
+        let timestamp_nanos = unix_nanos(epoch, TimeScale::UTC)?;
+
+        let mut rng = rand::rng();
+        let random = rng.random::<u64>() & ((1u64 << Self::RANDOM_BITS) - 1);
+
+        Ok(Self::from_nanos(timestamp_nanos, random))
+    }
+}
+
+impl TryFrom<Epoch> for Nulid {
+    type Error = Error;
+
+    /// Creates a NULID from a `hifitime::Epoch`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hifitime::Epoch;
+    /// use nulid::Nulid;
+    ///
+    /// let epoch = Epoch::from_gregorian_utc_at_midnight(2024, 1, 1);
+    /// let nulid: Nulid = epoch.try_into()?;
+    /// # Ok::<_, nulid::Error>(())
+    /// ```
+    fn try_from(epoch: Epoch) -> core::result::Result<Self, Self::Error> {
+        Self::from_hifitime_epoch(epoch)
+    }
+}
+
+impl From<Nulid> for Epoch {
+    /// Converts a NULID to a `hifitime::Epoch`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hifitime::Epoch;
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let nulid = Nulid::new()?;
+    /// let epoch: Epoch = nulid.into();
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from(nulid: Nulid) -> Self {
+        nulid.to_hifitime_epoch()
+    }
+}
+
+/// Clock reading the current time through hifitime.
+///
+/// In the default UTC time scale this reports Unix nanoseconds like
+/// [`SystemClock`](crate::SystemClock). With [`TimeScale::TAI`] (or GPST,
+/// which differs from TAI by a constant) the reading counts every elapsed
+/// SI second since 1970-01-01T00:00:00 UTC, leap seconds included, so IDs
+/// never repeat or jump backwards across a leap second. TAI readings run
+/// ahead of Unix time by the leap seconds inserted so far; convert such IDs
+/// back with the same time scale rather than [`Nulid::to_hifitime_epoch`].
+///
+/// # Examples
+///
+/// ```
+/// use hifitime::TimeScale;
+/// use nulid::Generator;
+/// use nulid::features::hifitime::HifitimeClock;
+///
+/// # fn main() -> nulid::Result<()> {
+/// let generator = Generator::with_clock(HifitimeClock::new(TimeScale::TAI));
+/// let id = generator.generate()?;
+/// assert!(id.nanos() > 0);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HifitimeClock {
+    time_scale: TimeScale,
+}
+
+impl HifitimeClock {
+    /// Creates a clock reporting nanoseconds since the Unix epoch in `time_scale`.
+    #[must_use]
+    pub const fn new(time_scale: TimeScale) -> Self {
+        Self { time_scale }
+    }
+
+    /// Returns the time scale this clock reports in.
+    #[must_use]
+    pub const fn time_scale(&self) -> TimeScale {
+        self.time_scale
+    }
+}
+
+impl Default for HifitimeClock {
+    /// Creates a clock in the UTC time scale.
+    fn default() -> Self {
+        Self::new(TimeScale::UTC)
+    }
+}
+
+impl Clock for HifitimeClock {
+    fn now_nanos(&self) -> Result<u128> {
+        let now = Epoch::now().map_err(|_| Error::SystemTimeError)?;
+        unix_nanos(now, self.time_scale)
+    }
+}
+
+/// Returns the nanoseconds from the Unix epoch to `epoch`, measured in `time_scale`.
+fn unix_nanos(epoch: Epoch, time_scale: TimeScale) -> Result<u128> {
+    let elapsed = epoch.to_duration_in_time_scale(time_scale)
+        - UNIX_REF_EPOCH.to_duration_in_time_scale(time_scale);
+    u128::try_from(elapsed.total_nanoseconds()).map_err(|_| Error::SystemTimeError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hifitime_epoch() {
+        let nulid = Nulid::from_nanos(1_704_067_200_123_456_789, 12345);
+        let epoch = nulid.to_hifitime_epoch();
+        assert_eq!(
+            epoch,
+            Epoch::from_gregorian_utc(2024, 1, 1, 0, 0, 0, 123_456_789)
+        );
+    }
+
+    #[test]
+    fn test_hifitime_epoch_unix_epoch() {
+        let epoch = Nulid::from_nanos(0, 0).to_hifitime_epoch();
+        assert_eq!(epoch, UNIX_REF_EPOCH);
+    }
+
+    #[test]
+    fn test_hifitime_roundtrip() {
+        let original = Nulid::new().expect("Failed to create NULID");
+        let roundtrip =
+            Nulid::from_hifitime_epoch(original.to_hifitime_epoch()).expect("after 1970");
+        assert_eq!(original.nanos(), roundtrip.nanos());
+    }
+
+    #[test]
+    fn test_hifitime_across_leap_second() {
+        // 2016-12-31T23:59:60Z was a leap second.
+        let before = Nulid::from_nanos(1_483_228_799_000_000_000, 0).to_hifitime_epoch();
+        let after = Nulid::from_nanos(1_483_228_800_000_000_000, 0).to_hifitime_epoch();
+        assert_eq!((after - before).total_nanoseconds(), 2_000_000_000);
+    }
+
+    #[test]
+    fn test_from_hifitime_epoch_before_unix_epoch() {
+        let epoch = Epoch::from_gregorian_utc_at_midnight(1969, 12, 31);
+        assert_eq!(
+            Nulid::from_hifitime_epoch(epoch),
+            Err(Error::SystemTimeError)
+        );
+    }
+
+    #[test]
+    fn test_hifitime_clock_time_scales() {
+        let utc = HifitimeClock::default().now_nanos().unwrap();
+        let tai = HifitimeClock::new(TimeScale::TAI).now_nanos().unwrap();
+        let system = crate::time::now_nanos().unwrap();
+
+        // Within a second of the system clock in UTC.
+        assert!(utc.abs_diff(system) < 1_000_000_000);
+        // TAI is ahead by the leap seconds since 1972 (27 at the time of writing).
+        let leap = (tai - utc) / 1_000_000_000;
+        assert!((27..40).contains(&leap), "{leap}");
+    }
+}
@@ -0,0 +1,83 @@
+//! GraphQL scalar support for NULID via `juniper`.
+//!
+//! This module exposes `Nulid` as a custom `Nulid` GraphQL scalar, transferred
+//! as its 26-character Base32 string, so juniper schemas can accept and return
+//! NULIDs directly.
+//!
+//! # Examples
+//!
+//! ```
+//! use juniper::{DefaultScalarValue, FromInputValue, InputValue, ToInputValue};
+//! use nulid::Nulid;
+//!
+//! # fn main() -> nulid::Result<()> {
+//! let id = Nulid::new()?;
+//! let input: InputValue<DefaultScalarValue> = id.to_input_value();
+//! assert_eq!(Nulid::from_input_value(&input), Ok(id));
+//! # Ok(())
+//! # }
+//! ```
+
+use juniper::{InputValue, ScalarValue, Value, graphql_scalar};
+
+/// NULID: 26-character Crockford Base32, nanosecond-precision sortable identifier
+#[graphql_scalar(with = nulid_scalar, parse_token(String))]
+type Nulid = crate::Nulid;
+
+mod nulid_scalar {
+    use super::{InputValue, Nulid, ScalarValue, Value};
+
+    pub(super) fn to_output<S: ScalarValue>(v: &Nulid) -> Value<S> {
+        Value::scalar(v.to_string())
+    }
+
+    pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Nulid, String> {
+        v.as_string_value()
+            .ok_or_else(|| format!("Expected `String`, found: {v}"))
+            .and_then(|s| {
+                s.parse()
+                    .map_err(|e| format!("Failed to parse `Nulid`: {e}"))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use juniper::parser::ScalarToken;
+    use juniper::{DefaultScalarValue, FromInputValue, ParseScalarValue, ToInputValue};
+
+    #[test]
+    fn test_juniper_input_round_trip() {
+        let id = Nulid::new().expect("Failed to create NULID");
+        let input: InputValue<DefaultScalarValue> = id.to_input_value();
+        assert_eq!(input.as_string_value(), Some(id.to_string().as_str()));
+        assert_eq!(Nulid::from_input_value(&input), Ok(id));
+    }
+
+    #[test]
+    fn test_juniper_invalid_input() {
+        let input: InputValue<DefaultScalarValue> = InputValue::scalar("not-a-nulid".to_string());
+        assert!(Nulid::from_input_value(&input).is_err());
+
+        let input: InputValue<DefaultScalarValue> = InputValue::scalar(42);
+        assert!(Nulid::from_input_value(&input).is_err());
+    }
+
+    #[test]
+    fn test_juniper_parse_scalar() {
+        let token = ScalarToken::String("01GZTV7EQ056J0E6N276XD6F3D");
+        let parsed: DefaultScalarValue =
+            <Nulid as ParseScalarValue<DefaultScalarValue>>::from_str(token)
+                .expect("Failed to parse scalar");
+        assert_eq!(
+            parsed,
+            DefaultScalarValue::String("01GZTV7EQ056J0E6N276XD6F3D".to_string())
+        );
+
+        assert!(
+            <Nulid as ParseScalarValue<DefaultScalarValue>>::from_str(ScalarToken::Int("1"))
+                .is_err()
+        );
+    }
+}
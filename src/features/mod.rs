@@ -8,6 +8,33 @@
 //! - `rkyv`: Zero-copy serialization support
 //! - `chrono`: `chrono::DateTime<Utc>` support
 //! - `jiff`: `jiff::Timestamp` support
+//! - `utoipa`: `OpenAPI` schema support
+//! - `juniper`: GraphQL scalar support
+//! - `schemars`: JSON Schema support
+//! - `async-graphql`: GraphQL scalar support for async-graphql
+//! - `diesel-postgres`, `diesel-sqlite`: `PostgreSQL` and `SQLite` support via Diesel
+//! - `sea-orm`: `SeaORM` entity field and primary key support
+//! - `clickhouse`: `ClickHouse` `RowBinary` serde adapters
+//! - `arrow`: Apache Arrow `FixedSizeBinary(16)` conversions
+//! - `avro`: `apache-avro` schema and value support, plus serde adapters
+//! - `capnp`: Cap'n Proto read/write helpers
+//! - `flatbuffers`: `FlatBuffers` read/write helpers
+//! - `prost`: Protocol Buffers message and field codec
+//! - `axum`: Path extraction and responses for axum handlers
+//! - `actix-web`: Path extraction, responses, and 400 error mapping for actix-web
+//! - `tracing`: Allocation-free field recording and span ID derivation
+//! - `rand`: `Distribution<Nulid>` implementations
+//! - `proptest`: `Arbitrary` implementation and shrinking strategies
+//! - `quickcheck`: `Arbitrary` implementation with part-wise shrinking
+//! - `fake`: `Dummy` implementations for fixture data
+//! - `speedy`: Fixed 16-byte `Readable`/`Writable` implementations
+//! - `bitcode`: `Encode`/`Decode` implementations
+//! - `ulid`: Bit-preserving and time-preserving `ulid::Ulid` conversions
+//! - `hifitime`: `hifitime::Epoch` conversions and a leap-second-aware clock
+//! - `wasm`: Browser clock and entropy, and `wasm-bindgen` exports
+//! - `defmt`: `defmt::Format` implementation for embedded logging
+//! - `zeroize`: `Zeroize` for `Nulid` and `NulidString`
+//! - `coarse-clock`: Tick-resolution OS clock for bulk generation
 
 #[cfg(feature = "uuid")]
 pub mod uuid;
@@ -29,3 +56,84 @@ pub mod chrono;
 
 #[cfg(feature = "jiff")]
 pub mod jiff;
+
+#[cfg(feature = "utoipa")]
+pub mod utoipa;
+
+#[cfg(feature = "juniper")]
+pub mod juniper;
+
+#[cfg(feature = "schemars")]
+pub mod schemars;
+
+#[cfg(feature = "async-graphql")]
+pub mod async_graphql;
+
+#[cfg(any(feature = "diesel-postgres", feature = "diesel-sqlite"))]
+pub mod diesel;
+
+#[cfg(feature = "sea-orm")]
+pub mod sea_orm;
+
+#[cfg(feature = "clickhouse")]
+pub mod clickhouse;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+#[cfg(feature = "avro")]
+pub mod avro;
+
+#[cfg(feature = "capnp")]
+pub mod capnp;
+
+#[cfg(feature = "flatbuffers")]
+pub mod flatbuffers;
+
+#[cfg(feature = "prost")]
+pub mod prost;
+
+#[cfg(feature = "axum")]
+pub mod axum;
+
+#[cfg(feature = "actix-web")]
+pub mod actix_web;
+
+#[cfg(feature = "tracing")]
+pub mod tracing;
+
+#[cfg(feature = "rand")]
+pub mod rand;
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+
+#[cfg(feature = "fake")]
+pub mod fake;
+
+#[cfg(feature = "speedy")]
+pub mod speedy;
+
+#[cfg(feature = "bitcode")]
+pub mod bitcode;
+
+#[cfg(feature = "ulid")]
+pub mod ulid;
+
+#[cfg(feature = "hifitime")]
+pub mod hifitime;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "defmt")]
+pub mod defmt;
+
+#[cfg(feature = "zeroize")]
+pub mod zeroize;
+
+#[cfg(feature = "coarse-clock")]
+pub mod coarse_clock;
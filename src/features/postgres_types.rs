@@ -2,13 +2,33 @@
 //!
 //! This module provides implementations for encoding and decoding NULIDs
 //! as `PostgreSQL` UUID types using the `postgres-types` crate.
+//!
+//! `TEXT`, `VARCHAR`, and `BPCHAR` columns holding the 26-character Base32
+//! form are accepted as well. Since `Nulid` implements `ToSql` and `FromSql`,
+//! `postgres-types` also maps `Vec<Nulid>` and `&[Nulid]` to `UUID[]` (or
+//! `TEXT[]`) arrays, so batch queries such as `WHERE id = ANY($1)` work
+//! without manual conversion.
 
 use crate::Nulid;
 use core::error::Error as StdError;
-use postgres_types::{FromSql, IsNull, ToSql, Type, accepts, to_sql_checked};
+use postgres_types::{FromSql, IsNull, ToSql, Type, to_sql_checked};
+
+/// Returns `true` for text types that hold the Base32 string form.
+fn is_text(ty: &Type) -> bool {
+    *ty == Type::TEXT || *ty == Type::VARCHAR || *ty == Type::BPCHAR
+}
+
+/// Returns `true` for every type a NULID can be mapped to.
+fn accepts(ty: &Type) -> bool {
+    *ty == Type::UUID || is_text(ty)
+}
 
 impl<'a> FromSql<'a> for Nulid {
-    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn StdError + Sync + Send>> {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn StdError + Sync + Send>> {
+        if is_text(ty) {
+            return Ok(Self::from_ascii_slice(raw)?);
+        }
+
         // PostgreSQL UUIDs are stored as 16 bytes in big-endian format
         if raw.len() != 16 {
             return Err("invalid UUID length".into());
@@ -19,22 +39,32 @@ impl<'a> FromSql<'a> for Nulid {
         Ok(Self::from_bytes(bytes))
     }
 
-    accepts!(UUID);
+    fn accepts(ty: &Type) -> bool {
+        accepts(ty)
+    }
 }
 
 impl ToSql for Nulid {
     fn to_sql(
         &self,
-        _ty: &Type,
+        ty: &Type,
         out: &mut bytes::BytesMut,
     ) -> Result<IsNull, Box<dyn StdError + Sync + Send>> {
+        if is_text(ty) {
+            out.extend_from_slice(self.to_arraystring().as_bytes());
+            return Ok(IsNull::No);
+        }
+
         // Convert NULID to bytes and write to buffer
         let bytes = self.to_bytes();
         out.extend_from_slice(&bytes);
         Ok(IsNull::No)
     }
 
-    accepts!(UUID);
+    fn accepts(ty: &Type) -> bool {
+        accepts(ty)
+    }
+
     to_sql_checked!();
 }
 
@@ -126,4 +156,55 @@ mod tests {
 
         assert_eq!(decoded.as_u128(), 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
     }
+
+    #[test]
+    fn test_text_roundtrip() {
+        let nulid = Nulid::new().expect("Failed to create NULID");
+        let mut buf = bytes::BytesMut::new();
+
+        nulid
+            .to_sql(&Type::TEXT, &mut buf)
+            .expect("Failed to serialize");
+        assert_eq!(&buf[..], nulid.to_string().as_bytes());
+
+        for ty in [Type::TEXT, Type::VARCHAR, Type::BPCHAR] {
+            let decoded = Nulid::from_sql(&ty, &buf).expect("Failed to deserialize");
+            assert_eq!(decoded, nulid);
+        }
+    }
+
+    #[test]
+    fn test_text_invalid() {
+        assert!(Nulid::from_sql(&Type::TEXT, b"not-a-nulid").is_err());
+        assert!(Nulid::from_sql(&Type::TEXT, b"").is_err());
+    }
+
+    #[test]
+    fn test_accepts() {
+        for ty in [Type::UUID, Type::TEXT, Type::VARCHAR, Type::BPCHAR] {
+            assert!(<Nulid as ToSql>::accepts(&ty));
+            assert!(<Nulid as FromSql>::accepts(&ty));
+        }
+        assert!(!<Nulid as ToSql>::accepts(&Type::INT8));
+        assert!(!<Nulid as FromSql>::accepts(&Type::BYTEA));
+    }
+
+    #[test]
+    fn test_array_roundtrip() {
+        let ids: Vec<Nulid> = (0..5)
+            .map(|_| Nulid::new().expect("Failed to create NULID"))
+            .collect();
+
+        for ty in [Type::UUID_ARRAY, Type::TEXT_ARRAY] {
+            assert!(<Vec<Nulid> as ToSql>::accepts(&ty));
+            assert!(<&[Nulid] as ToSql>::accepts(&ty));
+
+            let mut buf = bytes::BytesMut::new();
+            ids.as_slice()
+                .to_sql(&ty, &mut buf)
+                .expect("Failed to serialize");
+            let decoded = Vec::<Nulid>::from_sql(&ty, &buf).expect("Failed to deserialize");
+            assert_eq!(decoded, ids);
+        }
+    }
 }
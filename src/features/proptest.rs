@@ -0,0 +1,147 @@
+//! `proptest` strategy support for NULID.
+//!
+//! This module implements `Arbitrary` for [`Nulid`], so `any::<Nulid>()`
+//! works in property tests. Values are built from a timestamp and a random
+//! part, which proptest shrinks independently towards zero instead of
+//! treating the ID as an opaque `u128`. The default strategy also hits
+//! [`Nulid::nil`] and [`Nulid::max`] now and then.
+//!
+//! Narrower strategies are available as functions:
+//!
+//! - [`timestamp_range`]: NULIDs whose timestamp lies in a given range
+//! - [`any_timestamp`]: NULIDs across the full 68-bit timestamp range
+//! - [`nil`] and [`max`]: the edge values
+//! - [`same_timestamp`]: clusters of NULIDs sharing one timestamp
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use nulid::features::proptest::{same_timestamp, timestamp_range};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn roundtrips_through_string(id in any::<Nulid>()) {
+//!         prop_assert_eq!(id.to_string().parse::<Nulid>().unwrap(), id);
+//!     }
+//!
+//!     fn stays_in_range(id in timestamp_range(1_000..=2_000)) {
+//!         prop_assert!((1_000..=2_000).contains(&id.nanos()));
+//!     }
+//!
+//!     fn clusters_share_timestamp(ids in same_timestamp(2..8)) {
+//!         prop_assert!(ids.iter().all(|id| id.nanos() == ids[0].nanos()));
+//!     }
+//! }
+//! # roundtrips_through_string();
+//! # stays_in_range();
+//! # clusters_share_timestamp();
+//! ```
+
+use crate::Nulid;
+use core::ops::RangeInclusive;
+use proptest::arbitrary::Arbitrary;
+use proptest::collection::{SizeRange, vec};
+use proptest::prelude::{BoxedStrategy, Just, Strategy, prop_oneof};
+
+/// The largest timestamp a NULID can hold, in nanoseconds since Unix epoch.
+pub const MAX_TIMESTAMP: u128 = (1u128 << Nulid::TIMESTAMP_BITS) - 1;
+
+/// The largest value of the 60-bit random part.
+pub const MAX_RANDOM: u64 = (1u64 << Nulid::RANDOM_BITS) - 1;
+
+/// Strategy for NULIDs whose timestamp lies in `range`.
+///
+/// The random part covers its full 60 bits. Values above
+/// [`MAX_TIMESTAMP`] are masked to 68 bits.
+pub fn timestamp_range(range: RangeInclusive<u128>) -> impl Strategy<Value = Nulid> {
+    (range, 0..=MAX_RANDOM).prop_map(|(nanos, random)| Nulid::from_nanos(nanos, random))
+}
+
+/// Strategy for NULIDs across the full 68-bit timestamp range.
+pub fn any_timestamp() -> impl Strategy<Value = Nulid> {
+    timestamp_range(0..=MAX_TIMESTAMP)
+}
+
+/// Strategy that always yields [`Nulid::nil`].
+pub const fn nil() -> Just<Nulid> {
+    Just(Nulid::nil())
+}
+
+/// Strategy that always yields [`Nulid::max`].
+pub const fn max() -> Just<Nulid> {
+    Just(Nulid::max())
+}
+
+/// Strategy for clusters of NULIDs that share one timestamp.
+///
+/// Useful for exercising ordering and deduplication logic within a single
+/// nanosecond, where only the random part tells IDs apart. The cluster is
+/// not sorted and may contain duplicates.
+pub fn same_timestamp(size: impl Into<SizeRange>) -> impl Strategy<Value = Vec<Nulid>> {
+    (0..=MAX_TIMESTAMP, vec(0..=MAX_RANDOM, size)).prop_map(|(nanos, randoms)| {
+        randoms
+            .into_iter()
+            .map(|random| Nulid::from_nanos(nanos, random))
+            .collect()
+    })
+}
+
+impl Arbitrary for Nulid {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            1 => nil(),
+            1 => max(),
+            8 => any_timestamp(),
+        ]
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    proptest! {
+        #[test]
+        fn test_arbitrary_roundtrip(id in any::<Nulid>()) {
+            prop_assert_eq!(Nulid::from_nanos(id.nanos(), id.random()), id);
+        }
+
+        #[test]
+        fn test_timestamp_range_bounds(id in timestamp_range(10..=20)) {
+            prop_assert!((10..=20).contains(&id.nanos()));
+        }
+
+        #[test]
+        fn test_same_timestamp_cluster(ids in same_timestamp(1..16)) {
+            prop_assert!(!ids.is_empty());
+            prop_assert!(ids.iter().all(|id| id.nanos() == ids[0].nanos()));
+        }
+    }
+
+    #[test]
+    fn test_edge_strategies() {
+        let mut runner = TestRunner::deterministic();
+        let nil = nil().new_tree(&mut runner).unwrap().current();
+        let max = max().new_tree(&mut runner).unwrap().current();
+        assert_eq!(nil, Nulid::nil());
+        assert_eq!(max, Nulid::max());
+        assert_eq!(max.nanos(), MAX_TIMESTAMP);
+        assert_eq!(max.random(), MAX_RANDOM);
+    }
+
+    #[test]
+    fn test_shrinks_parts_towards_zero() {
+        let mut runner = TestRunner::deterministic();
+        let mut tree = any_timestamp().new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+        assert_eq!(tree.current(), Nulid::nil());
+    }
+}
@@ -0,0 +1,218 @@
+//! Protocol Buffers support for NULID via `prost`.
+//!
+//! This module provides:
+//!
+//! - [`ProtoNulid`]: the `nulid.v1.Nulid` message from the bundled
+//!   [`PROTO_FILE`], storing the ID as two `uint64` halves
+//! - [`bytes`]: a field codec for `bytes` fields holding the 16 big-endian
+//!   bytes, for hand-written `prost::Message` implementations
+//!
+//! Both work unchanged in `tonic` services, which use `prost` messages.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use nulid::features::prost::ProtoNulid;
+//! use prost::Message;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let id = Nulid::new()?;
+//! let encoded = ProtoNulid::from(id).encode_to_vec();
+//!
+//! let decoded = Nulid::from(ProtoNulid::decode(encoded.as_slice())?);
+//! assert_eq!(decoded, id);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::Nulid;
+
+/// Contents of `proto/nulid.proto`, defining the `nulid.v1.Nulid` message.
+///
+/// Write it out from a build script to import it from other `.proto` files.
+pub const PROTO_FILE: &str = include_str!("../../proto/nulid.proto");
+
+/// The `nulid.v1.Nulid` message: a NULID split into two 64-bit halves.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ProtoNulid {
+    /// Upper 64 bits.
+    #[prost(uint64, tag = "1")]
+    pub high: u64,
+    /// Lower 64 bits.
+    #[prost(uint64, tag = "2")]
+    pub low: u64,
+}
+
+impl ::prost::Name for ProtoNulid {
+    const NAME: &'static str = "Nulid";
+    const PACKAGE: &'static str = "nulid.v1";
+}
+
+impl From<Nulid> for ProtoNulid {
+    fn from(id: Nulid) -> Self {
+        let (high, low) = id.to_u64_pair();
+        Self { high, low }
+    }
+}
+
+impl From<ProtoNulid> for Nulid {
+    fn from(proto: ProtoNulid) -> Self {
+        Self::from_u64_pair(proto.high, proto.low)
+    }
+}
+
+/// Converts an optional message field into an optional NULID.
+///
+/// `prost` generates message-typed fields as `Option<ProtoNulid>`; orphan
+/// rules rule out a `From` impl between the two `Option` types.
+#[must_use]
+pub fn from_proto_option(proto: Option<ProtoNulid>) -> Option<Nulid> {
+    proto.map(Nulid::from)
+}
+
+/// Converts an optional NULID into an optional message field.
+#[must_use]
+pub fn to_proto_option(id: Option<Nulid>) -> Option<ProtoNulid> {
+    id.map(ProtoNulid::from)
+}
+
+/// Field codec storing a NULID in a `bytes` field as 16 big-endian bytes.
+///
+/// Mirrors the functions in `prost::encoding`, for use in hand-written
+/// `prost::Message` implementations.
+pub mod bytes {
+    use crate::Nulid;
+    use prost::DecodeError;
+    use prost::bytes::{Buf, BufMut};
+    use prost::encoding::{self, DecodeContext, WireType};
+
+    /// Encodes `id` as a length-delimited field with the given tag.
+    pub fn encode(tag: u32, id: &Nulid, buf: &mut impl BufMut) {
+        encoding::encode_key(tag, WireType::LengthDelimited, buf);
+        encoding::encode_varint(16, buf);
+        buf.put_slice(&id.to_bytes());
+    }
+
+    /// Merges a length-delimited field into `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on a wrong wire type, a truncated buffer, or a
+    /// payload that is not exactly 16 bytes.
+    pub fn merge(
+        wire_type: WireType,
+        id: &mut Nulid,
+        buf: &mut impl Buf,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError> {
+        let mut bytes = Vec::with_capacity(16);
+        encoding::bytes::merge(wire_type, &mut bytes, buf, ctx)?;
+        #[allow(deprecated)]
+        let invalid = |err: crate::Error| DecodeError::new(err.to_string());
+        *id = Nulid::try_from(bytes.as_slice()).map_err(invalid)?;
+        Ok(())
+    }
+
+    /// Returns the encoded length of `id` as a field with the given tag.
+    #[must_use]
+    pub const fn encoded_len(tag: u32, _id: &Nulid) -> usize {
+        encoding::key_len(tag) + encoding::encoded_len_varint(16) + 16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::prost::Message;
+    use ::prost::bytes::{Buf, BufMut};
+    use ::prost::encoding::{DecodeContext, WireType};
+
+    /// Hand-written message using the `bytes` codec for its ID field.
+    #[derive(Debug, Default, PartialEq)]
+    struct Event {
+        id: Nulid,
+    }
+
+    impl Message for Event {
+        fn encode_raw(&self, buf: &mut impl BufMut) {
+            bytes::encode(1, &self.id, buf);
+        }
+
+        fn merge_field(
+            &mut self,
+            tag: u32,
+            wire_type: WireType,
+            buf: &mut impl Buf,
+            ctx: DecodeContext,
+        ) -> Result<(), ::prost::DecodeError> {
+            if tag == 1 {
+                bytes::merge(wire_type, &mut self.id, buf, ctx)
+            } else {
+                ::prost::encoding::skip_field(wire_type, tag, buf, ctx)
+            }
+        }
+
+        fn encoded_len(&self) -> usize {
+            bytes::encoded_len(1, &self.id)
+        }
+
+        fn clear(&mut self) {
+            self.id = Nulid::nil();
+        }
+    }
+
+    #[test]
+    fn test_message_round_trip() {
+        let id = Nulid::new().unwrap();
+        let proto = ProtoNulid::from(id);
+        assert_eq!((proto.high, proto.low), id.to_u64_pair());
+
+        let encoded = proto.encode_to_vec();
+        let decoded = ProtoNulid::decode(encoded.as_slice()).unwrap();
+        assert_eq!(Nulid::from(decoded), id);
+    }
+
+    #[test]
+    fn test_message_name() {
+        use ::prost::Name;
+        assert_eq!(ProtoNulid::full_name(), "nulid.v1.Nulid");
+        assert!(PROTO_FILE.contains("message Nulid"));
+        assert!(PROTO_FILE.contains("package nulid.v1;"));
+    }
+
+    #[test]
+    fn test_option_conversions() {
+        let id = Nulid::new().unwrap();
+        assert_eq!(from_proto_option(to_proto_option(Some(id))), Some(id));
+        assert_eq!(from_proto_option(None), None);
+        assert_eq!(to_proto_option(None), None);
+    }
+
+    #[test]
+    fn test_bytes_codec_round_trip() {
+        let event = Event {
+            id: Nulid::new().unwrap(),
+        };
+        let encoded = event.encode_to_vec();
+        assert_eq!(encoded.len(), event.encoded_len());
+        assert_eq!(&encoded[2..], event.id.to_bytes());
+
+        let decoded = Event::decode(encoded.as_slice()).unwrap();
+        assert_eq!(decoded, event);
+    }
+
+    #[test]
+    fn test_bytes_codec_rejects_wrong_length() {
+        // Field 1, length-delimited, 3 bytes.
+        let encoded = [0x0A, 0x03, 1, 2, 3];
+        assert!(Event::decode(encoded.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_bytes_codec_rejects_wrong_wire_type() {
+        // Field 1, varint.
+        let encoded = [0x08, 0x01];
+        assert!(Event::decode(encoded.as_slice()).is_err());
+    }
+}
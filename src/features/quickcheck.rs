@@ -0,0 +1,124 @@
+//! `quickcheck` support for NULID.
+//!
+//! This module implements `Arbitrary` for [`Nulid`]. Generated values are
+//! mostly random across the full timestamp and random ranges, with
+//! [`Nulid::nil`] and [`Nulid::max`] mixed in. Shrinking works on the
+//! `(timestamp, random)` parts rather than on the raw `u128`, so a failing
+//! case shrinks to the smallest timestamp and random part that still fail.
+//!
+//! [`SameTimestamp`] generates clusters of NULIDs sharing one timestamp.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use nulid::features::quickcheck::SameTimestamp;
+//! use quickcheck::quickcheck;
+//!
+//! fn roundtrips_through_string(id: Nulid) -> bool {
+//!     id.to_string().parse::<Nulid>() == Ok(id)
+//! }
+//!
+//! fn clusters_share_timestamp(cluster: SameTimestamp) -> bool {
+//!     cluster.0.windows(2).all(|w| w[0].nanos() == w[1].nanos())
+//! }
+//!
+//! quickcheck(roundtrips_through_string as fn(Nulid) -> bool);
+//! quickcheck(clusters_share_timestamp as fn(SameTimestamp) -> bool);
+//! ```
+
+use crate::Nulid;
+use quickcheck::{Arbitrary, Gen};
+
+impl Arbitrary for Nulid {
+    fn arbitrary(g: &mut Gen) -> Self {
+        match u8::arbitrary(g) % 10 {
+            0 => Self::nil(),
+            1 => Self::max(),
+            _ => Self::from_nanos(u128::arbitrary(g), u64::arbitrary(g)),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            self.parts()
+                .shrink()
+                .map(|(nanos, random)| Self::from_nanos(nanos, random)),
+        )
+    }
+}
+
+/// A cluster of NULIDs that share one timestamp.
+///
+/// Useful for exercising ordering and deduplication logic within a single
+/// nanosecond. The cluster is not sorted and may contain duplicates. It
+/// shrinks by shrinking the shared timestamp and the random parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SameTimestamp(pub Vec<Nulid>);
+
+impl SameTimestamp {
+    fn from_parts(nanos: u128, randoms: Vec<u64>) -> Self {
+        Self(
+            randoms
+                .into_iter()
+                .map(|random| Nulid::from_nanos(nanos, random))
+                .collect(),
+        )
+    }
+}
+
+impl Arbitrary for SameTimestamp {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self::from_parts(u128::arbitrary(g), Vec::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let nanos = self.0.first().map_or(0, |id| id.nanos());
+        let randoms: Vec<u64> = self.0.iter().map(|id| id.random()).collect();
+        Box::new(
+            (nanos, randoms)
+                .shrink()
+                .map(|(nanos, randoms)| Self::from_parts(nanos, randoms)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::{QuickCheck, TestResult};
+
+    #[test]
+    fn test_arbitrary_roundtrip() {
+        fn prop(id: Nulid) -> bool {
+            Nulid::from_nanos(id.nanos(), id.random()) == id
+        }
+        QuickCheck::new().quickcheck(prop as fn(Nulid) -> bool);
+    }
+
+    #[test]
+    fn test_same_timestamp_cluster() {
+        fn prop(cluster: SameTimestamp) -> TestResult {
+            let mut ids = cluster.0.into_iter();
+            let Some(first) = ids.next() else {
+                return TestResult::discard();
+            };
+            TestResult::from_bool(ids.all(|id| id.nanos() == first.nanos()))
+        }
+        QuickCheck::new().quickcheck(prop as fn(SameTimestamp) -> TestResult);
+    }
+
+    #[test]
+    fn test_shrink_parts() {
+        let id = Nulid::from_nanos(1_000, 500);
+        let shrunk: Vec<Nulid> = id.shrink().collect();
+        assert!(!shrunk.is_empty());
+        assert!(
+            shrunk
+                .iter()
+                .all(|s| s.nanos() <= 1_000 && s.random() <= 500)
+        );
+        assert!(shrunk.contains(&Nulid::from_nanos(0, 500)));
+        assert!(Nulid::nil().shrink().next().is_none());
+    }
+}
@@ -0,0 +1,126 @@
+//! `rand` distribution support for NULID.
+//!
+//! This module implements `Distribution<Nulid>` for:
+//!
+//! - `StandardUniform`: a fully random 128-bit NULID, so `rng.random::<Nulid>()`
+//!   works. The timestamp part is random too, which makes the output depend
+//!   only on the RNG, as property tests want
+//! - [`AtTimestamp`]: a NULID with a fixed timestamp and random 60-bit
+//!   random part, e.g. [`AtTimestamp::now`] for load generators
+//!
+//! Unlike [`Generator`](crate::Generator), these distributions do not
+//! guarantee monotonicity.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use nulid::features::rand::AtTimestamp;
+//! use rand::{Rng, SeedableRng};
+//! use rand::rngs::StdRng;
+//!
+//! # fn main() -> nulid::Result<()> {
+//! let mut rng = StdRng::seed_from_u64(42);
+//!
+//! let id: Nulid = rng.random();
+//! assert_eq!(id, StdRng::seed_from_u64(42).random::<Nulid>());
+//!
+//! let now = AtTimestamp::now()?;
+//! let recent: Vec<Nulid> = (&mut rng).sample_iter(now).take(3).collect();
+//! assert!(recent.iter().all(|id| id.nanos() == now.nanos()));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::generator::{Clock, SystemClock};
+use crate::{Nulid, Result};
+use rand::Rng;
+use rand::distr::{Distribution, StandardUniform};
+
+impl Distribution<Nulid> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Nulid {
+        Nulid::from_u128(rng.random())
+    }
+}
+
+/// Distribution of NULIDs with a fixed timestamp and random 60-bit random part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AtTimestamp {
+    nanos: u128,
+}
+
+impl AtTimestamp {
+    /// Creates a distribution for the given timestamp in nanoseconds since
+    /// Unix epoch (masked to 68 bits).
+    #[must_use]
+    pub const fn new(nanos: u128) -> Self {
+        Self { nanos }
+    }
+
+    /// Creates a distribution for the current system time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SystemTimeError` if the system time is before Unix epoch.
+    pub fn now() -> Result<Self> {
+        SystemClock.now_nanos().map(Self::new)
+    }
+
+    /// Returns the timestamp, in nanoseconds since Unix epoch.
+    #[must_use]
+    pub const fn nanos(self) -> u128 {
+        self.nanos
+    }
+}
+
+impl Distribution<Nulid> for AtTimestamp {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Nulid {
+        Nulid::from_nanos(self.nanos, rng.random())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_standard_deterministic() {
+        let a: Vec<Nulid> = StdRng::seed_from_u64(7)
+            .sample_iter(StandardUniform)
+            .take(10)
+            .collect();
+        let b: Vec<Nulid> = StdRng::seed_from_u64(7)
+            .sample_iter(StandardUniform)
+            .take(10)
+            .collect();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_standard_round_trips() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..100 {
+            let id: Nulid = rng.random();
+            assert_eq!(id.to_string().parse::<Nulid>().unwrap(), id);
+        }
+    }
+
+    #[test]
+    fn test_at_timestamp() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let dist = AtTimestamp::new(1_000_000_000);
+        let ids: Vec<Nulid> = (0..10).map(|_| rng.sample(dist)).collect();
+
+        assert!(ids.iter().all(|id| id.nanos() == 1_000_000_000));
+        assert!(ids.windows(2).any(|w| w[0].random() != w[1].random()));
+    }
+
+    #[test]
+    fn test_at_timestamp_now() {
+        let before = Nulid::now().unwrap();
+        let id = rand::rng().sample(AtTimestamp::now().unwrap());
+        assert!(id.nanos() >= before.nanos());
+    }
+}
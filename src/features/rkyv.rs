@@ -4,29 +4,111 @@
 //! for NULID, enabling efficient zero-copy serialization and deserialization.
 //!
 //! The rkyv derive macros are applied directly to the `Nulid` struct in `nulid.rs`
-//! using `#[cfg_attr(feature = "rkyv", derive(...))]` attributes.
+//! using `#[cfg_attr(feature = "rkyv", derive(...))]` attributes. The archived
+//! type, [`ArchivedNulid`], implements `CheckBytes`, so validated access via
+//! `rkyv::access` works as well as `access_unchecked`. It can be compared with
+//! `Nulid` directly and converted back without a deserializer.
+//!
+//! Types deriving `Id` archive to [`ArchivedNulid`] too when the `derive`
+//! feature is also enabled.
 //!
 //! # Example
 //!
-//! ```ignore
+//! ```
 //! use nulid::Nulid;
-//! use rkyv::ser::{serializers::AllocSerializer, Serializer};
-//! use rkyv::{Deserialize, Archive};
+//! use nulid::features::rkyv::ArchivedNulid;
+//! use rkyv::rancor::Error;
 //!
-//! // Create a NULID
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
 //! let nulid = Nulid::new()?;
 //!
 //! // Serialize
-//! let mut serializer = AllocSerializer::<256>::default();
-//! serializer.serialize_value(&nulid)?;
-//! let bytes = serializer.into_serializer().into_inner();
+//! let bytes = rkyv::to_bytes::<Error>(&nulid)?;
 //!
-//! // Zero-copy access
-//! let archived = unsafe { rkyv::archived_root::<Nulid>(&bytes) };
+//! // Validated zero-copy access
+//! let archived = rkyv::access::<ArchivedNulid, Error>(&bytes)?;
+//! assert_eq!(*archived, nulid);
+//! assert_eq!(archived.to_nulid(), nulid);
 //!
 //! // Deserialize
-//! let mut deserializer = rkyv::de::deserializers::SharedDeserializeMap::new();
-//! let deserialized: Nulid = archived.deserialize(&mut deserializer)?;
+//! let deserialized = rkyv::from_bytes::<Nulid, Error>(&bytes)?;
+//! assert_eq!(deserialized, nulid);
+//! # Ok(())
+//! # }
 //! ```
 //!
 //! See `examples/rkyv_example.rs` for a complete working example.
+
+use crate::Nulid;
+use core::cmp::Ordering;
+use core::fmt;
+use rkyv::rancor::{Infallible, ResultExt};
+
+pub use crate::nulid::{ArchivedNulid, NulidResolver};
+
+impl ArchivedNulid {
+    /// Converts the archived value back to a `Nulid`.
+    #[must_use]
+    pub fn to_nulid(&self) -> Nulid {
+        rkyv::deserialize::<Nulid, Infallible>(self).always_ok()
+    }
+}
+
+impl From<&ArchivedNulid> for Nulid {
+    fn from(archived: &ArchivedNulid) -> Self {
+        archived.to_nulid()
+    }
+}
+
+impl PartialEq<Nulid> for ArchivedNulid {
+    fn eq(&self, other: &Nulid) -> bool {
+        self.to_nulid() == *other
+    }
+}
+
+impl PartialOrd<Nulid> for ArchivedNulid {
+    fn partial_cmp(&self, other: &Nulid) -> Option<Ordering> {
+        Some(self.to_nulid().cmp(other))
+    }
+}
+
+impl fmt::Display for ArchivedNulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_nulid(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rkyv::rancor::Error;
+
+    #[test]
+    fn test_checked_access() {
+        let id = Nulid::from_nanos(1_000_000_000, 42);
+        let bytes = rkyv::to_bytes::<Error>(&id).unwrap();
+        let archived = rkyv::access::<ArchivedNulid, Error>(&bytes).unwrap();
+
+        assert_eq!(*archived, id);
+        assert_eq!(Nulid::from(archived), id);
+        assert_eq!(archived.to_string(), id.to_string());
+    }
+
+    #[test]
+    fn test_checked_access_rejects_short_buffer() {
+        let bytes = rkyv::to_bytes::<Error>(&Nulid::max()).unwrap();
+        assert!(rkyv::access::<ArchivedNulid, Error>(&bytes[..8]).is_err());
+    }
+
+    #[test]
+    fn test_archived_ordering() {
+        let low = Nulid::from_nanos(1, 0);
+        let high = Nulid::from_nanos(2, 0);
+        let ids = rkyv::to_bytes::<Error>(&vec![low, high]).unwrap();
+        let archived = rkyv::access::<rkyv::Archived<Vec<Nulid>>, Error>(&ids).unwrap();
+
+        assert!(archived[0] < archived[1]);
+        assert!(archived[0] < high);
+        assert_eq!(archived[1], high);
+    }
+}
@@ -0,0 +1,72 @@
+//! JSON Schema support for NULID via `schemars`.
+//!
+//! This module implements [`schemars::JsonSchema`] for NULID, so `Nulid`
+//! fields appear in generated JSON Schemas as `type: string, format: nulid`
+//! with a 26-character pattern and an example, matching the `utoipa` schema.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//!
+//! let schema = schemars::schema_for!(Nulid);
+//! assert_eq!(schema.get("type"), Some(&"string".into()));
+//! assert_eq!(schema.get("format"), Some(&"nulid".into()));
+//! ```
+
+use crate::Nulid;
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use std::borrow::Cow;
+
+/// Example value shown in generated documentation.
+const EXAMPLE: &str = "01GZTV7EQ056J0E6N276XD6F3D";
+
+/// Pattern matching a canonical or lowercase NULID string.
+const PATTERN: &str = "^[0-7][0-9A-HJKMNP-TV-Za-hjkmnp-tv-z]{25}$";
+
+impl JsonSchema for Nulid {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("Nulid")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("nulid::Nulid")
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "format": "nulid",
+            "description": "NULID: 26-character Crockford Base32, nanosecond-precision sortable identifier",
+            "minLength": 26,
+            "maxLength": 26,
+            "pattern": PATTERN,
+            "examples": [EXAMPLE],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_shape() {
+        let schema = schemars::schema_for!(Nulid);
+        assert_eq!(schema.get("type"), Some(&"string".into()));
+        assert_eq!(schema.get("format"), Some(&"nulid".into()));
+        assert_eq!(schema.get("minLength"), Some(&26.into()));
+        assert_eq!(schema.get("maxLength"), Some(&26.into()));
+        assert_eq!(schema.get("pattern"), Some(&PATTERN.into()));
+        assert_eq!(schema.get("title"), Some(&"Nulid".into()));
+        assert!(EXAMPLE.parse::<Nulid>().is_ok());
+    }
+
+    #[test]
+    fn test_schema_as_field() {
+        let mut generator = SchemaGenerator::default();
+        let field = generator.subschema_for::<Nulid>();
+        assert_eq!(field.get("$ref"), Some(&"#/$defs/Nulid".into()));
+        assert!(generator.definitions().contains_key("Nulid"));
+    }
+}
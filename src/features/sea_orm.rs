@@ -0,0 +1,117 @@
+//! `SeaORM` support for NULID.
+//!
+//! This module implements the `SeaORM` / `sea-query` value traits for NULID,
+//! so `Nulid` can be used directly as an entity field or primary key:
+//!
+//! - `From<Nulid> for Value` and `Nullable` (stored as `Value::Uuid`)
+//! - `ValueType` with `ColumnType::Uuid`
+//! - `TryGetable` for reading from query results
+//! - `TryFromU64` (always fails, like `Uuid`) so it can be a primary key
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use sea_orm::sea_query::{Value, ValueType};
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let id = Nulid::new()?;
+//! let value: Value = id.into();
+//! assert_eq!(<Nulid as ValueType>::try_from(value)?, id);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::Nulid;
+use sea_orm::prelude::Uuid;
+use sea_orm::sea_query::{ArrayType, ColumnType, Nullable, Value, ValueType, ValueTypeErr};
+use sea_orm::{ColIdx, DbErr, QueryResult, TryFromU64, TryGetError, TryGetable};
+
+impl From<Nulid> for Value {
+    fn from(id: Nulid) -> Self {
+        Self::Uuid(Some(Box::new(Uuid::from_bytes(id.to_bytes()))))
+    }
+}
+
+impl Nullable for Nulid {
+    fn null() -> Value {
+        Value::Uuid(None)
+    }
+}
+
+impl ValueType for Nulid {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::Uuid(Some(uuid)) => Ok(Self::from_bytes(uuid.into_bytes())),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "Nulid".to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Uuid
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Uuid
+    }
+}
+
+impl TryGetable for Nulid {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let uuid = Uuid::try_get_by(res, index)?;
+        Ok(Self::from_bytes(uuid.into_bytes()))
+    }
+}
+
+impl TryFromU64 for Nulid {
+    fn try_from_u64(_: u64) -> Result<Self, DbErr> {
+        Err(DbErr::ConvertFromU64("Nulid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_round_trip() {
+        let id = Nulid::new().expect("Failed to create NULID");
+        let value: Value = id.into();
+        assert_eq!(
+            value,
+            Value::Uuid(Some(Box::new(Uuid::from_bytes(id.to_bytes()))))
+        );
+        assert_eq!(<Nulid as ValueType>::try_from(value).unwrap(), id);
+    }
+
+    #[test]
+    fn test_value_type_metadata() {
+        assert_eq!(Nulid::type_name(), "Nulid");
+        assert_eq!(Nulid::array_type(), ArrayType::Uuid);
+        assert_eq!(Nulid::column_type(), ColumnType::Uuid);
+        assert_eq!(Nulid::null(), Value::Uuid(None));
+    }
+
+    #[test]
+    fn test_value_type_rejects_other_values() {
+        assert!(<Nulid as ValueType>::try_from(Value::Uuid(None)).is_err());
+        assert!(<Nulid as ValueType>::try_from(Value::String(None)).is_err());
+        assert!(<Nulid as ValueType>::try_from(Value::BigInt(Some(1))).is_err());
+    }
+
+    #[test]
+    fn test_optional_value() {
+        let value: Value = None::<Nulid>.into();
+        assert_eq!(value, Value::Uuid(None));
+        assert_eq!(<Option<Nulid> as ValueType>::try_from(value).unwrap(), None);
+    }
+
+    #[test]
+    fn test_try_from_u64_fails() {
+        assert!(Nulid::try_from_u64(1).is_err());
+    }
+}
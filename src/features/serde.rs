@@ -22,8 +22,59 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # Fixed representations
+//!
+//! The default implementation switches between string and bytes based on
+//! `is_human_readable()`. To force a specific wire representation for a
+//! field regardless of format, use one of the adapter modules with
+//! `#[serde(with = "...")]`:
+//!
+//! - [`as_string`]: 26-character Base32 string
+//! - [`as_bytes`]: 16 big-endian bytes
+//! - [`as_u128`]: 128-bit integer
+//! - [`as_uuid_string`]: hyphenated UUID string
+//!
+//! Two more adapters map the nil NULID to `null`:
+//!
+//! - [`nil_as_none`]: for `Option<Nulid>` fields; nil deserializes as `None`
+//! - [`none_as_nil`]: for `Nulid` fields; nil serializes as `null`
+//!
+//! The adapters are also available as `nulid::serde::*`.
+//!
+//! ```
+//! use nulid::Nulid;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     // Always a string, even with MessagePack
+//!     #[serde(with = "nulid::serde::as_string")]
+//!     id: Nulid,
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let event = Event { id: Nulid::new()? };
+//! let bytes = rmp_serde::to_vec(&event)?;
+//! let decoded: Event = rmp_serde::from_slice(&bytes)?;
+//! assert_eq!(decoded.id, event.id);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Simulation setups
+//!
+//! The deterministic test doubles in [`generator`](crate::generator) can be
+//! saved and reloaded too: [`MockClock`] serializes as its current time,
+//! [`SeededRng`] as its seed and the number of values drawn, and
+//! [`GeneratorSnapshot`] as the generator's state and configuration. A
+//! reloaded setup continues with exactly the IDs the original would have
+//! produced.
 
-use crate::Nulid;
+#[cfg(doc)]
+use crate::generator::GeneratorSnapshot;
+use crate::generator::{MockClock, SeededRng};
+use crate::{Nulid, NulidString};
 use core::str::FromStr;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -37,7 +88,7 @@ impl Serialize for Nulid {
         S: Serializer,
     {
         if serializer.is_human_readable() {
-            serializer.serialize_str(&self.to_string())
+            serializer.serialize_str(&self.to_arraystring())
         } else {
             // Serialize as a fixed-size array for efficient binary formats like bincode
             use serde::ser::SerializeTuple;
@@ -51,6 +102,17 @@ impl Serialize for Nulid {
     }
 }
 
+impl Serialize for NulidString {
+    /// Serializes the encoded NULID as a string.
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "serde-flexible"))]
 impl<'de> Deserialize<'de> for Nulid {
     /// Deserializes a NULID.
     ///
@@ -71,6 +133,417 @@ impl<'de> Deserialize<'de> for Nulid {
     }
 }
 
+#[cfg(feature = "serde-flexible")]
+impl<'de> Deserialize<'de> for Nulid {
+    /// Deserializes a NULID from any supported input shape.
+    ///
+    /// With the `serde-flexible` feature, accepts a 26-character Base32
+    /// string, a hyphenated or 32-digit hex UUID string, 16 bytes (as a byte
+    /// buffer or sequence), or a non-negative integer. Self-describing
+    /// formats pick the shape from the input; non-self-describing formats
+    /// like Bincode still read a fixed-size byte array.
+    ///
+    /// Integers are limited to what the format reports as an integer; JSON
+    /// without `arbitrary_precision` turns values above `u64::MAX` into floats,
+    /// which are rejected.
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(FlexibleVisitor)
+        } else {
+            deserializer.deserialize_tuple(16, FlexibleVisitor)
+        }
+    }
+}
+
+/// Visitor accepting every input shape supported by `serde-flexible`.
+#[cfg(feature = "serde-flexible")]
+struct FlexibleVisitor;
+
+#[cfg(feature = "serde-flexible")]
+impl<'de> serde::de::Visitor<'de> for FlexibleVisitor {
+    type Value = Nulid;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("a NULID string, UUID string, 16 bytes, or integer")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> core::result::Result<Nulid, E> {
+        if v.len() == crate::base32::NULID_STRING_LENGTH {
+            return Nulid::from_str(v).map_err(E::custom);
+        }
+        parse_uuid_str(v).ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> core::result::Result<Nulid, E> {
+        BytesVisitor.visit_bytes(v)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> core::result::Result<Nulid, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        BytesVisitor.visit_seq(seq)
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> core::result::Result<Nulid, E> {
+        Ok(Nulid::from_u128(u128::from(v)))
+    }
+
+    fn visit_u128<E: serde::de::Error>(self, v: u128) -> core::result::Result<Nulid, E> {
+        Ok(Nulid::from_u128(v))
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> core::result::Result<Nulid, E> {
+        u128::try_from(v)
+            .map(Nulid::from_u128)
+            .map_err(|_| E::invalid_value(serde::de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_i128<E: serde::de::Error>(self, v: i128) -> core::result::Result<Nulid, E> {
+        u128::try_from(v)
+            .map(Nulid::from_u128)
+            .map_err(|_| E::custom("negative integer cannot be a NULID"))
+    }
+}
+
+/// Parses a hyphenated or 32-digit hex UUID string into a NULID.
+fn parse_uuid_str(v: &str) -> Option<Nulid> {
+    let hyphenated = v.len() == 36 && [8, 13, 18, 23].iter().all(|&i| v.as_bytes()[i] == b'-');
+    let mut digits = [0u8; 32];
+    let mut len = 0;
+    for byte in v.bytes().filter(|&b| !(hyphenated && b == b'-')) {
+        if !byte.is_ascii_hexdigit() || len == digits.len() {
+            return None;
+        }
+        digits[len] = byte;
+        len += 1;
+    }
+    core::str::from_utf8(&digits[..len])
+        .ok()
+        .filter(|_| len == digits.len())
+        .and_then(|hex| u128::from_str_radix(hex, 16).ok())
+        .map(Nulid::from_u128)
+}
+
+/// Visitor accepting borrowed or owned Base32 strings.
+struct StrVisitor;
+
+impl serde::de::Visitor<'_> for StrVisitor {
+    type Value = Nulid;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("a 26-character NULID string")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> core::result::Result<Nulid, E> {
+        Nulid::from_str(v).map_err(E::custom)
+    }
+}
+
+/// Visitor accepting 16 bytes as a byte buffer or a sequence.
+struct BytesVisitor;
+
+impl<'de> serde::de::Visitor<'de> for BytesVisitor {
+    type Value = Nulid;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("16 bytes")
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> core::result::Result<Nulid, E> {
+        Nulid::try_from(v).map_err(E::custom)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> core::result::Result<Nulid, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut bytes = [0u8; 16];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+        }
+        Ok(Nulid::from_bytes(bytes))
+    }
+}
+
+/// Serializes a NULID as its 26-character Base32 string in every format.
+///
+/// Use with `#[serde(with = "nulid::serde::as_string")]`.
+pub mod as_string {
+    use super::{Nulid, StrVisitor};
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes `id` as a Base32 string.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the serializer.
+    pub fn serialize<S: Serializer>(id: &Nulid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&id.to_arraystring())
+    }
+
+    /// Deserializes a NULID from a Base32 string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a valid NULID string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Nulid, D::Error> {
+        deserializer.deserialize_str(StrVisitor)
+    }
+}
+
+/// Serializes a NULID as 16 big-endian bytes in every format.
+///
+/// Use with `#[serde(with = "nulid::serde::as_bytes")]`.
+pub mod as_bytes {
+    use super::{BytesVisitor, Nulid};
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes `id` as a byte buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the serializer.
+    pub fn serialize<S: Serializer>(id: &Nulid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&id.to_bytes())
+    }
+
+    /// Deserializes a NULID from a byte buffer or a sequence of 16 bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not exactly 16 bytes.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Nulid, D::Error> {
+        deserializer.deserialize_bytes(BytesVisitor)
+    }
+}
+
+/// Serializes a NULID as a 128-bit unsigned integer in every format.
+///
+/// Use with `#[serde(with = "nulid::serde::as_u128")]`. The target format
+/// must support `u128`.
+pub mod as_u128 {
+    use super::Nulid;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serializes `id` as a `u128`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the serializer, e.g. if it lacks `u128` support.
+    pub fn serialize<S: Serializer>(id: &Nulid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u128(id.as_u128())
+    }
+
+    /// Deserializes a NULID from a `u128`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a `u128`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Nulid, D::Error> {
+        u128::deserialize(deserializer).map(Nulid::from_u128)
+    }
+}
+
+/// Serializes a NULID as a hyphenated UUID string in every format.
+///
+/// Use with `#[serde(with = "nulid::serde::as_uuid_string")]`. The same 128
+/// bits are written as `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx` in lowercase
+/// hex, for systems that store IDs in UUID columns. Deserialization also
+/// accepts uppercase and the unhyphenated 32-digit form.
+pub mod as_uuid_string {
+    use super::Nulid;
+    use core::fmt;
+    use serde::{Deserializer, Serializer};
+
+    /// Formats a `u128` as a hyphenated UUID.
+    struct Hyphenated(u128);
+
+    impl fmt::Display for Hyphenated {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let v = self.0;
+            write!(
+                f,
+                "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+                v >> 96,
+                (v >> 80) & 0xFFFF,
+                (v >> 64) & 0xFFFF,
+                (v >> 48) & 0xFFFF,
+                v & 0xFFFF_FFFF_FFFF
+            )
+        }
+    }
+
+    struct UuidVisitor;
+
+    impl serde::de::Visitor<'_> for UuidVisitor {
+        type Value = Nulid;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a UUID string")
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Nulid, E> {
+            super::parse_uuid_str(v)
+                .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+        }
+    }
+
+    /// Serializes `id` as a hyphenated UUID string.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the serializer.
+    pub fn serialize<S: Serializer>(id: &Nulid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Hyphenated(id.as_u128()))
+    }
+
+    /// Deserializes a NULID from a UUID string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a valid UUID string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Nulid, D::Error> {
+        deserializer.deserialize_str(UuidVisitor)
+    }
+}
+
+/// Treats the nil NULID as absent for `Option<Nulid>` fields.
+///
+/// Use with `#[serde(with = "nulid::serde::nil_as_none")]`. Both `None` and
+/// `Some(Nulid::nil())` serialize as `null`; `null` and the nil NULID
+/// deserialize as `None`. Other values use the default representation.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::Nulid;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Order {
+///     #[serde(with = "nulid::serde::nil_as_none")]
+///     parent: Option<Nulid>,
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+/// let order: Order = serde_json::from_str(r#"{"parent":"00000000000000000000000000"}"#)?;
+/// assert_eq!(order.parent, None);
+/// assert_eq!(serde_json::to_string(&order)?, r#"{"parent":null}"#);
+/// # Ok(())
+/// # }
+/// ```
+pub mod nil_as_none {
+    use super::Nulid;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serializes `None` and the nil NULID as `null`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the serializer.
+    #[allow(clippy::ref_option)] // signature required by `#[serde(with)]`
+    pub fn serialize<S: Serializer>(id: &Option<Nulid>, serializer: S) -> Result<S::Ok, S::Error> {
+        match id {
+            Some(id) if !id.is_nil() => serializer.serialize_some(id),
+            _ => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes `null` and the nil NULID as `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is neither `null` nor a valid NULID.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Nulid>, D::Error> {
+        Option::<Nulid>::deserialize(deserializer).map(|id| id.filter(|id| !id.is_nil()))
+    }
+}
+
+/// Represents the nil NULID as `null` for plain `Nulid` fields.
+///
+/// Use with `#[serde(with = "nulid::serde::none_as_nil")]`. The inverse of
+/// [`nil_as_none`]: the nil NULID serializes as `null`, and `null`
+/// deserializes as the nil NULID.
+pub mod none_as_nil {
+    use super::Nulid;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serializes the nil NULID as `null`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the serializer.
+    pub fn serialize<S: Serializer>(id: &Nulid, serializer: S) -> Result<S::Ok, S::Error> {
+        if id.is_nil() {
+            serializer.serialize_none()
+        } else {
+            serializer.serialize_some(id)
+        }
+    }
+
+    /// Deserializes `null` as the nil NULID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is neither `null` nor a valid NULID.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Nulid, D::Error> {
+        Option::<Nulid>::deserialize(deserializer).map(Option::unwrap_or_default)
+    }
+}
+
+/// Serialized form of [`MockClock`].
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "MockClock")]
+struct MockClockRepr {
+    nanos: u64,
+}
+
+impl Serialize for MockClock {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MockClockRepr { nanos: self.get() }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MockClock {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        MockClockRepr::deserialize(deserializer).map(|repr| Self::new(repr.nanos))
+    }
+}
+
+/// Serialized form of [`SeededRng`].
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "SeededRng")]
+struct SeededRngRepr {
+    seed: u64,
+    draws: u64,
+}
+
+impl Serialize for SeededRng {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SeededRngRepr {
+            seed: self.seed(),
+            draws: self.draws(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SeededRng {
+    /// Re-seeds the RNG and skips the values already drawn.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        SeededRngRepr::deserialize(deserializer)
+            .map(|repr| Self::at_position(repr.seed, repr.draws))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -180,4 +653,210 @@ mod tests {
 
         assert_eq!(nulids, decoded);
     }
+
+    #[test]
+    fn test_serde_nulid_string() {
+        let nulid = Nulid::new().expect("Failed to create NULID");
+        let json = serde_json::to_string(&nulid.to_arraystring()).expect("Failed to serialize");
+        assert_eq!(
+            json,
+            serde_json::to_string(&nulid).expect("Failed to serialize")
+        );
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Adapted {
+        #[serde(with = "as_string")]
+        string: Nulid,
+        #[serde(with = "as_bytes")]
+        bytes: Nulid,
+        #[serde(with = "as_u128")]
+        int: Nulid,
+        #[serde(with = "as_uuid_string")]
+        uuid: Nulid,
+    }
+
+    fn adapted() -> Adapted {
+        let id = Nulid::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+        Adapted {
+            string: id,
+            bytes: id,
+            int: id,
+            uuid: id,
+        }
+    }
+
+    #[test]
+    fn test_adapters_json() {
+        let value = adapted();
+        let text = serde_json::to_string(&value).expect("Failed to serialize");
+        assert!(text.contains(&format!(r#""string":"{}""#, value.string)));
+        assert!(text.contains(r#""bytes":[1,35,69,103,"#));
+        assert!(text.contains(r#""int":1512366075204170947332355369683137040"#));
+        assert!(text.contains(r#""uuid":"01234567-89ab-cdef-fedc-ba9876543210""#));
+
+        let decoded: Adapted = serde_json::from_str(&text).expect("Failed to deserialize");
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn test_adapters_msgpack() {
+        let value = adapted();
+        let bytes = rmp_serde::to_vec_named(&value).expect("Failed to serialize");
+        let decoded: Adapted = rmp_serde::from_slice(&bytes).expect("Failed to deserialize");
+        assert_eq!(decoded, value);
+
+        // as_string stays a string in a binary format
+        let id = value.string.to_string();
+        assert!(bytes.windows(id.len()).any(|w| w == id.as_bytes()));
+    }
+
+    #[test]
+    fn test_as_uuid_string_accepts_variants() {
+        #[derive(Deserialize)]
+        struct Wrapper(#[serde(with = "as_uuid_string")] Nulid);
+
+        let expected = Nulid::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+        for input in [
+            "\"01234567-89ab-cdef-fedc-ba9876543210\"",
+            "\"01234567-89AB-CDEF-FEDC-BA9876543210\"",
+            "\"0123456789abcdeffedcba9876543210\"",
+        ] {
+            let Wrapper(id) = serde_json::from_str(input).expect("Failed to deserialize");
+            assert_eq!(id, expected);
+        }
+
+        for input in [
+            "\"01234567-89ab-cdef-fedc-ba987654321\"",
+            "\"0123456789abcdeffedcba98765432100\"",
+            "\"+1234567-89ab-cdef-fedc-ba9876543210\"",
+        ] {
+            assert!(serde_json::from_str::<Wrapper>(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_nil_as_none() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Wrapper(#[serde(with = "nil_as_none")] Option<Nulid>);
+
+        let id = Nulid::new().expect("Failed to create NULID");
+        for (value, json) in [
+            (None, "null".to_string()),
+            (Some(Nulid::nil()), "null".to_string()),
+            (Some(id), format!("\"{id}\"")),
+        ] {
+            assert_eq!(
+                serde_json::to_string(&Wrapper(value)).expect("Failed to serialize"),
+                json
+            );
+        }
+
+        let nil: Wrapper =
+            serde_json::from_str("\"00000000000000000000000000\"").expect("Failed to deserialize");
+        assert_eq!(nil, Wrapper(None));
+        let null: Wrapper = serde_json::from_str("null").expect("Failed to deserialize");
+        assert_eq!(null, Wrapper(None));
+        let some: Wrapper =
+            serde_json::from_str(&format!("\"{id}\"")).expect("Failed to deserialize");
+        assert_eq!(some, Wrapper(Some(id)));
+    }
+
+    #[test]
+    fn test_none_as_nil() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Wrapper(#[serde(with = "none_as_nil")] Nulid);
+
+        assert_eq!(
+            serde_json::to_string(&Wrapper(Nulid::nil())).expect("Failed to serialize"),
+            "null"
+        );
+        let nil: Wrapper = serde_json::from_str("null").expect("Failed to deserialize");
+        assert_eq!(nil, Wrapper(Nulid::nil()));
+
+        let id = Nulid::new().expect("Failed to create NULID");
+        let json = serde_json::to_string(&Wrapper(id)).expect("Failed to serialize");
+        let decoded: Wrapper = serde_json::from_str(&json).expect("Failed to deserialize");
+        assert_eq!(decoded, Wrapper(id));
+    }
+
+    #[cfg(feature = "serde-flexible")]
+    #[test]
+    fn test_flexible_input_shapes() {
+        let expected = Nulid::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+        let inputs = [
+            format!("\"{expected}\""),
+            "\"01234567-89ab-cdef-fedc-ba9876543210\"".to_string(),
+            "\"0123456789ABCDEFFEDCBA9876543210\"".to_string(),
+            format!("{:?}", expected.to_bytes()),
+        ];
+        for input in &inputs {
+            let id: Nulid = serde_json::from_str(input).expect("Failed to deserialize");
+            assert_eq!(id, expected, "{input}");
+        }
+
+        let small: Nulid = serde_json::from_str("42").expect("Failed to deserialize");
+        assert_eq!(small.as_u128(), 42);
+        assert!(serde_json::from_str::<Nulid>("-1").is_err());
+        assert!(serde_json::from_str::<Nulid>("\"not-an-id\"").is_err());
+        assert!(serde_json::from_str::<Nulid>("[1,2,3]").is_err());
+
+        // Binary formats keep working
+        let bytes = rmp_serde::to_vec(&expected).expect("Failed to serialize");
+        let id: Nulid = rmp_serde::from_slice(&bytes).expect("Failed to deserialize");
+        assert_eq!(id, expected);
+        let id: Nulid = rmp_serde::from_slice(
+            &rmp_serde::to_vec(&expected.to_string()).expect("Failed to serialize"),
+        )
+        .expect("Failed to deserialize");
+        assert_eq!(id, expected);
+    }
+
+    #[test]
+    fn test_simulation_setup_round_trip() {
+        use crate::generator::{Generator, GeneratorSnapshot, NoNodeId};
+
+        #[derive(Serialize, Deserialize)]
+        struct Setup {
+            clock: MockClock,
+            rng: SeededRng,
+            generator: GeneratorSnapshot,
+        }
+
+        let clock = MockClock::new(1_000);
+        let rng = SeededRng::new(42);
+        let generator = Generator::<_, _, NoNodeId>::with_deps(&clock, &rng)
+            .with_timestamp_batch(4)
+            .with_sequence_bits(8)
+            .expect("Failed to enable sequence mode");
+        for _ in 0..6 {
+            generator.generate().expect("Failed to generate");
+        }
+
+        let json = serde_json::to_string(&Setup {
+            clock: MockClock::new(clock.get()),
+            rng: SeededRng::at_position(rng.seed(), rng.draws()),
+            generator: generator.snapshot().expect("Failed to snapshot"),
+        })
+        .expect("Failed to serialize");
+        let setup: Setup = serde_json::from_str(&json).expect("Failed to deserialize");
+        assert_eq!(setup.clock.get(), 1_000);
+        assert_eq!((setup.rng.seed(), setup.rng.draws()), (42, rng.draws()));
+        assert_eq!(setup.generator.timestamp_batch, 4);
+        assert_eq!(setup.generator.sequence_bits, 8);
+
+        let resumed = Generator::<_, _, NoNodeId>::with_deps(&setup.clock, &setup.rng)
+            .with_snapshot(setup.generator)
+            .expect("Failed to restore snapshot");
+        for step in 0..20 {
+            if step % 3 == 0 {
+                clock.advance(core::time::Duration::from_nanos(7));
+                setup.clock.advance(core::time::Duration::from_nanos(7));
+            }
+            assert_eq!(
+                resumed.generate().expect("Failed to generate"),
+                generator.generate().expect("Failed to generate")
+            );
+        }
+    }
 }
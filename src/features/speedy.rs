@@ -0,0 +1,100 @@
+//! `speedy` serialization support for NULID.
+//!
+//! This module implements `Readable` and `Writable` for [`Nulid`], so it
+//! can be used in `#[derive(Readable, Writable)]` types without manual
+//! byte conversion.
+//!
+//! A NULID is always written as its 16 big-endian bytes, regardless of the
+//! context's endianness. This keeps the wire form identical to
+//! [`Nulid::to_bytes`] and byte-wise sortable.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use speedy::{Readable, Writable};
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let id = Nulid::new()?;
+//!
+//! let bytes = id.write_to_vec()?;
+//! assert_eq!(bytes, id.to_bytes());
+//!
+//! let decoded = Nulid::read_from_buffer(&bytes)?;
+//! assert_eq!(decoded, id);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::Nulid;
+use speedy::{Context, Readable, Reader, Writable, Writer};
+
+/// Number of bytes a NULID occupies on the wire.
+pub const BYTE_LEN: usize = 16;
+
+impl<'a, C: Context> Readable<'a, C> for Nulid {
+    fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        let mut bytes = [0u8; BYTE_LEN];
+        reader.read_bytes(&mut bytes)?;
+        Ok(Self::from_bytes(bytes))
+    }
+
+    fn minimum_bytes_needed() -> usize {
+        BYTE_LEN
+    }
+}
+
+impl<C: Context> Writable<C> for Nulid {
+    fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+        writer.write_bytes(&self.to_bytes())
+    }
+
+    fn bytes_needed(&self) -> Result<usize, C::Error> {
+        Ok(BYTE_LEN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speedy::{BigEndian, LittleEndian};
+
+    #[derive(Debug, PartialEq, Eq, Readable, Writable)]
+    struct Packet {
+        id: Nulid,
+        sequence: u32,
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let id = Nulid::from_nanos(1_000_000_000, 42);
+        let bytes = id.write_to_vec().unwrap();
+        assert_eq!(bytes.len(), BYTE_LEN);
+        assert_eq!(Nulid::read_from_buffer(&bytes).unwrap(), id);
+    }
+
+    #[test]
+    fn test_endianness_independent() {
+        let id = Nulid::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+        let little = id.write_to_vec_with_ctx(LittleEndian::default()).unwrap();
+        let big = id.write_to_vec_with_ctx(BigEndian::default()).unwrap();
+        assert_eq!(little, id.to_bytes());
+        assert_eq!(big, id.to_bytes());
+    }
+
+    #[test]
+    fn test_derived_struct() {
+        let packet = Packet {
+            id: Nulid::new().unwrap(),
+            sequence: 7,
+        };
+        let bytes = packet.write_to_vec().unwrap();
+        assert_eq!(bytes.len(), BYTE_LEN + 4);
+        assert_eq!(Packet::read_from_buffer(&bytes).unwrap(), packet);
+    }
+
+    #[test]
+    fn test_short_buffer() {
+        assert!(Nulid::read_from_buffer(&[0u8; BYTE_LEN - 1]).is_err());
+    }
+}
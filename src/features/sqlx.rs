@@ -1,7 +1,13 @@
-//! `SQLx` support for `PostgreSQL` UUID storage.
+//! `SQLx` support for `PostgreSQL` UUID and text storage.
 //!
 //! This module provides implementations for storing NULIDs as UUIDs in `PostgreSQL`
-//! databases using the sqlx crate.
+//! databases using the sqlx crate. Columns that hold the 26-character string
+//! instead (`TEXT`, `VARCHAR(26)`, `CHAR(26)`) are read and written through
+//! the [`Text`] wrapper.
+//!
+//! Decoding a plain `Nulid` accepts either column type, checked at runtime,
+//! so queries keep working while a table is being migrated from text to
+//! `UUID`.
 //!
 //! # Examples
 //!
@@ -31,21 +37,48 @@
 //!         .await
 //! }
 //! ```
+//!
+//! For a legacy schema with a text column, wrap the field in [`Text`]; with
+//! the compile-time checked macros, override the column type:
+//!
+//! ```ignore
+//! use nulid::Nulid;
+//! use nulid::sqlx::Text;
+//!
+//! #[derive(sqlx::FromRow)]
+//! struct Event {
+//!     id: Text<Nulid>,
+//!     kind: String,
+//! }
+//!
+//! async fn get_event(pool: &sqlx::PgPool, id: Nulid) -> sqlx::Result<Event> {
+//!     sqlx::query_as!(
+//!         Event,
+//!         r#"SELECT id AS "id: Text<Nulid>", kind FROM events WHERE id = $1"#,
+//!         Text(id) as Text<Nulid>,
+//!     )
+//!     .fetch_one(pool)
+//!     .await
+//! }
+//! ```
 
 use crate::Nulid;
+use core::fmt;
+use core::str::FromStr;
 use sqlx::encode::IsNull;
 use sqlx::error::BoxDynError;
 use sqlx::postgres::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
-use sqlx::{Decode, Encode, Type};
+use sqlx::{Decode, Encode, Type, TypeInfo, ValueRef};
 use uuid::Uuid;
 
+/// Binds as `UUID`, but also accepts text columns when decoding.
 impl Type<Postgres> for Nulid {
     fn type_info() -> PgTypeInfo {
         <Uuid as Type<Postgres>>::type_info()
     }
 
     fn compatible(ty: &PgTypeInfo) -> bool {
-        <Uuid as Type<Postgres>>::compatible(ty)
+        <Uuid as Type<Postgres>>::compatible(ty) || <str as Type<Postgres>>::compatible(ty)
     }
 }
 
@@ -56,6 +89,7 @@ impl PgHasArrayType for Nulid {
 
     fn array_compatible(ty: &PgTypeInfo) -> bool {
         <Uuid as PgHasArrayType>::array_compatible(ty)
+            || <&str as PgHasArrayType>::array_compatible(ty)
     }
 }
 
@@ -66,13 +100,248 @@ impl Encode<'_, Postgres> for Nulid {
     }
 }
 
+/// Decodes a `UUID` value, or the Base32 string in a `TEXT`, `VARCHAR` or
+/// `CHAR` value.
 impl<'r> Decode<'r, Postgres> for Nulid {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
-        let uuid = <Uuid as Decode<Postgres>>::decode(value)?;
-        Ok(Self::from_uuid(uuid))
+        let ty = value.type_info().into_owned();
+        if <Uuid as Type<Postgres>>::compatible(&ty) {
+            let uuid = <Uuid as Decode<Postgres>>::decode(value)?;
+            Ok(Self::from_uuid(uuid))
+        } else if <str as Type<Postgres>>::compatible(&ty) {
+            let s = <&str as Decode<Postgres>>::decode(value)?;
+            Ok(s.trim_end().parse()?)
+        } else {
+            Err(format!(
+                "cannot decode Nulid from PostgreSQL type {}: expected UUID or TEXT",
+                ty.name()
+            )
+            .into())
+        }
+    }
+}
+
+/// Stores an ID as its string form in a `TEXT` or `VARCHAR` column.
+///
+/// `Nulid` itself maps to `UUID`. Wrap it, or any type deriving `Id`, in
+/// `Text` to bind and decode the Base32 string instead. Encoding uses
+/// `Display`, so prefixed `Id` types store their prefix; decoding uses
+/// `FromStr` and accepts lowercase.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::Nulid;
+/// use nulid::sqlx::Text;
+///
+/// let id = Nulid::from_u128(42);
+/// let text = Text::from(id);
+/// assert_eq!(text.to_string(), "0000000000000000000000001A");
+/// assert_eq!(text.into_inner(), id);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Text<T = Nulid>(pub T);
+
+impl<T> Text<T> {
+    /// Returns the wrapped ID.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl From<Nulid> for Text<Nulid> {
+    fn from(id: Nulid) -> Self {
+        Self(id)
     }
 }
 
+impl<T> core::ops::Deref for Text<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Text<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<T> Type<Postgres> for Text<T> {
+    fn type_info() -> PgTypeInfo {
+        <str as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl<T> PgHasArrayType for Text<T> {
+    fn array_type_info() -> PgTypeInfo {
+        <&str as PgHasArrayType>::array_type_info()
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        <&str as PgHasArrayType>::array_compatible(ty)
+    }
+}
+
+impl<T: fmt::Display> Encode<'_, Postgres> for Text<T> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <String as Encode<Postgres>>::encode(self.0.to_string(), buf)
+    }
+}
+
+impl<'r, T> Decode<'r, Postgres> for Text<T>
+where
+    T: FromStr,
+    T::Err: core::error::Error + Send + Sync + 'static,
+{
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let s = <&str as Decode<Postgres>>::decode(value)?;
+        Ok(Self(s.parse()?))
+    }
+}
+
+/// A named SQL snippet that installs NULID support in `PostgreSQL`.
+///
+/// Returned by [`migrations`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Migration {
+    /// Short identifier, usable as a migration file name.
+    pub name: &'static str,
+    /// The SQL to execute. Every snippet is idempotent.
+    pub sql: &'static str,
+}
+
+/// Returns SQL that installs NULID types and conversion functions in `PostgreSQL`.
+///
+/// Run the snippets in order, e.g. from a migration, so queries, views and
+/// ad-hoc psql sessions can convert and validate IDs the same way this
+/// crate does:
+///
+/// - `nulid_domain`: `CREATE DOMAIN nulid AS uuid`, the column type that
+///   [`Nulid`] maps to
+/// - `nulid_text_domain`: `CREATE DOMAIN nulid_text AS text`, constrained to
+///   valid 26-character strings (any case), for columns read through [`Text`]
+/// - `nulid_to_text`: `nulid_to_text(uuid) RETURNS text`, the canonical
+///   uppercase Base32 string
+/// - `text_to_nulid`: `text_to_nulid(text) RETURNS uuid`, accepting the
+///   strings [`Nulid::parse_strict`] accepts, in any case, and raising
+///   `invalid_text_representation` (22P02) otherwise. Unlike `FromStr`, it
+///   rejects a first character above `7`, which would encode more than 128
+///   bits, instead of dropping the excess bits
+///
+/// The functions are PL/pgSQL, `IMMUTABLE` and `PARALLEL SAFE`, so they can
+/// back expression indexes and generated columns.
+///
+/// # Examples
+///
+/// ```ignore
+/// async fn install(pool: &sqlx::PgPool) -> sqlx::Result<()> {
+///     for migration in nulid::sqlx::migrations() {
+///         sqlx::raw_sql(migration.sql).execute(pool).await?;
+///     }
+///     Ok(())
+/// }
+/// ```
+///
+/// ```sql
+/// SELECT nulid_to_text('018cc251-f400-7800-8abc-def012345678');
+/// SELECT * FROM users WHERE id = text_to_nulid('01gztv7eq056j0e6n276xd6f3d');
+/// ```
+#[must_use]
+pub const fn migrations() -> &'static [Migration] {
+    MIGRATIONS
+}
+
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        name: "nulid_domain",
+        sql: "\
+DO $$
+BEGIN
+    CREATE DOMAIN nulid AS uuid;
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END
+$$;
+",
+    },
+    Migration {
+        name: "nulid_text_domain",
+        sql: "\
+DO $$
+BEGIN
+    CREATE DOMAIN nulid_text AS text
+        CHECK (VALUE ~* '^[0-7][0-9A-HJKMNP-TV-Z]{25}$');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END
+$$;
+",
+    },
+    Migration {
+        name: "nulid_to_text",
+        sql: "\
+CREATE OR REPLACE FUNCTION nulid_to_text(id uuid) RETURNS text
+LANGUAGE plpgsql IMMUTABLE STRICT PARALLEL SAFE AS $$
+DECLARE
+    alphabet CONSTANT text := '0123456789ABCDEFGHJKMNPQRSTVWXYZ';
+    -- 128 bits padded to 130, five bits per character
+    bits bit(130) := B'00' || ('x' || replace(id::text, '-', ''))::bit(128);
+    result text := '';
+BEGIN
+    FOR i IN 0..25 LOOP
+        result := result
+            || substr(alphabet, substring(bits FROM i * 5 + 1 FOR 5)::bit(5)::integer + 1, 1);
+    END LOOP;
+    RETURN result;
+END
+$$;
+",
+    },
+    Migration {
+        name: "text_to_nulid",
+        sql: "\
+CREATE OR REPLACE FUNCTION text_to_nulid(s text) RETURNS uuid
+LANGUAGE plpgsql IMMUTABLE STRICT PARALLEL SAFE AS $$
+DECLARE
+    alphabet CONSTANT text := '0123456789ABCDEFGHJKMNPQRSTVWXYZ';
+    bits bit varying := B'';
+    digit integer;
+BEGIN
+    IF length(s) <> 26 THEN
+        RAISE EXCEPTION 'Invalid length: expected 26 characters, found %', length(s)
+            USING ERRCODE = 'invalid_text_representation';
+    END IF;
+    FOR i IN 1..26 LOOP
+        digit := strpos(alphabet, upper(substr(s, i, 1))) - 1;
+        IF digit < 0 THEN
+            RAISE EXCEPTION 'Invalid character ''%'' at position %', substr(s, i, 1), i - 1
+                USING ERRCODE = 'invalid_text_representation';
+        END IF;
+        bits := bits || digit::bit(5);
+    END LOOP;
+    -- The first character carries only three bits
+    IF substring(bits FROM 1 FOR 2) <> B'00' THEN
+        RAISE EXCEPTION 'Invalid character ''%'' at position 0', substr(s, 1, 1)
+            USING ERRCODE = 'invalid_text_representation';
+    END IF;
+    RETURN (
+        lpad(to_hex(substring(bits FROM 3 FOR 64)::bit(64)::bigint), 16, '0')
+        || lpad(to_hex(substring(bits FROM 67 FOR 64)::bit(64)::bigint), 16, '0')
+    )::uuid;
+END
+$$;
+",
+    },
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,6 +372,91 @@ mod tests {
         assert_eq!(decoded.random(), 0);
     }
 
+    #[test]
+    fn test_text_type_and_encoding() {
+        let text = <String as Type<Postgres>>::type_info();
+        let uuid = <Uuid as Type<Postgres>>::type_info();
+        assert_eq!(Text::<Nulid>::type_info(), text);
+        assert!(Text::<Nulid>::compatible(&text));
+        assert!(!Text::<Nulid>::compatible(&uuid));
+
+        let id: Nulid = "01GZTV7EQ056J0E6N276XD6F3D".parse().unwrap();
+        let mut buf = PgArgumentBuffer::default();
+        let is_null = Text(id).encode_by_ref(&mut buf).unwrap();
+        assert!(matches!(is_null, IsNull::No));
+        assert_eq!(&buf[..], b"01GZTV7EQ056J0E6N276XD6F3D");
+    }
+
+    #[test]
+    fn test_decode_accepts_uuid_and_text() {
+        let text = <String as Type<Postgres>>::type_info();
+        let uuid = <Uuid as Type<Postgres>>::type_info();
+        let int = <i64 as Type<Postgres>>::type_info();
+        assert!(Nulid::compatible(&uuid));
+        assert!(Nulid::compatible(&text));
+        assert!(!Nulid::compatible(&int));
+        assert_eq!(Nulid::type_info(), uuid);
+
+        let text_array = <Vec<String> as Type<Postgres>>::type_info();
+        assert!(Nulid::array_compatible(&text_array));
+        assert!(!Nulid::array_compatible(&text));
+    }
+
+    /// Base32 alphabet spelled out in the SQL helpers.
+    const SQL_ALPHABET: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+    #[test]
+    fn test_migrations() {
+        let names: Vec<&str> = migrations().iter().map(|m| m.name).collect();
+        assert_eq!(
+            names,
+            [
+                "nulid_domain",
+                "nulid_text_domain",
+                "nulid_to_text",
+                "text_to_nulid"
+            ]
+        );
+
+        // The SQL alphabet must match the one the encoder uses
+        let alphabet: String = (0..32u128)
+            .map(|digit| Nulid::from_u128(digit).to_string().pop().unwrap())
+            .collect();
+        assert_eq!(alphabet, SQL_ALPHABET);
+        for migration in &migrations()[2..] {
+            assert!(migration.sql.contains(SQL_ALPHABET), "{}", migration.name);
+            assert!(migration.sql.contains("IMMUTABLE STRICT PARALLEL SAFE"));
+        }
+    }
+
+    #[test]
+    fn test_text_to_nulid_matches_parse_strict() {
+        let domain = migrations()[1].sql;
+        let text_to_nulid = migrations()[3].sql;
+        assert!(domain.contains("'^[0-7][0-9A-HJKMNP-TV-Z]{25}$'"));
+        assert!(text_to_nulid.contains("upper(substr(s, i, 1))"));
+        assert!(text_to_nulid.contains("IF substring(bits FROM 1 FOR 2) <> B'00' THEN"));
+
+        // The SQL accepts a first character iff its top two bits are zero,
+        // which is exactly what `parse_strict` checks after uppercasing
+        for (digit, first) in SQL_ALPHABET.chars().enumerate() {
+            let upper = format!("{first}{}", "0".repeat(25));
+            let lower = upper.to_ascii_lowercase();
+            let accepted_by_sql = digit < 8;
+            assert_eq!(
+                Nulid::parse_strict(&upper).is_ok(),
+                accepted_by_sql,
+                "{upper}"
+            );
+            assert_eq!(
+                Nulid::parse_strict(&lower.to_ascii_uppercase()).is_ok(),
+                accepted_by_sql
+            );
+            // `FromStr` keeps the low 128 bits instead
+            assert!(lower.parse::<Nulid>().is_ok());
+        }
+    }
+
     #[test]
     fn test_nulid_uuid_equivalence() {
         // Test that NULID and UUID store the same 128-bit value
@@ -0,0 +1,167 @@
+//! `tracing` integration for NULID.
+//!
+//! This module provides:
+//!
+//! - [`Nulid::as_tracing_value`]: records a NULID as a span or event field
+//!   using its Base32 form, encoded on the stack without allocating
+//! - [`Nulid::span_id_u64`] and [`Nulid::to_span_id`]: derive a stable,
+//!   non-zero 64-bit trace/span ID from a NULID, so request IDs and trace
+//!   IDs can be correlated without a lookup table
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//!
+//! # fn main() -> nulid::Result<()> {
+//! let request_id = Nulid::new()?;
+//!
+//! let span = tracing::info_span!("request", id = request_id.as_tracing_value());
+//! let _guard = span.enter();
+//! tracing::info!(user = Nulid::new()?.as_tracing_value(), "loaded user");
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{Nulid, NulidString};
+use core::num::NonZeroU64;
+use tracing::field::DisplayValue;
+
+impl Nulid {
+    /// Returns a value that records this NULID as its Base32 string in
+    /// `tracing` fields.
+    ///
+    /// The string is encoded into a stack buffer, so recording does not
+    /// allocate.
+    #[must_use]
+    pub fn as_tracing_value(self) -> DisplayValue<NulidString> {
+        tracing::field::display(self.to_arraystring())
+    }
+
+    /// Derives a non-zero 64-bit ID from this NULID.
+    ///
+    /// Both halves are folded and mixed with the `SplitMix64` finalizer, so
+    /// every bit of the NULID affects the result and nearby NULIDs map to
+    /// unrelated values. The mapping is stable across releases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// let id = Nulid::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+    /// assert_eq!(id.span_id_u64(), id.span_id_u64());
+    /// assert_ne!(id.span_id_u64(), Nulid::from_u128(id.as_u128() + 1).span_id_u64());
+    /// ```
+    #[must_use]
+    pub const fn span_id_u64(self) -> NonZeroU64 {
+        let (high, low) = self.to_u64_pair();
+        let mut z = high.rotate_left(32) ^ low;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        match NonZeroU64::new(z) {
+            Some(id) => id,
+            None => NonZeroU64::MIN,
+        }
+    }
+
+    /// Derives a `tracing` span ID from this NULID.
+    ///
+    /// See [`span_id_u64`](Self::span_id_u64).
+    #[must_use]
+    pub const fn to_span_id(self) -> tracing::span::Id {
+        tracing::span::Id::from_non_zero_u64(self.span_id_u64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Subscriber that collects every recorded field as `name=value`.
+    #[derive(Clone, Default)]
+    struct Collector(Arc<Mutex<Vec<String>>>);
+
+    impl Visit for Collector {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{}={value:?}", field.name()));
+        }
+    }
+
+    impl Subscriber for Collector {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> tracing::span::Id {
+            span.record(&mut self.clone());
+            tracing::span::Id::from_u64(1)
+        }
+
+        fn record(&self, _: &tracing::span::Id, values: &Record<'_>) {
+            values.record(&mut self.clone());
+        }
+
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut self.clone());
+        }
+
+        fn enter(&self, _: &tracing::span::Id) {}
+
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    #[test]
+    fn test_records_base32_string() {
+        let collector = Collector::default();
+        let id = Nulid::new().unwrap();
+
+        tracing::subscriber::with_default(collector.clone(), || {
+            let _span = tracing::info_span!("request", request_id = id.as_tracing_value());
+            tracing::info!(user_id = id.as_tracing_value());
+        });
+
+        let fields = collector.0.lock().unwrap().clone();
+        assert!(fields.contains(&format!("request_id={id}")));
+        assert!(fields.contains(&format!("user_id={id}")));
+    }
+
+    #[test]
+    fn test_span_id_deterministic() {
+        let id = Nulid::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+        assert_eq!(id.span_id_u64(), id.span_id_u64());
+        assert_eq!(id.to_span_id().into_u64(), id.span_id_u64().get());
+    }
+
+    #[test]
+    fn test_span_id_distinct() {
+        let ids: Vec<u64> = (0..1000)
+            .map(|_| Nulid::new().unwrap().span_id_u64().get())
+            .collect();
+        let mut unique = ids.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(unique.len(), ids.len());
+
+        // Both halves contribute.
+        let base = Nulid::from_u64_pair(1, 1);
+        assert_ne!(base.span_id_u64(), Nulid::from_u64_pair(2, 1).span_id_u64());
+        assert_ne!(base.span_id_u64(), Nulid::from_u64_pair(1, 2).span_id_u64());
+    }
+
+    #[test]
+    fn test_span_id_nil_is_non_zero() {
+        assert_eq!(Nulid::nil().span_id_u64(), NonZeroU64::MIN);
+    }
+}
@@ -0,0 +1,248 @@
+//! ULID interoperability for NULID.
+//!
+//! This module provides conversion between NULID and `ulid::Ulid` in two
+//! modes, for fleets migrating from `ulid-rs`:
+//!
+//! - **Bit-preserving** ([`Nulid::to_ulid`], [`Nulid::from_ulid`] and the
+//!   `From` impls): the 128 bits are reinterpreted unchanged, like the UUID
+//!   conversions. Round trips are exact in both directions, but each side
+//!   reads the other's timestamp wrongly.
+//! - **Time-preserving** ([`Nulid::to_ulid_preserving_time`],
+//!   [`Nulid::from_ulid_preserving_time`]): the timestamp is converted
+//!   between nanoseconds and milliseconds, so `Ulid::timestamp_ms()` and
+//!   [`Nulid::millis`] agree. The sub-millisecond nanoseconds travel in the
+//!   top 20 bits of the ULID's 80 random bits, so NULIDs round-trip exactly
+//!   and keep their sort order; the extra 20 bits of a foreign ULID's
+//!   entropy are truncated on the way in.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use ulid::Ulid;
+//!
+//! # fn main() -> nulid::Result<()> {
+//! let nulid = Nulid::new()?;
+//!
+//! // Same bits, different timestamp interpretation
+//! let ulid: Ulid = nulid.into();
+//! assert_eq!(Nulid::from(ulid), nulid);
+//!
+//! // Same instant, readable by ULID tooling
+//! let ulid = nulid.to_ulid_preserving_time()?;
+//! assert_eq!(u128::from(ulid.timestamp_ms()), nulid.millis());
+//! assert_eq!(Nulid::from_ulid_preserving_time(ulid), nulid);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{Error, Nulid, Result};
+
+impl Nulid {
+    /// Converts this NULID to a ULID with the same 128 bits.
+    ///
+    /// The conversion is lossless, but ULID tooling reads the NULID's
+    /// nanosecond timestamp as milliseconds. Use
+    /// [`to_ulid_preserving_time`](Self::to_ulid_preserving_time) when the
+    /// ULID's timestamp must be correct.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let nulid = Nulid::new()?;
+    /// let ulid = nulid.to_ulid();
+    /// assert_eq!(ulid.0, nulid.as_u128());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub const fn to_ulid(self) -> ulid::Ulid {
+        ulid::Ulid(self.as_u128())
+    }
+
+    /// Creates a NULID from a ULID with the same 128 bits.
+    ///
+    /// The conversion is lossless, but the ULID's millisecond timestamp and
+    /// entropy are read as NULID nanoseconds and random bits. Use
+    /// [`from_ulid_preserving_time`](Self::from_ulid_preserving_time) to keep
+    /// the ULID's timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    /// use ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from_parts(1_704_067_200_000, 42);
+    /// let nulid = Nulid::from_ulid(ulid);
+    /// assert_eq!(nulid.to_ulid(), ulid);
+    /// ```
+    #[must_use]
+    pub const fn from_ulid(ulid: ulid::Ulid) -> Self {
+        Self::from_u128(ulid.0)
+    }
+
+    /// Converts this NULID to a ULID for the same instant.
+    ///
+    /// The ULID's timestamp is this NULID's millisecond. Its 80 random bits
+    /// hold the sub-millisecond nanoseconds (20 bits) followed by this
+    /// NULID's 60 random bits, the layout of
+    /// [`to_ulid_string`](Self::to_ulid_string), so the conversion is
+    /// lossless and preserves sort order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Overflow` if the timestamp is beyond the ULID range
+    /// (48-bit milliseconds, year 10889).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let nulid = Nulid::from_nanos(1_704_067_200_123_456_789, 7);
+    /// let ulid = nulid.to_ulid_preserving_time()?;
+    /// assert_eq!(ulid.timestamp_ms(), 1_704_067_200_123);
+    /// assert_eq!(ulid.to_string(), nulid.to_ulid_string()?.as_str());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn to_ulid_preserving_time(self) -> Result<ulid::Ulid> {
+        match self.to_ulid_layout() {
+            Some(value) => Ok(ulid::Ulid(value)),
+            None => Err(Error::Overflow),
+        }
+    }
+
+    /// Creates a NULID for the same instant as a ULID.
+    ///
+    /// The inverse of [`to_ulid_preserving_time`](Self::to_ulid_preserving_time):
+    /// the top 20 bits of the ULID's entropy are read as sub-millisecond
+    /// nanoseconds and the low 60 bits become the random bits. For ULIDs
+    /// from other generators those 20 bits are random; values above 999,999
+    /// saturate, so the result stays within the ULID's millisecond and
+    /// ULIDs keep their relative order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    /// use ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from_parts(1_704_067_200_123, u128::MAX);
+    /// let nulid = Nulid::from_ulid_preserving_time(ulid);
+    /// assert_eq!(nulid.millis(), 1_704_067_200_123);
+    /// assert_eq!(nulid.random(), (1 << 60) - 1);
+    /// ```
+    #[must_use]
+    pub const fn from_ulid_preserving_time(ulid: ulid::Ulid) -> Self {
+        Self::from_ulid_layout(ulid.0)
+    }
+}
+
+impl From<ulid::Ulid> for Nulid {
+    /// Converts a ULID to a NULID with the same 128 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    /// use ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from_parts(1_704_067_200_000, 42);
+    /// let nulid: Nulid = ulid.into();
+    /// assert_eq!(nulid.as_u128(), ulid.0);
+    /// ```
+    fn from(ulid: ulid::Ulid) -> Self {
+        Self::from_ulid(ulid)
+    }
+}
+
+impl From<Nulid> for ulid::Ulid {
+    /// Converts a NULID to a ULID with the same 128 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    /// use ulid::Ulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let nulid = Nulid::new()?;
+    /// let ulid: Ulid = nulid.into();
+    /// assert_eq!(ulid.0, nulid.as_u128());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from(nulid: Nulid) -> Self {
+        nulid.to_ulid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn test_bit_preserving_round_trip() {
+        let nulid = Nulid::new().expect("Failed to create NULID");
+        let ulid: ulid::Ulid = nulid.into();
+        assert_eq!(ulid.0, nulid.as_u128());
+        assert_eq!(Nulid::from(ulid), nulid);
+
+        let ulid = ulid::Ulid::from_parts(1_704_067_200_000, 0xABCD);
+        assert_eq!(Nulid::from_ulid(ulid).to_ulid(), ulid);
+    }
+
+    #[test]
+    fn test_time_preserving_round_trip() {
+        let nulid = Nulid::from_nanos(1_704_067_200_123_456_789, 0x0FED_CBA9_8765_4321);
+        let ulid = nulid.to_ulid_preserving_time().expect("in ULID range");
+        assert_eq!(ulid.timestamp_ms(), 1_704_067_200_123);
+        assert_eq!(ulid.random() >> 60, 456_789);
+        assert_eq!(Nulid::from_ulid_preserving_time(ulid), nulid);
+    }
+
+    #[test]
+    fn test_time_preserving_matches_ulid_string() {
+        let nulid = Nulid::new().expect("Failed to create NULID");
+        let ulid = nulid.to_ulid_preserving_time().expect("in ULID range");
+        let string = nulid.to_ulid_string().expect("in ULID range");
+        assert_eq!(ulid.to_string(), string.as_str());
+    }
+
+    #[test]
+    fn test_time_preserving_preserves_order() {
+        let a = Nulid::from_nanos(1_704_067_200_000_000_001, u64::MAX);
+        let b = Nulid::from_nanos(1_704_067_200_000_000_002, 0);
+        let (ua, ub) = (
+            a.to_ulid_preserving_time().expect("in ULID range"),
+            b.to_ulid_preserving_time().expect("in ULID range"),
+        );
+        assert!(ua < ub);
+    }
+
+    #[test]
+    fn test_foreign_ulid_saturates_sub_millis() {
+        let low = ulid::Ulid::from_parts(1_000, 999_999 << 60);
+        let high = ulid::Ulid::from_parts(1_000, u128::MAX);
+        let (low, high) = (
+            Nulid::from_ulid_preserving_time(low),
+            Nulid::from_ulid_preserving_time(high),
+        );
+        assert_eq!(low.millis(), 1_000);
+        assert_eq!(high.millis(), 1_000);
+        assert_eq!(high.nanos(), 1_000_999_999);
+        assert!(low < high);
+    }
+
+    #[test]
+    fn test_time_preserving_overflow() {
+        assert_eq!(Nulid::MAX.to_ulid_preserving_time(), Err(Error::Overflow));
+    }
+}
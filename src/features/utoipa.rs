@@ -0,0 +1,83 @@
+//! `OpenAPI` schema support for NULID via `utoipa`.
+//!
+//! This module implements [`utoipa::PartialSchema`] and [`utoipa::ToSchema`]
+//! for NULID, so `Nulid` fields appear in generated `OpenAPI` documents as
+//! `type: string, format: nulid` with a 26-character pattern and an example.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use utoipa::PartialSchema;
+//!
+//! let schema = serde_json::to_value(Nulid::schema()).unwrap();
+//! assert_eq!(schema["type"], "string");
+//! assert_eq!(schema["format"], "nulid");
+//! ```
+
+use crate::Nulid;
+use std::borrow::Cow;
+use utoipa::openapi::RefOr;
+use utoipa::openapi::schema::{ObjectBuilder, Schema, SchemaFormat, Type};
+
+/// Example value shown in generated documentation.
+const EXAMPLE: &str = "01GZTV7EQ056J0E6N276XD6F3D";
+
+/// Pattern matching a canonical or lowercase NULID string.
+const PATTERN: &str = "^[0-7][0-9A-HJKMNP-TV-Za-hjkmnp-tv-z]{25}$";
+
+impl utoipa::PartialSchema for Nulid {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .format(Some(SchemaFormat::Custom("nulid".into())))
+            .description(Some(
+                "NULID: 26-character Crockford Base32, nanosecond-precision sortable identifier",
+            ))
+            .min_length(Some(26))
+            .max_length(Some(26))
+            .pattern(Some(PATTERN))
+            .examples([EXAMPLE])
+            .into()
+    }
+}
+
+impl utoipa::ToSchema for Nulid {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("Nulid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utoipa::{PartialSchema, ToSchema};
+
+    #[test]
+    fn test_schema_shape() {
+        let schema = serde_json::to_value(Nulid::schema()).expect("Failed to serialize schema");
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["format"], "nulid");
+        assert_eq!(schema["minLength"], 26);
+        assert_eq!(schema["maxLength"], 26);
+        assert_eq!(schema["pattern"], PATTERN);
+        assert_eq!(schema["examples"][0], EXAMPLE);
+        assert!(EXAMPLE.parse::<Nulid>().is_ok());
+        assert_eq!(Nulid::name(), "Nulid");
+    }
+
+    #[test]
+    fn test_schema_in_derived_struct() {
+        #[derive(utoipa::ToSchema)]
+        #[allow(dead_code)]
+        struct User {
+            id: Nulid,
+        }
+
+        let schema = serde_json::to_value(User::schema()).expect("Failed to serialize schema");
+        assert_eq!(
+            schema["properties"]["id"]["$ref"],
+            "#/components/schemas/Nulid"
+        );
+    }
+}
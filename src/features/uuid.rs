@@ -23,8 +23,17 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! [`Nulid::to_uuid_v7`] and [`Nulid::from_uuid_v7`] convert through the
+//! timestamp instead, for systems standardized on RFC 9562 version 7 UUIDs.
+
+use crate::{Error, Nulid, Result};
 
-use crate::Nulid;
+/// Nanoseconds per millisecond.
+const NANOS_PER_MILLI: u128 = 1_000_000;
+
+/// Bits of sub-millisecond fraction carried by a version 7 UUID (~61 ns steps).
+const UUID_V7_FRACTION_BITS: u32 = 14;
 
 impl Nulid {
     /// Converts this NULID to a UUID.
@@ -69,6 +78,102 @@ impl Nulid {
     pub const fn from_uuid(uuid: uuid::Uuid) -> Self {
         Self::from_u128(uuid.as_u128())
     }
+
+    /// Converts this NULID to an RFC 9562 version 7 UUID for the same instant.
+    ///
+    /// `unix_ts_ms` holds the milliseconds, and the sub-millisecond remainder
+    /// is kept as a 14-bit fraction (~61 ns steps) in `rand_a` and the top two
+    /// bits of `rand_b`, as RFC 9562 section 6.2 method 3 allows. The 60
+    /// random bits fill the rest of `rand_b`, so UUIDs sort like their NULIDs
+    /// except for IDs within the same ~61 ns step.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Overflow` if the timestamp is beyond the 48-bit
+    /// version 7 UUID millisecond range (year 10889).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let nulid = Nulid::from_nanos(1_704_067_200_000_500_000, 42);
+    /// let uuid = nulid.to_uuid_v7()?;
+    /// assert_eq!(uuid.get_version_num(), 7);
+    /// assert_eq!(uuid.as_u128() >> 80, 1_704_067_200_000);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn to_uuid_v7(self) -> Result<uuid::Uuid> {
+        let nanos = self.nanos();
+        let millis = nanos / NANOS_PER_MILLI;
+        if millis >> 48 != 0 {
+            return Err(Error::Overflow);
+        }
+        let fraction = ((nanos % NANOS_PER_MILLI) << UUID_V7_FRACTION_BITS) / NANOS_PER_MILLI;
+
+        Ok(uuid::Uuid::from_u128(
+            (millis << 80)
+                | (0x7 << 76)
+                | ((fraction >> 2) << 64)
+                | (0b10 << 62)
+                | ((fraction & 0b11) << 60)
+                | self.random() as u128,
+        ))
+    }
+
+    /// Creates a NULID from an RFC 9562 version 7 UUID for the same instant.
+    ///
+    /// The inverse of [`to_uuid_v7`](Self::to_uuid_v7): the timestamp is the
+    /// UUID's milliseconds plus its 14-bit sub-millisecond fraction, and the
+    /// low 60 bits of `rand_b` become the random bits. UUIDs round-trip
+    /// exactly; NULIDs come back rounded down to a ~61 ns step. UUIDs from
+    /// other generators keep their time order, since their `rand_a` is read
+    /// as the fraction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidUuidVersion` if the UUID is not version 7.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    /// use uuid::Uuid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let uuid = Uuid::parse_str("018cc251-f400-7800-8000-00000000002a").unwrap();
+    /// let nulid = Nulid::from_uuid_v7(uuid)?;
+    /// assert_eq!(nulid.nanos(), 1_704_067_200_000_500_000);
+    /// assert_eq!(nulid.random(), 42);
+    /// assert_eq!(nulid.to_uuid_v7()?, uuid);
+    ///
+    /// assert!(Nulid::from_uuid_v7(Uuid::new_v4()).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn from_uuid_v7(uuid: uuid::Uuid) -> Result<Self> {
+        let value = uuid.as_u128();
+        let version = (value >> 76) & 0xF;
+        if version != 7 {
+            return Err(Error::InvalidUuidVersion {
+                found: version as usize,
+            });
+        }
+
+        let millis = value >> 80;
+        let fraction = (((value >> 64) & 0xFFF) << 2) | ((value >> 60) & 0b11);
+        // Rounding up makes `to_uuid_v7` recover the same fraction.
+        let sub_millis = (fraction * NANOS_PER_MILLI).div_ceil(1 << UUID_V7_FRACTION_BITS);
+        #[allow(clippy::cast_possible_truncation)] // masked to 60 bits
+        let random = (value & ((1 << Self::RANDOM_BITS) - 1)) as u64;
+
+        Ok(Self::from_nanos(
+            millis * NANOS_PER_MILLI + sub_millis,
+            random,
+        ))
+    }
 }
 
 impl From<uuid::Uuid> for Nulid {
@@ -160,4 +265,64 @@ mod tests {
         let uuid = nulid.to_uuid();
         assert_eq!(uuid.as_u128(), test_value);
     }
+
+    #[test]
+    fn test_uuid_v7_layout() {
+        // 2024-01-01T00:00:00.000500000Z: half a millisecond past the second.
+        let nulid = Nulid::from_nanos(1_704_067_200_000_500_000, 0x0ABC_DEF0_1234_5678);
+        let value = nulid.to_uuid_v7().unwrap().as_u128();
+        assert_eq!(value >> 80, 1_704_067_200_000);
+        assert_eq!((value >> 76) & 0xF, 7);
+        assert_eq!((value >> 62) & 0b11, 0b10);
+        // 0.5 ms is fraction 8192 of 16384: rand_a = 2048, low bits 0.
+        assert_eq!((value >> 64) & 0xFFF, 2048);
+        assert_eq!((value >> 60) & 0b11, 0);
+        assert_eq!(value & ((1 << 60) - 1), 0x0ABC_DEF0_1234_5678);
+
+        let uuid = nulid.to_uuid_v7().unwrap();
+        assert_eq!(uuid.get_version_num(), 7);
+        assert_eq!(uuid.get_variant(), uuid::Variant::RFC4122);
+    }
+
+    #[test]
+    fn test_uuid_v7_round_trip() {
+        let nulid = Nulid::new().expect("Failed to create NULID");
+        let uuid = nulid.to_uuid_v7().unwrap();
+        let back = Nulid::from_uuid_v7(uuid).unwrap();
+        assert_eq!(back.millis(), nulid.millis());
+        assert_eq!(back.random(), nulid.random());
+        assert!(back.nanos() <= nulid.nanos());
+        assert!(nulid.nanos() - back.nanos() < 62);
+        assert_eq!(back.to_uuid_v7().unwrap(), uuid);
+
+        for fraction in [0, 1, 8191, 16_383] {
+            let value = (1_704_067_200_000 << 80)
+                | (0x7 << 76)
+                | ((fraction >> 2) << 64)
+                | (0b10 << 62)
+                | ((fraction & 0b11) << 60)
+                | 0x63;
+            let uuid = uuid::Uuid::from_u128(value);
+            assert_eq!(
+                Nulid::from_uuid_v7(uuid).unwrap().to_uuid_v7().unwrap(),
+                uuid
+            );
+        }
+    }
+
+    #[test]
+    fn test_uuid_v7_preserves_order() {
+        let earlier = Nulid::from_nanos(1_704_067_200_000_000_000, u64::MAX);
+        let later = Nulid::from_nanos(1_704_067_200_000_000_100, 0);
+        assert!(earlier.to_uuid_v7().unwrap() < later.to_uuid_v7().unwrap());
+    }
+
+    #[test]
+    fn test_uuid_v7_errors() {
+        assert_eq!(Nulid::MAX.to_uuid_v7(), Err(Error::Overflow));
+        assert_eq!(
+            Nulid::from_uuid_v7(uuid::Uuid::new_v4()),
+            Err(Error::InvalidUuidVersion { found: 4 })
+        );
+    }
 }
@@ -0,0 +1,56 @@
+//! WebAssembly support for NULID.
+//!
+//! Enabling the `wasm` feature makes [`Nulid::new`] work on
+//! `wasm32-unknown-unknown`: the wall-clock base comes from `Date.now()`,
+//! elapsed time from `performance.now()`, and entropy from `crypto.getRandomValues`
+//! through `getrandom`'s `wasm_js` backend. Other targets are unaffected.
+//!
+//! The functions below are exported with `wasm-bindgen`, so a frontend built
+//! with `wasm-pack` can generate and validate the same IDs as the backend:
+//!
+//! ```js
+//! import init, { generate, parse } from "./pkg/nulid.js";
+//!
+//! await init();
+//! const id = generate();          // "01GZTV7EQ056J0E6N276XD6F3D"
+//! const canonical = parse(id.toLowerCase());
+//! ```
+
+use wasm_bindgen::prelude::*;
+
+use crate::Nulid;
+
+/// Generates a new NULID and returns its canonical Base32 string.
+///
+/// # Errors
+///
+/// Throws a JavaScript `Error` if the clock or the random source is unavailable.
+#[wasm_bindgen]
+pub fn generate() -> Result<String, JsError> {
+    Ok(Nulid::new()?.to_string())
+}
+
+/// Parses a NULID string and returns it in canonical uppercase form.
+///
+/// Accepts the same input as [`Nulid::from_str`](core::str::FromStr::from_str),
+/// including lowercase.
+///
+/// # Errors
+///
+/// Throws a JavaScript `Error` describing why the input is not a valid NULID.
+#[wasm_bindgen]
+pub fn parse(s: &str) -> Result<String, JsError> {
+    Ok(s.parse::<Nulid>()?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_parses_back() {
+        let id = generate().expect("generate");
+        assert_eq!(id.len(), 26);
+        assert_eq!(parse(&id.to_lowercase()).expect("valid NULID"), id);
+    }
+}
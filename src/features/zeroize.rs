@@ -0,0 +1,66 @@
+//! `zeroize` support for NULID.
+//!
+//! This module implements `Zeroize` for [`Nulid`] and for the encoded form
+//! [`NulidString`], for deployments that treat IDs as secrets (session
+//! tokens, invite links) and must scrub them from memory once used. Both
+//! are reset to the nil ID through volatile writes the compiler cannot
+//! elide.
+//!
+//! Both types are `Copy`, so they cannot implement `ZeroizeOnDrop`: every
+//! copy would need scrubbing. Hold secret IDs in `zeroize::Zeroizing`, which
+//! zeroizes its contents on drop, or derive `Id` with `#[id(no_copy,
+//! zeroize)]` for a wrapper type that scrubs itself.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use zeroize::{Zeroize, Zeroizing};
+//!
+//! # fn main() -> nulid::Result<()> {
+//! let mut id = Nulid::new()?;
+//! id.zeroize();
+//! assert!(id.is_nil());
+//!
+//! let token = Zeroizing::new(Nulid::new()?.to_arraystring());
+//! assert_eq!(token.len(), 26);
+//! // `token` is overwritten when it goes out of scope
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{Nulid, NulidString};
+use zeroize::DefaultIsZeroes;
+
+// `Default` is the nil ID for both, so zeroizing leaves a valid value.
+impl DefaultIsZeroes for Nulid {}
+
+impl DefaultIsZeroes for NulidString {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zeroize::{Zeroize, Zeroizing};
+
+    #[test]
+    fn test_zeroize() {
+        let mut id = Nulid::from_nanos(1_000, 42);
+        id.zeroize();
+        assert_eq!(id, Nulid::ZERO);
+
+        let mut encoded = Nulid::MAX.to_arraystring();
+        encoded.zeroize();
+        assert_eq!(encoded, Nulid::ZERO.to_arraystring());
+        assert_eq!(encoded.parse::<Nulid>().unwrap(), Nulid::ZERO);
+
+        let mut ids = [Nulid::MAX; 3];
+        ids.zeroize();
+        assert_eq!(ids, [Nulid::ZERO; 3]);
+    }
+
+    #[test]
+    fn test_zeroizing_wrapper() {
+        let token = Zeroizing::new(Nulid::MAX);
+        assert_eq!(*token, Nulid::MAX);
+    }
+}
@@ -50,6 +50,122 @@ impl Clock for SystemClock {
     }
 }
 
+/// Hybrid clock anchored on wall time and advanced by a monotonic clock.
+///
+/// The wall-clock time is read exactly once, when the clock is created.
+/// Every subsequent reading adds the elapsed [`Instant`](std::time::Instant)
+/// time to that anchor, so NTP step adjustments (or any other change to the
+/// system clock) after construction never move timestamps backward or forward.
+/// Timestamps stay approximately correct, drifting only as far as the monotonic
+/// clock drifts from wall time.
+///
+/// Unlike [`SystemClock`], which shares one process-wide anchor, each
+/// `HybridClock` holds its own anchor.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::generator::{Clock, HybridClock};
+///
+/// # fn main() -> nulid::Result<()> {
+/// let clock = HybridClock::new()?;
+/// let t1 = clock.now_nanos()?;
+/// let t2 = clock.now_nanos()?;
+/// assert!(t2 >= t1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct HybridClock {
+    base_wall_nanos: u128,
+    base_instant: std::time::Instant,
+}
+
+impl HybridClock {
+    /// Creates a hybrid clock anchored on the current wall-clock time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SystemTimeError` if the system time is before Unix epoch.
+    pub fn new() -> Result<Self> {
+        let base_instant = std::time::Instant::now();
+        let duration = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|_| Error::SystemTimeError)?;
+
+        Ok(Self::anchored_at(duration.as_nanos(), base_instant))
+    }
+
+    /// Creates a hybrid clock anchored on the given wall-clock time and instant.
+    ///
+    /// Readings return `base_wall_nanos` plus the time elapsed since `base_instant`.
+    #[must_use]
+    pub const fn anchored_at(base_wall_nanos: u128, base_instant: std::time::Instant) -> Self {
+        Self {
+            base_wall_nanos,
+            base_instant,
+        }
+    }
+
+    /// Returns the wall-clock anchor in nanoseconds since Unix epoch.
+    #[must_use]
+    pub const fn base_nanos(&self) -> u128 {
+        self.base_wall_nanos
+    }
+}
+
+impl Clock for HybridClock {
+    fn now_nanos(&self) -> Result<u128> {
+        Ok(self.base_wall_nanos + self.base_instant.elapsed().as_nanos())
+    }
+}
+
+/// System clock that reuses a reading for up to a fixed resolution.
+///
+/// Reads [`time::now_nanos_cached`](crate::time::now_nanos_cached), so IDs
+/// generated within `resolution` of each other share a timestamp and are
+/// ordered by the generator's increment. Use it where clock reads dominate
+/// generation cost and sub-resolution timestamps are not needed.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+/// use nulid::generator::{CachedClock, Generator};
+///
+/// # fn main() -> nulid::Result<()> {
+/// let generator = Generator::with_clock(CachedClock::new(Duration::from_micros(1)));
+/// let id1 = generator.generate()?;
+/// let id2 = generator.generate()?;
+/// assert!(id2 > id1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CachedClock {
+    resolution: core::time::Duration,
+}
+
+impl CachedClock {
+    /// Creates a clock that reuses each reading for up to `resolution`.
+    #[must_use]
+    pub const fn new(resolution: core::time::Duration) -> Self {
+        Self { resolution }
+    }
+
+    /// Returns how long a reading is reused.
+    #[must_use]
+    pub const fn resolution(&self) -> core::time::Duration {
+        self.resolution
+    }
+}
+
+impl Clock for CachedClock {
+    fn now_nanos(&self) -> Result<u128> {
+        crate::time::now_nanos_cached(self.resolution)
+    }
+}
+
 /// Mock clock for testing with interior mutability.
 ///
 /// Uses `AtomicU64` so the clock can be modified while the generator
@@ -158,6 +274,11 @@ impl Rng for CryptoRng {
 ///
 /// Uses internal `Mutex` for interior mutability since `StdRng` requires `&mut self`.
 ///
+/// The RNG counts its draws, so its position in the sequence can be saved
+/// as a `(seed, draws)` pair and restored with
+/// [`at_position`](Self::at_position). With the `serde` feature, it
+/// serializes as that pair.
+///
 /// # Examples
 ///
 /// ```
@@ -170,7 +291,15 @@ impl Rng for CryptoRng {
 /// assert_eq!(rng1.random_u64(), rng2.random_u64());
 /// ```
 pub struct SeededRng {
-    rng: Mutex<rand::rngs::StdRng>,
+    seed: u64,
+    state: Mutex<SeededState>,
+}
+
+/// Mutable state of a [`SeededRng`], guarded by its mutex.
+struct SeededState {
+    rng: rand::rngs::StdRng,
+    /// Number of values drawn since seeding.
+    draws: u64,
 }
 
 impl SeededRng {
@@ -179,36 +308,79 @@ impl SeededRng {
     pub fn new(seed: u64) -> Self {
         use rand::SeedableRng;
         Self {
-            rng: Mutex::new(rand::rngs::StdRng::seed_from_u64(seed)),
+            seed,
+            state: Mutex::new(SeededState {
+                rng: rand::rngs::StdRng::seed_from_u64(seed),
+                draws: 0,
+            }),
+        }
+    }
+
+    /// Creates a seeded RNG that has already drawn `draws` values.
+    ///
+    /// The next value is the one a fresh `SeededRng::new(seed)` would return
+    /// after `draws` calls. Skipping ahead takes time linear in `draws`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::generator::{Rng, SeededRng};
+    ///
+    /// let rng = SeededRng::new(42);
+    /// rng.random_u64();
+    /// let resumed = SeededRng::at_position(rng.seed(), rng.draws());
+    /// assert_eq!(resumed.random_u64(), rng.random_u64());
+    /// ```
+    #[must_use]
+    pub fn at_position(seed: u64, draws: u64) -> Self {
+        let rng = Self::new(seed);
+        for _ in 0..draws {
+            rng.next();
         }
+        rng
+    }
+
+    /// Returns the seed this RNG was created with.
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the number of values drawn so far.
+    #[must_use]
+    pub fn draws(&self) -> u64 {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .draws
+    }
+
+    #[allow(clippy::expect_used)]
+    fn next(&self) -> u64 {
+        use rand::RngCore;
+        let mut state = self.state.lock().expect("SeededRng mutex poisoned");
+        state.draws += 1;
+        state.rng.next_u64()
     }
 }
 
 impl core::fmt::Debug for SeededRng {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("SeededRng").finish_non_exhaustive()
+        f.debug_struct("SeededRng")
+            .field("seed", &self.seed)
+            .finish_non_exhaustive()
     }
 }
 
 impl Rng for SeededRng {
-    #[allow(clippy::expect_used)]
     fn random_u64(&self) -> u64 {
-        use rand::RngCore;
-        self.rng
-            .lock()
-            .expect("SeededRng mutex poisoned")
-            .next_u64()
+        self.next()
     }
 }
 
 impl Rng for &SeededRng {
-    #[allow(clippy::expect_used)]
     fn random_u64(&self) -> u64 {
-        use rand::RngCore;
-        self.rng
-            .lock()
-            .expect("SeededRng mutex poisoned")
-            .next_u64()
+        self.next()
     }
 }
 
@@ -390,7 +562,48 @@ pub struct Generator<C: Clock = SystemClock, R: Rng = CryptoRng, N: NodeId = NoN
     clock: C,
     rng: R,
     node_id: N,
-    state: Mutex<Option<Nulid>>,
+    timestamp_batch: u32,
+    sequence_bits: u32,
+    state: Mutex<GeneratorState>,
+}
+
+/// A saved [`Generator`] state and configuration.
+///
+/// Taken with [`Generator::snapshot`] and applied with
+/// [`Generator::with_snapshot`], so a simulation can stop and later resume
+/// generation exactly where it left off. The clock, RNG and node ID are not
+/// included: build the generator with the same node ID, and save
+/// [`MockClock`] and [`SeededRng`] alongside (all three implement serde's
+/// traits with the `serde` feature).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct GeneratorSnapshot {
+    /// The last generated NULID.
+    pub last: Option<Nulid>,
+    /// Generations left in the current timestamp batch.
+    pub batch_remaining: u32,
+    /// See [`Generator::with_timestamp_batch`].
+    pub timestamp_batch: u32,
+    /// See [`Generator::with_sequence_bits`].
+    pub sequence_bits: u32,
+}
+
+/// Mutable state of a [`Generator`], guarded by its mutex.
+#[derive(Debug, Clone, Copy)]
+struct GeneratorState {
+    /// The last generated NULID.
+    last: Option<Nulid>,
+    /// Generations left before the clock is read again (batch timestamp mode).
+    batch_remaining: u32,
+}
+
+impl GeneratorState {
+    const fn new() -> Self {
+        Self {
+            last: None,
+            batch_remaining: 0,
+        }
+    }
 }
 
 // Production constructors for single-node use
@@ -412,7 +625,9 @@ impl Generator<SystemClock, CryptoRng, NoNodeId> {
             clock: SystemClock,
             rng: CryptoRng,
             node_id: NoNodeId,
-            state: Mutex::new(None),
+            timestamp_batch: 1,
+            sequence_bits: 0,
+            state: Mutex::new(GeneratorState::new()),
         }
     }
 }
@@ -446,7 +661,39 @@ impl Generator<SystemClock, CryptoRng, WithNodeId> {
             clock: SystemClock,
             rng: CryptoRng,
             node_id: WithNodeId::new(node_id),
-            state: Mutex::new(None),
+            timestamp_batch: 1,
+            sequence_bits: 0,
+            state: Mutex::new(GeneratorState::new()),
+        }
+    }
+}
+
+// Production constructor with a custom clock source
+impl<C: Clock> Generator<C, CryptoRng, NoNodeId> {
+    /// Creates a generator with a custom clock source and cryptographic RNG.
+    ///
+    /// Use this to select a clock other than [`SystemClock`], such as
+    /// [`HybridClock`] for immunity to NTP steps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::generator::{Generator, HybridClock};
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let generator = Generator::with_clock(HybridClock::new()?);
+    /// let id = generator.generate()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn with_clock(clock: C) -> Self {
+        Self {
+            clock,
+            rng: CryptoRng,
+            node_id: NoNodeId,
+            timestamp_batch: 1,
+            sequence_bits: 0,
+            state: Mutex::new(GeneratorState::new()),
         }
     }
 }
@@ -469,7 +716,9 @@ impl<C: Clock, R: Rng, N: NodeId> Generator<C, R, N> {
             clock,
             rng,
             node_id: N::default(),
-            state: Mutex::new(None),
+            timestamp_batch: 1,
+            sequence_bits: 0,
+            state: Mutex::new(GeneratorState::new()),
         }
     }
 
@@ -489,7 +738,9 @@ impl<C: Clock, R: Rng, N: NodeId> Generator<C, R, N> {
             clock,
             rng,
             node_id,
-            state: Mutex::new(None),
+            timestamp_batch: 1,
+            sequence_bits: 0,
+            state: Mutex::new(GeneratorState::new()),
         }
     }
 
@@ -526,43 +777,62 @@ impl<C: Clock, R: Rng, N: NodeId> Generator<C, R, N> {
     /// # }
     /// ```
     pub fn generate(&self) -> Result<Nulid> {
+        if self.timestamp_batch > 1 {
+            return self.generate_batched();
+        }
+
         let timestamp = self.clock.now_nanos()?;
+        let candidate = Nulid::from_nanos(timestamp, self.random_bits());
 
-        // Generate random bits with optional node ID
-        // Layout with node ID: [node_id: 16 bits][random: 44 bits] = 60 bits total
-        // Layout without node ID: [random: 60 bits]
-        let random_bits = self.node_id.get().map_or_else(
-            || self.rng.random_u64() & ((1u64 << 60) - 1),
-            |node_id| {
-                let random_44 = self.rng.random_u64() & ((1u64 << 44) - 1);
-                (u64::from(node_id) << 44) | random_44
-            },
-        );
+        let mut state = self.state.lock().map_err(|_| Error::MutexPoisoned)?;
+        let next = self.next_after(state.last, candidate)?;
+        state.last = Some(next);
+        drop(state);
 
-        let candidate = Nulid::from_nanos(timestamp, random_bits);
+        Ok(next)
+    }
 
+    /// Batch timestamp mode: reads the clock once per `timestamp_batch`
+    /// generations and increments the previous ID in between.
+    fn generate_batched(&self) -> Result<Nulid> {
         let mut state = self.state.lock().map_err(|_| Error::MutexPoisoned)?;
 
-        let result = match *state {
-            None => {
-                *state = Some(candidate);
-                Ok(candidate)
-            }
-            Some(last_id) => {
-                if candidate > last_id {
-                    *state = Some(candidate);
-                    Ok(candidate)
+        let next = match state.last {
+            Some(last_id) if state.batch_remaining > 0 => {
+                state.batch_remaining -= 1;
+                if self.sequence_bits > 0 {
+                    next_in_sequence(last_id, self.sequence_bits)?
                 } else {
-                    // Clock skew or same nanosecond with lower random
-                    let incremented = last_id.increment().ok_or(Error::Overflow)?;
-                    *state = Some(incremented);
-                    Ok(incremented)
+                    last_id.increment().ok_or(Error::Overflow)?
                 }
             }
+            last => {
+                let timestamp = self.clock.now_nanos()?;
+                let candidate = Nulid::from_nanos(timestamp, self.random_bits());
+                state.batch_remaining = self.timestamp_batch - 1;
+                self.next_after(last, candidate)?
+            }
         };
 
+        state.last = Some(next);
         drop(state);
-        result
+
+        Ok(next)
+    }
+
+    /// Random bits for a candidate ID, with the sequence counter cleared.
+    fn random_bits(&self) -> u64 {
+        random_bits(&self.rng, &self.node_id) & !sequence_mask(self.sequence_bits)
+    }
+
+    /// Picks the ID that follows `last`, given a fresh candidate.
+    fn next_after(&self, last: Option<Nulid>, candidate: Nulid) -> Result<Nulid> {
+        match last {
+            Some(last_id) if self.sequence_bits > 0 && candidate.nanos() <= last_id.nanos() => {
+                next_in_sequence(last_id, self.sequence_bits)
+            }
+            last => next_monotonic(last, candidate),
+        }
     }
 
     /// Returns the last generated NULID, if any.
@@ -583,7 +853,7 @@ impl<C: Clock, R: Rng, N: NodeId> Generator<C, R, N> {
     /// ```
     #[must_use]
     pub fn last(&self) -> Option<Nulid> {
-        self.state.lock().ok().and_then(|s| *s)
+        self.state.lock().ok().and_then(|s| s.last)
     }
 
     /// Resets the generator state.
@@ -608,8 +878,156 @@ impl<C: Clock, R: Rng, N: NodeId> Generator<C, R, N> {
     /// ```
     pub fn reset(&self) {
         if let Ok(mut state) = self.state.lock() {
-            *state = None;
+            *state = GeneratorState::new();
+        }
+    }
+
+    /// Enables batch timestamp mode.
+    ///
+    /// The clock is read once per `batch_size` generations; the remaining IDs
+    /// in each batch share that timestamp and are produced by incrementing the
+    /// previous ID. This trades timestamp freshness for fewer clock reads and
+    /// RNG calls. IDs remain strictly monotonic.
+    ///
+    /// A `batch_size` of `0` or `1` reads the clock on every generation
+    /// (the default behavior).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Generator;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let generator = Generator::new().with_timestamp_batch(64);
+    /// assert_eq!(generator.timestamp_batch(), 64);
+    ///
+    /// let id1 = generator.generate()?;
+    /// let id2 = generator.generate()?;
+    /// assert_eq!(id2.as_u128(), id1.as_u128() + 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub const fn with_timestamp_batch(mut self, batch_size: u32) -> Self {
+        self.timestamp_batch = if batch_size == 0 { 1 } else { batch_size };
+        self
+    }
+
+    /// Returns the number of generations that share one clock reading.
+    ///
+    /// This is `1` unless batch timestamp mode is enabled with
+    /// [`with_timestamp_batch`](Self::with_timestamp_batch).
+    #[must_use]
+    pub const fn timestamp_batch(&self) -> u32 {
+        self.timestamp_batch
+    }
+
+    /// Enables sequence counter mode with the low `bits` of the random field.
+    ///
+    /// The first ID in each nanosecond has sequence `0`; every further ID
+    /// with the same timestamp takes the next sequence number, read back
+    /// with [`Nulid::sequence`]. Within one nanosecond, consumers can then
+    /// order IDs from this generator by their counter alone. When the counter
+    /// is exhausted, generation moves on to the next nanosecond. With a
+    /// regressing clock, IDs keep counting in the last nanosecond used.
+    ///
+    /// The counter takes bits away from the random part: with a node ID,
+    /// `44 - bits` random bits remain, otherwise `60 - bits`. A `bits` of `0`
+    /// disables the mode (the default).
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidArgument`: If `bits` is greater than [`MAX_SEQUENCE_BITS`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::generator::{Generator, MockClock, NoNodeId, SeededRng};
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let clock = MockClock::new(1_000);
+    /// let generator = Generator::<_, _, NoNodeId>::with_deps(&clock, SeededRng::new(1))
+    ///     .with_sequence_bits(12)?;
+    ///
+    /// let ids = [generator.generate()?, generator.generate()?, generator.generate()?];
+    /// assert_eq!(ids.map(|id| id.sequence(12)), [Some(0), Some(1), Some(2)]);
+    ///
+    /// clock.set(2_000);
+    /// assert_eq!(generator.generate()?.sequence(12), Some(0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_sequence_bits(mut self, bits: u32) -> Result<Self> {
+        if bits > MAX_SEQUENCE_BITS {
+            return Err(Error::InvalidArgument {
+                reason: "sequence counter cannot exceed 32 bits",
+            });
         }
+        self.sequence_bits = bits;
+        Ok(self)
+    }
+
+    /// Saves the generator's state and configuration.
+    ///
+    /// # Errors
+    ///
+    /// - `MutexPoisoned`: If internal mutex is poisoned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::generator::{Generator, MockClock, NoNodeId, SeededRng};
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let clock = MockClock::new(1_000);
+    /// let generator = Generator::<_, _, NoNodeId>::with_deps(&clock, SeededRng::new(7));
+    /// generator.generate()?;
+    /// let snapshot = generator.snapshot()?;
+    ///
+    /// let resumed = Generator::<_, _, NoNodeId>::with_deps(&clock, SeededRng::new(7))
+    ///     .with_snapshot(snapshot)?;
+    /// assert_eq!(resumed.last(), generator.last());
+    /// assert!(resumed.generate()? > snapshot.last.unwrap());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn snapshot(&self) -> Result<GeneratorSnapshot> {
+        let state = *self.state.lock().map_err(|_| Error::MutexPoisoned)?;
+        Ok(GeneratorSnapshot {
+            last: state.last,
+            batch_remaining: state.batch_remaining,
+            timestamp_batch: self.timestamp_batch,
+            sequence_bits: self.sequence_bits,
+        })
+    }
+
+    /// Restores a state and configuration saved with [`snapshot`](Self::snapshot).
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidArgument`: If the snapshot's `sequence_bits` is greater than
+    ///   [`MAX_SEQUENCE_BITS`]
+    pub fn with_snapshot(self, snapshot: GeneratorSnapshot) -> Result<Self> {
+        let mut generator = self
+            .with_timestamp_batch(snapshot.timestamp_batch)
+            .with_sequence_bits(snapshot.sequence_bits)?;
+        let state = generator
+            .state
+            .get_mut()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        *state = GeneratorState {
+            last: snapshot.last,
+            batch_remaining: snapshot.batch_remaining.min(generator.timestamp_batch - 1),
+        };
+        Ok(generator)
+    }
+
+    /// Returns the width of the sequence counter, or `0` if it is disabled.
+    ///
+    /// Pass it to [`Nulid::sequence`] to read the counter of a generated ID.
+    #[must_use]
+    pub const fn sequence_bits(&self) -> u32 {
+        self.sequence_bits
     }
 
     /// Returns the node ID if configured.
@@ -632,6 +1050,257 @@ impl<C: Clock, R: Rng, N: NodeId> Generator<C, R, N> {
     }
 }
 
+// ============================================================================
+// Local Generator
+// ============================================================================
+
+/// Single-threaded NULID generator without locking.
+///
+/// `LocalGenerator` uses the same increment-on-skew algorithm as [`Generator`],
+/// but keeps its state in a [`Cell`](core::cell::Cell) instead of a `Mutex`.
+/// This removes the locking overhead from hot loops such as parsers and batch
+/// importers, at the cost of being `!Sync`: it cannot be shared between threads.
+///
+/// Use one `LocalGenerator` per thread (or per task), or [`Generator`] when the
+/// generator must be shared.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::LocalGenerator;
+///
+/// # fn main() -> nulid::Result<()> {
+/// let generator = LocalGenerator::new();
+///
+/// let id1 = generator.generate()?;
+/// let id2 = generator.generate()?;
+/// assert!(id2 > id1);
+/// # Ok(())
+/// # }
+/// ```
+pub struct LocalGenerator<C: Clock = SystemClock, R: Rng = CryptoRng, N: NodeId = NoNodeId> {
+    clock: C,
+    rng: R,
+    node_id: N,
+    state: core::cell::Cell<Option<Nulid>>,
+}
+
+impl LocalGenerator<SystemClock, CryptoRng, NoNodeId> {
+    /// Creates a new local generator for production use (single node).
+    ///
+    /// Uses system clock and cryptographic RNG.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::LocalGenerator;
+    ///
+    /// let generator = LocalGenerator::new();
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            clock: SystemClock,
+            rng: CryptoRng,
+            node_id: NoNodeId,
+            state: core::cell::Cell::new(None),
+        }
+    }
+}
+
+impl Default for LocalGenerator<SystemClock, CryptoRng, NoNodeId> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalGenerator<SystemClock, CryptoRng, WithNodeId> {
+    /// Creates a new local generator with node ID for distributed deployments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::generator::{CryptoRng, LocalGenerator, SystemClock, WithNodeId};
+    ///
+    /// let generator = LocalGenerator::<SystemClock, CryptoRng, WithNodeId>::with_node_id(1);
+    /// assert_eq!(generator.node_id(), Some(1));
+    /// ```
+    #[must_use]
+    pub const fn with_node_id(node_id: u16) -> Self {
+        Self {
+            clock: SystemClock,
+            rng: CryptoRng,
+            node_id: WithNodeId::new(node_id),
+            state: core::cell::Cell::new(None),
+        }
+    }
+}
+
+impl<C: Clock, R: Rng, N: NodeId> LocalGenerator<C, R, N> {
+    /// Creates a local generator with custom clock and RNG (for testing).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::generator::{LocalGenerator, MockClock, SeededRng, NoNodeId};
+    ///
+    /// let clock = MockClock::new(1_000_000_000);
+    /// let rng = SeededRng::new(42);
+    /// let generator = LocalGenerator::<_, _, NoNodeId>::with_deps(&clock, &rng);
+    /// ```
+    pub fn with_deps(clock: C, rng: R) -> Self {
+        Self {
+            clock,
+            rng,
+            node_id: N::default(),
+            state: core::cell::Cell::new(None),
+        }
+    }
+
+    /// Creates a local generator with custom clock, RNG, and node ID (for testing).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::generator::{LocalGenerator, MockClock, SeededRng, WithNodeId};
+    ///
+    /// let clock = MockClock::new(1_000_000_000);
+    /// let rng = SeededRng::new(42);
+    /// let generator = LocalGenerator::with_deps_and_node_id(&clock, &rng, WithNodeId::new(1));
+    /// ```
+    pub const fn with_deps_and_node_id(clock: C, rng: R, node_id: N) -> Self {
+        Self {
+            clock,
+            rng,
+            node_id,
+            state: core::cell::Cell::new(None),
+        }
+    }
+
+    /// Generates a new NULID with monotonicity guarantee.
+    ///
+    /// Follows the same increment-on-skew algorithm as [`Generator::generate`].
+    ///
+    /// # Errors
+    ///
+    /// - `Overflow`: If increment would overflow 128-bit space
+    /// - `SystemTimeError`: If clock read fails
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::LocalGenerator;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let generator = LocalGenerator::new();
+    /// let id = generator.generate()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn generate(&self) -> Result<Nulid> {
+        let timestamp = self.clock.now_nanos()?;
+        let candidate = Nulid::from_nanos(timestamp, random_bits(&self.rng, &self.node_id));
+
+        let next = next_monotonic(self.state.get(), candidate)?;
+        self.state.set(Some(next));
+
+        Ok(next)
+    }
+
+    /// Returns the last generated NULID, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::LocalGenerator;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let generator = LocalGenerator::new();
+    /// assert!(generator.last().is_none());
+    ///
+    /// let id = generator.generate()?;
+    /// assert_eq!(generator.last(), Some(id));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub const fn last(&self) -> Option<Nulid> {
+        self.state.get()
+    }
+
+    /// Resets the generator state.
+    ///
+    /// This clears the last generated NULID, allowing the generator
+    /// to start fresh. This is primarily useful for testing.
+    pub fn reset(&self) {
+        self.state.set(None);
+    }
+
+    /// Returns the node ID if configured.
+    #[must_use]
+    pub fn node_id(&self) -> Option<u16> {
+        self.node_id.get()
+    }
+}
+
+// ============================================================================
+// Shared Generation Helpers
+// ============================================================================
+
+/// Produces the 60 random bits for a candidate ID, embedding the node ID if present.
+///
+/// Layout with node ID: `[node_id: 16 bits][random: 44 bits]`.
+/// Layout without node ID: `[random: 60 bits]`.
+#[inline]
+pub(crate) fn random_bits<R: Rng, N: NodeId>(rng: &R, node_id: &N) -> u64 {
+    node_id.get().map_or_else(
+        || rng.random_u64() & ((1u64 << 60) - 1),
+        |node_id| {
+            let random_44 = rng.random_u64() & ((1u64 << 44) - 1);
+            (u64::from(node_id) << 44) | random_44
+        },
+    )
+}
+
+/// Widest sequence counter accepted by [`Generator::with_sequence_bits`].
+pub const MAX_SEQUENCE_BITS: u32 = 32;
+
+/// Mask for the low `bits` of the random field.
+#[inline]
+const fn sequence_mask(bits: u32) -> u64 {
+    (1u64 << bits) - 1
+}
+
+/// Returns the ID after `last` in sequence counter mode.
+///
+/// Bumps the counter, or once it is exhausted, moves to the next nanosecond
+/// with the counter reset and the remaining random bits kept.
+fn next_in_sequence(last: Nulid, bits: u32) -> Result<Nulid> {
+    let mask = sequence_mask(bits);
+    if last.random() & mask < mask {
+        return last.increment().ok_or(Error::Overflow);
+    }
+    if last.nanos() == Nulid::MAX.nanos() {
+        return Err(Error::Overflow);
+    }
+    Ok(Nulid::from_nanos(last.nanos() + 1, last.random() & !mask))
+}
+
+/// Applies the increment-on-skew rule to a candidate ID.
+///
+/// Returns the candidate if it is greater than the last generated ID,
+/// otherwise the last ID incremented by one.
+#[inline]
+pub(crate) fn next_monotonic(last: Option<Nulid>, candidate: Nulid) -> Result<Nulid> {
+    match last {
+        Some(last_id) if candidate <= last_id => {
+            // Clock skew or same nanosecond with lower random
+            last_id.increment().ok_or(Error::Overflow)
+        }
+        _ => Ok(candidate),
+    }
+}
+
 // ============================================================================
 // Type Aliases
 // ============================================================================
@@ -822,6 +1491,52 @@ mod tests {
         assert_eq!(clock.get(), 900_000_000);
     }
 
+    // ========================================================================
+    // Hybrid Clock Tests
+    // ========================================================================
+
+    #[test]
+    fn test_hybrid_clock_anchored_on_wall_time() {
+        let clock = HybridClock::new().unwrap();
+        let wall = crate::time::now_nanos().unwrap();
+        let diff = clock.now_nanos().unwrap().abs_diff(wall);
+        assert!(diff < 1_000_000_000, "Hybrid clock should track wall time");
+    }
+
+    #[test]
+    fn test_hybrid_clock_advances_monotonically() {
+        let clock = HybridClock::anchored_at(1_000_000_000, std::time::Instant::now());
+        assert_eq!(clock.base_nanos(), 1_000_000_000);
+
+        let mut prev = clock.now_nanos().unwrap();
+        assert!(prev >= 1_000_000_000);
+        for _ in 0..100 {
+            let now = clock.now_nanos().unwrap();
+            assert!(now >= prev);
+            prev = now;
+        }
+    }
+
+    #[test]
+    fn test_generator_with_hybrid_clock() {
+        let generator = Generator::with_clock(HybridClock::new().unwrap());
+        let id1 = generator.generate().unwrap();
+        let id2 = generator.generate().unwrap();
+        assert!(id2 > id1);
+        assert_eq!(generator.node_id(), None);
+    }
+
+    #[test]
+    fn test_generator_with_cached_clock() {
+        let clock = CachedClock::new(Duration::from_secs(3600));
+        assert_eq!(clock.resolution(), Duration::from_secs(3600));
+
+        let generator = Generator::with_clock(clock);
+        let ids: Vec<Nulid> = (0..100).map(|_| generator.generate().unwrap()).collect();
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+        assert!(ids[0].nanos() <= crate::time::now_nanos().unwrap());
+    }
+
     // ========================================================================
     // Seeded RNG Tests
     // ========================================================================
@@ -1086,4 +1801,208 @@ mod tests {
 
         assert!(second > first);
     }
+
+    // ========================================================================
+    // Batch Timestamp Tests
+    // ========================================================================
+
+    #[test]
+    fn test_timestamp_batch_reads_clock_once_per_batch() {
+        let clock = MockClock::new(1_000_000_000);
+        let rng = SequentialRng::starting_at(1_000);
+        let generator =
+            Generator::<_, _, NoNodeId>::with_deps(&clock, &rng).with_timestamp_batch(4);
+
+        let first = generator.generate().unwrap();
+        for i in 1..4u128 {
+            clock.advance(Duration::from_micros(1));
+            let id = generator.generate().unwrap();
+            assert_eq!(id.as_u128(), first.as_u128() + i);
+            assert_eq!(id.nanos(), 1_000_000_000);
+        }
+
+        // Next batch picks up the advanced clock
+        let next_batch = generator.generate().unwrap();
+        assert_eq!(next_batch.nanos(), 1_000_003_000);
+        assert_eq!(next_batch.random(), 1_001);
+    }
+
+    #[test]
+    fn test_timestamp_batch_monotonic_under_regression() {
+        let clock = MockClock::new(1_000_000_000);
+        let rng = SeededRng::new(42);
+        let generator =
+            Generator::<_, _, NoNodeId>::with_deps(&clock, &rng).with_timestamp_batch(3);
+
+        let mut prev = generator.generate().unwrap();
+        for i in 0..30 {
+            clock.set(if i % 2 == 0 {
+                900_000_000
+            } else {
+                1_100_000_000
+            });
+            let id = generator.generate().unwrap();
+            assert!(id > prev);
+            prev = id;
+        }
+    }
+
+    // ========================================================================
+    // Sequence Counter Tests
+    // ========================================================================
+
+    #[test]
+    fn test_sequence_counts_within_nanosecond() {
+        let clock = MockClock::new(1_000_000_000);
+        let rng = SeededRng::new(42);
+        let generator = Generator::<_, _, NoNodeId>::with_deps(&clock, &rng)
+            .with_sequence_bits(8)
+            .unwrap();
+        assert_eq!(generator.sequence_bits(), 8);
+
+        let ids: Vec<Nulid> = (0..5).map(|_| generator.generate().unwrap()).collect();
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+        assert!(ids.iter().all(|id| id.nanos() == 1_000_000_000));
+        assert_eq!(
+            ids.iter().map(|id| id.sequence(8)).collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4].map(Some)
+        );
+
+        // A new nanosecond resets the counter
+        clock.advance(Duration::from_nanos(1));
+        let id = generator.generate().unwrap();
+        assert_eq!(id.sequence(8), Some(0));
+
+        // A regressing clock keeps counting in the last nanosecond
+        clock.regress(Duration::from_millis(1));
+        let next = generator.generate().unwrap();
+        assert_eq!(next.nanos(), id.nanos());
+        assert_eq!(next.sequence(8), Some(1));
+    }
+
+    #[test]
+    fn test_sequence_exhaustion_moves_to_next_nanosecond() {
+        let clock = MockClock::new(500);
+        let rng = SeededRng::new(7);
+        let generator = Generator::with_deps_and_node_id(&clock, &rng, WithNodeId::new(3))
+            .with_sequence_bits(2)
+            .unwrap();
+
+        let ids: Vec<Nulid> = (0..6).map(|_| generator.generate().unwrap()).collect();
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(
+            ids.iter()
+                .map(|id| (id.nanos(), id.sequence(2).unwrap()))
+                .collect::<Vec<_>>(),
+            [(500, 0), (500, 1), (500, 2), (500, 3), (501, 0), (501, 1)]
+        );
+        assert!(ids.iter().all(|id| id.random() >> 44 == 3));
+    }
+
+    #[test]
+    fn test_sequence_with_timestamp_batch() {
+        let clock = MockClock::new(1_000);
+        let rng = SeededRng::new(1);
+        let generator = Generator::<_, _, NoNodeId>::with_deps(&clock, &rng)
+            .with_timestamp_batch(4)
+            .with_sequence_bits(1)
+            .unwrap();
+
+        let ids: Vec<Nulid> = (0..4).map(|_| generator.generate().unwrap()).collect();
+        assert_eq!(
+            ids.iter()
+                .map(|id| (id.nanos(), id.sequence(1).unwrap()))
+                .collect::<Vec<_>>(),
+            [(1_000, 0), (1_000, 1), (1_001, 0), (1_001, 1)]
+        );
+    }
+
+    #[test]
+    fn test_sequence_bits_limit() {
+        assert_eq!(
+            Generator::new().with_sequence_bits(33).err(),
+            Some(Error::InvalidArgument {
+                reason: "sequence counter cannot exceed 32 bits"
+            })
+        );
+        assert_eq!(
+            Generator::new()
+                .with_sequence_bits(MAX_SEQUENCE_BITS)
+                .unwrap()
+                .sequence_bits(),
+            32
+        );
+
+        let snapshot = GeneratorSnapshot {
+            sequence_bits: 33,
+            ..GeneratorSnapshot::default()
+        };
+        assert!(Generator::new().with_snapshot(snapshot).is_err());
+        assert_eq!(Nulid::MAX.sequence(32), Some(u32::MAX));
+        assert_eq!(Nulid::MAX.sequence(33), None);
+    }
+
+    #[test]
+    fn test_timestamp_batch_zero_and_reset() {
+        let generator = Generator::new().with_timestamp_batch(0);
+        assert_eq!(generator.timestamp_batch(), 1);
+
+        let generator = Generator::new().with_timestamp_batch(8);
+        let _ = generator.generate().unwrap();
+        generator.reset();
+        assert!(generator.last().is_none());
+    }
+
+    // ========================================================================
+    // Local Generator Tests
+    // ========================================================================
+
+    #[test]
+    fn test_local_generator_monotonic() {
+        let generator = LocalGenerator::new();
+        let ids: Vec<Nulid> = (0..1000).map(|_| generator.generate().unwrap()).collect();
+
+        for i in 1..ids.len() {
+            assert!(ids[i] > ids[i - 1], "ID {} not > ID {}", i, i - 1);
+        }
+        assert_eq!(generator.last(), ids.last().copied());
+    }
+
+    #[test]
+    fn test_local_generator_clock_regression() {
+        let clock = MockClock::new(1_000_000_000);
+        let rng = SeededRng::new(42);
+        let generator = LocalGenerator::<_, _, NoNodeId>::with_deps(&clock, &rng);
+
+        let id1 = generator.generate().unwrap();
+        clock.regress(Duration::from_millis(100));
+        let id2 = generator.generate().unwrap();
+
+        assert_eq!(id2.as_u128(), id1.as_u128() + 1);
+    }
+
+    #[test]
+    fn test_local_generator_matches_generator() {
+        let clock = MockClock::new(1_000_000_000);
+        let rng1 = SeededRng::new(7);
+        let rng2 = SeededRng::new(7);
+        let shared = Generator::with_deps_and_node_id(&clock, &rng1, WithNodeId::new(9));
+        let local = LocalGenerator::with_deps_and_node_id(&clock, &rng2, WithNodeId::new(9));
+
+        for i in 0..20 {
+            clock.set(1_000_000_000 + (i % 3) * 10);
+            assert_eq!(shared.generate().unwrap(), local.generate().unwrap());
+        }
+        assert_eq!(local.node_id(), Some(9));
+    }
+
+    #[test]
+    fn test_local_generator_reset() {
+        let generator = LocalGenerator::default();
+        let _ = generator.generate().unwrap();
+        assert!(generator.last().is_some());
+
+        generator.reset();
+        assert!(generator.last().is_none());
+    }
 }
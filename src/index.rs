@@ -0,0 +1,257 @@
+//! Time-window index over NULIDs.
+//!
+//! [`TimeIndex`] keeps IDs, with an optional value each, sorted by ID and
+//! therefore by timestamp. "Everything between `t1` and `t2`" is two binary
+//! searches and returns a contiguous run, with no per-entry time field to
+//! scan. Appending a newer ID is O(1), which is the common case for a cache
+//! of recent events fed by a generator, and [`evict_before`] drops the
+//! oldest entries in one step.
+//!
+//! Timestamps are nanoseconds since the Unix epoch, as returned by
+//! [`Nulid::nanos`] and [`time::now_nanos`](crate::time::now_nanos).
+//!
+//! [`evict_before`]: TimeIndex::evict_before
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use nulid::index::TimeIndex;
+//!
+//! let mut events = TimeIndex::new();
+//! events.insert(Nulid::from_nanos(1_000, 0), "login");
+//! events.insert(Nulid::from_nanos(2_500, 0), "upload");
+//! events.insert(Nulid::from_nanos(4_000, 0), "logout");
+//!
+//! let window: Vec<_> = events.query(1_000..4_000).map(|(_, event)| *event).collect();
+//! assert_eq!(window, ["login", "upload"]);
+//!
+//! let evicted = events.evict_before(2_000);
+//! assert_eq!(evicted.len(), 1);
+//! assert_eq!(events.len(), 2);
+//! ```
+
+use crate::Nulid;
+use crate::collections::{NulidMap, range_indices};
+use core::ops::{Bound, RangeBounds};
+
+/// Converts a timestamp range into the range of IDs with those timestamps.
+fn id_bounds(range: &impl RangeBounds<u128>) -> (Bound<Nulid>, Bound<Nulid>) {
+    let first = |nanos: u128| Nulid::from_nanos(nanos.min(Nulid::MAX.nanos()), 0);
+    let last = |nanos: u128| Nulid::from_nanos(nanos.min(Nulid::MAX.nanos()), u64::MAX);
+    let start = match range.start_bound() {
+        Bound::Included(&nanos) => Bound::Included(first(nanos)),
+        Bound::Excluded(&nanos) => Bound::Excluded(last(nanos)),
+        Bound::Unbounded => Bound::Unbounded,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&nanos) => Bound::Included(last(nanos)),
+        Bound::Excluded(&nanos) => Bound::Excluded(first(nanos)),
+        Bound::Unbounded => Bound::Unbounded,
+    };
+    (start, end)
+}
+
+/// IDs and values indexed for time-range queries.
+///
+/// A thin layer over [`NulidMap`] that takes timestamp ranges instead of ID
+/// ranges. Use `TimeIndex<()>` (the default) to store bare IDs.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::Nulid;
+/// use nulid::index::TimeIndex;
+///
+/// let mut recent: TimeIndex = TimeIndex::new();
+/// for n in 0..10 {
+///     recent.insert(Nulid::from_nanos(n * 100, 0), ());
+/// }
+/// assert_eq!(recent.count(200..=500), 4);
+/// assert_eq!(recent.ids(..300), &recent.as_map().keys()[..3]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TimeIndex<V = ()> {
+    entries: NulidMap<V>,
+}
+
+impl<V> Default for TimeIndex<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> TimeIndex<V> {
+    /// Creates an empty index.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: NulidMap::new(),
+        }
+    }
+
+    /// Creates an empty index with room for `capacity` entries.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: NulidMap::with_capacity(capacity),
+        }
+    }
+
+    /// Inserts an entry, returning the previous value for `id` if any.
+    pub fn insert(&mut self, id: Nulid, value: V) -> Option<V> {
+        self.entries.insert(id, value)
+    }
+
+    /// Removes `id`, returning its value if it was present.
+    pub fn remove(&mut self, id: Nulid) -> Option<V> {
+        self.entries.remove(id)
+    }
+
+    /// Returns the value for `id`.
+    #[must_use]
+    pub fn get(&self, id: Nulid) -> Option<&V> {
+        self.entries.get(id)
+    }
+
+    /// Returns the entries with timestamps within `range`, oldest first.
+    ///
+    /// `range` is in nanoseconds since the Unix epoch; `t1..t2` selects the
+    /// half-open window `[t1, t2)`.
+    pub fn query(
+        &self,
+        range: impl RangeBounds<u128>,
+    ) -> core::iter::Zip<core::slice::Iter<'_, Nulid>, core::slice::Iter<'_, V>> {
+        self.entries.range(id_bounds(&range))
+    }
+
+    /// Returns the IDs with timestamps within `range`, oldest first.
+    #[must_use]
+    pub fn ids(&self, range: impl RangeBounds<u128>) -> &[Nulid] {
+        let keys = self.entries.keys();
+        &keys[range_indices(keys, &id_bounds(&range))]
+    }
+
+    /// Returns the number of entries with timestamps within `range`.
+    #[must_use]
+    pub fn count(&self, range: impl RangeBounds<u128>) -> usize {
+        self.ids(range).len()
+    }
+
+    /// Removes and returns every entry with a timestamp before `nanos`.
+    #[must_use = "use `let _ =` to discard the evicted entries"]
+    pub fn evict_before(&mut self, nanos: u128) -> NulidMap<V> {
+        let kept = self
+            .entries
+            .split_off(Nulid::from_nanos(nanos.min(Nulid::MAX.nanos()), 0));
+        core::mem::replace(&mut self.entries, kept)
+    }
+
+    /// Returns the oldest ID, if any.
+    #[must_use]
+    pub fn oldest(&self) -> Option<Nulid> {
+        self.entries.keys().first().copied()
+    }
+
+    /// Returns the newest ID, if any.
+    #[must_use]
+    pub fn newest(&self) -> Option<Nulid> {
+        self.entries.keys().last().copied()
+    }
+
+    /// Returns the number of entries.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the index is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the underlying map.
+    #[must_use]
+    pub const fn as_map(&self) -> &NulidMap<V> {
+        &self.entries
+    }
+}
+
+impl<V> From<NulidMap<V>> for TimeIndex<V> {
+    fn from(entries: NulidMap<V>) -> Self {
+        Self { entries }
+    }
+}
+
+impl<V> FromIterator<(Nulid, V)> for TimeIndex<V> {
+    fn from_iter<I: IntoIterator<Item = (Nulid, V)>>(iter: I) -> Self {
+        Self::from(NulidMap::from_iter(iter))
+    }
+}
+
+impl<V> Extend<(Nulid, V)> for TimeIndex<V> {
+    fn extend<I: IntoIterator<Item = (Nulid, V)>>(&mut self, iter: I) {
+        self.entries.extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_bounds() {
+        // Several IDs per timestamp, including the extremes of the random bits
+        let index: TimeIndex<u128> = (1..=5)
+            .flat_map(|t| [0, 7, u64::MAX].map(|r| (Nulid::from_nanos(t * 10, r), t * 10)))
+            .collect();
+        assert_eq!(index.len(), 15);
+
+        let times = |range: (Bound<u128>, Bound<u128>)| -> Vec<u128> {
+            let mut times: Vec<u128> = index.query(range).map(|(_, t)| *t).collect();
+            times.dedup();
+            times
+        };
+        assert_eq!(times((Bound::Included(20), Bound::Excluded(40))), [20, 30]);
+        assert_eq!(times((Bound::Excluded(20), Bound::Included(40))), [30, 40]);
+        assert_eq!(times((Bound::Unbounded, Bound::Excluded(11))), [10]);
+        assert_eq!(times((Bound::Included(45), Bound::Unbounded)), [50]);
+        assert!(times((Bound::Included(40), Bound::Excluded(20))).is_empty());
+
+        assert_eq!(index.count(..), 15);
+        assert_eq!(index.count(30..=30), 3);
+        assert_eq!(
+            index.ids(30..31),
+            index.query(30..31).map(|(id, _)| *id).collect::<Vec<_>>()
+        );
+        assert_eq!(index.count(u128::MAX..), 0);
+    }
+
+    #[test]
+    fn test_insert_remove_evict() {
+        let mut index = TimeIndex::new();
+        for n in [5u128, 1, 3, 9, 7] {
+            assert_eq!(index.insert(Nulid::from_nanos(n, 0), n), None);
+        }
+        assert_eq!(index.insert(Nulid::from_nanos(3, 0), 33), Some(3));
+        assert_eq!(index.remove(Nulid::from_nanos(9, 0)), Some(9));
+        assert_eq!(index.get(Nulid::from_nanos(3, 0)), Some(&33));
+        assert_eq!(index.oldest(), Some(Nulid::from_nanos(1, 0)));
+        assert_eq!(index.newest(), Some(Nulid::from_nanos(7, 0)));
+
+        let evicted = index.evict_before(5);
+        assert_eq!(evicted.values(), &[1, 33]);
+        assert_eq!(index.as_map().values(), &[5, 7]);
+        assert!(index.evict_before(0).is_empty());
+
+        index.clear();
+        assert!(index.is_empty());
+        assert_eq!(index.newest(), None);
+    }
+}
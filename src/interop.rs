@@ -0,0 +1,374 @@
+//! Conversions to and from other time-ordered ID formats.
+//!
+//! Brown-field systems often carry several ID formats at once. This module
+//! converts NULIDs to and from:
+//!
+//! - **KSUID**: 20 bytes, a 32-bit second timestamp since [`KSUID_EPOCH_SECS`]
+//!   followed by a 128-bit payload
+//! - **Snowflake**: an `i64` with a 41-bit millisecond timestamp since a
+//!   configurable epoch (Twitter's is [`TWITTER_EPOCH_MILLIS`]) and 22 bits
+//!   of worker ID and sequence
+//! - **TSID**: a `u64` with a 42-bit millisecond timestamp since
+//!   [`TSID_EPOCH_MILLIS`] and 22 bits of node ID and counter
+//!
+//! Every format has less timestamp precision or less entropy than a NULID,
+//! so only one direction of each round trip is exact:
+//!
+//! | Format    | NULID → format → NULID                        | format → NULID → format |
+//! |-----------|-----------------------------------------------|-------------------------|
+//! | KSUID     | exact                                         | drops 38 payload bits   |
+//! | Snowflake | keeps the millisecond and 22 random bits      | exact                   |
+//! | TSID      | keeps the millisecond and 22 random bits      | exact                   |
+//!
+//! Conversions into the narrower formats fail with `Error::Overflow` when the
+//! timestamp falls outside the format's range, including before its epoch.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use nulid::interop::TWITTER_EPOCH_MILLIS;
+//!
+//! # fn main() -> nulid::Result<()> {
+//! let id = Nulid::new()?;
+//!
+//! let ksuid = id.to_ksuid()?;
+//! assert_eq!(Nulid::from_ksuid(ksuid), id);
+//!
+//! let snowflake = id.to_snowflake(TWITTER_EPOCH_MILLIS)?;
+//! let back = Nulid::from_snowflake(snowflake, TWITTER_EPOCH_MILLIS);
+//! assert_eq!(back.millis(), id.millis());
+//! assert_eq!(back.to_snowflake(TWITTER_EPOCH_MILLIS)?, snowflake);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{Error, Nulid, Result};
+
+/// KSUID epoch (2014-05-13T16:53:20Z) in seconds since the Unix epoch.
+pub const KSUID_EPOCH_SECS: u64 = 1_400_000_000;
+
+/// Twitter's Snowflake epoch (2010-11-04T01:42:54.657Z) in milliseconds
+/// since the Unix epoch.
+pub const TWITTER_EPOCH_MILLIS: u64 = 1_288_834_974_657;
+
+/// TSID epoch (2020-01-01T00:00:00Z) in milliseconds since the Unix epoch.
+pub const TSID_EPOCH_MILLIS: u64 = 1_577_836_800_000;
+
+/// Nanoseconds per second.
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Nanoseconds per millisecond.
+const NANOS_PER_MILLI: u128 = 1_000_000;
+
+/// Bits below the timestamp in Snowflake and TSID values.
+const LOW_BITS: u32 = 22;
+
+/// Bits of a KSUID payload below the sub-second nanoseconds and random bits.
+const KSUID_PAYLOAD_SPARE_BITS: u32 = 128 - 30 - Nulid::RANDOM_BITS;
+
+impl Nulid {
+    /// Converts this NULID to a 20-byte KSUID for the same instant.
+    ///
+    /// The KSUID timestamp holds the whole seconds since
+    /// [`KSUID_EPOCH_SECS`]. The payload starts with the sub-second
+    /// nanoseconds (30 bits), followed by the 60 random bits and 38 zero
+    /// bits, so the conversion is lossless and KSUIDs sort like their NULIDs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Overflow` if the timestamp is before the KSUID epoch
+    /// or beyond its 32-bit second range (year 2150).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let id = Nulid::from_nanos(1_704_067_200_123_456_789, 42);
+    /// let ksuid = id.to_ksuid()?;
+    /// assert_eq!(u32::from_be_bytes([ksuid[0], ksuid[1], ksuid[2], ksuid[3]]), 304_067_200);
+    /// assert_eq!(Nulid::from_ksuid(ksuid), id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_ksuid(self) -> Result<[u8; 20]> {
+        let secs = self
+            .seconds()
+            .checked_sub(KSUID_EPOCH_SECS)
+            .and_then(|secs| u32::try_from(secs).ok())
+            .ok_or(Error::Overflow)?;
+        let payload = (u128::from(self.subsec_nanos())
+            << (Self::RANDOM_BITS + KSUID_PAYLOAD_SPARE_BITS))
+            | (u128::from(self.random()) << KSUID_PAYLOAD_SPARE_BITS);
+
+        let mut bytes = [0u8; 20];
+        bytes[..4].copy_from_slice(&secs.to_be_bytes());
+        bytes[4..].copy_from_slice(&payload.to_be_bytes());
+        Ok(bytes)
+    }
+
+    /// Creates a NULID for the same instant as a 20-byte KSUID.
+    ///
+    /// The inverse of [`to_ksuid`](Self::to_ksuid): the top 30 payload bits
+    /// are read as sub-second nanoseconds and the next 60 become the random
+    /// bits. The last 38 payload bits are dropped. For KSUIDs from other
+    /// generators the payload is random; sub-second values above
+    /// 999,999,999 saturate, so the result stays within the KSUID's second
+    /// and KSUIDs keep their relative order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// let mut ksuid = [0xFF; 20];
+    /// ksuid[..4].copy_from_slice(&304_067_200u32.to_be_bytes());
+    /// let id = Nulid::from_ksuid(ksuid);
+    /// assert_eq!(id.seconds(), 1_704_067_200);
+    /// assert_eq!(id.subsec_nanos(), 999_999_999);
+    /// ```
+    #[must_use]
+    pub fn from_ksuid(bytes: [u8; 20]) -> Self {
+        let (secs, payload) = bytes.split_at(4);
+        let secs = u32::from_be_bytes([secs[0], secs[1], secs[2], secs[3]]);
+        let mut buf = [0u8; 16];
+        buf.copy_from_slice(payload);
+        let payload = u128::from_be_bytes(buf);
+
+        let subsec_nanos =
+            (payload >> (Self::RANDOM_BITS + KSUID_PAYLOAD_SPARE_BITS)).min(NANOS_PER_SEC - 1);
+        #[allow(clippy::cast_possible_truncation)] // masked to 60 bits
+        let random =
+            ((payload >> KSUID_PAYLOAD_SPARE_BITS) & ((1 << Self::RANDOM_BITS) - 1)) as u64;
+        let secs = u128::from(KSUID_EPOCH_SECS) + u128::from(secs);
+        Self::from_nanos(secs * NANOS_PER_SEC + subsec_nanos, random)
+    }
+
+    /// Converts this NULID to a Snowflake ID for the same millisecond.
+    ///
+    /// The Snowflake timestamp holds the milliseconds since `epoch_millis`
+    /// ([`TWITTER_EPOCH_MILLIS`] for Twitter's layout). The 22 worker and
+    /// sequence bits are the top 22 random bits, which include the node ID
+    /// of a [`DistributedGenerator`](crate::DistributedGenerator). The
+    /// sub-millisecond nanoseconds and the other 38 random bits are lost, so
+    /// IDs within the same millisecond may sort differently.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Overflow` if the timestamp is before `epoch_millis` or
+    /// more than 2^41 milliseconds (about 69 years) after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    /// use nulid::interop::TWITTER_EPOCH_MILLIS;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let id = Nulid::from_nanos(1_704_067_200_000_000_000, 0);
+    /// let snowflake = id.to_snowflake(TWITTER_EPOCH_MILLIS)?;
+    /// assert_eq!(snowflake >> 22, 415_232_225_343);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_snowflake(self, epoch_millis: u64) -> Result<i64> {
+        let value = self.to_epoch_millis_layout(epoch_millis, 41)?;
+        i64::try_from(value).map_err(|_| Error::Overflow)
+    }
+
+    /// Creates a NULID for the same millisecond as a Snowflake ID.
+    ///
+    /// The inverse of [`to_snowflake`](Self::to_snowflake): the 22 worker and
+    /// sequence bits become the top 22 random bits, and the rest of the
+    /// random bits and the sub-millisecond nanoseconds are zero, so
+    /// converting back gives the same Snowflake ID. The sign bit is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    /// use nulid::interop::TWITTER_EPOCH_MILLIS;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let snowflake = 1_541_815_603_606_036_480;
+    /// let id = Nulid::from_snowflake(snowflake, TWITTER_EPOCH_MILLIS);
+    /// assert_eq!(id.millis(), 1_656_432_460_105);
+    /// assert_eq!(id.to_snowflake(TWITTER_EPOCH_MILLIS)?, snowflake);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn from_snowflake(id: i64, epoch_millis: u64) -> Self {
+        Self::from_epoch_millis_layout(id.cast_unsigned() & (u64::MAX >> 1), epoch_millis)
+    }
+
+    /// Converts this NULID to a TSID for the same millisecond.
+    ///
+    /// The TSID timestamp holds the milliseconds since [`TSID_EPOCH_MILLIS`].
+    /// As with [`to_snowflake`](Self::to_snowflake), the 22 node and counter
+    /// bits are the top 22 random bits; the sub-millisecond nanoseconds and
+    /// the other 38 random bits are lost. The canonical 13-character TSID
+    /// string is the Crockford Base32 encoding of the returned value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Overflow` if the timestamp is before 2020 or more than
+    /// 2^42 milliseconds (about 139 years) after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let id = Nulid::from_nanos(1_704_067_200_000_000_000, 1 << 59);
+    /// let tsid = id.to_tsid()?;
+    /// assert_eq!(tsid >> 22, 126_230_400_000);
+    /// assert_eq!(tsid & ((1 << 22) - 1), 1 << 21);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_tsid(self) -> Result<u64> {
+        self.to_epoch_millis_layout(TSID_EPOCH_MILLIS, 42)
+    }
+
+    /// Creates a NULID for the same millisecond as a TSID.
+    ///
+    /// The inverse of [`to_tsid`](Self::to_tsid): the 22 node and counter
+    /// bits become the top 22 random bits, and the rest of the random bits
+    /// and the sub-millisecond nanoseconds are zero, so converting back gives
+    /// the same TSID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let tsid = (126_230_400_000 << 22) | 7;
+    /// let id = Nulid::from_tsid(tsid);
+    /// assert_eq!(id.millis(), 1_704_067_200_000);
+    /// assert_eq!(id.to_tsid()?, tsid);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn from_tsid(id: u64) -> Self {
+        Self::from_epoch_millis_layout(id, TSID_EPOCH_MILLIS)
+    }
+
+    /// Packs the milliseconds since `epoch_millis` above the top 22 random
+    /// bits, failing if they do not fit in `timestamp_bits`.
+    fn to_epoch_millis_layout(self, epoch_millis: u64, timestamp_bits: u32) -> Result<u64> {
+        let millis = (self.nanos() / NANOS_PER_MILLI)
+            .checked_sub(u128::from(epoch_millis))
+            .filter(|millis| millis >> timestamp_bits == 0)
+            .ok_or(Error::Overflow)?;
+        let low = self.random() >> (Self::RANDOM_BITS - LOW_BITS);
+        u64::try_from(millis << LOW_BITS)
+            .map(|high| high | low)
+            .map_err(|_| Error::Overflow)
+    }
+
+    /// Unpacks a value built by [`to_epoch_millis_layout`](Self::to_epoch_millis_layout).
+    fn from_epoch_millis_layout(value: u64, epoch_millis: u64) -> Self {
+        let millis = u128::from(value >> LOW_BITS) + u128::from(epoch_millis);
+        let random = (value & ((1 << LOW_BITS) - 1)) << (Self::RANDOM_BITS - LOW_BITS);
+        Self::from_nanos(millis * NANOS_PER_MILLI, random)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ksuid_round_trip() {
+        let id = Nulid::new().expect("Failed to create NULID");
+        let ksuid = id.to_ksuid().expect("in KSUID range");
+        assert_eq!(Nulid::from_ksuid(ksuid), id);
+    }
+
+    #[test]
+    fn test_ksuid_layout() {
+        let id = Nulid::from_nanos(1_704_067_200_123_456_789, 0x0ABC_DEF0_1234_5678);
+        let ksuid = id.to_ksuid().expect("in KSUID range");
+        assert_eq!(&ksuid[..4], &304_067_200u32.to_be_bytes());
+
+        let mut payload = [0u8; 16];
+        payload.copy_from_slice(&ksuid[4..]);
+        let payload = u128::from_be_bytes(payload);
+        assert_eq!(payload >> 98, 123_456_789);
+        assert_eq!((payload >> 38) & ((1 << 60) - 1), 0x0ABC_DEF0_1234_5678);
+        assert_eq!(payload & ((1 << 38) - 1), 0);
+    }
+
+    #[test]
+    fn test_ksuid_preserves_order() {
+        let earlier = Nulid::from_nanos(1_704_067_200_000_000_001, u64::MAX);
+        let later = Nulid::from_nanos(1_704_067_200_000_000_002, 0);
+        assert!(earlier.to_ksuid().unwrap() < later.to_ksuid().unwrap());
+    }
+
+    #[test]
+    fn test_ksuid_out_of_range() {
+        let before_epoch = Nulid::from_nanos(u128::from(KSUID_EPOCH_SECS - 1) * NANOS_PER_SEC, 0);
+        assert_eq!(before_epoch.to_ksuid(), Err(Error::Overflow));
+        assert_eq!(Nulid::MAX.to_ksuid(), Err(Error::Overflow));
+    }
+
+    #[test]
+    fn test_snowflake_round_trip() {
+        let snowflake = 1_541_815_603_606_036_480;
+        let id = Nulid::from_snowflake(snowflake, TWITTER_EPOCH_MILLIS);
+        assert_eq!(id.to_snowflake(TWITTER_EPOCH_MILLIS), Ok(snowflake));
+
+        let id = Nulid::new().expect("Failed to create NULID");
+        let back = Nulid::from_snowflake(
+            id.to_snowflake(TWITTER_EPOCH_MILLIS).unwrap(),
+            TWITTER_EPOCH_MILLIS,
+        );
+        assert_eq!(back.millis(), id.millis());
+        assert_eq!(back.random() >> 38, id.random() >> 38);
+        assert_eq!(back.random() & ((1 << 38) - 1), 0);
+    }
+
+    #[test]
+    fn test_snowflake_keeps_node_id() {
+        let id = Nulid::from_nanos(1_704_067_200_000_000_000, 0x3FF << 50);
+        let snowflake = id.to_snowflake(TWITTER_EPOCH_MILLIS).unwrap();
+        assert_eq!((snowflake >> 12) & 0x3FF, 0x3FF);
+    }
+
+    #[test]
+    fn test_snowflake_out_of_range() {
+        let before_epoch =
+            Nulid::from_nanos(u128::from(TWITTER_EPOCH_MILLIS - 1) * NANOS_PER_MILLI, 0);
+        assert_eq!(
+            before_epoch.to_snowflake(TWITTER_EPOCH_MILLIS),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Nulid::MAX.to_snowflake(TWITTER_EPOCH_MILLIS),
+            Err(Error::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_tsid_round_trip() {
+        let tsid = (126_230_400_000 << 22) | 0x2A_BCDE;
+        let id = Nulid::from_tsid(tsid);
+        assert_eq!(id.millis(), 1_704_067_200_000);
+        assert_eq!(id.to_tsid(), Ok(tsid));
+    }
+
+    #[test]
+    fn test_tsid_out_of_range() {
+        let before_epoch =
+            Nulid::from_nanos(u128::from(TSID_EPOCH_MILLIS - 1) * NANOS_PER_MILLI, 0);
+        assert_eq!(before_epoch.to_tsid(), Err(Error::Overflow));
+        assert_eq!(Nulid::MAX.to_tsid(), Err(Error::Overflow));
+    }
+}
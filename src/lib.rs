@@ -13,16 +13,36 @@
 //! high-throughput, distributed systems.
 
 pub mod base32;
+#[cfg(feature = "bench-support")]
+pub mod bench_support;
+#[cfg(feature = "bloom")]
+pub mod bloom;
+#[cfg(feature = "rayon")]
+pub mod bulk;
+pub mod collections;
+#[cfg(feature = "cursor")]
+pub mod cursor;
+pub mod diagnostics;
+#[cfg(feature = "embedded")]
+pub mod embedded;
 pub mod error;
 pub mod generator;
+pub mod index;
+pub mod interop;
+pub mod merge;
 pub mod nulid;
+pub mod ordering;
+pub mod prefetch;
+pub mod replay;
 pub mod time;
 
 pub mod features;
 
-pub use error::{Error, Result};
+pub use base32::NulidString;
+pub use error::{Error, ErrorKind, ParseError, ParseErrorKind, ParseFormat, Result};
 pub use generator::{
     // Clock trait and implementations
+    CachedClock,
     Clock,
     CryptoRng,
     // Type aliases
@@ -30,6 +50,9 @@ pub use generator::{
     DistributedGenerator,
     // Main generator type
     Generator,
+    HybridClock,
+    // Lock-free single-threaded generator
+    LocalGenerator,
     MockClock,
     NoNodeId,
     // NodeId trait and implementations
@@ -41,10 +64,20 @@ pub use generator::{
     SystemClock,
     WithNodeId,
 };
-pub use nulid::Nulid;
+pub use merge::merge;
+pub use nulid::{DetectedFormat, DisplayList, GroupedDisplay, Nulid, RedactedDisplay};
+
+#[cfg(feature = "serde")]
+pub use features::serde;
+
+#[cfg(feature = "sqlx")]
+pub use features::sqlx;
+
+#[cfg(feature = "coarse-clock")]
+pub use features::coarse_clock;
 
 #[cfg(feature = "derive")]
 pub use nulid_derive::Id;
 
 #[cfg(feature = "macros")]
-pub use nulid_macros::nulid;
+pub use nulid_macros::{nulid, nulid_str};
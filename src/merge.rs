@@ -0,0 +1,267 @@
+//! Merging sorted NULID streams.
+//!
+//! Each node's generator emits IDs in increasing order, so a consumer that
+//! collects IDs from several nodes holds several sorted runs. [`merge`]
+//! combines them into one globally sorted iterator with a k-way heap merge:
+//! every step costs `O(log k)` for `k` inputs, and only one pending ID per
+//! input is held in memory. With the `stream` feature, [`merge_stream`] does
+//! the same for async streams.
+//!
+//! The inputs must each be sorted; the merge does not check this (feed the
+//! output through an [`OrderingValidator`](crate::ordering::OrderingValidator)
+//! to audit it). IDs that appear in several inputs are all yielded, and ties
+//! are broken by input position, so the merge is stable.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//!
+//! let node_a = [1, 4, 7].map(|n| Nulid::from_nanos(n, 0));
+//! let node_b = [2, 3, 9].map(|n| Nulid::from_nanos(n, 0));
+//!
+//! let merged: Vec<u128> = nulid::merge([node_a, node_b]).map(Nulid::nanos).collect();
+//! assert_eq!(merged, [1, 2, 3, 4, 7, 9]);
+//! ```
+
+use crate::Nulid;
+use core::cmp::Reverse;
+use core::iter::FusedIterator;
+use std::collections::BinaryHeap;
+
+/// Merges sorted NULID iterators into one sorted iterator.
+///
+/// See the [module documentation](self) for the requirements on the inputs.
+pub fn merge<I>(inputs: impl IntoIterator<Item = I>) -> Merge<I::IntoIter>
+where
+    I: IntoIterator<Item = Nulid>,
+{
+    let mut sources: Vec<I::IntoIter> = inputs.into_iter().map(IntoIterator::into_iter).collect();
+    let heap = sources
+        .iter_mut()
+        .enumerate()
+        .filter_map(|(source, iter)| iter.next().map(|id| Reverse((id, source))))
+        .collect();
+    Merge { sources, heap }
+}
+
+/// Iterator returned by [`merge`].
+#[derive(Debug, Clone)]
+pub struct Merge<I> {
+    sources: Vec<I>,
+    /// The next ID of every input that is not exhausted, with its index.
+    heap: BinaryHeap<Reverse<(Nulid, usize)>>,
+}
+
+impl<I: Iterator<Item = Nulid>> Iterator for Merge<I> {
+    type Item = Nulid;
+
+    fn next(&mut self) -> Option<Nulid> {
+        let Reverse((id, source)) = self.heap.pop()?;
+        if let Some(next) = self.sources[source].next() {
+            self.heap.push(Reverse((next, source)));
+        }
+        Some(id)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.heap.len();
+        self.sources.iter().map(Iterator::size_hint).fold(
+            (pending, Some(pending)),
+            |(lo, hi), (source_lo, source_hi)| {
+                (
+                    lo.saturating_add(source_lo),
+                    hi.zip(source_hi).and_then(|(a, b)| a.checked_add(b)),
+                )
+            },
+        )
+    }
+}
+
+impl<I: FusedIterator<Item = Nulid>> FusedIterator for Merge<I> {}
+
+#[cfg(feature = "stream")]
+pub use self::stream::{MergeStream, merge_stream};
+
+#[cfg(feature = "stream")]
+mod stream {
+    use super::{BinaryHeap, Nulid, Reverse};
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+    use futures_core::Stream;
+    use futures_core::stream::FusedStream;
+
+    /// Merges sorted NULID streams into one sorted stream.
+    ///
+    /// An ID is yielded once every input that is not exhausted has an ID
+    /// ready, so a stalled input holds back the merged stream. See the
+    /// [module documentation](super) for the requirements on the inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use futures::StreamExt;
+    ///
+    /// let mut merged = nulid::merge::merge_stream(node_streams);
+    /// while let Some(id) = merged.next().await {
+    ///     process(id);
+    /// }
+    /// ```
+    pub fn merge_stream<S>(inputs: impl IntoIterator<Item = S>) -> MergeStream<S>
+    where
+        S: Stream<Item = Nulid> + Unpin,
+    {
+        let sources: Vec<S> = inputs.into_iter().collect();
+        MergeStream {
+            waiting: (0..sources.len()).collect(),
+            sources,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    /// Stream returned by [`merge_stream`].
+    #[derive(Debug)]
+    pub struct MergeStream<S> {
+        sources: Vec<S>,
+        /// The next ID of every input that has one ready, with its index.
+        heap: BinaryHeap<Reverse<(Nulid, usize)>>,
+        /// Inputs that are not exhausted and have no ID in the heap.
+        waiting: Vec<usize>,
+    }
+
+    impl<S: Stream<Item = Nulid> + Unpin> Stream for MergeStream<S> {
+        type Item = Nulid;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Nulid>> {
+            let this = self.get_mut();
+
+            let mut i = 0;
+            while i < this.waiting.len() {
+                let source = this.waiting[i];
+                match Pin::new(&mut this.sources[source]).poll_next(cx) {
+                    Poll::Ready(Some(id)) => {
+                        this.heap.push(Reverse((id, source)));
+                        this.waiting.swap_remove(i);
+                    }
+                    Poll::Ready(None) => {
+                        this.waiting.swap_remove(i);
+                    }
+                    Poll::Pending => i += 1,
+                }
+            }
+            if !this.waiting.is_empty() {
+                return Poll::Pending;
+            }
+
+            Poll::Ready(this.heap.pop().map(|Reverse((id, source))| {
+                this.waiting.push(source);
+                id
+            }))
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.heap.len(), None)
+        }
+    }
+
+    impl<S: Stream<Item = Nulid> + Unpin> FusedStream for MergeStream<S> {
+        fn is_terminated(&self) -> bool {
+            self.heap.is_empty() && self.waiting.is_empty()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(nanos: &[u128]) -> Vec<Nulid> {
+        nanos.iter().map(|&n| Nulid::from_nanos(n, 0)).collect()
+    }
+
+    #[test]
+    fn test_merge_sorted_inputs() {
+        let merged: Vec<Nulid> =
+            merge([ids(&[1, 5, 9]), ids(&[]), ids(&[2, 3, 10, 11]), ids(&[4])]).collect();
+        assert_eq!(merged, ids(&[1, 2, 3, 4, 5, 9, 10, 11]));
+
+        let mut iter = merge([ids(&[1, 2]), ids(&[3])]);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+
+        assert_eq!(merge(Vec::<Vec<Nulid>>::new()).next(), None);
+    }
+
+    #[test]
+    fn test_merge_keeps_duplicates_stably() {
+        let a = Nulid::from_nanos(1, 0);
+        let merged: Vec<Nulid> = merge([vec![a, a], vec![a]]).collect();
+        assert_eq!(merged, [a, a, a]);
+    }
+
+    #[test]
+    fn test_merge_generated_streams() {
+        use crate::generator::{Generator, MockClock, SeededRng, WithNodeId};
+
+        let clock = MockClock::new(1_000);
+        let streams: Vec<Vec<Nulid>> = (0..4)
+            .map(|node| {
+                let rng = SeededRng::new(u64::from(node));
+                let generator =
+                    Generator::with_deps_and_node_id(&clock, &rng, WithNodeId::new(node));
+                (0..100)
+                    .map(|i| {
+                        clock.set(1_000 + i * 7 % 13);
+                        generator.generate().unwrap()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut expected: Vec<Nulid> = streams.concat();
+        expected.sort_unstable();
+        assert_eq!(merge(streams).collect::<Vec<_>>(), expected);
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn test_merge_stream() {
+        use core::pin::Pin;
+        use core::task::{Context, Poll};
+        use futures_core::Stream;
+        use futures_core::stream::FusedStream;
+
+        /// Yields its IDs, returning `Pending` before every other one.
+        struct Stuttering {
+            ids: std::vec::IntoIter<Nulid>,
+            stall: bool,
+        }
+
+        impl Stream for Stuttering {
+            type Item = Nulid;
+
+            fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Nulid>> {
+                self.stall = !self.stall;
+                if self.stall {
+                    cx.waker().wake_by_ref();
+                    return Poll::Pending;
+                }
+                Poll::Ready(self.ids.next())
+            }
+        }
+
+        let inputs = [ids(&[1, 4, 6]), ids(&[2, 3]), ids(&[]), ids(&[5])].map(|ids| Stuttering {
+            ids: ids.into_iter(),
+            stall: false,
+        });
+        let mut merged = merge_stream(inputs);
+
+        let mut out = Vec::new();
+        while let Some(id) = std::future::poll_fn(|cx| Pin::new(&mut merged).poll_next(cx)).await {
+            out.push(id);
+        }
+        assert_eq!(out, ids(&[1, 2, 3, 4, 5, 6]));
+        assert!(merged.is_terminated());
+    }
+}
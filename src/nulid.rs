@@ -1,12 +1,22 @@
 //! Core NULID type with 128-bit layout (68-bit timestamp + 60-bit random).
 
-use crate::{Error, Result};
+use crate::base32::NulidString;
+use crate::{Error, ParseFormat, Result};
 use core::cmp::Ordering;
 use core::fmt;
+use core::num::NonZeroU32;
 use core::str::FromStr;
 use rand::Rng;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+// `use nulid::{nulid, nulid_str};` also imports this module under the name
+// `nulid`, which then shadows the crate when resolving `nulid_str` (and any
+// later `nulid::nulid_str!` path). The macros themselves expand to `::nulid`
+// paths; this re-export only keeps that import working (see tests/macros.rs).
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use nulid_macros::nulid_str;
+
 /// A NULID (Nanosecond-Precision Universally Lexicographically Sortable Identifier).
 ///
 /// NULID is a 128-bit identifier with:
@@ -40,8 +50,20 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(
     feature = "rkyv",
-    derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize)
+    derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize),
+    rkyv(derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))
+)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(::diesel::expression::AsExpression, ::diesel::deserialize::FromSqlRow),
+    diesel(sql_type = ::diesel::sql_types::Binary),
+    diesel(sql_type = ::diesel::sql_types::Text)
+)]
+#[cfg_attr(
+    feature = "diesel-postgres",
+    diesel(sql_type = ::diesel::sql_types::Uuid)
 )]
+#[cfg_attr(feature = "bitcode", derive(::bitcode::Encode, ::bitcode::Decode))]
 #[repr(transparent)]
 pub struct Nulid(u128);
 
@@ -58,6 +80,18 @@ impl Nulid {
     /// Mask for extracting the random bits (lower 60 bits).
     const RANDOM_MASK: u128 = (1u128 << Self::RANDOM_BITS) - 1;
 
+    /// Number of bits in a ULID's millisecond timestamp.
+    const ULID_TIMESTAMP_BITS: u32 = 48;
+
+    /// Bits of a ULID below its millisecond timestamp.
+    const ULID_TIMESTAMP_SHIFT: u32 = 128 - Self::ULID_TIMESTAMP_BITS;
+
+    /// Mask for the sub-millisecond nanoseconds in the ULID layout (20 bits).
+    const ULID_SUB_MILLIS_MASK: u128 = (1 << 20) - 1;
+
+    /// Nanoseconds per millisecond.
+    const NANOS_PER_MILLI: u128 = 1_000_000;
+
     /// Mask for the timestamp (68 bits).
     const TIMESTAMP_MASK: u128 = (1u128 << Self::TIMESTAMP_BITS) - 1;
 
@@ -259,6 +293,149 @@ impl Nulid {
         Self(u128::from_be_bytes(bytes))
     }
 
+    /// Parses a NULID from its 26-byte Base32 ASCII form.
+    ///
+    /// Skips UTF-8 validation entirely, for parsers that read IDs straight out
+    /// of wire buffers.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidChar` if the input contains invalid characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let id = Nulid::from_ascii(b"01GZTV7EQ056J0E6N276XD6F3D")?;
+    /// assert_eq!(id.to_string(), "01GZTV7EQ056J0E6N276XD6F3D");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_ascii(bytes: &[u8; 26]) -> Result<Self> {
+        crate::base32::decode_ascii(bytes).map(Self)
+    }
+
+    /// Parses a NULID from a Base32 ASCII byte slice.
+    ///
+    /// Like [`from_ascii`](Self::from_ascii), but accepts a slice of any length.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidLength`: If the slice is not exactly 26 bytes
+    /// - `InvalidChar`: If the slice contains invalid characters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let wire = b"id=01GZTV7EQ056J0E6N276XD6F3D;";
+    /// let id = Nulid::from_ascii_slice(&wire[3..29])?;
+    /// assert_eq!(id.to_string(), "01GZTV7EQ056J0E6N276XD6F3D");
+    /// assert!(Nulid::from_ascii_slice(&wire[3..]).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_ascii_slice(bytes: &[u8]) -> Result<Self> {
+        crate::base32::decode_ascii(bytes).map(Self)
+    }
+
+    /// Parses a NULID in canonical form only: 26 uppercase Base32 characters.
+    ///
+    /// Use this to validate IDs at API boundaries. [`FromStr`] additionally
+    /// accepts lowercase.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Parse`] recording the input, with one of these reasons:
+    ///
+    /// - `InvalidLength`: If the string is not exactly 26 characters
+    /// - `InvalidChar`: If the string contains lowercase or invalid characters
+    /// - `Overflow`: If the first character is above `'7'`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// assert!(Nulid::parse_strict("01GZTV7EQ056J0E6N276XD6F3D").is_ok());
+    /// assert!(Nulid::parse_strict("01gztv7eq056j0e6n276xd6f3d").is_err());
+    /// assert!(Nulid::parse_strict("81GZTV7EQ056J0E6N276XD6F3D").is_err());
+    /// ```
+    pub fn parse_strict(s: &str) -> Result<Self> {
+        crate::base32::decode_strict(s)
+            .map(Self)
+            .map_err(|e| Error::parse(ParseFormat::Text, s, e))
+    }
+
+    /// Parses a NULID from messy, human-entered input.
+    ///
+    /// Accepts lowercase, surrounding whitespace, and `-` or `_` separators
+    /// inserted for readability.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Parse`] recording the input, with one of these reasons:
+    ///
+    /// - `InvalidLength`: If there are not exactly 26 Base32 characters after removing separators
+    /// - `InvalidChar`: If the string contains invalid characters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let id = Nulid::parse_lenient(" 01gztv7e-q056j0e6-n276xd6f3d ")?;
+    /// assert_eq!(id, Nulid::parse_strict("01GZTV7EQ056J0E6N276XD6F3D")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<Self> {
+        crate::base32::decode_lenient(s).map(Self).map_err(|e| {
+            // The decoder reports positions in the trimmed input
+            let e = match e {
+                Error::InvalidChar(ch, i) => {
+                    Error::InvalidChar(ch, i + s.len() - s.trim_start().len())
+                }
+                e => e,
+            };
+            Error::parse(ParseFormat::Text, s, e)
+        })
+    }
+
+    /// Parses a NULID, accepting Crockford's aliases for ambiguous characters.
+    ///
+    /// `I` and `L` are read as `1`, and `O` as `0`, so IDs transcribed by
+    /// humans still parse. `U` is still rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Parse`] recording the input, with one of these reasons:
+    ///
+    /// - `InvalidLength`: If the string is not exactly 26 characters
+    /// - `InvalidChar`: If the string contains invalid characters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let id = Nulid::parse_crockford_aliases("OIGZTV7EQ056JOE6N276XD6F3D")?;
+    /// assert_eq!(id.to_string(), "01GZTV7EQ056J0E6N276XD6F3D");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_crockford_aliases(s: &str) -> Result<Self> {
+        crate::base32::decode_crockford_aliases(s)
+            .map(Self)
+            .map_err(|e| Error::parse(ParseFormat::Text, s, e))
+    }
+
     /// Extracts the timestamp in nanoseconds since Unix epoch.
     ///
     /// # Examples
@@ -319,6 +496,61 @@ impl Nulid {
         (self.0 & Self::RANDOM_MASK) as u64
     }
 
+    /// Returns the sequence counter in the low `bits` of the random field.
+    ///
+    /// Only meaningful for IDs from a [`Generator`](crate::Generator) in
+    /// sequence counter mode, where `bits` is its
+    /// [`sequence_bits`](crate::Generator::sequence_bits): the counter is `0`
+    /// for the first ID in each nanosecond and counts up from there.
+    ///
+    /// Returns `None` if `bits` is greater than
+    /// [`MAX_SEQUENCE_BITS`](crate::generator::MAX_SEQUENCE_BITS).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// let id = Nulid::from_nanos(1_000, 0xABC_0005);
+    /// assert_eq!(id.sequence(16), Some(5));
+    /// assert_eq!(id.sequence(0), Some(0));
+    /// assert_eq!(id.sequence(33), None);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // masked to at most 32 bits
+    pub const fn sequence(self, bits: u32) -> Option<u32> {
+        if bits > crate::generator::MAX_SEQUENCE_BITS {
+            return None;
+        }
+        Some((self.random() & ((1u64 << bits) - 1)) as u32)
+    }
+
+    /// Returns how many of the 60 random bits vary across `sample`.
+    ///
+    /// A quick health check for the entropy source: a working RNG reaches
+    /// 60 within a few dozen IDs, while an all-zero or low-entropy source
+    /// stays far below. This is the
+    /// [`varying_bits`](crate::diagnostics::EntropyEstimate::varying_bits) of
+    /// [`entropy_estimate`](crate::diagnostics::entropy_estimate), which
+    /// explains how to sample and reports stuck bits and repeats as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// let broken: Vec<Nulid> = (0..64).map(|n| Nulid::from_nanos(n, 42)).collect();
+    /// assert_eq!(Nulid::random_entropy_bits(&broken), 0);
+    ///
+    /// let sample: Vec<Nulid> = (0..64).map(|_| Nulid::new()).collect::<nulid::Result<_>>()?;
+    /// assert_eq!(Nulid::random_entropy_bits(&sample), 60);
+    /// # Ok::<(), nulid::Error>(())
+    /// ```
+    #[must_use]
+    pub fn random_entropy_bits(sample: &[Self]) -> u32 {
+        crate::diagnostics::entropy_estimate(sample).varying_bits
+    }
+
     /// Extracts both timestamp and random components.
     ///
     /// # Returns
@@ -425,6 +657,40 @@ impl Nulid {
         self.0.to_be_bytes()
     }
 
+    /// Splits this NULID into its high and low 64-bit halves.
+    ///
+    /// Useful for schemas that store an ID as two `u64` fields, such as
+    /// Cap'n Proto or `FlatBuffers` structs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// let id = Nulid::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+    /// assert_eq!(id.to_u64_pair(), (0x0123_4567_89AB_CDEF, 0xFEDC_BA98_7654_3210));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn to_u64_pair(self) -> (u64, u64) {
+        ((self.0 >> 64) as u64, self.0 as u64)
+    }
+
+    /// Creates a NULID from its high and low 64-bit halves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// let id = Nulid::from_u64_pair(0x0123_4567_89AB_CDEF, 0xFEDC_BA98_7654_3210);
+    /// assert_eq!(id.as_u128(), 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+    /// ```
+    #[must_use]
+    pub const fn from_u64_pair(high: u64, low: u64) -> Self {
+        Self(((high as u128) << 64) | low as u128)
+    }
+
     /// Converts this NULID to a `SystemTime`.
     ///
     /// # Examples
@@ -470,6 +736,174 @@ impl Nulid {
         Duration::new(secs, subsec_nanos)
     }
 
+    /// Returns the index of the time bucket containing this NULID.
+    ///
+    /// Buckets are `granularity` wide and aligned to the Unix epoch, so
+    /// `bucket(Duration::from_secs(1))` is the same as [`seconds`](Self::seconds)
+    /// and NULIDs with equal bucket indices fall in the same window. The
+    /// division is done on the full nanosecond timestamp, so granularities
+    /// that are not whole milliseconds or seconds bucket exactly.
+    ///
+    /// Returns `None` if `granularity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    /// use std::time::Duration;
+    ///
+    /// let id = Nulid::from_nanos(1_704_067_265_123_456_789, 0);
+    /// assert_eq!(id.bucket(Duration::from_secs(60)), Some(28_401_121));
+    /// assert_eq!(id.bucket(Duration::from_millis(1)), Some(id.millis()));
+    /// assert_eq!(id.bucket(Duration::ZERO), None);
+    /// ```
+    #[must_use]
+    pub const fn bucket(self, granularity: Duration) -> Option<u128> {
+        match self.nanos().checked_div(granularity.as_nanos()) {
+            Some(bucket) => Some(bucket),
+            None => None,
+        }
+    }
+
+    /// Returns `true` if both NULIDs fall in the same `granularity`-wide time bucket.
+    ///
+    /// See [`bucket`](Self::bucket) for how buckets are aligned.
+    ///
+    /// Returns `None` if `granularity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    /// use std::time::Duration;
+    ///
+    /// let a = Nulid::from_nanos(1_704_067_200_000_000_000, 1);
+    /// let b = Nulid::from_nanos(1_704_067_259_999_999_999, 2);
+    /// let c = Nulid::from_nanos(1_704_067_260_000_000_000, 3);
+    /// assert_eq!(a.same_bucket(b, Duration::from_secs(60)), Some(true));
+    /// assert_eq!(b.same_bucket(c, Duration::from_secs(60)), Some(false));
+    /// ```
+    #[must_use]
+    pub const fn same_bucket(self, other: Self, granularity: Duration) -> Option<bool> {
+        match (self.bucket(granularity), other.bucket(granularity)) {
+            (Some(a), Some(b)) => Some(a == b),
+            _ => None,
+        }
+    }
+
+    /// Returns a partition index in `0..n_partitions` for use as a message key.
+    ///
+    /// Only the 60 random bits are hashed, so IDs created at the same moment
+    /// spread evenly instead of piling onto one partition, as a timestamp-led
+    /// key would with Kafka's default partitioner. The result is stable across
+    /// versions and platforms, and easy to reproduce in other languages:
+    ///
+    /// 1. `h = random()` as a `u64`
+    /// 2. The `MurmurHash3` `fmix64` finalizer: `h ^= h >> 33; h *= 0xff51afd7ed558ccd;
+    ///    h ^= h >> 33; h *= 0xc4ceb9fe1a85ec53; h ^= h >> 33` (wrapping)
+    /// 3. `(h * n_partitions) >> 64`, computed in 128 bits
+    ///
+    /// The same ID always maps to the same partition, so per-key ordering
+    /// holds. Use [`partition_by_time`](Self::partition_by_time) when
+    /// consumers need time-ordered partitions instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    /// use std::num::NonZeroU32;
+    ///
+    /// let partitions = NonZeroU32::new(12).unwrap();
+    /// let id = Nulid::from_nanos(1_704_067_200_000_000_000, 42);
+    /// assert_eq!(id.partition(partitions), 6);
+    /// assert!(Nulid::new()?.partition(partitions) < 12);
+    /// # Ok::<(), nulid::Error>(())
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // less than `n_partitions`
+    pub const fn partition(self, n_partitions: NonZeroU32) -> u32 {
+        let mut h = self.random();
+        h ^= h >> 33;
+        h = h.wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+        h ^= h >> 33;
+        h = h.wrapping_mul(0xC4CE_B9FE_1A85_EC53);
+        h ^= h >> 33;
+        ((h as u128 * n_partitions.get() as u128) >> 64) as u32
+    }
+
+    /// Returns a partition index that depends only on the time bucket.
+    ///
+    /// Every ID in the same `granularity`-wide [`bucket`](Self::bucket)
+    /// lands on the same partition, and consecutive buckets rotate through
+    /// the partitions (`bucket % n_partitions`). Consumers of one partition
+    /// then see whole windows in order, at the cost of sending each window's
+    /// traffic to a single partition.
+    ///
+    /// Returns `None` if `granularity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    /// use std::num::NonZeroU32;
+    /// use std::time::Duration;
+    ///
+    /// let four = NonZeroU32::new(4).unwrap();
+    /// let minute = Duration::from_secs(60);
+    /// let a = Nulid::from_nanos(1_704_067_200_000_000_000, 1);
+    /// let b = Nulid::from_nanos(1_704_067_259_999_999_999, 2);
+    /// let c = Nulid::from_nanos(1_704_067_260_000_000_000, 3);
+    /// assert_eq!(a.partition_by_time(four, minute), b.partition_by_time(four, minute));
+    /// assert_eq!(a.partition_by_time(four, minute), Some(0));
+    /// assert_eq!(c.partition_by_time(four, minute), Some(1));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // less than `n_partitions`
+    pub const fn partition_by_time(
+        self,
+        n_partitions: NonZeroU32,
+        granularity: Duration,
+    ) -> Option<u32> {
+        match self.bucket(granularity) {
+            Some(bucket) => Some((bucket % n_partitions.get() as u128) as u32),
+            None => None,
+        }
+    }
+
+    /// Returns `true` if both NULIDs were created in the same Unix second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// let a = Nulid::from_nanos(5_000_000_000, 0);
+    /// let b = Nulid::from_nanos(5_999_999_999, 0);
+    /// assert!(a.same_second(b));
+    /// assert!(!a.same_second(Nulid::from_nanos(6_000_000_000, 0)));
+    /// ```
+    #[must_use]
+    pub const fn same_second(self, other: Self) -> bool {
+        self.seconds() == other.seconds()
+    }
+
+    /// Returns `true` if both NULIDs were created in the same millisecond.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// let a = Nulid::from_nanos(5_000_000, 0);
+    /// let b = Nulid::from_nanos(5_999_999, 0);
+    /// assert!(a.same_millis(b));
+    /// assert!(!a.same_millis(Nulid::from_nanos(6_000_000, 0)));
+    /// ```
+    #[must_use]
+    pub const fn same_millis(self, other: Self) -> bool {
+        self.millis() == other.millis()
+    }
+
     /// Increments this NULID by 1, returning `None` on overflow.
     ///
     /// This is useful for monotonic generation when multiple IDs are generated
@@ -516,44 +950,760 @@ impl Nulid {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn encode(self, buf: &mut [u8; 26]) -> Result<&str> {
+    pub const fn encode(self, buf: &mut [u8; 26]) -> Result<&str> {
         crate::base32::encode_u128(self.0, buf)
     }
-}
-
-impl fmt::Debug for Nulid {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut buf = [0u8; 26];
-        let s = self.encode(&mut buf).map_err(|_| fmt::Error)?;
-        f.debug_tuple("Nulid").field(&s).finish()
-    }
-}
-
-impl fmt::Display for Nulid {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut buf = [0u8; 26];
-        let s = self.encode(&mut buf).map_err(|_| fmt::Error)?;
-        f.write_str(s)
-    }
-}
 
-impl FromStr for Nulid {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self> {
-        let value = crate::base32::decode_u128(s)?;
-        Ok(Self::from_u128(value))
+    /// Writes the 16-byte big-endian binary form to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error from the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let id = Nulid::new()?;
+    /// let mut wire = Vec::new();
+    /// id.write_to(&mut wire)?;
+    /// assert_eq!(wire.len(), 16);
+    ///
+    /// let read = Nulid::read_from(&mut wire.as_slice())?;
+    /// assert_eq!(read, id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_to<W: std::io::Write + ?Sized>(self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&self.to_bytes())
     }
-}
 
-impl Ord for Nulid {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.0.cmp(&other.0)
+    /// Reads a NULID from its 16-byte big-endian binary form.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error from the reader, including `UnexpectedEof` if
+    /// fewer than 16 bytes are available.
+    pub fn read_from<R: std::io::Read + ?Sized>(reader: &mut R) -> std::io::Result<Self> {
+        let mut bytes = [0u8; 16];
+        reader.read_exact(&mut bytes)?;
+        Ok(Self::from_bytes(bytes))
     }
-}
 
-impl PartialOrd for Nulid {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    /// Writes the 26-character Base32 text form to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error from the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let id = Nulid::new()?;
+    /// let mut out = Vec::new();
+    /// id.write_text_to(&mut out)?;
+    /// assert_eq!(out, id.to_string().into_bytes());
+    ///
+    /// let read = Nulid::read_text_from(&mut out.as_slice())?;
+    /// assert_eq!(read, id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_text_to<W: std::io::Write + ?Sized>(self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(self.to_arraystring().as_bytes())
+    }
+
+    /// Reads a NULID from exactly 26 bytes of Base32 text.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error from the reader, including `UnexpectedEof` if
+    /// fewer than 26 bytes are available. Invalid text is reported as
+    /// `InvalidData` wrapping the parse [`Error`].
+    pub fn read_text_from<R: std::io::Read + ?Sized>(reader: &mut R) -> std::io::Result<Self> {
+        let mut buf = [0u8; crate::base32::NULID_STRING_LENGTH];
+        reader.read_exact(&mut buf)?;
+        Self::from_ascii(&buf)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+
+    /// Encodes this NULID to Base32 (Crockford) as an inline, stack-allocated string.
+    ///
+    /// Unlike `to_string()`, this never allocates, and unlike [`encode`](Self::encode)
+    /// the result is an owned value that can be returned or stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let id = Nulid::new()?;
+    /// let s = id.to_arraystring();
+    /// assert_eq!(s.len(), 26);
+    /// assert_eq!(s.parse::<Nulid>()?, id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub const fn to_arraystring(self) -> NulidString {
+        NulidString::from_u128(self.0)
+    }
+
+    /// Returns whether this NULID's encoding starts with `prefix`.
+    ///
+    /// The comparison is case-insensitive, like parsing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `prefix` is not a valid NULID prefix; see
+    /// [`base32::decode_prefix`](crate::base32::decode_prefix).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let id: Nulid = "01GZTV7EQ056J0E6N276XD6F3D".parse()?;
+    /// assert!(id.starts_with_prefix("01gztv7e")?);
+    /// assert!(!id.starts_with_prefix("01GZTV7F")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn starts_with_prefix(self, prefix: &str) -> Result<bool> {
+        let (min, max) = crate::base32::decode_prefix(prefix)?;
+        Ok(min <= self && self <= max)
+    }
+
+    /// Returns a [`Display`](fmt::Display) adapter that splits the encoded
+    /// string into groups of `group_len` characters joined by `sep`.
+    ///
+    /// Long unbroken strings are error-prone to read aloud or transcribe, so
+    /// support-facing UIs can show the grouped form and parse it back with
+    /// [`parse_grouped`](Self::parse_grouped). Formatting never allocates.
+    /// A `group_len` of `0` disables grouping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let id: Nulid = "01GZTV7EQ056J0E6N276XD6F3D".parse()?;
+    /// let grouped = id.display_grouped(5, '-').to_string();
+    /// assert_eq!(grouped, "01GZT-V7EQ0-56J0E-6N276-XD6F3-D");
+    /// assert_eq!(Nulid::parse_grouped(&grouped, '-')?, id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub const fn display_grouped(self, group_len: usize, sep: char) -> GroupedDisplay {
+        GroupedDisplay {
+            nulid: self,
+            group_len,
+            sep,
+        }
+    }
+
+    /// Parses a NULID formatted with [`display_grouped`](Self::display_grouped).
+    ///
+    /// Every occurrence of `sep` is ignored; the remaining characters are
+    /// parsed like [`FromStr`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Parse`] recording the input, with one of these reasons:
+    ///
+    /// - `InvalidLength`: If there are not exactly 26 characters besides `sep`
+    /// - `InvalidChar`: If the string contains invalid characters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let id = Nulid::parse_grouped("01GZTV 7EQ056 J0E6N2 76XD6F 3D", ' ')?;
+    /// assert_eq!(id.to_string(), "01GZTV7EQ056J0E6N276XD6F3D");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_grouped(s: &str, sep: char) -> Result<Self> {
+        Self::parse_grouped_inner(s, sep).map_err(|e| Error::parse(ParseFormat::Text, s, e))
+    }
+
+    fn parse_grouped_inner(s: &str, sep: char) -> Result<Self> {
+        let mut buf = [0u8; crate::base32::NULID_STRING_LENGTH];
+        let mut len = 0;
+        for (i, ch) in s.char_indices() {
+            if ch == sep {
+                continue;
+            }
+            if !ch.is_ascii() {
+                return Err(Error::InvalidChar(ch, i));
+            }
+            if let Some(slot) = buf.get_mut(len) {
+                *slot = ch as u8;
+            }
+            len += 1;
+        }
+
+        if len != buf.len() {
+            return Err(Error::InvalidLength {
+                expected: buf.len(),
+                found: len,
+            });
+        }
+        Self::from_ascii(&buf)
+    }
+
+    /// Returns a [`Display`](fmt::Display) adapter that masks the middle of
+    /// the encoded string, e.g. `01GZTV…6F3D`.
+    ///
+    /// Use it to log IDs that double as capability tokens, such as session or
+    /// invite IDs. The first [`RedactedDisplay::VISIBLE_START`] characters
+    /// (a coarse timestamp) and the last [`RedactedDisplay::VISIBLE_END`]
+    /// are kept, which is enough to correlate log lines without revealing an
+    /// ID that could be replayed. Its `Debug` output is redacted as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let id: Nulid = "01GZTV7EQ056J0E6N276XD6F3D".parse()?;
+    /// assert_eq!(id.display_redacted().to_string(), "01GZTV…6F3D");
+    /// assert_eq!(
+    ///     id.display_redacted().with_prefix("sess_").to_string(),
+    ///     "sess_01GZTV…6F3D"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub const fn display_redacted(self) -> RedactedDisplay {
+        RedactedDisplay {
+            nulid: self,
+            prefix: "",
+        }
+    }
+
+    /// Encodes this NULID in the 26-character ULID layout.
+    ///
+    /// The result is a standard ULID: the first 10 characters hold the
+    /// 48-bit millisecond timestamp, so ULID tooling reads the correct time.
+    /// The 80 ULID randomness bits carry the sub-millisecond nanoseconds
+    /// (20 bits) followed by this NULID's 60 random bits, so the conversion
+    /// is lossless and preserves sort order.
+    ///
+    /// The canonical [`Display`](fmt::Display) form is also 26 Crockford
+    /// characters and matches ULID validation regexes, but ULID tooling
+    /// would misread its timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Overflow` if the timestamp is beyond the ULID range
+    /// (48-bit milliseconds, year 10889).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let id = Nulid::new()?;
+    /// let ulid = id.to_ulid_string()?;
+    /// assert_eq!(ulid.len(), 26);
+    /// assert_eq!(Nulid::from_ulid_string(&ulid)?, id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_ulid_string(self) -> Result<NulidString> {
+        self.to_ulid_layout()
+            .map(NulidString::from_u128)
+            .ok_or(Error::Overflow)
+    }
+
+    /// Packs this NULID into the order-preserving ULID layout.
+    ///
+    /// Returns `None` past the 48-bit millisecond ULID range.
+    pub(crate) const fn to_ulid_layout(self) -> Option<u128> {
+        let nanos = self.nanos();
+        let millis = nanos / Self::NANOS_PER_MILLI;
+        if millis >> Self::ULID_TIMESTAMP_BITS != 0 {
+            return None;
+        }
+        let sub_millis = nanos % Self::NANOS_PER_MILLI;
+        Some(
+            (millis << Self::ULID_TIMESTAMP_SHIFT)
+                | (sub_millis << Self::RANDOM_BITS)
+                | self.random() as u128,
+        )
+    }
+
+    /// Unpacks a value in the ULID layout of [`to_ulid_layout`](Self::to_ulid_layout).
+    ///
+    /// Sub-millisecond fields above 999,999 ns saturate, so ULIDs that were
+    /// not produced from a NULID keep their millisecond and sort order.
+    pub(crate) const fn from_ulid_layout(value: u128) -> Self {
+        let millis = value >> Self::ULID_TIMESTAMP_SHIFT;
+        let mut sub_millis = (value >> Self::RANDOM_BITS) & Self::ULID_SUB_MILLIS_MASK;
+        if sub_millis >= Self::NANOS_PER_MILLI {
+            sub_millis = Self::NANOS_PER_MILLI - 1;
+        }
+        #[allow(clippy::cast_possible_truncation)] // masked to 60 bits
+        let random = (value & Self::RANDOM_MASK) as u64;
+        Self::from_nanos(millis * Self::NANOS_PER_MILLI + sub_millis, random)
+    }
+
+    /// Parses a ULID string produced by [`to_ulid_string`](Self::to_ulid_string).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Parse`] recording the input, with one of these reasons:
+    ///
+    /// - `InvalidLength`: If the string is not exactly 26 characters
+    /// - `InvalidChar`: If the string contains invalid characters
+    /// - `Overflow`: If the sub-millisecond field exceeds 999,999 ns, meaning
+    ///   the ULID was not produced from a NULID
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let id = Nulid::from_ulid_string("01ARZ3NDEKTSV4RRFFQ69G5FAV")?;
+    /// assert_eq!(id.millis(), 1_469_922_850_259);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_ulid_string(s: &str) -> Result<Self> {
+        let value =
+            crate::base32::decode_u128(s).map_err(|e| Error::parse(ParseFormat::Text, s, e))?;
+        let sub_millis = (value >> Self::RANDOM_BITS) & Self::ULID_SUB_MILLIS_MASK;
+        if sub_millis >= Self::NANOS_PER_MILLI {
+            return Err(Error::parse(ParseFormat::Text, s, Error::Overflow));
+        }
+        Ok(Self::from_ulid_layout(value))
+    }
+
+    /// Encodes this NULID to Base32 with a trailing Crockford check symbol.
+    ///
+    /// The 27th character is the value mod 37, which catches any
+    /// single-character substitution when IDs are typed by humans.
+    /// Parse the result with [`from_str_checked`](Self::from_str_checked).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let id = Nulid::new()?;
+    /// let checked = id.to_string_checked();
+    /// assert_eq!(checked.len(), 27);
+    /// assert!(checked.starts_with(&id.to_string()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_string_checked(self) -> String {
+        let mut s = String::with_capacity(crate::base32::NULID_CHECKED_STRING_LENGTH);
+        s.push_str(&self.to_arraystring());
+        s.push(crate::base32::check_symbol(self.0));
+        s
+    }
+
+    /// Parses a NULID produced by [`to_string_checked`](Self::to_string_checked).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Parse`] recording the input, with one of these reasons:
+    ///
+    /// - `InvalidLength`: If the string is not exactly 27 characters
+    /// - `InvalidChar`: If the string contains invalid characters
+    /// - `InvalidChecksum`: If the check symbol does not match
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Nulid;
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let id = Nulid::new()?;
+    /// let parsed = Nulid::from_str_checked(&id.to_string_checked())?;
+    /// assert_eq!(parsed, id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_str_checked(s: &str) -> Result<Self> {
+        crate::base32::decode_checked(s)
+            .map(Self)
+            .map_err(|e| Error::parse(ParseFormat::Text, s, e))
+    }
+
+    /// Parses an ID pasted in any common shape, reporting the shape found.
+    ///
+    /// Surrounding whitespace is ignored. The input is detected as:
+    ///
+    /// - 26 Crockford Base32 characters: a NULID, or a ULID if reading it as
+    ///   a NULID would put its timestamp more than a day in the future. ULIDs
+    ///   are converted with [`from_ulid_string`](Self::from_ulid_string)'s
+    ///   layout, so the result keeps the ULID's millisecond.
+    /// - A UUID in any case: hyphenated, in braces, or with a `urn:uuid:`
+    ///   prefix. The 128 bits are kept unchanged.
+    /// - 32 hex digits, optionally prefixed with `0x`, as printed by
+    ///   [`to_bytes`](Self::to_bytes) in hex.
+    /// - Decimal digits of a `u128` (any other length).
+    ///
+    /// ULIDs and NULIDs share their alphabet and length, so detection relies
+    /// on the timestamp. Read as a NULID, a ULID's time lands about 5% further
+    /// from 1970 than it really is, a couple of years in the future for a
+    /// fresh ULID. Only ULIDs from roughly the last two years are detected;
+    /// older ones are reported as NULIDs. Decimal inputs of exactly 26 or 32
+    /// digits are read as Base32 or hex.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Parse`] recording the input, with one of these reasons:
+    ///
+    /// - `InvalidLength`: If the input matches none of the shapes above
+    /// - `InvalidChar`: If a Base32, UUID, or hex input contains an invalid character
+    /// - `Overflow`: If a decimal input does not fit in 128 bits
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::{DetectedFormat, Nulid};
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let id = Nulid::new()?;
+    ///
+    /// assert_eq!(Nulid::parse_any(&id.to_string())?, (id, DetectedFormat::Nulid));
+    /// assert_eq!(
+    ///     Nulid::parse_any(&id.as_u128().to_string())?,
+    ///     (id, DetectedFormat::U128)
+    /// );
+    ///
+    /// let (parsed, format) = Nulid::parse_any("{018CC251-F400-7800-8ABC-DEF012345678}")?;
+    /// assert_eq!(format, DetectedFormat::Uuid);
+    /// assert_eq!(parsed.as_u128(), 0x018C_C251_F400_7800_8ABC_DEF0_1234_5678);
+    ///
+    /// let (parsed, format) = Nulid::parse_any(&id.to_ulid_string()?)?;
+    /// assert_eq!((parsed, format), (id, DetectedFormat::Ulid));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_any(s: &str) -> Result<(Self, DetectedFormat)> {
+        /// How far in the future a NULID reading may be before it is taken as a ULID.
+        const FUTURE_TOLERANCE_NANOS: u128 = 86_400 * 1_000_000_000;
+
+        let s = s.trim();
+        if s.len() == crate::base32::NULID_STRING_LENGTH {
+            let nulid = s.parse::<Self>()?;
+            let now = crate::time::now_nanos().unwrap_or(u128::MAX);
+            if nulid.nanos() > now.saturating_add(FUTURE_TOLERANCE_NANOS) {
+                return Ok((Self::from_ulid_layout(nulid.0), DetectedFormat::Ulid));
+            }
+            return Ok((nulid, DetectedFormat::Nulid));
+        }
+
+        let uuid = s
+            .get(..9)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("urn:uuid:"))
+            .map_or_else(
+                || s.strip_prefix('{').and_then(|s| s.strip_suffix('}')),
+                |_| Some(&s[9..]),
+            )
+            .unwrap_or(s);
+        let bytes = uuid.as_bytes();
+        if bytes.len() == 36 && [8, 13, 18, 23].iter().all(|&i| bytes[i] == b'-') {
+            let offset = s.len() - uuid.len() - usize::from(s.ends_with('}'));
+            return parse_hex_digits(uuid.bytes().enumerate().filter(|&(_, b)| b != b'-'), offset)
+                .map(|nulid| (nulid, DetectedFormat::Uuid))
+                .map_err(|e| Error::parse(ParseFormat::Uuid, s, e));
+        }
+
+        let hex = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s);
+        if hex.len() == 32 {
+            return parse_hex_digits(hex.bytes().enumerate(), s.len() - hex.len())
+                .map(|nulid| (nulid, DetectedFormat::Hex))
+                .map_err(|e| Error::parse(ParseFormat::Hex, s, e));
+        }
+
+        if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+            return s
+                .parse::<u128>()
+                .map(|value| (Self(value), DetectedFormat::U128))
+                .map_err(|_| Error::parse(ParseFormat::Decimal, s, Error::Overflow));
+        }
+
+        Err(Error::parse(
+            ParseFormat::Unknown,
+            s,
+            Error::InvalidLength {
+                expected: crate::base32::NULID_STRING_LENGTH,
+                found: s.chars().count(),
+            },
+        ))
+    }
+}
+
+/// Folds 32 `(position, byte)` hex digits into a NULID.
+///
+/// `offset` is added to positions reported in `InvalidChar` errors.
+fn parse_hex_digits(digits: impl Iterator<Item = (usize, u8)>, offset: usize) -> Result<Nulid> {
+    let mut value: u128 = 0;
+    for (i, byte) in digits {
+        let digit = char::from(byte)
+            .to_digit(16)
+            .ok_or_else(|| Error::InvalidChar(char::from(byte), i + offset))?;
+        value = (value << 4) | u128::from(digit);
+    }
+    Ok(Nulid(value))
+}
+
+/// Input shape detected by [`Nulid::parse_any`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DetectedFormat {
+    /// 26-character Crockford Base32 NULID.
+    Nulid,
+    /// 26-character ULID, converted keeping its millisecond timestamp.
+    Ulid,
+    /// UUID, hyphenated and optionally braced or `urn:uuid:` prefixed.
+    Uuid,
+    /// 32 hex digits, optionally prefixed with `0x`.
+    Hex,
+    /// Decimal `u128`.
+    U128,
+}
+
+/// Grouped [`Display`](fmt::Display) adapter returned by [`Nulid::display_grouped`].
+#[derive(Debug, Clone, Copy)]
+pub struct GroupedDisplay {
+    nulid: Nulid,
+    group_len: usize,
+    sep: char,
+}
+
+impl fmt::Display for GroupedDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let encoded = self.nulid.to_arraystring();
+        if self.group_len == 0 {
+            return f.write_str(&encoded);
+        }
+
+        let mut sep_buf = [0u8; 4];
+        let sep: &str = self.sep.encode_utf8(&mut sep_buf);
+        for (i, group) in encoded.as_bytes().chunks(self.group_len).enumerate() {
+            if i > 0 {
+                f.write_str(sep)?;
+            }
+            f.write_str(core::str::from_utf8(group).map_err(|_| fmt::Error)?)?;
+        }
+        Ok(())
+    }
+}
+
+/// Masking [`Display`](fmt::Display) adapter returned by [`Nulid::display_redacted`].
+#[derive(Clone, Copy)]
+pub struct RedactedDisplay {
+    nulid: Nulid,
+    prefix: &'static str,
+}
+
+impl RedactedDisplay {
+    /// Number of leading characters shown.
+    pub const VISIBLE_START: usize = 6;
+
+    /// Number of trailing characters shown.
+    pub const VISIBLE_END: usize = 4;
+
+    /// Writes `prefix` before the masked ID, as prefixed ID types display.
+    #[must_use]
+    pub const fn with_prefix(self, prefix: &'static str) -> Self {
+        Self { prefix, ..self }
+    }
+}
+
+impl fmt::Display for RedactedDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let encoded = self.nulid.to_arraystring();
+        let encoded: &str = &encoded;
+        let end = encoded.len() - Self::VISIBLE_END;
+        write!(
+            f,
+            "{}{}…{}",
+            self.prefix,
+            &encoded[..Self::VISIBLE_START],
+            &encoded[end..]
+        )
+    }
+}
+
+// Never print the full ID, even through `{:?}`
+impl fmt::Debug for RedactedDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// [`Display`](fmt::Display) adapter rendering a slice of NULIDs separated by a string.
+///
+/// Log lines listing many IDs would otherwise format each one separately or
+/// collect `to_string()` results and join them. `DisplayList` encodes the IDs
+/// into a stack buffer and hands the formatter one chunk per ~18 IDs, without
+/// allocating.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::{DisplayList, Nulid};
+///
+/// let ids = [Nulid::from_u128(1), Nulid::from_u128(2)];
+/// assert_eq!(
+///     DisplayList::new(&ids).to_string(),
+///     "00000000000000000000000001, 00000000000000000000000002"
+/// );
+/// assert_eq!(
+///     format!("[{}]", DisplayList::new(&ids).with_separator(",")),
+///     "[00000000000000000000000001,00000000000000000000000002]"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayList<'a> {
+    ids: &'a [Nulid],
+    sep: &'a str,
+}
+
+impl<'a> DisplayList<'a> {
+    /// Size of the stack buffer the IDs are encoded into.
+    const BUFFER_LEN: usize = 512;
+
+    /// Creates an adapter rendering `ids` separated by `", "`.
+    #[must_use]
+    pub const fn new(ids: &'a [Nulid]) -> Self {
+        Self { ids, sep: ", " }
+    }
+
+    /// Sets the separator written between IDs.
+    #[must_use]
+    pub const fn with_separator(mut self, sep: &'a str) -> Self {
+        self.sep = sep;
+        self
+    }
+}
+
+impl<'a> From<&'a [Nulid]> for DisplayList<'a> {
+    fn from(ids: &'a [Nulid]) -> Self {
+        Self::new(ids)
+    }
+}
+
+impl fmt::Display for DisplayList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn flush(f: &mut fmt::Formatter<'_>, buf: &[u8]) -> fmt::Result {
+            f.write_str(core::str::from_utf8(buf).map_err(|_| fmt::Error)?)
+        }
+
+        let mut buf = [0u8; Self::BUFFER_LEN];
+        let mut len = 0;
+        for (i, id) in self.ids.iter().enumerate() {
+            if i > 0 {
+                if len + self.sep.len() > buf.len() {
+                    flush(f, &buf[..len])?;
+                    len = 0;
+                }
+                if self.sep.len() > buf.len() {
+                    f.write_str(self.sep)?;
+                } else {
+                    buf[len..len + self.sep.len()].copy_from_slice(self.sep.as_bytes());
+                    len += self.sep.len();
+                }
+            }
+
+            if len + 26 > buf.len() {
+                flush(f, &buf[..len])?;
+                len = 0;
+            }
+            let slot =
+                <&mut [u8; 26]>::try_from(&mut buf[len..len + 26]).map_err(|_| fmt::Error)?;
+            id.encode(slot).map_err(|_| fmt::Error)?;
+            len += 26;
+        }
+        flush(f, &buf[..len])
+    }
+}
+
+impl fmt::Debug for Nulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0u8; 26];
+        let s = self.encode(&mut buf).map_err(|_| fmt::Error)?;
+        f.debug_tuple("Nulid").field(&s).finish()
+    }
+}
+
+impl fmt::Display for Nulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0u8; 26];
+        let s = self.encode(&mut buf).map_err(|_| fmt::Error)?;
+        f.write_str(s)
+    }
+}
+
+impl fmt::LowerHex for Nulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl fmt::UpperHex for Nulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Octal for Nulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Binary for Nulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for Nulid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        crate::base32::decode_u128(s)
+            .map(Self::from_u128)
+            .map_err(|e| Error::parse(ParseFormat::Text, s, e))
+    }
+}
+
+impl Ord for Nulid {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialOrd for Nulid {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
@@ -787,6 +1937,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bucket() {
+        let id = Nulid::from_nanos(1_234_567_890_123_456_789, 42);
+        assert_eq!(
+            id.bucket(Duration::from_secs(1)),
+            Some(u128::from(id.seconds()))
+        );
+        assert_eq!(id.bucket(Duration::from_millis(1)), Some(id.millis()));
+        assert_eq!(id.bucket(Duration::from_nanos(1)), Some(id.nanos()));
+        // Sub-second granularities that are not whole milliseconds
+        assert_eq!(
+            id.bucket(Duration::from_micros(1_500)),
+            Some(1_234_567_890_123_456_789 / 1_500_000)
+        );
+        assert_eq!(Nulid::MAX.bucket(Duration::from_secs(u64::MAX)), Some(0));
+    }
+
+    #[test]
+    fn test_same_bucket_edges() {
+        let minute = Duration::from_secs(60);
+        let start = Nulid::from_nanos(60_000_000_000, u64::MAX);
+        let last = Nulid::from_nanos(119_999_999_999, 0);
+        let next = Nulid::from_nanos(120_000_000_000, 0);
+        assert_eq!(start.same_bucket(last, minute), Some(true));
+        assert_eq!(last.same_bucket(next, minute), Some(false));
+
+        assert!(start.same_second(Nulid::from_nanos(60_999_999_999, 0)));
+        assert!(!start.same_second(Nulid::from_nanos(59_999_999_999, 0)));
+        assert!(last.same_millis(Nulid::from_nanos(119_999_000_000, 0)));
+        assert!(!last.same_millis(next));
+    }
+
+    #[test]
+    fn test_random_entropy_bits() {
+        assert_eq!(Nulid::random_entropy_bits(&[]), 0);
+        let low_bits: Vec<Nulid> = (0..16).map(|n| Nulid::from_nanos(1, n)).collect();
+        assert_eq!(Nulid::random_entropy_bits(&low_bits), 4);
+    }
+
+    #[test]
+    fn test_bucket_zero_granularity() {
+        let id = Nulid::from_nanos(1_234_567_890_123_456_789, 42);
+        assert_eq!(id.bucket(Duration::ZERO), None);
+        assert_eq!(id.same_bucket(id, Duration::ZERO), None);
+        assert_eq!(id.partition_by_time(partitions(4), Duration::ZERO), None);
+    }
+
     #[test]
     fn test_from_to_bytes() {
         let id = Nulid::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
@@ -877,6 +2074,16 @@ mod tests {
         assert_eq!(value, 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
     }
 
+    #[test]
+    fn test_u64_pair_round_trip() {
+        let id = Nulid::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+        let (high, low) = id.to_u64_pair();
+        assert_eq!(high, 0x0123_4567_89AB_CDEF);
+        assert_eq!(low, 0xFEDC_BA98_7654_3210);
+        assert_eq!(Nulid::from_u64_pair(high, low), id);
+        assert_eq!(Nulid::from_u64_pair(u64::MAX, u64::MAX), Nulid::max());
+    }
+
     #[test]
     fn test_from_bytes_trait() {
         let bytes = [
@@ -955,4 +2162,327 @@ mod tests {
             _ => panic!("Expected InvalidLength error"),
         }
     }
+
+    #[test]
+    fn test_to_arraystring() {
+        let id = Nulid::new().unwrap();
+        let s = id.to_arraystring();
+        assert_eq!(&*s, id.to_string());
+        assert_eq!(s.parse::<Nulid>().unwrap(), id);
+    }
+
+    #[test]
+    fn test_from_ascii() {
+        let id = Nulid::new().unwrap();
+        let encoded = id.to_arraystring();
+        assert_eq!(Nulid::from_ascii(encoded.as_bytes()).unwrap(), id);
+        assert_eq!(Nulid::from_ascii_slice(encoded.as_bytes()).unwrap(), id);
+        assert!(matches!(
+            Nulid::from_ascii_slice(b"0123"),
+            Err(Error::InvalidLength {
+                expected: 26,
+                found: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn test_string_checked_round_trip() {
+        for _ in 0..100 {
+            let id = Nulid::new().unwrap();
+            let checked = id.to_string_checked();
+            assert_eq!(Nulid::from_str_checked(&checked).unwrap(), id);
+            assert!(Nulid::from_str_checked(&id.to_string()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_ulid_string_round_trip() {
+        let mut ids: Vec<Nulid> = (0..100).map(|_| Nulid::new().unwrap()).collect();
+        ids.push(Nulid::from_nanos(1_999_999, u64::MAX >> 4));
+        ids.sort();
+
+        let ulids: Vec<String> = ids
+            .iter()
+            .map(|id| id.to_ulid_string().unwrap().to_string())
+            .collect();
+        assert!(ulids.windows(2).all(|w| w[0] <= w[1]));
+        for (id, ulid) in ids.iter().zip(&ulids) {
+            assert_eq!(Nulid::from_ulid_string(ulid).unwrap(), *id);
+        }
+    }
+
+    #[test]
+    fn test_ulid_string_timestamp_prefix() {
+        // 1_469_922_850_259 ms is the example timestamp from the ULID spec
+        let id = Nulid::from_nanos(1_469_922_850_259_000_042, 0);
+        assert!(id.to_ulid_string().unwrap().starts_with("01ARZ3NDEK"));
+    }
+
+    #[test]
+    fn test_ulid_string_out_of_range() {
+        assert_eq!(Nulid::max().to_ulid_string(), Err(Error::Overflow));
+
+        let input = "7ZZZZZZZZZZZZZZZZZZZZZZZZZ";
+        let Err(Error::Parse(error)) = Nulid::from_ulid_string(input) else {
+            panic!("expected a parse error for {input}");
+        };
+        assert_eq!(error.format(), ParseFormat::Text);
+        assert_eq!(error.input(), input);
+        assert_eq!(Error::from(error.kind()), Error::Overflow);
+    }
+
+    #[test]
+    fn test_parse_any_shapes() {
+        let id = Nulid::from_nanos(1_704_067_200_123_456_789, 0x0ABC_DEF0_1234_5678);
+        let hex = format!("{:032x}", id.as_u128());
+        let uuid = format!(
+            "{}-{}-{}-{}-{}",
+            &hex[..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..]
+        );
+
+        for (input, format) in [
+            (id.to_string(), DetectedFormat::Nulid),
+            (id.to_string().to_lowercase(), DetectedFormat::Nulid),
+            (uuid.clone(), DetectedFormat::Uuid),
+            (uuid.to_uppercase(), DetectedFormat::Uuid),
+            (format!("{{{uuid}}}"), DetectedFormat::Uuid),
+            (format!("URN:UUID:{uuid}"), DetectedFormat::Uuid),
+            (hex.clone(), DetectedFormat::Hex),
+            (format!("0x{}", hex.to_uppercase()), DetectedFormat::Hex),
+            (format!("  {}\n", id.as_u128()), DetectedFormat::U128),
+        ] {
+            assert_eq!(Nulid::parse_any(&input), Ok((id, format)), "{input}");
+        }
+
+        // Only recent ULIDs read as a NULID from the future.
+        let fresh = Nulid::new().unwrap();
+        let recent = fresh.to_ulid_string().unwrap();
+        assert_eq!(Nulid::parse_any(&recent), Ok((fresh, DetectedFormat::Ulid)));
+        let old = Nulid::from_nanos(1_469_922_850_259_000_042, 0);
+        let legacy = old.to_ulid_string().unwrap();
+        assert_eq!(
+            Nulid::parse_any(&legacy).map(|(_, format)| format),
+            Ok(DetectedFormat::Nulid)
+        );
+    }
+
+    #[test]
+    fn test_parse_any_errors() {
+        let parse_any = |s: &str| Nulid::parse_any(s).map_err(|e| e.reason());
+        assert_eq!(
+            parse_any("01GZTV7EQ056J0E6N276XD6F3U"),
+            Err(Error::InvalidChar('U', 25))
+        );
+        assert_eq!(
+            parse_any("{018cc251-f400-7800-8abc-def01234567g}"),
+            Err(Error::InvalidChar('g', 36))
+        );
+        assert_eq!(
+            parse_any("0x018cc251f40078008abcdef01234567g"),
+            Err(Error::InvalidChar('g', 33))
+        );
+        assert_eq!(parse_any(&"9".repeat(40)), Err(Error::Overflow));
+        assert_eq!(
+            parse_any("not an id"),
+            Err(Error::InvalidLength {
+                expected: 26,
+                found: 9
+            })
+        );
+        assert!(parse_any("").is_err());
+
+        // Errors record the format the input was detected as
+        for (input, format) in [
+            ("01GZTV7EQ056J0E6N276XD6F3U", ParseFormat::Text),
+            ("{018cc251-f400-7800-8abc-def01234567g}", ParseFormat::Uuid),
+            ("0x018cc251f40078008abcdef01234567g", ParseFormat::Hex),
+            (
+                "9999999999999999999999999999999999999999",
+                ParseFormat::Decimal,
+            ),
+            ("not an id", ParseFormat::Unknown),
+        ] {
+            let Err(Error::Parse(error)) = Nulid::parse_any(input) else {
+                panic!("expected a parse error for {input}");
+            };
+            assert_eq!(error.format(), format);
+            assert_eq!(error.input(), input);
+        }
+    }
+
+    #[test]
+    fn test_display_grouped() {
+        let id: Nulid = "01GZTV7EQ056J0E6N276XD6F3D".parse().unwrap();
+        assert_eq!(
+            id.display_grouped(4, '_').to_string(),
+            "01GZ_TV7E_Q056_J0E6_N276_XD6F_3D"
+        );
+        assert_eq!(
+            id.display_grouped(13, '·').to_string(),
+            "01GZTV7EQ056J·0E6N276XD6F3D"
+        );
+        assert_eq!(id.display_grouped(0, '-').to_string(), id.to_string());
+        assert_eq!(id.display_grouped(26, '-').to_string(), id.to_string());
+
+        for (len, sep) in [(1, '-'), (5, ' '), (7, '·')] {
+            let grouped = id.display_grouped(len, sep).to_string();
+            assert_eq!(Nulid::parse_grouped(&grouped, sep).unwrap(), id);
+        }
+    }
+
+    fn partitions(n: u32) -> NonZeroU32 {
+        NonZeroU32::new(n).unwrap()
+    }
+
+    #[test]
+    fn test_partition() {
+        // Pinned values: the hash is part of the public contract
+        for (random, expected) in [(0, [0, 0, 0]), (1, [8, 11, 704]), (42, [6, 8, 504])] {
+            let id = Nulid::from_nanos(123_456_789, random);
+            assert_eq!(
+                [
+                    id.partition(partitions(12)),
+                    id.partition(partitions(16)),
+                    id.partition(partitions(1000))
+                ],
+                expected
+            );
+            // The timestamp takes no part
+            assert_eq!(
+                Nulid::from_nanos(0, random).partition(partitions(1000)),
+                expected[2]
+            );
+        }
+        assert_eq!(Nulid::MAX.partition(NonZeroU32::MIN), 0);
+
+        // A burst from one nanosecond spreads over every partition
+        let generator = crate::Generator::<_, _, crate::generator::NoNodeId>::with_deps(
+            crate::generator::MockClock::new(1_000),
+            crate::generator::SequentialRng::new(),
+        );
+        let mut counts = [0u32; 8];
+        for _ in 0..8_000 {
+            counts[generator.generate().unwrap().partition(partitions(8)) as usize] += 1;
+        }
+        assert!(
+            counts.iter().all(|&c| (800..1_200).contains(&c)),
+            "{counts:?}"
+        );
+    }
+
+    #[test]
+    fn test_partition_by_time() {
+        let second = Duration::from_secs(1);
+        let ids: Vec<Option<u32>> = (0..6u64)
+            .map(|s| {
+                Nulid::from_nanos(u128::from(s) * 1_000_000_000 + 5, s)
+                    .partition_by_time(partitions(3), second)
+            })
+            .collect();
+        assert_eq!(ids, [0, 1, 2, 0, 1, 2].map(Some));
+    }
+
+    #[test]
+    fn test_display_redacted() {
+        let id: Nulid = "01GZTV7EQ056J0E6N276XD6F3D".parse().unwrap();
+        assert_eq!(id.display_redacted().to_string(), "01GZTV…6F3D");
+        assert_eq!(format!("{:?}", id.display_redacted()), "01GZTV…6F3D");
+        assert_eq!(
+            id.display_redacted().with_prefix("inv_").to_string(),
+            "inv_01GZTV…6F3D"
+        );
+        assert_eq!(Nulid::MAX.display_redacted().to_string(), "7ZZZZZ…ZZZZ");
+    }
+
+    #[test]
+    fn test_parse_strict_overflow() {
+        let max = "7ZZZZZZZZZZZZZZZZZZZZZZZZZ";
+        assert_eq!(Nulid::parse_strict(max).unwrap(), Nulid::MAX);
+        assert_eq!(
+            Nulid::parse_strict("8ZZZZZZZZZZZZZZZZZZZZZZZZZ").map_err(|e| e.reason()),
+            Err(Error::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_parse_grouped_errors() {
+        assert!(matches!(
+            Nulid::parse_grouped("01GZT-V7EQ0-56J0E-6N276-XD6F3", '-').map_err(|e| e.reason()),
+            Err(Error::InvalidLength {
+                expected: 26,
+                found: 25
+            })
+        ));
+        // A different separator is not stripped
+        assert!(Nulid::parse_grouped("01GZT-V7EQ0-56J0E-6N276-XD6F3-D", ' ').is_err());
+        assert!(matches!(
+            Nulid::parse_grouped("01GZT·V7EQ0", '-').map_err(|e| e.reason()),
+            Err(Error::InvalidChar('·', 5))
+        ));
+    }
+
+    #[test]
+    fn test_display_list_matches_join() {
+        let ids: Vec<Nulid> = (0..100).map(|_| Nulid::new().unwrap()).collect();
+        // Separators that do and do not divide the buffer evenly, and one larger than it
+        let long_sep = "-".repeat(600);
+        for sep in [", ", "\n", " · ", long_sep.as_str()] {
+            for count in [0, 1, 2, 18, 19, 100] {
+                let expected = ids[..count]
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(sep);
+                let rendered = DisplayList::new(&ids[..count]).with_separator(sep);
+                assert_eq!(rendered.to_string(), expected, "sep {sep:?}, count {count}");
+            }
+        }
+        assert_eq!(DisplayList::from(&ids[..0]).to_string(), "");
+    }
+
+    #[test]
+    fn test_io_round_trip() {
+        let ids: Vec<Nulid> = (0..10).map(|_| Nulid::new().unwrap()).collect();
+
+        let mut binary = Vec::new();
+        let mut text = Vec::new();
+        for id in &ids {
+            id.write_to(&mut binary).unwrap();
+            id.write_text_to(&mut text).unwrap();
+        }
+        assert_eq!(binary.len(), 160);
+        assert_eq!(text.len(), 260);
+
+        let mut binary = binary.as_slice();
+        let mut text = text.as_slice();
+        for id in &ids {
+            assert_eq!(Nulid::read_from(&mut binary).unwrap(), *id);
+            assert_eq!(Nulid::read_text_from(&mut text).unwrap(), *id);
+        }
+
+        let err = Nulid::read_from(&mut binary).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_read_text_invalid() {
+        let mut input: &[u8] = b"01GZTV7EQ056J0E6N276XD6F3U";
+        let err = Nulid::read_text_from(&mut input).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_radix_formatting() {
+        let id = Nulid::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+        assert_eq!(format!("{id:x}"), "123456789abcdeffedcba9876543210");
+        assert_eq!(format!("{id:032X}"), "0123456789ABCDEFFEDCBA9876543210");
+        assert_eq!(format!("{id:#x}"), "0x123456789abcdeffedcba9876543210");
+        assert_eq!(format!("{:o}", Nulid::from_u128(8)), "10");
+        assert_eq!(format!("{:#010b}", Nulid::from_u128(5)), "0b00000101");
+    }
 }
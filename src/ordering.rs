@@ -0,0 +1,365 @@
+//! Ordering audits for NULID streams.
+//!
+//! A generator guarantees that its own IDs are strictly increasing, but a
+//! consumer usually sees IDs after a queue, a database, or a merge of
+//! several producers. [`OrderingValidator`] checks that guarantee from the
+//! receiving end: feed it every ID in arrival order and it reports
+//! duplicates and regressions as they happen, while keeping
+//! [`OrderingStats`] for a summary.
+//!
+//! Streams merged from several producers are never perfectly ordered, since
+//! their clocks disagree slightly. [`OrderingValidator::with_skew_tolerance`]
+//! accepts IDs that arrive late by up to a given time and records how far
+//! behind they were, so clock skew between producers shows up in the stats
+//! instead of as errors.
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::Nulid;
+//! use nulid::ordering::{OrderingValidator, Violation};
+//!
+//! let ids = [1, 2, 2, 1, 3].map(|n| Nulid::from_nanos(n, 0));
+//! let mut validator = OrderingValidator::new();
+//!
+//! let results: Vec<_> = ids.iter().map(|&id| validator.observe(id)).collect();
+//! assert_eq!(results[2], Err(Violation::Duplicate(ids[2])));
+//! assert_eq!(results[3], Err(Violation::Regression { id: ids[3], newest: ids[2] }));
+//!
+//! let stats = validator.stats();
+//! assert_eq!(stats.observed, 5);
+//! assert_eq!((stats.duplicates, stats.regressions), (1, 1));
+//! ```
+
+use crate::Nulid;
+use core::fmt;
+use core::time::Duration;
+use std::collections::BTreeSet;
+
+/// A break in the expected ordering of an ID stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Violation {
+    /// The ID was already observed.
+    ///
+    /// Only the newest ID and the late IDs accepted within the skew
+    /// tolerance are remembered, so any other repeat is reported as a
+    /// [`Regression`](Self::Regression).
+    Duplicate(Nulid),
+
+    /// The ID is older than the newest observed ID by more than the skew
+    /// tolerance.
+    Regression {
+        /// The ID that arrived out of order.
+        id: Nulid,
+        /// The newest ID observed before it.
+        newest: Nulid,
+    },
+}
+
+impl Violation {
+    /// Returns the ID that caused the violation.
+    #[must_use]
+    pub const fn id(&self) -> Nulid {
+        match *self {
+            Self::Duplicate(id) | Self::Regression { id, .. } => id,
+        }
+    }
+
+    /// Returns how far the ID's timestamp is behind the newest ID's.
+    ///
+    /// This is zero for duplicates, and for regressions within one
+    /// nanosecond.
+    #[must_use]
+    pub const fn behind(&self) -> Duration {
+        match *self {
+            Self::Duplicate(_) => Duration::ZERO,
+            Self::Regression { id, newest } => lag(id, newest),
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Duplicate(id) => write!(f, "Duplicate ID {id}"),
+            Self::Regression { id, newest } => write!(
+                f,
+                "ID {id} is {:?} behind the newest ID {newest}",
+                self.behind()
+            ),
+        }
+    }
+}
+
+impl core::error::Error for Violation {}
+
+/// Summary of the IDs seen by an [`OrderingValidator`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OrderingStats {
+    /// Number of IDs passed to [`observe`](OrderingValidator::observe).
+    pub observed: u64,
+    /// IDs accepted although they were older than the newest ID, because
+    /// they were within the skew tolerance.
+    pub late: u64,
+    /// IDs rejected as [`Violation::Duplicate`].
+    pub duplicates: u64,
+    /// IDs rejected as [`Violation::Regression`].
+    pub regressions: u64,
+    /// Largest timestamp lag behind the newest ID, over all late and
+    /// regressed IDs.
+    pub max_skew: Duration,
+    /// The newest ID observed.
+    pub newest: Option<Nulid>,
+}
+
+impl OrderingStats {
+    /// Returns the number of IDs rejected as violations.
+    #[must_use]
+    pub const fn violations(&self) -> u64 {
+        self.duplicates + self.regressions
+    }
+}
+
+/// Checks that a stream of IDs is strictly increasing.
+///
+/// By default every ID must be greater than the one before it. With a skew
+/// tolerance, IDs may also arrive late, as long as their timestamp is within
+/// the tolerance of the newest ID and they are not duplicates. IDs in that
+/// window are remembered to catch duplicates, so memory grows with the
+/// number of IDs per tolerance window; a strict validator remembers only
+/// the newest ID.
+///
+/// # Examples
+///
+/// Auditing a stream merged from two producers whose clocks differ by a few
+/// microseconds:
+///
+/// ```
+/// use core::time::Duration;
+/// use nulid::Nulid;
+/// use nulid::ordering::OrderingValidator;
+///
+/// let mut validator = OrderingValidator::new().with_skew_tolerance(Duration::from_millis(1));
+/// validator.observe(Nulid::from_nanos(10_000, 1))?;
+/// validator.observe(Nulid::from_nanos(7_000, 2))?; // 3µs late: accepted
+/// assert!(validator.observe(Nulid::from_nanos(7_000, 2)).is_err()); // seen before
+///
+/// let stats = validator.stats();
+/// assert_eq!(stats.late, 1);
+/// assert_eq!(stats.max_skew, Duration::from_micros(3));
+/// # Ok::<(), nulid::ordering::Violation>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OrderingValidator {
+    tolerance: Duration,
+    window: BTreeSet<Nulid>,
+    stats: OrderingStats,
+}
+
+impl OrderingValidator {
+    /// Creates a validator that requires strictly increasing IDs.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            tolerance: Duration::ZERO,
+            window: BTreeSet::new(),
+            stats: OrderingStats {
+                observed: 0,
+                late: 0,
+                duplicates: 0,
+                regressions: 0,
+                max_skew: Duration::ZERO,
+                newest: None,
+            },
+        }
+    }
+
+    /// Accepts IDs up to `tolerance` older than the newest ID.
+    #[must_use]
+    pub const fn with_skew_tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Returns the configured skew tolerance.
+    #[must_use]
+    pub const fn skew_tolerance(&self) -> Duration {
+        self.tolerance
+    }
+
+    /// Checks the next ID in the stream and records it.
+    ///
+    /// Rejected IDs are counted but do not change the newest ID.
+    ///
+    /// # Errors
+    ///
+    /// - [`Violation::Duplicate`] if the ID was already observed
+    /// - [`Violation::Regression`] if the ID is older than the newest one by
+    ///   more than the skew tolerance
+    pub fn observe(&mut self, id: Nulid) -> Result<(), Violation> {
+        self.stats.observed += 1;
+
+        let Some(newest) = self.stats.newest.filter(|&newest| id <= newest) else {
+            self.stats.newest = Some(id);
+            self.window.insert(id);
+            self.prune(id);
+            return Ok(());
+        };
+
+        if self.window.contains(&id) {
+            self.stats.duplicates += 1;
+            return Err(Violation::Duplicate(id));
+        }
+
+        let behind = lag(id, newest);
+        self.stats.max_skew = self.stats.max_skew.max(behind);
+        if self.tolerance.is_zero() || behind > self.tolerance {
+            self.stats.regressions += 1;
+            return Err(Violation::Regression { id, newest });
+        }
+
+        self.stats.late += 1;
+        self.window.insert(id);
+        Ok(())
+    }
+
+    /// Returns the statistics collected so far.
+    #[must_use]
+    pub const fn stats(&self) -> OrderingStats {
+        self.stats
+    }
+
+    /// Forgets every observed ID and resets the statistics.
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.stats = OrderingStats::default();
+    }
+
+    /// Drops remembered IDs that fall outside the tolerance of `newest`.
+    fn prune(&mut self, newest: Nulid) {
+        if self.tolerance.is_zero() {
+            self.window.retain(|&id| id == newest);
+            return;
+        }
+        let oldest = newest.nanos().saturating_sub(self.tolerance.as_nanos());
+        self.window = self.window.split_off(&Nulid::from_nanos(oldest, 0));
+    }
+}
+
+/// Returns how far `id`'s timestamp is behind `newest`'s.
+#[allow(clippy::cast_possible_truncation)] // NULID timestamps fit in a Duration
+const fn lag(id: Nulid, newest: Nulid) -> Duration {
+    let nanos = newest.nanos().saturating_sub(id.nanos());
+    Duration::new(
+        (nanos / 1_000_000_000) as u64,
+        (nanos % 1_000_000_000) as u32,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strict_stream() {
+        let mut validator = OrderingValidator::new();
+        for n in 0..100 {
+            validator.observe(Nulid::from_nanos(1_000, n)).unwrap();
+        }
+
+        let newest = Nulid::from_nanos(1_000, 99);
+        let older = Nulid::from_nanos(1_000, 50);
+        assert_eq!(validator.observe(newest), Err(Violation::Duplicate(newest)));
+        assert_eq!(
+            validator.observe(older),
+            Err(Violation::Regression { id: older, newest })
+        );
+        assert_eq!(validator.window.len(), 1);
+
+        let stale = Nulid::from_nanos(400, 0);
+        let violation = validator.observe(stale).unwrap_err();
+        assert_eq!(violation, Violation::Regression { id: stale, newest });
+        assert_eq!(violation.behind(), Duration::from_nanos(600));
+        assert_eq!(violation.id(), stale);
+
+        let stats = validator.stats();
+        assert_eq!(stats.observed, 103);
+        assert_eq!(stats.violations(), 3);
+        assert_eq!(stats.late, 0);
+        assert_eq!(stats.newest, Some(newest));
+        assert_eq!(stats.max_skew, Duration::from_nanos(600));
+    }
+
+    #[test]
+    fn test_skew_tolerance() {
+        let mut validator = OrderingValidator::new().with_skew_tolerance(Duration::from_micros(5));
+        assert_eq!(validator.skew_tolerance(), Duration::from_micros(5));
+
+        validator.observe(Nulid::from_nanos(10_000, 0)).unwrap();
+        validator.observe(Nulid::from_nanos(6_000, 0)).unwrap();
+        validator.observe(Nulid::from_nanos(20_000, 0)).unwrap();
+
+        // Pruned when the newest ID moved on, so it regresses rather than duplicates
+        let old = Nulid::from_nanos(10_000, 0);
+        assert!(matches!(
+            validator.observe(old),
+            Err(Violation::Regression { .. })
+        ));
+        assert_eq!(
+            validator.observe(Nulid::from_nanos(20_000, 0)),
+            Err(Violation::Duplicate(Nulid::from_nanos(20_000, 0)))
+        );
+
+        let stats = validator.stats();
+        assert_eq!((stats.late, stats.duplicates, stats.regressions), (1, 1, 1));
+        assert_eq!(stats.max_skew, Duration::from_micros(10));
+        assert!(validator.window.len() <= 2);
+
+        validator.reset();
+        assert_eq!(validator.stats(), OrderingStats::default());
+        validator.observe(old).unwrap();
+    }
+
+    #[test]
+    fn test_generated_stream_with_injected_faults() {
+        let generator = crate::Generator::new();
+        let mut validator = OrderingValidator::new();
+        let ids: Vec<Nulid> = (0..1_000).map(|_| generator.generate().unwrap()).collect();
+
+        for (i, &id) in ids.iter().enumerate() {
+            validator.observe(id).unwrap();
+            if i % 100 == 99 {
+                assert_eq!(validator.observe(id), Err(Violation::Duplicate(id)));
+                let replayed = ids[i - 50];
+                assert_eq!(
+                    validator.observe(replayed),
+                    Err(Violation::Regression {
+                        id: replayed,
+                        newest: id
+                    })
+                );
+            }
+        }
+
+        let stats = validator.stats();
+        assert_eq!(stats.observed, 1_020);
+        assert_eq!((stats.duplicates, stats.regressions), (10, 10));
+        assert_eq!(stats.newest, ids.last().copied());
+    }
+
+    #[test]
+    fn test_violation_display() {
+        let id = Nulid::from_nanos(1, 0);
+        let newest = Nulid::from_nanos(2_000_001, 0);
+        assert!(
+            Violation::Duplicate(id)
+                .to_string()
+                .starts_with("Duplicate ID 0000")
+        );
+        assert!(
+            Violation::Regression { id, newest }
+                .to_string()
+                .contains(" is 2ms behind the newest ID ")
+        );
+    }
+}
@@ -0,0 +1,402 @@
+//! Prefetching generator for latency-critical paths.
+//!
+//! [`PrefetchingGenerator`] keeps a small ring buffer of pre-generated NULIDs
+//! that a background thread refills. On the request path, [`generate`] is
+//! reduced to a buffer pop, moving clock reads, RNG calls, and generator
+//! locking off the hot path.
+//!
+//! IDs are taken from a single underlying [`Generator`], so the sequence
+//! returned by `generate()` is strictly monotonic. Note that each ID carries
+//! the timestamp of when it was *prefetched*, which may be slightly earlier
+//! than when it is handed out.
+//!
+//! # Backpressure
+//!
+//! When consumers drain the buffer faster than the worker refills it, the
+//! configured [`Backpressure`] policy decides what `generate()` does:
+//!
+//! - [`Backpressure::GenerateInline`] (default): generate the ID on the calling
+//!   thread, so latency degrades gracefully to that of a plain `Generator`
+//! - [`Backpressure::Block`]: wait until the worker has refilled the buffer
+//!
+//! [`generate`]: PrefetchingGenerator::generate
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::prefetch::PrefetchingGenerator;
+//!
+//! # fn main() -> nulid::Result<()> {
+//! let generator = PrefetchingGenerator::new(256);
+//!
+//! let id1 = generator.generate()?;
+//! let id2 = generator.generate()?;
+//! assert!(id2 > id1);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::generator::{Clock, CryptoRng, NoNodeId, NodeId, Rng, SystemClock};
+use crate::{Error, Generator, Nulid, Result};
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+
+/// Most IDs the worker generates before publishing them to the buffer.
+const CHUNK_LEN: usize = 16;
+
+/// What [`PrefetchingGenerator::generate`] does when the buffer is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Backpressure {
+    /// Generate the ID on the calling thread.
+    #[default]
+    GenerateInline,
+    /// Block until the background worker refills the buffer.
+    Block,
+}
+
+/// Buffer contents shared between consumers and the worker.
+struct Buffer {
+    ids: VecDeque<Nulid>,
+    /// Error that stopped the worker, if any.
+    failed: Option<Error>,
+    /// Latest ID a consumer generated inline since the worker last published.
+    inline_floor: Option<Nulid>,
+    shutdown: bool,
+}
+
+/// State shared between the handle and the background worker.
+struct Shared<C: Clock, R: Rng, N: NodeId> {
+    generator: Generator<C, R, N>,
+    buffer: Mutex<Buffer>,
+    /// Signals the worker that the buffer needs refilling.
+    refill: Condvar,
+    /// Signals blocked consumers that IDs are available.
+    available: Condvar,
+    capacity: usize,
+}
+
+impl<C: Clock, R: Rng, N: NodeId> Shared<C, R, N> {
+    fn lock(&self) -> Result<MutexGuard<'_, Buffer>> {
+        self.buffer.lock().map_err(|_| Error::MutexPoisoned)
+    }
+
+    /// Worker loop: keeps the buffer full until shutdown or a generation error.
+    ///
+    /// IDs are generated in chunks of up to [`CHUNK_LEN`] without holding the
+    /// buffer lock, then published in one step, so consumers can pop IDs
+    /// while the clock and RNG are being read.
+    fn run(&self) -> Result<()> {
+        loop {
+            let missing = {
+                let mut buffer = self.lock()?;
+                while !buffer.shutdown && buffer.ids.len() >= self.capacity {
+                    buffer = self.refill.wait(buffer).map_err(|_| Error::MutexPoisoned)?;
+                }
+                if buffer.shutdown {
+                    return Ok(());
+                }
+                (self.capacity - buffer.ids.len()).min(CHUNK_LEN)
+            };
+
+            let mut chunk = Vec::with_capacity(missing);
+            let mut failed = None;
+            for _ in 0..missing {
+                match self.generator.generate() {
+                    Ok(id) => chunk.push(id),
+                    Err(err) => {
+                        failed = Some(err);
+                        break;
+                    }
+                }
+            }
+
+            let mut buffer = self.lock()?;
+            // A consumer that found the buffer empty may have generated an ID
+            // newer than part of this chunk; drop those so the IDs handed
+            // out stay strictly increasing.
+            let floor = buffer.inline_floor.take();
+            buffer.ids.extend(
+                chunk
+                    .into_iter()
+                    .filter(|&id| floor.is_none_or(|floor| id > floor)),
+            );
+            if let Some(err) = failed {
+                buffer.failed = Some(err.clone());
+                drop(buffer);
+                self.available.notify_all();
+                return Err(err);
+            }
+            drop(buffer);
+            self.available.notify_all();
+        }
+    }
+}
+
+/// Generator that serves NULIDs from a buffer refilled by a background thread.
+///
+/// See the [module documentation](self) for details.
+pub struct PrefetchingGenerator<C = SystemClock, R = CryptoRng, N = NoNodeId>
+where
+    C: Clock + 'static,
+    R: Rng + 'static,
+    N: NodeId + 'static,
+{
+    shared: Arc<Shared<C, R, N>>,
+    backpressure: Backpressure,
+    worker: Option<JoinHandle<Result<()>>>,
+}
+
+impl PrefetchingGenerator<SystemClock, CryptoRng, NoNodeId> {
+    /// Creates a prefetching generator with the given buffer capacity.
+    ///
+    /// Uses system clock, cryptographic RNG, and [`Backpressure::GenerateInline`].
+    /// A capacity of `0` is treated as `1`.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self::from_generator(Generator::new(), capacity, Backpressure::default())
+    }
+}
+
+impl<C, R, N> PrefetchingGenerator<C, R, N>
+where
+    C: Clock + 'static,
+    R: Rng + 'static,
+    N: NodeId + 'static,
+{
+    /// Creates a prefetching generator around an existing generator.
+    ///
+    /// Spawns the background worker immediately; the buffer starts filling
+    /// right away. A capacity of `0` is treated as `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nulid::Generator;
+    /// use nulid::generator::{CryptoRng, SystemClock, WithNodeId};
+    /// use nulid::prefetch::{Backpressure, PrefetchingGenerator};
+    ///
+    /// # fn main() -> nulid::Result<()> {
+    /// let inner = Generator::<SystemClock, CryptoRng, WithNodeId>::with_node_id(7);
+    /// let generator = PrefetchingGenerator::from_generator(inner, 64, Backpressure::Block);
+    /// let id = generator.generate()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn from_generator(
+        generator: Generator<C, R, N>,
+        capacity: usize,
+        backpressure: Backpressure,
+    ) -> Self {
+        let capacity = capacity.max(1);
+        let shared = Arc::new(Shared {
+            generator,
+            buffer: Mutex::new(Buffer {
+                ids: VecDeque::with_capacity(capacity),
+                failed: None,
+                inline_floor: None,
+                shutdown: false,
+            }),
+            refill: Condvar::new(),
+            available: Condvar::new(),
+            capacity,
+        });
+
+        let worker_shared = Arc::clone(&shared);
+        let worker = std::thread::Builder::new()
+            .name("nulid-prefetch".into())
+            .spawn(move || worker_shared.run())
+            .ok();
+
+        Self {
+            shared,
+            backpressure,
+            worker,
+        }
+    }
+
+    /// Returns the next NULID from the buffer.
+    ///
+    /// If the buffer is empty, behaves according to the configured
+    /// [`Backpressure`] policy.
+    ///
+    /// # Errors
+    ///
+    /// - `MutexPoisoned`: If the internal mutex is poisoned
+    /// - Any error from the underlying generator, once the buffer is drained
+    pub fn generate(&self) -> Result<Nulid> {
+        let mut buffer = self.shared.lock()?;
+        loop {
+            if let Some(id) = buffer.ids.pop_front() {
+                if buffer.ids.len() <= self.shared.capacity / 2 {
+                    self.shared.refill.notify_one();
+                }
+                drop(buffer);
+                return Ok(id);
+            }
+
+            let worker_alive = buffer.failed.is_none() && self.worker.is_some();
+            if self.backpressure == Backpressure::GenerateInline || !worker_alive {
+                // The buffer is empty, so this ID follows every one handed
+                // out so far; the worker discards older IDs it publishes later.
+                let id = self.shared.generator.generate();
+                if let Ok(id) = id {
+                    buffer.inline_floor = Some(id);
+                }
+                self.shared.refill.notify_one();
+                drop(buffer);
+                return id;
+            }
+
+            self.shared.refill.notify_one();
+            buffer = self
+                .shared
+                .available
+                .wait(buffer)
+                .map_err(|_| Error::MutexPoisoned)?;
+        }
+    }
+
+    /// Returns the number of IDs currently buffered.
+    #[must_use]
+    pub fn buffered(&self) -> usize {
+        self.shared.lock().map_or(0, |buffer| buffer.ids.len())
+    }
+
+    /// Returns the buffer capacity.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.shared.capacity
+    }
+
+    /// Returns the configured backpressure policy.
+    #[must_use]
+    pub const fn backpressure(&self) -> Backpressure {
+        self.backpressure
+    }
+}
+
+impl<C, R, N> Drop for PrefetchingGenerator<C, R, N>
+where
+    C: Clock + 'static,
+    R: Rng + 'static,
+    N: NodeId + 'static,
+{
+    fn drop(&mut self) {
+        if let Ok(mut buffer) = self.shared.buffer.lock() {
+            buffer.shutdown = true;
+        }
+        self.shared.refill.notify_all();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_strictly_increasing(ids: &[Nulid]) {
+        for i in 1..ids.len() {
+            assert!(ids[i] > ids[i - 1], "ID {} not > ID {}", i, i - 1);
+        }
+    }
+
+    #[test]
+    fn test_prefetch_monotonic_inline() {
+        let generator = PrefetchingGenerator::new(4);
+        let ids: Vec<Nulid> = (0..1000).map(|_| generator.generate().unwrap()).collect();
+        assert_strictly_increasing(&ids);
+    }
+
+    #[test]
+    fn test_prefetch_monotonic_block() {
+        let generator =
+            PrefetchingGenerator::from_generator(Generator::new(), 4, Backpressure::Block);
+        assert_eq!(generator.backpressure(), Backpressure::Block);
+        let ids: Vec<Nulid> = (0..1000).map(|_| generator.generate().unwrap()).collect();
+        assert_strictly_increasing(&ids);
+    }
+
+    #[test]
+    fn test_prefetch_fills_buffer() {
+        let generator = PrefetchingGenerator::new(16);
+        assert_eq!(generator.capacity(), 16);
+
+        let start = std::time::Instant::now();
+        while generator.buffered() < 16 && start.elapsed().as_secs() < 5 {
+            std::thread::yield_now();
+        }
+        assert_eq!(generator.buffered(), 16);
+    }
+
+    #[test]
+    fn test_prefetch_concurrent_unique() {
+        let generator = Arc::new(PrefetchingGenerator::new(8));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let generator = Arc::clone(&generator);
+                std::thread::spawn(move || {
+                    (0..250)
+                        .map(|_| generator.generate().unwrap())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut all = Vec::new();
+        for handle in handles {
+            all.extend(handle.join().unwrap());
+        }
+        all.sort();
+        all.dedup();
+        assert_eq!(all.len(), 1000);
+    }
+
+    /// Clock that takes a fixed time per reading, to make refills slow.
+    struct SlowClock(std::time::Duration);
+
+    impl Clock for SlowClock {
+        fn now_nanos(&self) -> Result<u128> {
+            std::thread::sleep(self.0);
+            crate::time::now_nanos()
+        }
+    }
+
+    #[test]
+    fn test_prefetch_generate_during_refill() {
+        let inner = Generator::<_, CryptoRng, NoNodeId>::with_clock(SlowClock(
+            std::time::Duration::from_millis(1),
+        ));
+        let generator = PrefetchingGenerator::from_generator(inner, 1000, Backpressure::Block);
+
+        // A full refill takes at least a second; consumers must not wait for it.
+        let start = std::time::Instant::now();
+        let ids: Vec<Nulid> = (0..5).map(|_| generator.generate().unwrap()).collect();
+        assert!(start.elapsed() < std::time::Duration::from_millis(500));
+        assert!(generator.buffered() < generator.capacity() / 2);
+        assert_strictly_increasing(&ids);
+    }
+
+    #[test]
+    fn test_prefetch_inline_ids_interleave_with_chunks() {
+        let inner = Generator::<_, CryptoRng, NoNodeId>::with_clock(SlowClock(
+            std::time::Duration::from_micros(200),
+        ));
+        let generator = PrefetchingGenerator::from_generator(inner, 64, Backpressure::default());
+
+        // Draining faster than the worker refills mixes inline IDs with
+        // chunks generated before them.
+        let ids: Vec<Nulid> = (0..500).map(|_| generator.generate().unwrap()).collect();
+        assert_strictly_increasing(&ids);
+    }
+
+    #[test]
+    fn test_prefetch_zero_capacity() {
+        let generator = PrefetchingGenerator::new(0);
+        assert_eq!(generator.capacity(), 1);
+        assert!(generator.generate().is_ok());
+    }
+}
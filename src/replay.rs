@@ -0,0 +1,327 @@
+//! Deterministic record and replay of NULID generation sequences.
+//!
+//! Deterministic simulation testing needs to reproduce production ID sequences
+//! bit-for-bit. This module provides two generators for that:
+//!
+//! - [`RecordingGenerator`] generates IDs like [`Generator`](crate::Generator)
+//!   and logs every `(timestamp, random)` pair it draws
+//! - [`ReplayGenerator`] consumes such a log and reproduces the exact same
+//!   ID sequence, including any increment-on-skew adjustments
+//!
+//! # Examples
+//!
+//! ```
+//! use nulid::replay::{RecordingGenerator, ReplayGenerator};
+//!
+//! # fn main() -> nulid::Result<()> {
+//! let recorder = RecordingGenerator::new();
+//! let original: Vec<_> = (0..5).map(|_| recorder.generate()).collect::<nulid::Result<_>>()?;
+//!
+//! let replay = ReplayGenerator::new(recorder.records()?);
+//! let replayed: Vec<_> = (0..5).map(|_| replay.generate()).collect::<nulid::Result<_>>()?;
+//!
+//! assert_eq!(original, replayed);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::generator::{
+    Clock, CryptoRng, NoNodeId, NodeId, Rng, SystemClock, next_monotonic, random_bits,
+};
+use crate::{Error, Nulid, Result};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// A single `(timestamp, random)` pair drawn during generation.
+///
+/// `random` holds the full 60-bit random field, including the embedded
+/// node ID when the recording generator was configured with one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GenerationRecord {
+    /// Timestamp read from the clock, in nanoseconds since Unix epoch.
+    pub timestamp_nanos: u128,
+    /// The 60-bit random field of the candidate ID.
+    pub random: u64,
+}
+
+impl GenerationRecord {
+    /// Creates a record from a timestamp and random field.
+    #[must_use]
+    pub const fn new(timestamp_nanos: u128, random: u64) -> Self {
+        Self {
+            timestamp_nanos,
+            random,
+        }
+    }
+}
+
+// ============================================================================
+// Recording Generator
+// ============================================================================
+
+/// Internal state of a [`RecordingGenerator`].
+struct RecordingState {
+    last: Option<Nulid>,
+    log: Vec<GenerationRecord>,
+}
+
+/// Generator that logs every `(timestamp, random)` pair it draws.
+///
+/// IDs are generated exactly as [`Generator`](crate::Generator) would,
+/// so a recording generator can be dropped into production code paths.
+/// The log can later be fed to a [`ReplayGenerator`].
+///
+/// # Examples
+///
+/// ```
+/// use nulid::replay::RecordingGenerator;
+///
+/// # fn main() -> nulid::Result<()> {
+/// let recorder = RecordingGenerator::new();
+/// let id = recorder.generate()?;
+///
+/// let records = recorder.records()?;
+/// assert_eq!(records.len(), 1);
+/// assert_eq!(records[0].timestamp_nanos, id.nanos());
+/// # Ok(())
+/// # }
+/// ```
+pub struct RecordingGenerator<C: Clock = SystemClock, R: Rng = CryptoRng, N: NodeId = NoNodeId> {
+    clock: C,
+    rng: R,
+    node_id: N,
+    state: Mutex<RecordingState>,
+}
+
+impl RecordingGenerator<SystemClock, CryptoRng, NoNodeId> {
+    /// Creates a recording generator using system clock and cryptographic RNG.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_deps_and_node_id(SystemClock, CryptoRng, NoNodeId)
+    }
+}
+
+impl Default for RecordingGenerator<SystemClock, CryptoRng, NoNodeId> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Clock, R: Rng, N: NodeId> RecordingGenerator<C, R, N> {
+    /// Creates a recording generator with custom clock and RNG.
+    pub fn with_deps(clock: C, rng: R) -> Self {
+        Self::with_deps_and_node_id(clock, rng, N::default())
+    }
+
+    /// Creates a recording generator with custom clock, RNG, and node ID.
+    pub const fn with_deps_and_node_id(clock: C, rng: R, node_id: N) -> Self {
+        Self {
+            clock,
+            rng,
+            node_id,
+            state: Mutex::new(RecordingState {
+                last: None,
+                log: Vec::new(),
+            }),
+        }
+    }
+
+    /// Generates a new NULID and records the drawn `(timestamp, random)` pair.
+    ///
+    /// # Errors
+    ///
+    /// - `Overflow`: If increment would overflow 128-bit space
+    /// - `MutexPoisoned`: If internal mutex is poisoned
+    /// - `SystemTimeError`: If clock read fails
+    pub fn generate(&self) -> Result<Nulid> {
+        let timestamp = self.clock.now_nanos()?;
+        let random = random_bits(&self.rng, &self.node_id);
+        let candidate = Nulid::from_nanos(timestamp, random);
+
+        let mut state = self.state.lock().map_err(|_| Error::MutexPoisoned)?;
+        let next = next_monotonic(state.last, candidate)?;
+        state.last = Some(next);
+        state
+            .log
+            .push(GenerationRecord::new(candidate.nanos(), random));
+        drop(state);
+
+        Ok(next)
+    }
+
+    /// Returns a copy of all records logged so far.
+    ///
+    /// # Errors
+    ///
+    /// - `MutexPoisoned`: If internal mutex is poisoned
+    pub fn records(&self) -> Result<Vec<GenerationRecord>> {
+        self.state
+            .lock()
+            .map(|state| state.log.clone())
+            .map_err(|_| Error::MutexPoisoned)
+    }
+
+    /// Removes and returns all records logged so far.
+    ///
+    /// The monotonic state is kept, so generation continues seamlessly.
+    ///
+    /// # Errors
+    ///
+    /// - `MutexPoisoned`: If internal mutex is poisoned
+    pub fn take_records(&self) -> Result<Vec<GenerationRecord>> {
+        self.state
+            .lock()
+            .map(|mut state| core::mem::take(&mut state.log))
+            .map_err(|_| Error::MutexPoisoned)
+    }
+
+    /// Returns the last generated NULID, if any.
+    #[must_use]
+    pub fn last(&self) -> Option<Nulid> {
+        self.state.lock().ok().and_then(|state| state.last)
+    }
+}
+
+// ============================================================================
+// Replay Generator
+// ============================================================================
+
+/// Internal state of a [`ReplayGenerator`].
+struct ReplayState {
+    last: Option<Nulid>,
+    pending: VecDeque<GenerationRecord>,
+}
+
+/// Generator that reproduces an ID sequence from a recorded log.
+///
+/// Each call to [`generate`](Self::generate) consumes the next record and
+/// applies the same increment-on-skew rule as the original generator, so the
+/// output matches the recorded run bit-for-bit.
+///
+/// # Examples
+///
+/// ```
+/// use nulid::replay::{GenerationRecord, ReplayGenerator};
+///
+/// # fn main() -> nulid::Result<()> {
+/// let replay = ReplayGenerator::new([
+///     GenerationRecord::new(1_000, 5),
+///     GenerationRecord::new(1_000, 3), // lower random, same nanosecond
+/// ]);
+///
+/// let id1 = replay.generate()?;
+/// let id2 = replay.generate()?;
+/// assert_eq!(id2.as_u128(), id1.as_u128() + 1);
+/// assert!(replay.generate().is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub struct ReplayGenerator {
+    state: Mutex<ReplayState>,
+}
+
+impl ReplayGenerator {
+    /// Creates a replay generator from a recorded log.
+    pub fn new(records: impl IntoIterator<Item = GenerationRecord>) -> Self {
+        Self {
+            state: Mutex::new(ReplayState {
+                last: None,
+                pending: records.into_iter().collect(),
+            }),
+        }
+    }
+
+    /// Reproduces the next NULID from the log.
+    ///
+    /// # Errors
+    ///
+    /// - `ReplayExhausted`: If every record has already been replayed
+    /// - `Overflow`: If increment would overflow 128-bit space
+    /// - `MutexPoisoned`: If internal mutex is poisoned
+    pub fn generate(&self) -> Result<Nulid> {
+        let mut state = self.state.lock().map_err(|_| Error::MutexPoisoned)?;
+        let record = state.pending.pop_front().ok_or(Error::ReplayExhausted)?;
+        let candidate = Nulid::from_nanos(record.timestamp_nanos, record.random);
+        let next = next_monotonic(state.last, candidate)?;
+        state.last = Some(next);
+        drop(state);
+
+        Ok(next)
+    }
+
+    /// Returns the number of records not yet replayed.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.state.lock().map_or(0, |state| state.pending.len())
+    }
+
+    /// Returns the last replayed NULID, if any.
+    #[must_use]
+    pub fn last(&self) -> Option<Nulid> {
+        self.state.lock().ok().and_then(|state| state.last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::{MockClock, SeededRng, WithNodeId};
+    use core::time::Duration;
+
+    #[test]
+    fn test_replay_reproduces_recording() {
+        let recorder = RecordingGenerator::new();
+        let original: Vec<Nulid> = (0..500).map(|_| recorder.generate().unwrap()).collect();
+
+        let replay = ReplayGenerator::new(recorder.records().unwrap());
+        assert_eq!(replay.remaining(), 500);
+        let replayed: Vec<Nulid> = (0..500).map(|_| replay.generate().unwrap()).collect();
+
+        assert_eq!(original, replayed);
+        assert_eq!(replay.remaining(), 0);
+        assert_eq!(replay.last(), recorder.last());
+    }
+
+    #[test]
+    fn test_replay_reproduces_clock_regression() {
+        let clock = MockClock::new(1_000_000_000);
+        let rng = SeededRng::new(42);
+        let recorder = RecordingGenerator::with_deps_and_node_id(&clock, &rng, WithNodeId::new(7));
+
+        let mut original = vec![recorder.generate().unwrap()];
+        clock.regress(Duration::from_millis(10));
+        original.push(recorder.generate().unwrap());
+        clock.advance(Duration::from_millis(20));
+        original.push(recorder.generate().unwrap());
+
+        let replay = ReplayGenerator::new(recorder.take_records().unwrap());
+        let replayed: Vec<Nulid> = (0..3).map(|_| replay.generate().unwrap()).collect();
+
+        assert_eq!(original, replayed);
+        assert_eq!(original[1].as_u128(), original[0].as_u128() + 1);
+        assert!(recorder.records().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_records_report_poisoned_mutex() {
+        let recorder = RecordingGenerator::new();
+        recorder.generate().unwrap();
+        std::thread::scope(|scope| {
+            let poisoner = scope.spawn(|| {
+                let _state = recorder.state.lock().unwrap();
+                panic!("poison the recorder");
+            });
+            assert!(poisoner.join().is_err());
+        });
+
+        assert_eq!(recorder.records(), Err(Error::MutexPoisoned));
+        assert_eq!(recorder.take_records(), Err(Error::MutexPoisoned));
+    }
+
+    #[test]
+    fn test_replay_exhausted() {
+        let replay = ReplayGenerator::new(Vec::new());
+        assert_eq!(replay.generate(), Err(Error::ReplayExhausted));
+        assert!(replay.last().is_none());
+    }
+}
@@ -1,9 +1,18 @@
 //! Time utilities for nanosecond-precision timestamps.
+//!
+//! [`now_nanos`] is the clock behind [`Nulid::new`](crate::Nulid::new) and
+//! [`SystemClock`](crate::generator::SystemClock). Code that builds its own
+//! IDs with [`Nulid::from_nanos`](crate::Nulid::from_nanos), or implements
+//! a custom [`Clock`](crate::generator::Clock), can read the same clock so
+//! its timestamps line up with generated IDs. [`now_nanos_cached`] trades
+//! resolution for fewer clock reads, and is what
+//! [`CachedClock`](crate::generator::CachedClock) plugs into a generator.
 
 use crate::{Error, Result};
 use core::time::Duration;
 use quanta::Clock;
 use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Initialization data for the clock.
@@ -19,6 +28,31 @@ struct ClockBase {
 static CLOCK: OnceLock<Clock> = OnceLock::new();
 static CLOCK_BASE: OnceLock<ClockBase> = OnceLock::new();
 
+/// Raw counter value of the reading cached by `now_nanos_cached()`, or `0`.
+///
+/// The time is derived from the counter, so one atomic holds the whole cache.
+static CACHED_RAW: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the global clock and its base, initializing both on first use.
+fn clock() -> (&'static Clock, &'static ClockBase) {
+    let clock = CLOCK.get_or_init(Clock::new);
+    let clock_base = CLOCK_BASE.get_or_init(|| {
+        let wall_nanos = get_wall_clock_nanos().unwrap_or(0);
+        let quanta_nanos = clock.raw();
+        ClockBase {
+            base_wall_nanos: wall_nanos,
+            base_quanta_nanos: quanta_nanos,
+        }
+    });
+    (clock, clock_base)
+}
+
+/// Converts a raw counter reading to nanoseconds since Unix epoch.
+fn raw_to_nanos(clock: &Clock, clock_base: &ClockBase, raw: u64) -> u128 {
+    let elapsed_nanos = clock.delta_as_nanos(clock_base.base_quanta_nanos, raw);
+    clock_base.base_wall_nanos + u128::from(elapsed_nanos)
+}
+
 /// Returns the current time as nanoseconds since Unix epoch.
 ///
 /// Uses `quanta` for true nanosecond precision on all platforms:
@@ -39,6 +73,9 @@ static CLOCK_BASE: OnceLock<ClockBase> = OnceLock::new();
 /// - **Accuracy**: Relative to system wall-clock, may drift slightly over long periods
 /// - For NULID uniqueness and ordering, precision is more important than absolute accuracy
 ///
+/// The raw counter is scaled to nanoseconds, so readings advance at the rate
+/// of the wall clock whether quanta uses the TSC or the OS monotonic clock.
+///
 /// # Errors
 ///
 /// Returns an error if the system time is before Unix epoch.
@@ -55,29 +92,59 @@ static CLOCK_BASE: OnceLock<ClockBase> = OnceLock::new();
 /// # }
 /// ```
 pub fn now_nanos() -> Result<u128> {
-    // Initialize clock on first use
-    let clock = CLOCK.get_or_init(Clock::new);
-
-    // Get or initialize the clock base
-    let clock_base = CLOCK_BASE.get_or_init(|| {
-        let wall_nanos = get_wall_clock_nanos().unwrap_or(0);
-        let quanta_nanos = clock.raw();
-        ClockBase {
-            base_wall_nanos: wall_nanos,
-            base_quanta_nanos: quanta_nanos,
-        }
-    });
-
-    // Calculate elapsed time since base using quanta's high-resolution clock
-    let current_quanta_nanos = clock.raw();
-    let elapsed_nanos = current_quanta_nanos - clock_base.base_quanta_nanos;
+    let (clock, clock_base) = clock();
+    Ok(raw_to_nanos(clock, clock_base, clock.raw()))
+}
 
-    // Add elapsed time to base wall-clock time
-    Ok(clock_base.base_wall_nanos + u128::from(elapsed_nanos))
+/// Returns the current time, reusing a reading up to `resolution` old.
+///
+/// A process-wide cache holds the last reading in a single atomic. Calls
+/// within `resolution` of it return the cached time instead of converting a
+/// fresh one, so bursts of IDs share a timestamp and a [`Generator`]
+/// orders them by incrementing. Readings never go backward across threads.
+///
+/// Only the clock's raw counter is read on a cache hit, which costs less
+/// than a full reading where that counter is the TSC. A `resolution` of
+/// zero always takes a fresh reading.
+///
+/// [`Generator`]: crate::Generator
+///
+/// # Errors
+///
+/// Returns an error if the system time is before Unix epoch.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+/// use nulid::time::now_nanos_cached;
+///
+/// # fn main() -> nulid::Result<()> {
+/// let t1 = now_nanos_cached(Duration::from_micros(1))?;
+/// let t2 = now_nanos_cached(Duration::from_micros(1))?;
+/// assert!(t2 >= t1);
+/// # Ok(())
+/// # }
+/// ```
+#[allow(clippy::cast_possible_truncation)] // resolutions over 584 years saturate
+pub fn now_nanos_cached(resolution: Duration) -> Result<u128> {
+    let (clock, clock_base) = clock();
+    let now = clock.raw();
+    let cached = CACHED_RAW.load(Ordering::Acquire);
+    let window = resolution.as_nanos().min(u128::from(u64::MAX)) as u64;
+
+    let raw = if cached != 0 && clock.delta_as_nanos(cached, now) < window {
+        cached
+    } else {
+        // Keep the latest reading if another thread refreshed concurrently
+        CACHED_RAW.fetch_max(now, Ordering::AcqRel).max(now)
+    };
+    Ok(raw_to_nanos(clock, clock_base, raw))
 }
 
 /// Gets the current wall-clock time in nanoseconds since Unix epoch.
 /// This is used for initialization only; subsequent calls use quanta's high-resolution timer.
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32", target_os = "unknown")))]
 fn get_wall_clock_nanos() -> Result<u128> {
     let duration = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -86,6 +153,22 @@ fn get_wall_clock_nanos() -> Result<u128> {
     Ok(u128::from(duration.as_secs()) * 1_000_000_000 + u128::from(duration.subsec_nanos()))
 }
 
+/// Gets the current wall-clock time in nanoseconds since Unix epoch.
+///
+/// `SystemTime::now()` panics on `wasm32-unknown-unknown`, so the browser's
+/// `Date.now()` (millisecond precision) is used as the base instead; quanta
+/// adds `performance.now()` elapsed time on top of it.
+#[cfg(all(feature = "wasm", target_arch = "wasm32", target_os = "unknown"))]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn get_wall_clock_nanos() -> Result<u128> {
+    let millis = js_sys::Date::now();
+    if millis < 0.0 {
+        return Err(Error::SystemTimeError);
+    }
+
+    Ok(millis as u128 * 1_000_000)
+}
+
 /// Converts nanoseconds since Unix epoch to `SystemTime`.
 ///
 /// # Examples
@@ -140,6 +223,40 @@ mod tests {
         assert!(nanos < MAX_TIMESTAMP_NANOS);
     }
 
+    #[test]
+    fn test_now_nanos_tracks_wall_clock() {
+        let start = now_nanos().unwrap();
+        let wall_start = std::time::Instant::now();
+        std::thread::sleep(Duration::from_millis(50));
+        let elapsed = now_nanos().unwrap() - start;
+        let wall_elapsed = wall_start.elapsed().as_nanos();
+
+        // Allow for scheduling, but not for an unscaled counter
+        assert!(elapsed >= 45_000_000, "{elapsed}");
+        assert!(
+            elapsed <= wall_elapsed + 5_000_000,
+            "{elapsed} > {wall_elapsed}"
+        );
+    }
+
+    #[test]
+    fn test_now_nanos_cached() {
+        let window = Duration::from_secs(3600);
+        let t1 = now_nanos_cached(window).unwrap();
+        let t2 = now_nanos_cached(window).unwrap();
+        assert!(t2 >= t1);
+        assert!(t2 <= now_nanos().unwrap());
+
+        // Without a window, every call is a fresh, non-decreasing reading
+        let mut prev = now_nanos_cached(Duration::ZERO).unwrap();
+        assert!(prev >= t2);
+        for _ in 0..100 {
+            let nanos = now_nanos_cached(Duration::ZERO).unwrap();
+            assert!(nanos >= prev);
+            prev = nanos;
+        }
+    }
+
     #[test]
     fn test_from_nanos() {
         let nanos = 1_234_567_890_123_456_789u128;
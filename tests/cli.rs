@@ -0,0 +1,33 @@
+//! End-to-end tests that run the `nulid` binary.
+
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+
+/// `nulid gen -n 100000 | head -1` must not panic when `head` exits.
+#[test]
+fn test_generate_exits_cleanly_when_stdout_closes() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_nulid"))
+        .args(["generate", "--count", "100000"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("spawning nulid");
+
+    let mut first = String::new();
+    {
+        let stdout = child.stdout.take().expect("piped stdout");
+        BufReader::new(stdout)
+            .read_line(&mut first)
+            .expect("reading the first ID");
+    }
+    let output = child.wait_with_output().expect("waiting for nulid");
+
+    assert_eq!(first.trim_end().len(), 26);
+    assert!(
+        output.status.success(),
+        "exit status {}, stderr: {}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(output.stderr.is_empty());
+}
@@ -0,0 +1,23 @@
+//! Tests for importing the `nulid!` and `nulid_str!` macros downstream.
+
+// Importing `nulid` also brings in the `nulid::nulid` module, so this line
+// only resolves because that module re-exports `nulid_str`.
+use nulid::{Nulid, nulid, nulid_str};
+
+const ADMIN: &str = nulid_str!("01jcqz8abcdefghjkmnpqrstvw");
+
+#[test]
+fn test_macros_imported_together() {
+    assert_eq!(ADMIN, "01JCQZ8ABCDEFGHJKMNPQRSTVW");
+    let id: Nulid = nulid!();
+    assert_eq!(ADMIN.parse::<Nulid>().unwrap().to_string(), ADMIN);
+    assert!(id > Nulid::ZERO);
+}
+
+#[test]
+fn test_qualified_nulid_str_after_importing_nulid() {
+    // `nulid` here is the imported module, not the crate.
+    assert_eq!(nulid::nulid_str!("01JCQZ8ABCDEFGHJKMNPQRSTVW"), ADMIN);
+    let id: nulid::Nulid = nulid!(at: "2024-01-01T00:00:00Z");
+    assert_eq!(id.seconds(), 1_704_067_200);
+}